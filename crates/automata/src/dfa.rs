@@ -147,6 +147,7 @@ pub fn construct_dfa_with_state_mapping<'a>(
 }
 
 #[cfg(test)]
+#[allow(clippy::char_lit_as_u8)]
 mod tests {
     use crate::nfa::NFA;
 
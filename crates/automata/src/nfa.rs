@@ -184,6 +184,7 @@ impl Default for NFA<'_> {
 }
 
 #[cfg(test)]
+#[allow(clippy::char_lit_as_u8)]
 mod tests {
     use super::*;
 
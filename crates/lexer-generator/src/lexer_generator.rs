@@ -272,11 +272,23 @@ pub fn generate() {
     // Extract keyword list
     let mut keywords = Vec::new();
     for line in include_str!("../resources/kwlist.h").lines() {
-        if line.starts_with("PG_KEYWORD") {
-            let mut it = line.split(&['(', ',']).skip(1);
+        if let Some(args) = line.strip_prefix("PG_KEYWORD(") {
+            // The line may end with a comment, e.g. `PG_KEYWORD(...)		/* British spelling */`
+            let (args, _) = args.split_once(')').unwrap();
+            let mut it = args.split(',');
             let name = it.next().unwrap().trim();
             let value = it.next().unwrap().trim();
-            keywords.push(format!(r#"({name}, "{value}"),"#));
+            let category = match it.next().unwrap().trim() {
+                "UNRESERVED_KEYWORD" => "Unreserved",
+                "COL_NAME_KEYWORD" => "ColName",
+                "TYPE_FUNC_NAME_KEYWORD" => "TypeFuncName",
+                "RESERVED_KEYWORD" => "Reserved",
+                category => panic!("unknown keyword category: {category}"),
+            };
+            let bare_label = it.next().unwrap().trim() == "BARE_LABEL";
+            keywords.push(format!(
+                r#"({name}, "{value}", KeywordCategory::{category}, {bare_label}),"#
+            ));
         }
     }
 
@@ -286,17 +298,15 @@ pub fn generate() {
         .replace("{pattern_actions_by_index}", &pattern_actions_by_index)
         .replace("{rule_defs}", &rule_defs)
         .replace("{states}", &states)
-        .replace("{keyword_map}", &keywords.join("\n"))
+        .replace("{keyword_list}", &keywords.join("\n"))
         .replace("{{dfa_table}}", &dfa_table_def)
         .replace("{{state_id_to_dfa_table}}", &state_id_to_dfa_table_defs);
 
-    let paths = [
-        "./crates/postgresql-cst-parser/src/lexer/generated.rs",
-        "./crates/parser-generator/src/parser_generator/lexer/generated.rs",
-    ];
-
-    for path in paths {
-        std::fs::write(path, &res).unwrap();
-        Command::new("rustfmt").arg(path).output().unwrap();
-    }
+    let path = "./crates/postgresql-cst-parser/src/lexer/generated.rs";
+    std::fs::write(path, &res).unwrap();
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2021", path])
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to format {path}");
 }
@@ -5,8 +5,10 @@
 
 use std::collections::HashMap;
 
+use crate::keywords::KeywordCategory;
+
 use super::{
-    Lexer, NAMEDATALEN, ParserError, TokenKind, Yylval,
+    BackslashQuote, Lexer, NAMEDATALEN, ParserError, ScanReport, TokenKind, Yylval,
     lexer_ported::{
         get_char_by_byte_pos, is_highbit_set, is_utf16_surrogate_first, is_utf16_surrogate_second,
         surrogate_pair_to_codepoint,
@@ -17,17 +19,26 @@ macro_rules! ereport {
     ($lexer:expr, ERROR, (errcode($err_code:expr), errmsg($err_msg:expr), errdetail($err_detail:expr), $err_position:expr)) => {
         return Err(ParserError::new_report($err_msg, $err_detail, $err_position));
     };
-    ($lexer:expr, ERROR, (errcode($err_code:expr), errmsg($err_msg:expr), errhint($err_detail:expr), $err_position:expr)) => {
-        return Err(ParserError::new_report($err_msg, $err_detail, $err_position));
+    ($lexer:expr, ERROR, (errcode($err_code:expr), errmsg($err_msg:expr), errhint($err_hint:expr), $err_position:expr)) => {
+        return Err(ParserError::ScanReport(ScanReport::new_hint($err_msg, $err_hint, $err_position)));
     };
     ($lexer:expr, WARNING, (errcode($err_code:expr), errmsg($err_msg:expr), errdetail($err_detail:expr), $err_position:expr)) => {
-        $lexer.add_warning(ScanReport::new($err_msg, $err_detail, $err_position));
+        $lexer.add_warning(
+            ScanReport::new($err_msg, $err_detail, $err_position)
+                .with_level(ReportLevel::Warning),
+        );
+    };
+    ($lexer:expr, WARNING, (errcode($err_code:expr), errmsg($err_msg:expr), errhint($err_hint:expr), $err_position:expr)) => {
+        $lexer.add_warning(
+            ScanReport::new_hint($err_msg, $err_hint, $err_position)
+                .with_level(ReportLevel::Warning),
+        );
     };
 }
 
 macro_rules! yyerror {
-    ($msg:expr) => {
-        return Err(ParserError::new_error($msg))
+    ($lexer:expr, $msg:expr) => {
+        return Err($lexer.yyerror($msg))
     };
 }
 
@@ -60,8 +71,6 @@ pub enum RuleKind {{rule_kinds}}
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum State {{states}}
 
-const STANDARD_CONFORMING_STRINGS: bool = true;
-
 impl Lexer {
     #[cfg(not(feature = "regex-match"))]
     pub fn parse_token(&mut self) -> Result<Option<TokenKind>, ParserError> {
@@ -98,9 +107,14 @@ pub fn get_rules() -> Vec<crate::lexer::Rule> {
     vec![{rule_defs}]
 }
 
+/// Keywords of kwlist.h: the name, the token, the category and whether it can be a bare column label
+pub static KEYWORD_LIST: &[(&str, &str, KeywordCategory, bool)] = &[
+{keyword_list}
+];
+
 pub fn get_keyword_map() -> HashMap<&'static str, &'static str> {
     let mut map = HashMap::new();
-    for (kw, tok) in [{keyword_map}] {
+    for &(kw, tok, _, _) in KEYWORD_LIST {
         map.insert(kw, tok);
     }
     map
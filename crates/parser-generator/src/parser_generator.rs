@@ -6,7 +6,10 @@ mod id_mapper;
 mod lalr;
 mod lexer;
 
-use std::{collections::BTreeMap, process::Command};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    process::Command,
+};
 
 use bison::Bison;
 
@@ -384,11 +387,14 @@ fn generate_syntax_kinds_source_code(
     let source = format!(
         r#"use cstree::Syntax;
     
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Syntax)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Syntax)]
     #[repr(u32)]
     pub enum SyntaxKind {{
         {}
         Whitespace,
+        PSQL_META_COMMAND,
+        PSQL_VARIABLE,
+        COPY_DATA,
         Root,
     }}"#,
         kinds.join("\n\t")
@@ -399,6 +405,520 @@ fn generate_syntax_kinds_source_code(
     let _ = Command::new("rustfmt").arg(path).output();
 }
 
+/// An entry of `resources/fields.txt`
+struct FieldEntry {
+    parent: String,
+    child: String,
+    position: Option<usize>,
+    field: String,
+}
+
+/// Loads the field entries from `resources/fields.txt`
+///
+/// Each entry is checked against the grammar:
+/// an entry with a position must match a right-hand side of the parent rule at that position,
+/// and an entry without a position must name a child that can be derived from the parent rule.
+fn load_fields(
+    bison: &Bison,
+    terminal_symbols: &[Component],
+    non_terminal_symbols: &[Component],
+) -> Vec<FieldEntry> {
+    let symbols = terminal_symbols
+        .iter()
+        .chain(non_terminal_symbols)
+        .map(|c| (c.to_rule_identifier(), c))
+        .collect::<BTreeMap<_, _>>();
+
+    let reachable = |parent: &str, child: &Component| -> bool {
+        let mut visited = HashSet::new();
+        let mut stack = vec![parent.to_string()];
+        while let Some(name) = stack.pop() {
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+            for rule in bison.rules.iter().filter(|rule| rule.name == name) {
+                for component in &rule.components {
+                    if component == child {
+                        return true;
+                    }
+                    if let Component::NonTerminal(s) = component {
+                        stack.push(s.clone());
+                    }
+                }
+            }
+        }
+        false
+    };
+
+    let mut entries = Vec::new();
+    for (line_no, line) in include_str!("../resources/fields.txt").lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error =
+            |message: &str| -> ! { panic!("fields.txt:{}: {message}: {line}", line_no + 1) };
+
+        let [parent, child, field] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+            error("expected `<parent> <child>[@<position>] <field>`");
+        };
+
+        let (child, position) = match child.split_once('@') {
+            Some((child, position)) => match position.parse::<usize>() {
+                Ok(position) => (child, Some(position)),
+                Err(_) => error("invalid position"),
+            },
+            None => (child, None),
+        };
+
+        if !matches!(symbols.get(parent), Some(Component::NonTerminal(_))) {
+            error("unknown rule");
+        }
+        let Some(&child_component) = symbols.get(child) else {
+            error("unknown symbol");
+        };
+
+        let valid = match position {
+            Some(position) => bison.rules.iter().any(|rule| {
+                rule.name == parent && rule.components.get(position) == Some(child_component)
+            }),
+            None => reachable(parent, child_component),
+        };
+        if !valid {
+            error("the child does not appear in the parent rule");
+        }
+
+        entries.push(FieldEntry {
+            parent: parent.to_string(),
+            child: child.to_string(),
+            position,
+            field: field.to_string(),
+        });
+    }
+
+    entries
+}
+
+/// Generates the field table
+fn generate_fields_source_code(fields: &[FieldEntry]) {
+    let entries = fields
+        .iter()
+        .map(|entry| {
+            format!(
+                r#"(SyntaxKind::{}, SyntaxKind::{}, {}, "{}"),"#,
+                entry.parent,
+                entry.child,
+                match entry.position {
+                    Some(position) => format!("Some({position})"),
+                    None => "None".to_string(),
+                },
+                entry.field
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let source = format!(
+        r#"use crate::syntax_kind::SyntaxKind;
+
+    /// (parent, child, position of the child, field name)
+    pub(crate) const FIELDS: &[(SyntaxKind, SyntaxKind, Option<usize>, &str)] = &[
+        {}
+    ];"#,
+        entries.join("\n\t")
+    );
+
+    let path = "./crates/postgresql-cst-parser/src/fields/generated.rs";
+    std::fs::write(path, source).unwrap();
+    let _ = Command::new("rustfmt").arg(path).output();
+}
+
+/// Rules of the tree-sitter-like tree, loaded from `resources/tree_sitter.txt`
+#[derive(Default)]
+struct TreeSitterRules {
+    /// Tokens that are named nodes
+    named_tokens: Vec<String>,
+    /// Symbols whose directly nested nodes are flattened
+    flatten: Vec<String>,
+    /// Symbols whose nodes are removed
+    remove: Vec<String>,
+}
+
+impl TreeSitterRules {
+    /// Whether `child` in a rule of `parent` is hidden, so that its children are attached to the parent
+    fn is_transparent(&self, parent: &str, child: &str) -> bool {
+        self.remove.iter().any(|kind| kind == child)
+            || (child == parent && self.flatten.iter().any(|kind| kind == child))
+    }
+}
+
+/// Loads the rules of the tree-sitter-like tree from `resources/tree_sitter.txt`
+fn load_tree_sitter_rules(
+    terminal_symbols: &[Component],
+    non_terminal_symbols: &[Component],
+) -> TreeSitterRules {
+    let terminals = terminal_symbols
+        .iter()
+        .map(|c| c.to_rule_identifier())
+        .collect::<HashSet<_>>();
+    let non_terminals = non_terminal_symbols
+        .iter()
+        .map(|c| c.to_rule_identifier())
+        .collect::<HashSet<_>>();
+
+    let mut rules = TreeSitterRules::default();
+    for (line_no, line) in include_str!("../resources/tree_sitter.txt")
+        .lines()
+        .enumerate()
+    {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error =
+            |message: &str| -> ! { panic!("tree_sitter.txt:{}: {message}: {line}", line_no + 1) };
+
+        let [rule, symbol] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+            error("expected `<rule> <symbol>`");
+        };
+
+        let (symbols, list) = match rule {
+            "named" => (&terminals, &mut rules.named_tokens),
+            "flatten" => (&non_terminals, &mut rules.flatten),
+            "remove" => (&non_terminals, &mut rules.remove),
+            _ => error("unknown rule"),
+        };
+        if !symbols.contains(symbol) {
+            error("unknown symbol");
+        }
+        list.push(symbol.to_string());
+    }
+
+    rules
+}
+
+/// Generates the rules of the tree-sitter-like conversion
+fn generate_tree_sitter_source_code(rules: &TreeSitterRules) {
+    let kinds = |symbols: &[String]| {
+        symbols
+            .iter()
+            .map(|symbol| format!("SyntaxKind::{symbol},"))
+            .collect::<Vec<_>>()
+            .join("\n\t")
+    };
+
+    let source = format!(
+        r#"//! Rules of the tree-sitter-like tree, generated by the parser generator from `resources/tree_sitter.txt`
+
+use crate::syntax_kind::SyntaxKind;
+
+/// Tokens that are named nodes
+pub(crate) const NAMED_TOKENS: &[SyntaxKind] = &[
+    {}
+];
+
+/// Kinds whose directly nested nodes are flattened by default
+pub(crate) const DEFAULT_FLATTEN_KINDS: &[SyntaxKind] = &[
+    {}
+];
+
+/// Kinds whose nodes are removed by default
+pub(crate) const DEFAULT_REMOVE_KINDS: &[SyntaxKind] = &[
+    {}
+];
+"#,
+        kinds(&rules.named_tokens),
+        kinds(&rules.flatten),
+        kinds(&rules.remove),
+    );
+
+    let path = "./crates/postgresql-cst-parser/src/tree_sitter/generated.rs";
+    std::fs::write(path, source).unwrap();
+    let _ = Command::new("rustfmt").arg(path).output();
+}
+
+/// Children of a node in the tree-sitter-like tree, derived from the rules of the grammar
+#[derive(Debug, Clone)]
+struct NodeChildren {
+    /// Field name (or `None` for the other named children) -> (types, minimum count, maximum count up to 2)
+    children: BTreeMap<Option<String>, (BTreeSet<String>, usize, usize)>,
+    /// Transparent symbols referred to recursively, whose children repeat
+    recursive: BTreeSet<String>,
+    /// Number of children, if it is the same in every derivation
+    len: Option<usize>,
+}
+
+impl NodeChildren {
+    fn empty() -> Self {
+        Self {
+            children: BTreeMap::new(),
+            recursive: BTreeSet::new(),
+            len: Some(0),
+        }
+    }
+
+    /// Children in sequence: `self` followed by `other`
+    fn then(mut self, other: NodeChildren) -> Self {
+        for (key, (types, min, max)) in other.children {
+            let entry = self
+                .children
+                .entry(key)
+                .or_insert_with(|| (BTreeSet::new(), 0, 0));
+            entry.0.extend(types);
+            entry.1 += min;
+            entry.2 = (entry.2 + max).min(2);
+        }
+        self.recursive.extend(other.recursive);
+        self.len = self.len.zip(other.len).map(|(a, b)| a + b);
+        self
+    }
+
+    /// Children of either `self` or `other`
+    fn or(mut self, other: NodeChildren) -> Self {
+        let keys = self
+            .children
+            .keys()
+            .chain(other.children.keys())
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        let mut other_children = other.children;
+        for key in keys {
+            let (types, min, max) = other_children
+                .remove(&key)
+                .unwrap_or_else(|| (BTreeSet::new(), 0, 0));
+            let entry = self
+                .children
+                .entry(key)
+                .or_insert_with(|| (BTreeSet::new(), 0, 0));
+            entry.0.extend(types);
+            entry.1 = entry.1.min(min);
+            entry.2 = entry.2.max(max);
+        }
+        self.recursive.extend(other.recursive);
+        self.len = self.len.filter(|&len| other.len == Some(len));
+        self
+    }
+
+    /// Marks the children as repeated, for a symbol referred to recursively
+    fn repeat(&mut self) {
+        for (_, _, max) in self.children.values_mut() {
+            *max = 2;
+        }
+        self.len = None;
+    }
+}
+
+/// Computes the children of nodes in the tree-sitter-like tree
+struct NodeTypes<'a> {
+    bison: &'a Bison,
+    fields: &'a [FieldEntry],
+    rules: &'a TreeSitterRules,
+    named_symbols: HashSet<String>,
+}
+
+impl NodeTypes<'_> {
+    /// Children of the `visible` node from the rules of `symbol`, which is `visible` or a transparent node in it
+    ///
+    /// `start` is the position of the first child among the children of `visible`, if it is known.
+    fn children(
+        &self,
+        visible: &str,
+        symbol: &str,
+        start: Option<usize>,
+        stack: &mut Vec<String>,
+    ) -> NodeChildren {
+        let mut alternatives = self
+            .bison
+            .rules
+            .iter()
+            .filter(|rule| rule.name == symbol)
+            .map(|rule| {
+                rule.components
+                    .iter()
+                    .fold(NodeChildren::empty(), |children, component| {
+                        let position = start.zip(children.len).map(|(start, len)| start + len);
+                        let child = self.child(
+                            visible,
+                            symbol,
+                            &component.to_rule_identifier(),
+                            position,
+                            stack,
+                        );
+                        children.then(child)
+                    })
+            });
+
+        let first = alternatives.next().unwrap_or_else(NodeChildren::empty);
+        alternatives.fold(first, NodeChildren::or)
+    }
+
+    /// Children of the `visible` node from a `child` in a rule of `parent`
+    fn child(
+        &self,
+        visible: &str,
+        parent: &str,
+        child: &str,
+        position: Option<usize>,
+        stack: &mut Vec<String>,
+    ) -> NodeChildren {
+        if !self.rules.is_transparent(parent, child) {
+            let field = self
+                .fields
+                .iter()
+                .find(|entry| {
+                    entry.parent == visible
+                        && entry.child == child
+                        && entry.position.is_none_or(|p| Some(p) == position)
+                })
+                .map(|entry| entry.field.clone());
+
+            let mut children = NodeChildren::empty();
+            if field.is_some() || self.named_symbols.contains(child) {
+                children
+                    .children
+                    .insert(field, (BTreeSet::from([child.to_string()]), 1, 1));
+            }
+            children.len = Some(1);
+            return children;
+        }
+
+        if stack.iter().any(|symbol| symbol == child) {
+            let mut children = NodeChildren::empty();
+            children.recursive.insert(child.to_string());
+            children.len = None;
+            return children;
+        }
+
+        stack.push(child.to_string());
+        let mut children = self.children(visible, child, position, stack);
+        stack.pop();
+
+        if children.recursive.remove(child) {
+            children.repeat();
+        }
+        children
+    }
+}
+
+/// Generates `node-types.json` in the format of tree-sitter
+///
+/// The children and fields of each node are computed from the right-hand sides of its rules,
+/// with the removed and flattened nodes of `resources/tree_sitter.txt` replaced by their children,
+/// so they describe the tree-sitter-like tree with the default `ConversionOptions`.
+fn generate_node_types_json(
+    bison: &Bison,
+    terminal_symbols: &[Component],
+    non_terminal_symbols: &[Component],
+    comments: &[Component],
+    fields: &[FieldEntry],
+    rules: &TreeSitterRules,
+) {
+    let named_symbols = non_terminal_symbols
+        .iter()
+        .chain(comments)
+        .map(|c| c.to_rule_identifier())
+        .chain(rules.named_tokens.iter().cloned())
+        .chain(["Root".to_string()])
+        .collect::<HashSet<_>>();
+    let is_named = |name: &str| named_symbols.contains(name);
+
+    let types_json = |types: &BTreeSet<String>| {
+        types
+            .iter()
+            .map(|t| format!(r#"{{"type":"{t}","named":{}}}"#, is_named(t)))
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    let node_types_generator = NodeTypes {
+        bison,
+        fields,
+        rules,
+        named_symbols: named_symbols.clone(),
+    };
+
+    let node_entry = |name: &str, children: NodeChildren| {
+        let mut entry = format!(r#"{{"type":"{name}","named":true"#);
+
+        let info_json = |(types, min, max): &(BTreeSet<String>, usize, usize)| {
+            format!(
+                r#"{{"multiple":{},"required":{},"types":[{}]}}"#,
+                *max > 1,
+                *min > 0,
+                types_json(types)
+            )
+        };
+
+        let fields_json = children
+            .children
+            .iter()
+            .filter_map(|(field, info)| {
+                Some(format!(r#""{}":{}"#, field.as_ref()?, info_json(info)))
+            })
+            .collect::<Vec<_>>();
+        if !fields_json.is_empty() {
+            entry.push_str(&format!(r#","fields":{{{}}}"#, fields_json.join(",")));
+        }
+
+        if let Some(info) = children.children.get(&None) {
+            entry.push_str(&format!(r#","children":{}"#, info_json(info)));
+        }
+
+        entry.push('}');
+        entry
+    };
+
+    // Same order as `SyntaxKind`, without the removed nodes that do not appear in the tree
+    let mut node_types = Vec::new();
+
+    for symbol in terminal_symbols {
+        let name = symbol.to_rule_identifier();
+        node_types.push(format!(
+            r#"{{"type":"{name}","named":{}}}"#,
+            is_named(&name)
+        ));
+    }
+
+    for symbol in non_terminal_symbols {
+        let name = symbol.to_rule_identifier();
+        if rules.remove.contains(&name) {
+            continue;
+        }
+
+        let mut stack = vec![name.clone()];
+        let mut children = node_types_generator.children(&name, &name, Some(0), &mut stack);
+        if children.recursive.remove(&name) {
+            children.repeat();
+        }
+        node_types.push(node_entry(&name, children));
+    }
+
+    for symbol in comments {
+        let name = symbol.to_rule_identifier();
+        node_types.push(format!(
+            r#"{{"type":"{name}","named":{}}}"#,
+            is_named(&name)
+        ));
+    }
+    node_types.push(r#"{"type":"Whitespace","named":false}"#.to_string());
+    // Tokens of psql scripts, which are not in the grammar
+    for name in ["PSQL_META_COMMAND", "PSQL_VARIABLE", "COPY_DATA"] {
+        node_types.push(format!(r#"{{"type":"{name}","named":true}}"#));
+    }
+
+    // The root has the children of `parse_toplevel`, which is removed
+    let mut stack = vec!["Root".to_string()];
+    let children =
+        node_types_generator.child("Root", "Root", "parse_toplevel", Some(0), &mut stack);
+    node_types.push(node_entry("Root", children));
+
+    let source = format!("[\n{}\n]\n", node_types.join(",\n"));
+
+    let path = "./crates/postgresql-cst-parser/src/tree_sitter/node-types.json";
+    std::fs::write(path, source).unwrap();
+}
+
 fn write_file(bison: &Bison, lalr: &Lalr) {
     let terminal_symbols: Vec<_> = lalr
         .id_mapper
@@ -431,29 +951,19 @@ fn write_file(bison: &Bison, lalr: &Lalr) {
 
     generate_syntax_kinds_source_code(&terminal_symbols, &non_terminal_symbols, &comments);
 
-    std::fs::copy(
-        "./crates/parser-generator/src/parser_generator/lexer/lexer_ported.rs",
-        "./crates/postgresql-cst-parser/src/lexer/lexer_ported.rs",
-    )
-    .unwrap();
-
-    std::fs::copy(
-        "./crates/parser-generator/src/parser_generator/lexer/util.rs",
-        "./crates/postgresql-cst-parser/src/lexer/util.rs",
-    )
-    .unwrap();
-
-    std::fs::copy(
-        "./crates/parser-generator/src/parser_generator/lexer/parser_error.rs",
-        "./crates/postgresql-cst-parser/src/lexer/parser_error.rs",
-    )
-    .unwrap();
-
-    std::fs::copy(
-        "./crates/parser-generator/src/parser_generator/lexer.rs",
-        "./crates/postgresql-cst-parser/src/lexer.rs",
-    )
-    .unwrap();
+    let fields = load_fields(bison, &terminal_symbols, &non_terminal_symbols);
+    generate_fields_source_code(&fields);
+
+    let tree_sitter_rules = load_tree_sitter_rules(&terminal_symbols, &non_terminal_symbols);
+    generate_tree_sitter_source_code(&tree_sitter_rules);
+    generate_node_types_json(
+        bison,
+        &terminal_symbols,
+        &non_terminal_symbols,
+        &comments,
+        &fields,
+        &tree_sitter_rules,
+    );
 }
 
 pub fn generate() {
@@ -1,61 +1,4 @@
-#[macro_use]
-mod generated;
-pub mod lexer_ported;
-pub mod parser_error;
-mod util;
-
-use std::collections::HashMap;
-
-use parser_error::{ParserError, ScanReport};
-
-use self::generated::State;
-
-pub const NAMEDATALEN: usize = 64;
-
-#[allow(dead_code)]
-#[derive(Debug, Clone)]
-pub enum Yylval {
-    Str(String),
-    I(i32),
-    Keyword(String),
-    Uninitialized,
-}
-
-pub struct Lexer {
-    pub input: String,
-    pub index_bytes: usize,
-    pub state: State,
-    pub yyleng: usize,
-
-    pub literal: String,
-
-    // user state
-    pub xcdepth: usize,
-    pub yyllocend_bytes: usize,
-    pub state_before_str_stop: State,
-    pub yylloc_stack: Vec<usize>,
-    pub dolqstart: String,
-    pub warn_on_first_escape: bool,
-    pub saw_non_ascii: bool,
-    pub utf16_first_part: u32,
-
-    // states
-    pub yylval: Yylval,
-    pub yylloc_bytes: usize,
-
-    #[cfg(feature = "regex-match")]
-    pub rules: Vec<Rule>,
-    pub keyword_map: HashMap<&'static str, &'static str>,
-    pub reports: Vec<ScanReport>,
-}
-
-#[cfg(feature = "regex-match")]
-pub struct Rule {
-    pub state: State,
-    pub pattern: regex::bytes::Regex,
-    pub eof: bool,
-    pub kind: self::generated::RuleKind,
-}
+// The lexer lives in postgresql-cst-parser; the generator only needs the token kinds of the grammar.
 
 #[allow(clippy::all)]
 // #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -146,49 +89,3 @@ where
         }
     }
 }
-
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Debug, Clone)]
-pub struct Token {
-    pub start_byte_pos: usize,
-    pub end_byte_pos: usize,
-    pub kind: TokenKind,
-    pub value: String,
-}
-
-pub fn lex(input: &str) -> Result<Vec<Token>, ParserError> {
-    let mut lexer = Lexer::new(input);
-
-    let mut tokens = vec![];
-    while let Some(kind) = lexer.parse_token()? {
-        // dbg!(&kind);
-        if kind == TokenKind::EOF {
-            break;
-        }
-
-        let start_byte_pos = lexer.yylloc_bytes;
-        let end_byte_pos = if matches!(
-            kind,
-            TokenKind::SCONST
-                | TokenKind::BCONST
-                | TokenKind::XCONST
-                | TokenKind::IDENT
-                | TokenKind::C_COMMENT
-        ) {
-            lexer.yyllocend_bytes
-        } else {
-            lexer.yylloc_bytes + lexer.yyleng
-        };
-
-        tokens.push(Token {
-            start_byte_pos,
-            end_byte_pos,
-            kind,
-            value: input[start_byte_pos..end_byte_pos].to_string(),
-        });
-        lexer.advance();
-    }
-
-    // dbg!(&tokens);
-    Ok(tokens)
-}
@@ -1,7901 +0,0 @@
-#![allow(clippy::all)]
-#![allow(unreachable_code)]
-
-/// This file contains ported sources from PostgreSQL
-use std::collections::HashMap;
-
-use super::{
-    lexer_ported::{
-        get_char_by_byte_pos, is_highbit_set, is_utf16_surrogate_first, is_utf16_surrogate_second,
-        surrogate_pair_to_codepoint,
-    },
-    Lexer, ParserError, TokenKind, Yylval, NAMEDATALEN,
-};
-
-macro_rules! ereport {
-    ($lexer:expr, ERROR, (errcode($err_code:expr), errmsg($err_msg:expr), errdetail($err_detail:expr), $err_position:expr)) => {
-        return Err(ParserError::new_report(
-            $err_msg,
-            $err_detail,
-            $err_position,
-        ));
-    };
-    ($lexer:expr, ERROR, (errcode($err_code:expr), errmsg($err_msg:expr), errhint($err_detail:expr), $err_position:expr)) => {
-        return Err(ParserError::new_report(
-            $err_msg,
-            $err_detail,
-            $err_position,
-        ));
-    };
-    ($lexer:expr, WARNING, (errcode($err_code:expr), errmsg($err_msg:expr), errdetail($err_detail:expr), $err_position:expr)) => {
-        $lexer.add_warning(ScanReport::new($err_msg, $err_detail, $err_position));
-    };
-}
-
-macro_rules! yyerror {
-    ($msg:expr) => {
-        return Err(ParserError::new_error($msg))
-    };
-}
-
-macro_rules! yyterminate {
-    () => {
-        return Ok(None);
-    };
-}
-
-#[cfg(not(feature = "regex-match"))]
-pub mod dfa {
-    use super::State;
-
-    // DFA transition table (state=INITIAL)
-    pub const TRANSITION_TABLE_0: [[u8; 256]; 116] = [
-        [
-            1, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 3, 4, 5, 6, 7, 8, 6, 9, 10, 10, 8, 8, 10, 11, 12, 13, 14, 15, 15, 15, 15, 15,
-            15, 15, 15, 15, 16, 10, 17, 18, 19, 6, 6, 20, 21, 20, 20, 22, 20, 20, 20, 20, 20, 20,
-            20, 20, 23, 20, 20, 20, 20, 20, 20, 24, 20, 20, 25, 20, 20, 10, 2, 10, 8, 20, 6, 20,
-            21, 20, 20, 22, 20, 20, 20, 20, 20, 20, 20, 20, 23, 20, 20, 20, 20, 20, 20, 24, 20, 20,
-            25, 20, 20, 2, 6, 2, 6, 2, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
-            20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
-            20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
-            20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
-            20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
-            20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
-            20, 20, 20,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 115, 115, 115, 115, 115, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 115, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 34, 34, 255, 34, 255, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 114, 34, 34, 34, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 34, 34, 255, 34, 255, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 34, 34, 34, 34, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 110, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 111, 111, 111,
-            111, 111, 111, 111, 111, 111, 111, 255, 255, 255, 255, 255, 255, 255, 112, 112, 112,
-            112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-            112, 112, 112, 112, 112, 112, 255, 255, 255, 255, 112, 255, 112, 112, 112, 112, 112,
-            112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-            112, 112, 112, 112, 255, 255, 255, 255, 255, 112, 112, 112, 112, 112, 112, 112, 112,
-            112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-            112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-            112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-            112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-            112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-            112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-            112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-            112,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 34, 34, 255, 34, 255, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 34, 34, 34, 34, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 34, 34, 255, 107, 255, 34, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 34, 34, 34, 34, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 101, 255, 102, 102, 102,
-            102, 102, 102, 102, 102, 102, 102, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 99, 34, 255, 34, 255, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 34, 34, 34, 34, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 41, 255, 42, 42, 42, 42,
-            42, 42, 42, 42, 42, 42, 255, 255, 255, 255, 255, 255, 255, 43, 87, 43, 43, 44, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 88, 43, 43, 43, 43, 43, 43, 43, 43, 89, 43, 43, 255, 255,
-            255, 255, 45, 255, 43, 87, 43, 43, 44, 43, 43, 43, 43, 43, 43, 43, 43, 43, 88, 43, 43,
-            43, 43, 43, 43, 43, 43, 89, 43, 43, 255, 255, 255, 255, 255, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 41, 255, 42, 42, 42, 42,
-            42, 42, 42, 42, 42, 42, 255, 255, 255, 255, 255, 255, 255, 43, 43, 43, 43, 44, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 255, 255,
-            255, 255, 45, 255, 43, 43, 43, 43, 44, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 255, 255, 255, 255, 255, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 39, 255, 255, 40, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 34, 34, 255, 34, 255, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 37, 38, 34, 34, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 34, 34, 255, 34, 255, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 34, 36, 34, 34, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 34, 34, 255, 34, 255, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 35, 34, 34, 34, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 26, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 255, 255,
-            255, 255, 26, 255, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 26, 255, 255, 33, 255, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 255, 255, 255,
-            255, 26, 255, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 26, 255, 255, 32, 255, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 255, 255, 255,
-            255, 26, 255, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 26, 255, 255, 31, 255, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 255, 255, 255,
-            255, 26, 255, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 26, 255, 28, 255, 255, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 255, 255, 255,
-            255, 26, 255, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 26, 255, 255, 27, 255, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 255, 255, 255,
-            255, 26, 255, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 26, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 255, 255,
-            255, 255, 26, 255, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 255, 255, 255, 255, 255, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            29, 255, 255, 255, 255, 30, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 34, 34, 255, 34, 255, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 34, 34, 34, 34, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 34, 34, 255, 34, 255, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 34, 34, 34, 34, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 34, 34, 255, 34, 255, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 34, 34, 34, 34, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 34, 34, 255, 34, 255, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 34, 34, 34, 34, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 34, 34, 255, 34, 255, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 34, 34, 34, 34, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 68, 255, 69, 69, 69, 69,
-            69, 69, 69, 69, 69, 69, 255, 255, 255, 255, 255, 255, 255, 70, 70, 70, 70, 71, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 255, 255,
-            255, 255, 70, 255, 70, 70, 70, 70, 71, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 255, 255, 255, 255, 255, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 41, 255, 42, 42, 42, 42,
-            42, 42, 42, 42, 42, 42, 255, 255, 255, 255, 255, 255, 255, 43, 43, 43, 43, 44, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 255, 255,
-            255, 255, 45, 255, 43, 43, 43, 43, 44, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 255, 255, 255, 255, 255, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 46, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 51, 255, 51, 255, 255, 52, 52, 52, 52, 52,
-            52, 52, 52, 52, 52, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255,
-            255, 46, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 47, 47, 47, 47,
-            47, 47, 47, 47, 47, 47, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 46, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 46, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 41, 255, 47, 47, 47, 47, 47,
-            47, 47, 47, 47, 47, 255, 255, 255, 255, 255, 255, 255, 48, 48, 48, 48, 49, 48, 48, 48,
-            48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 255, 255, 255,
-            255, 50, 255, 48, 48, 48, 48, 49, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
-            48, 48, 48, 48, 48, 48, 48, 48, 255, 255, 255, 255, 255, 48, 48, 48, 48, 48, 48, 48,
-            48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
-            48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
-            48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
-            48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
-            48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
-            48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 46, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 51, 255, 51, 255, 255, 52, 52, 52, 52, 52,
-            52, 52, 52, 52, 52, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255,
-            255, 46, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 47, 47, 47, 47,
-            47, 47, 47, 47, 47, 47, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 46, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 60, 60, 60, 60,
-            60, 60, 60, 60, 60, 60, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 53, 53, 53, 53,
-            53, 53, 53, 53, 53, 53, 255, 255, 255, 255, 255, 255, 255, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 255, 255,
-            255, 255, 55, 255, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 255, 255, 255, 255, 255, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 53, 53, 53, 53,
-            53, 53, 53, 53, 53, 53, 255, 255, 255, 255, 255, 255, 255, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 255, 255,
-            255, 255, 55, 255, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 255, 255, 255, 255, 255, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-            54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 56, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 255, 255, 255, 255, 255, 255, 255, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 255, 255,
-            255, 255, 56, 255, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 255, 255, 255, 255, 255, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 56, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 57, 57, 57, 57,
-            57, 57, 57, 57, 57, 57, 255, 255, 255, 255, 255, 255, 255, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 255, 255,
-            255, 255, 56, 255, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 255, 255, 255, 255, 255, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 56, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 255, 255, 255, 255, 255, 255, 255, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 255, 255,
-            255, 255, 56, 255, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 255, 255, 255, 255, 255, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 56, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 57, 57, 57, 57,
-            57, 57, 57, 57, 57, 57, 255, 255, 255, 255, 255, 255, 255, 58, 58, 58, 58, 58, 58, 58,
-            58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 255, 255,
-            255, 255, 59, 255, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58,
-            58, 58, 58, 58, 58, 58, 58, 58, 58, 255, 255, 255, 255, 255, 58, 58, 58, 58, 58, 58,
-            58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58,
-            58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58,
-            58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58,
-            58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58,
-            58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58,
-            58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 56, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 255, 255, 255, 255, 255, 255, 255, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 255, 255,
-            255, 255, 56, 255, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 255, 255, 255, 255, 255, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 56, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 57, 57, 57, 57,
-            57, 57, 57, 57, 57, 57, 255, 255, 255, 255, 255, 255, 255, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 255, 255,
-            255, 255, 56, 255, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 255, 255, 255, 255, 255, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-            56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 61, 61, 61, 61,
-            61, 61, 61, 61, 61, 61, 255, 255, 255, 255, 255, 255, 255, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 255, 255,
-            255, 255, 63, 255, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 255, 255, 255, 255, 255, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 61, 61, 61, 61,
-            61, 61, 61, 61, 61, 61, 255, 255, 255, 255, 255, 255, 255, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 255, 255,
-            255, 255, 63, 255, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 255, 255, 255, 255, 255, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-            62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 64, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 255, 255, 255, 255, 255, 255, 255, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 255, 255,
-            255, 255, 64, 255, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 255, 255, 255, 255, 255, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 64, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 65, 65, 65, 65,
-            65, 65, 65, 65, 65, 65, 255, 255, 255, 255, 255, 255, 255, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 255, 255,
-            255, 255, 64, 255, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 255, 255, 255, 255, 255, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 64, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 255, 255, 255, 255, 255, 255, 255, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 255, 255,
-            255, 255, 64, 255, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 255, 255, 255, 255, 255, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 64, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 65, 65, 65, 65,
-            65, 65, 65, 65, 65, 65, 255, 255, 255, 255, 255, 255, 255, 66, 66, 66, 66, 66, 66, 66,
-            66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 255, 255,
-            255, 255, 67, 255, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66,
-            66, 66, 66, 66, 66, 66, 66, 66, 66, 255, 255, 255, 255, 255, 66, 66, 66, 66, 66, 66,
-            66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66,
-            66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66,
-            66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66,
-            66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66,
-            66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66,
-            66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 64, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 255, 255, 255, 255, 255, 255, 255, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 255, 255,
-            255, 255, 64, 255, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 255, 255, 255, 255, 255, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 64, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 65, 65, 65, 65,
-            65, 65, 65, 65, 65, 65, 255, 255, 255, 255, 255, 255, 255, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 255, 255,
-            255, 255, 64, 255, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 255, 255, 255, 255, 255, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-            64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 81, 81, 81, 81,
-            81, 81, 81, 81, 81, 81, 255, 255, 255, 255, 255, 255, 255, 70, 70, 70, 70, 71, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 255, 255,
-            255, 255, 82, 255, 70, 70, 70, 70, 71, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 255, 255, 255, 255, 255, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 72, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 255, 255, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255,
-            255, 255, 72, 255, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 72, 255, 255, 255, 255, 255, 255, 51, 255, 51, 255, 255, 73, 73, 73, 73, 73,
-            73, 73, 73, 73, 73, 255, 255, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255, 255,
-            255, 72, 255, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 72, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 255, 255, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255,
-            255, 255, 72, 255, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 72, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 74, 74, 74, 74,
-            74, 74, 74, 74, 74, 74, 255, 255, 255, 255, 255, 255, 255, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 255, 255,
-            255, 255, 76, 255, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 255, 255, 255, 255, 255, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 72, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 74, 74, 74, 74,
-            74, 74, 74, 74, 74, 74, 255, 255, 255, 255, 255, 255, 255, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 255, 255,
-            255, 255, 76, 255, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 255, 255, 255, 255, 255, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-            75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 77, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 255, 255, 255, 255, 255, 255, 255, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 255, 255,
-            255, 255, 77, 255, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 255, 255, 255, 255, 255, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 77, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 78, 78, 78, 78,
-            78, 78, 78, 78, 78, 78, 255, 255, 255, 255, 255, 255, 255, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 255, 255,
-            255, 255, 77, 255, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 255, 255, 255, 255, 255, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 77, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 255, 255, 255, 255, 255, 255, 255, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 255, 255,
-            255, 255, 77, 255, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 255, 255, 255, 255, 255, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 77, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 78, 78, 78, 78,
-            78, 78, 78, 78, 78, 78, 255, 255, 255, 255, 255, 255, 255, 79, 79, 79, 79, 79, 79, 79,
-            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 255, 255,
-            255, 255, 80, 255, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
-            79, 79, 79, 79, 79, 79, 79, 79, 79, 255, 255, 255, 255, 255, 79, 79, 79, 79, 79, 79,
-            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
-            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
-            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
-            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
-            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
-            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 77, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 255, 255, 255, 255, 255, 255, 255, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 255, 255,
-            255, 255, 77, 255, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 255, 255, 255, 255, 255, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 77, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 78, 78, 78, 78,
-            78, 78, 78, 78, 78, 78, 255, 255, 255, 255, 255, 255, 255, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 255, 255,
-            255, 255, 77, 255, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 255, 255, 255, 255, 255, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-            77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 81, 81, 81, 81,
-            81, 81, 81, 81, 81, 81, 255, 255, 255, 255, 255, 255, 255, 70, 70, 70, 70, 71, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 255, 255,
-            255, 255, 82, 255, 70, 70, 70, 70, 71, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 255, 255, 255, 255, 255, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 72, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 83, 83, 83, 83,
-            83, 83, 83, 83, 83, 83, 255, 255, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255,
-            255, 255, 72, 255, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 72, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 83, 83, 83, 83,
-            83, 83, 83, 83, 83, 83, 255, 255, 255, 255, 255, 255, 255, 84, 84, 84, 84, 85, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 255, 255,
-            255, 255, 86, 255, 84, 84, 84, 84, 85, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 255, 255, 255, 255, 255, 84, 84, 84, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 72, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 255, 255, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255,
-            255, 255, 72, 255, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 72, 255, 255, 255, 255, 255, 255, 51, 255, 51, 255, 255, 73, 73, 73, 73, 73,
-            73, 73, 73, 73, 73, 255, 255, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255, 255,
-            255, 72, 255, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 72, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 83, 83, 83, 83,
-            83, 83, 83, 83, 83, 83, 255, 255, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255,
-            255, 255, 72, 255, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255, 255, 255, 255, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 96, 96, 46, 46,
-            46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 97, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 93, 93, 93, 93,
-            93, 93, 93, 93, 46, 46, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 94, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 90, 90, 90, 90,
-            90, 90, 90, 90, 90, 90, 255, 255, 255, 255, 255, 255, 255, 90, 90, 90, 90, 90, 90, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 91, 255, 90, 90, 90, 90, 90, 90, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 90, 90, 90, 90,
-            90, 90, 90, 90, 90, 90, 255, 255, 255, 255, 255, 255, 255, 90, 90, 90, 90, 90, 90, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 92, 255, 90, 90, 90, 90, 90, 90, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 90, 90, 90, 90,
-            90, 90, 90, 90, 90, 90, 255, 255, 255, 255, 255, 255, 255, 90, 90, 90, 90, 90, 90, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 46, 255, 90, 90, 90, 90, 90, 90, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 90, 90, 90, 90,
-            90, 90, 90, 90, 90, 90, 255, 255, 255, 255, 255, 255, 255, 90, 90, 90, 90, 90, 90, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 46, 255, 90, 90, 90, 90, 90, 90, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 93, 93, 93, 93,
-            93, 93, 93, 93, 46, 46, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 95, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 93, 93, 93, 93,
-            93, 93, 93, 93, 46, 46, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 46, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 93, 93, 93, 93,
-            93, 93, 93, 93, 46, 46, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 46, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 96, 96, 46, 46,
-            46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 98, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 96, 96, 46, 46,
-            46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 46, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 96, 96, 46, 46,
-            46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255,
-            255, 255, 46, 255, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 255, 255, 255, 255, 255, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 100,
-            255, 100, 255, 100, 100, 255, 255, 255, 100, 100, 255, 100, 255, 100, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 100, 100, 100, 100, 100, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 100, 255, 100, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 100, 255, 100, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 100,
-            255, 100, 255, 100, 100, 255, 255, 255, 100, 100, 255, 100, 255, 100, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 100, 100, 100, 100, 100, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 100, 255, 100, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 100, 255, 100, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 103, 103, 103,
-            103, 103, 103, 103, 103, 103, 103, 255, 255, 255, 255, 255, 255, 255, 70, 70, 70, 70,
-            71, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            255, 255, 255, 255, 104, 255, 70, 70, 70, 70, 71, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 255, 255, 255, 255, 255, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 103, 103, 103,
-            103, 103, 103, 103, 103, 103, 103, 255, 255, 255, 255, 255, 255, 255, 70, 70, 70, 70,
-            71, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            255, 255, 255, 255, 104, 255, 70, 70, 70, 70, 71, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 255, 255, 255, 255, 255, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-            70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70, 70,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 72, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 105, 105, 105,
-            105, 105, 105, 105, 105, 105, 105, 255, 255, 255, 255, 255, 255, 255, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            255, 255, 255, 255, 72, 255, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255, 255, 255, 255, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 72, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 105, 105, 105,
-            105, 105, 105, 105, 105, 105, 105, 255, 255, 255, 255, 255, 255, 255, 84, 84, 84, 84,
-            85, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-            255, 255, 255, 255, 106, 255, 84, 84, 84, 84, 85, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 255, 255, 255, 255, 255, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-            84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84, 84,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 72, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 105, 105, 105,
-            105, 105, 105, 105, 105, 105, 105, 255, 255, 255, 255, 255, 255, 255, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            255, 255, 255, 255, 72, 255, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 255, 255, 255, 255, 255, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-        ],
-        [
-            255, 108, 108, 108, 108, 108, 108, 108, 108, 108, 255, 108, 108, 255, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 109,
-            108, 109, 108, 109, 109, 108, 108, 108, 109, 109, 108, 109, 108, 109, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 109, 109, 109, 109, 109, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 109, 108, 109, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 109, 108, 109, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108,
-        ],
-        [
-            255, 108, 108, 108, 108, 108, 108, 108, 108, 108, 255, 108, 108, 255, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108,
-        ],
-        [
-            255, 108, 108, 108, 108, 108, 108, 108, 108, 108, 255, 108, 108, 255, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 109,
-            108, 109, 108, 109, 109, 108, 108, 108, 109, 109, 108, 109, 108, 109, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 109, 109, 109, 109, 109, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 109, 108, 109, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 109, 108, 109, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108,
-            108,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 111, 111, 111,
-            111, 111, 111, 111, 111, 111, 111, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 110, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 255, 255, 255, 255, 255, 255, 255, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 255, 255, 255, 255, 113, 255, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 255, 255, 255, 255, 255, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 110, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 255, 255, 255, 255, 255, 255, 255, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 255, 255, 255, 255, 113, 255, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 255, 255, 255, 255, 255, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-            113,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 34,
-            255, 34, 255, 34, 34, 255, 255, 255, 34, 34, 255, 34, 255, 34, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 34, 34, 34, 34, 34, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 34, 255, 34, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 115, 115, 115, 115, 115, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 115, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-    ];
-
-    // DFA accept table (state=INITIAL)
-    pub const ACCEPT_TABLE_0: [u8; 116] = [
-        255, 80, 79, 0, 62, 45, 62, 79, 61, 16, 61, 61, 61, 61, 64, 64, 61, 61, 61, 61, 78, 78, 78,
-        78, 78, 78, 78, 13, 52, 46, 18, 15, 17, 9, 62, 58, 56, 57, 59, 53, 55, 71, 64, 75, 75, 75,
-        75, 64, 75, 75, 75, 74, 73, 73, 75, 75, 75, 73, 75, 75, 73, 73, 77, 77, 77, 73, 77, 77, 72,
-        71, 76, 76, 76, 73, 73, 76, 76, 76, 73, 76, 76, 71, 76, 71, 76, 76, 76, 70, 69, 68, 65, 68,
-        75, 66, 69, 75, 67, 70, 75, 2, 2, 54, 71, 71, 76, 71, 76, 1, 1, 1, 38, 63, 39, 39, 60, 0,
-    ];
-
-    // DFA transition table (state=xb)
-    pub const TRANSITION_TABLE_1: [[u8; 256]; 4] = [
-        [
-            1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-    ];
-
-    // DFA accept table (state=xb)
-    pub const ACCEPT_TABLE_1: [u8; 4] = [11, 12, 11, 19];
-
-    // DFA transition table (state=xc)
-    pub const TRANSITION_TABLE_2: [[u8; 256]; 10] = [
-        [
-            1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 3, 2, 3, 2, 3, 3, 2, 2, 2, 4, 3, 2, 3, 2, 5, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 3, 3, 3, 3, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 3, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 255, 2, 2, 2, 2, 255, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 255, 2, 2, 2, 2, 255, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 8, 255, 255, 255, 255, 9, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 6, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 7, 255,
-            7, 255, 7, 7, 255, 255, 255, 7, 7, 255, 7, 255, 7, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 7, 7, 7, 7, 7, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 7, 255, 7, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 7, 255, 7,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 7, 255,
-            7, 255, 7, 7, 255, 255, 255, 7, 7, 255, 7, 255, 7, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 7, 7, 7, 7, 7, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 7, 255, 7, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 7, 255, 7,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 8, 255, 255, 255, 255, 9, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-    ];
-
-    // DFA accept table (state=xc)
-    pub const ACCEPT_TABLE_2: [u8; 10] = [255, 8, 5, 5, 6, 6, 3, 3, 7, 4];
-
-    // DFA transition table (state=xd)
-    pub const TRANSITION_TABLE_3: [[u8; 256]; 5] = [
-        [
-            1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 4,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-    ];
-
-    // DFA accept table (state=xd)
-    pub const ACCEPT_TABLE_3: [u8; 5] = [255, 51, 50, 47, 49];
-
-    // DFA transition table (state=xh)
-    pub const TRANSITION_TABLE_4: [[u8; 256]; 4] = [
-        [
-            1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-    ];
-
-    // DFA accept table (state=xh)
-    pub const ACCEPT_TABLE_4: [u8; 4] = [10, 14, 10, 19];
-
-    // DFA transition table (state=xq)
-    pub const TRANSITION_TABLE_5: [[u8; 256]; 5] = [
-        [
-            1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 4, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-    ];
-
-    // DFA accept table (state=xq)
-    pub const ACCEPT_TABLE_5: [u8; 5] = [255, 37, 25, 19, 24];
-
-    // DFA transition table (state=xqs)
-    pub const TRANSITION_TABLE_6: [[u8; 256]; 11] = [
-        [
-            1, 2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 3, 4, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 5, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 9, 10, 9, 9, 10, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 9, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 8, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 6, 6, 6, 6, 6, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 6, 255, 255, 255, 255,
-            255, 255, 7, 255, 255, 255, 255, 255, 8, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 6, 6, 6, 6, 6, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 6, 255, 255, 255, 255,
-            255, 255, 7, 255, 255, 255, 255, 255, 8, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 9, 10, 9, 9, 10, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 9, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 8, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 6, 6, 6, 6, 6, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 6, 255, 255, 255, 255,
-            255, 255, 7, 255, 255, 255, 255, 255, 8, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-    ];
-
-    // DFA accept table (state=xqs)
-    pub const ACCEPT_TABLE_6: [u8; 11] = [21, 23, 22, 21, 21, 21, 21, 20, 21, 21, 21];
-
-    // DFA transition table (state=xe)
-    pub const TRANSITION_TABLE_7: [[u8; 256]; 28] = [
-        [
-            1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 4, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 255, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 255, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 26, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 6, 6, 5,
-            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 7,
-            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-            5, 5, 8, 5, 5, 9, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 24, 24, 24, 24,
-            24, 24, 24, 24, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 16, 16, 16, 16,
-            16, 16, 16, 16, 16, 16, 255, 255, 255, 255, 255, 255, 255, 16, 16, 16, 16, 16, 16, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 16, 16, 16, 16, 16, 16, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 12, 12, 12, 12,
-            12, 12, 12, 12, 12, 12, 255, 255, 255, 255, 255, 255, 255, 12, 12, 12, 12, 12, 12, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 12, 12, 12, 12, 12, 12, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10,
-            10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 11, 11, 11, 11,
-            11, 11, 11, 11, 11, 11, 255, 255, 255, 255, 255, 255, 255, 11, 11, 11, 11, 11, 11, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 11, 11, 11, 11, 11, 11, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 13, 13, 13, 13,
-            13, 13, 13, 13, 13, 13, 255, 255, 255, 255, 255, 255, 255, 13, 13, 13, 13, 13, 13, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 13, 13, 13, 13, 13, 13, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 14, 14, 14, 14,
-            14, 14, 14, 14, 14, 14, 255, 255, 255, 255, 255, 255, 255, 14, 14, 14, 14, 14, 14, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 14, 14, 14, 14, 14, 14, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 15, 15, 15, 15,
-            15, 15, 15, 15, 15, 15, 255, 255, 255, 255, 255, 255, 255, 15, 15, 15, 15, 15, 15, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 15, 15, 15, 15, 15, 15, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 17, 17, 17, 17,
-            17, 17, 17, 17, 17, 17, 255, 255, 255, 255, 255, 255, 255, 17, 17, 17, 17, 17, 17, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 17, 17, 17, 17, 17, 17, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 18, 18, 18, 18,
-            18, 18, 18, 18, 18, 18, 255, 255, 255, 255, 255, 255, 255, 18, 18, 18, 18, 18, 18, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 18, 18, 18, 18, 18, 18, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 19, 19, 19, 19,
-            19, 19, 19, 19, 19, 19, 255, 255, 255, 255, 255, 255, 255, 19, 19, 19, 19, 19, 19, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 19, 19, 19, 19, 19, 19, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 20, 20, 20, 20,
-            20, 20, 20, 20, 20, 20, 255, 255, 255, 255, 255, 255, 255, 20, 20, 20, 20, 20, 20, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 20, 20, 20, 20, 20, 20, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 21, 21, 21, 21,
-            21, 21, 21, 21, 21, 21, 255, 255, 255, 255, 255, 255, 255, 21, 21, 21, 21, 21, 21, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 21, 21, 21, 21, 21, 21, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 22, 22, 22, 22,
-            22, 22, 22, 22, 22, 22, 255, 255, 255, 255, 255, 255, 255, 22, 22, 22, 22, 22, 22, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 22, 22, 22, 22, 22, 22, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 23, 23, 23, 23,
-            23, 23, 23, 23, 23, 23, 255, 255, 255, 255, 255, 255, 255, 23, 23, 23, 23, 23, 23, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 23, 23, 23, 23, 23, 23, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 25, 25, 25, 25,
-            25, 25, 25, 25, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 255, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 255, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
-        ],
-    ];
-
-    // DFA accept table (state=xe)
-    pub const ACCEPT_TABLE_7: [u8; 28] = [
-        255, 37, 26, 19, 36, 33, 34, 32, 32, 33, 35, 35, 32, 32, 32, 27, 32, 32, 32, 32, 32, 32,
-        32, 27, 34, 34, 24, 26,
-    ];
-
-    // DFA transition table (state=xdolq)
-    pub const TRANSITION_TABLE_8: [[u8; 256]; 8] = [
-        [
-            1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 255, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 4, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 5, 5, 5, 5, 5, 5, 5,
-            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 255, 255, 255, 255, 5, 255, 5,
-            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 255, 255,
-            255, 255, 255, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 4, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 255, 255, 255, 255, 255, 255, 255, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 255, 255, 255, 255, 6, 255, 6, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 255, 255, 255, 255, 255, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 4, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 255, 255, 255, 255, 255, 255, 255, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 255, 255, 255, 255, 6, 255, 6, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 255, 255, 255, 255, 255, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-            6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-        ],
-        [
-            255, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 255, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-        ],
-    ];
-
-    // DFA accept table (state=xdolq)
-    pub const ACCEPT_TABLE_8: [u8; 8] = [255, 44, 41, 43, 40, 42, 42, 41];
-
-    // DFA transition table (state=xui)
-    pub const TRANSITION_TABLE_9: [[u8; 256]; 5] = [
-        [
-            1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 4,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-    ];
-
-    // DFA accept table (state=xui)
-    pub const ACCEPT_TABLE_9: [u8; 5] = [255, 51, 50, 48, 49];
-
-    // DFA transition table (state=xus)
-    pub const TRANSITION_TABLE_10: [[u8; 256]; 5] = [
-        [
-            1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 255, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 4, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-    ];
-
-    // DFA accept table (state=xus)
-    pub const ACCEPT_TABLE_10: [u8; 5] = [255, 37, 25, 19, 24];
-
-    // DFA transition table (state=xeu)
-    pub const TRANSITION_TABLE_11: [[u8; 256]; 19] = [
-        [
-            1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 4, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 5,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 6, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 11, 11, 11, 11,
-            11, 11, 11, 11, 11, 11, 255, 255, 255, 255, 255, 255, 255, 11, 11, 11, 11, 11, 11, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 11, 11, 11, 11, 11, 11, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 7, 7, 7, 7, 7, 7,
-            7, 7, 7, 7, 255, 255, 255, 255, 255, 255, 255, 7, 7, 7, 7, 7, 7, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 7, 7, 7, 7, 7, 7, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 8, 8, 8, 8, 8, 8,
-            8, 8, 8, 8, 255, 255, 255, 255, 255, 255, 255, 8, 8, 8, 8, 8, 8, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 8, 8, 8, 8, 8, 8, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 9, 9, 9, 9, 9, 9,
-            9, 9, 9, 9, 255, 255, 255, 255, 255, 255, 255, 9, 9, 9, 9, 9, 9, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 9, 9, 9, 9, 9, 9, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10,
-            10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 12, 12, 12, 12,
-            12, 12, 12, 12, 12, 12, 255, 255, 255, 255, 255, 255, 255, 12, 12, 12, 12, 12, 12, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 12, 12, 12, 12, 12, 12, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 13, 13, 13, 13,
-            13, 13, 13, 13, 13, 13, 255, 255, 255, 255, 255, 255, 255, 13, 13, 13, 13, 13, 13, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 13, 13, 13, 13, 13, 13, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 14, 14, 14, 14,
-            14, 14, 14, 14, 14, 14, 255, 255, 255, 255, 255, 255, 255, 14, 14, 14, 14, 14, 14, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 14, 14, 14, 14, 14, 14, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 15, 15, 15, 15,
-            15, 15, 15, 15, 15, 15, 255, 255, 255, 255, 255, 255, 255, 15, 15, 15, 15, 15, 15, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 15, 15, 15, 15, 15, 15, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 16, 16, 16, 16,
-            16, 16, 16, 16, 16, 16, 255, 255, 255, 255, 255, 255, 255, 16, 16, 16, 16, 16, 16, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 16, 16, 16, 16, 16, 16, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 17, 17, 17, 17,
-            17, 17, 17, 17, 17, 17, 255, 255, 255, 255, 255, 255, 255, 17, 17, 17, 17, 17, 17, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 17, 17, 17, 17, 17, 17, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 18, 18, 18, 18,
-            18, 18, 18, 18, 18, 18, 255, 255, 255, 255, 255, 255, 255, 18, 18, 18, 18, 18, 18, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 18, 18, 18, 18, 18, 18, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-        ],
-        [
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-            255,
-        ],
-    ];
-
-    // DFA accept table (state=xeu)
-    pub const ACCEPT_TABLE_11: [u8; 19] = [
-        255, 31, 29, 29, 29, 32, 32, 32, 32, 32, 28, 32, 32, 32, 32, 32, 32, 32, 28,
-    ];
-
-    pub fn get_dfa_table(state: State) -> (&'static [[u8; 256]], &'static [u8]) {
-        let state_id = state as u8;
-
-        match state_id {
-            0 => (TRANSITION_TABLE_0.as_slice(), ACCEPT_TABLE_0.as_slice()),
-            1 => (TRANSITION_TABLE_1.as_slice(), ACCEPT_TABLE_1.as_slice()),
-            2 => (TRANSITION_TABLE_2.as_slice(), ACCEPT_TABLE_2.as_slice()),
-            3 => (TRANSITION_TABLE_3.as_slice(), ACCEPT_TABLE_3.as_slice()),
-            4 => (TRANSITION_TABLE_4.as_slice(), ACCEPT_TABLE_4.as_slice()),
-            5 => (TRANSITION_TABLE_5.as_slice(), ACCEPT_TABLE_5.as_slice()),
-            6 => (TRANSITION_TABLE_6.as_slice(), ACCEPT_TABLE_6.as_slice()),
-            7 => (TRANSITION_TABLE_7.as_slice(), ACCEPT_TABLE_7.as_slice()),
-            8 => (TRANSITION_TABLE_8.as_slice(), ACCEPT_TABLE_8.as_slice()),
-            9 => (TRANSITION_TABLE_9.as_slice(), ACCEPT_TABLE_9.as_slice()),
-            10 => (TRANSITION_TABLE_10.as_slice(), ACCEPT_TABLE_10.as_slice()),
-            11 => (TRANSITION_TABLE_11.as_slice(), ACCEPT_TABLE_11.as_slice()),
-            _ => unreachable!(),
-        }
-    }
-}
-
-#[cfg(feature = "regex-match")]
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub enum RuleKind {
-    INITIAL1,
-    INITIAL2,
-    INITIAL3,
-    xc1,
-    xc2,
-    xc3,
-    xc4,
-    xc5,
-    xc6,
-    INITIAL4,
-    xh1,
-    xb1,
-    xb2,
-    INITIAL5,
-    xh2,
-    INITIAL6,
-    INITIAL7,
-    INITIAL8,
-    INITIAL9,
-    xb3,
-    xh3,
-    xq1,
-    xe1,
-    xus1,
-    xqs1,
-    xqs2,
-    xqs3,
-    xqs4,
-    xq2,
-    xe2,
-    xus2,
-    xq3,
-    xus3,
-    xe3,
-    xe4,
-    xeu1,
-    xeu2,
-    xeu3,
-    xeu4,
-    xe5,
-    xeu5,
-    xe6,
-    xe7,
-    xe8,
-    xe9,
-    xq4,
-    xe10,
-    xus4,
-    INITIAL10,
-    INITIAL11,
-    xdolq1,
-    xdolq2,
-    xdolq3,
-    xdolq4,
-    xdolq5,
-    INITIAL12,
-    INITIAL13,
-    xd1,
-    xui1,
-    xd2,
-    xui2,
-    xd3,
-    xui3,
-    xd4,
-    xui4,
-    INITIAL14,
-    INITIAL15,
-    INITIAL16,
-    INITIAL17,
-    INITIAL18,
-    INITIAL19,
-    INITIAL20,
-    INITIAL21,
-    INITIAL22,
-    INITIAL23,
-    INITIAL24,
-    INITIAL25,
-    INITIAL26,
-    INITIAL27,
-    INITIAL28,
-    INITIAL29,
-    INITIAL30,
-    INITIAL31,
-    INITIAL32,
-    INITIAL33,
-    INITIAL34,
-    INITIAL35,
-    INITIAL36,
-    INITIAL37,
-    INITIAL38,
-    INITIAL39,
-    INITIAL40,
-    INITIAL41,
-    INITIAL42,
-}
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub enum State {
-    INITIAL,
-    xb,
-    xc,
-    xd,
-    xh,
-    xq,
-    xqs,
-    xe,
-    xdolq,
-    xui,
-    xus,
-    xeu,
-}
-
-const STANDARD_CONFORMING_STRINGS: bool = true;
-
-impl Lexer {
-    #[cfg(not(feature = "regex-match"))]
-    pub fn parse_token(&mut self) -> Result<Option<TokenKind>, ParserError> {
-        loop {
-            let (match_len, pattern_index) = self.find_match_len();
-
-            self.yyleng = match_len;
-            let yytext = self.yytext();
-
-            match pattern_index {
-                0 => {
-                    { /* ignore */ }
-                }
-                1 => {
-                    {
-                        // SET_YYLLOC();
-                        // return SQL_COMMENT;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::SQL_COMMENT));
-                    }
-                }
-                2 => {
-                    {
-                        // /* Set location in case of syntax error in comment */
-                        // SET_YYLLOC();
-                        // yyextra->xcdepth = 0;
-                        // BEGIN(xc);
-                        // /* Put back any characters past slash-star; see above */
-                        // yyless(2);
-
-                        /* Set location in case of syntax error in comment */
-                        self.set_yylloc();
-                        self.xcdepth = 0;
-                        self.begin(State::xc);
-                        /* Put back any characters past slash-star; see above */
-                        self.yyless(2);
-                    }
-                }
-                3 => {
-                    {
-                        // (yyextra->xcdepth)++;
-                        // /* Put back any characters past slash-star; see above */
-                        // yyless(2);
-
-                        self.xcdepth += 1;
-                        /* Put back any characters past slash-star; see above */
-                        self.yyless(2);
-                    }
-                }
-                4 => {
-                    {
-                        // if (yyextra->xcdepth <= 0)
-                        // {
-                        // 	BEGIN(INITIAL);
-                        // 	yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 	return C_COMMENT;
-                        // }
-                        // else
-                        // 	(yyextra->xcdepth)--;
-
-                        if self.xcdepth <= 0 {
-                            self.begin(State::INITIAL);
-                            self.set_yyllocend();
-                            return Ok(Some(TokenKind::C_COMMENT));
-                        } else {
-                            self.xcdepth -= 1;
-                        }
-                    }
-                }
-                5 => {
-                    { /* ignore */ }
-                }
-                6 => {
-                    { /* ignore */ }
-                }
-                7 => {
-                    { /* ignore */ }
-                }
-                8 => {
-                    yyerror!("unterminated /* comment");
-                }
-                9 => {
-                    {
-                        // /* Binary bit type.
-                        //  * At some point we should simply pass the string
-                        //  * forward to the parser and label it there.
-                        //  * In the meantime, place a leading "b" on the string
-                        //  * to mark it for the input routine as a binary string.
-                        //  */
-                        // SET_YYLLOC();
-                        // BEGIN(xb);
-                        // startlit();
-                        // addlitchar('b', yyscanner);
-
-                        /* Binary bit type.
-                         * At some point we should simply pass the string
-                         * forward to the parser and label it there.
-                         * In the meantime, place a leading "b" on the string
-                         * to mark it for the input routine as a binary string.
-                         */
-                        self.set_yylloc();
-                        self.begin(State::xb);
-                        self.literal.clear();
-                        self.addlitchar('b');
-                    }
-                }
-                10 | 11 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                12 => {
-                    yyerror!("unterminated bit string literal");
-                }
-                13 => {
-                    {
-                        // /* Hexadecimal bit type.
-                        //  * At some point we should simply pass the string
-                        //  * forward to the parser and label it there.
-                        //  * In the meantime, place a leading "x" on the string
-                        //  * to mark it for the input routine as a hex string.
-                        //  */
-                        // SET_YYLLOC();
-                        // BEGIN(xh);
-                        // startlit();
-                        // addlitchar('x', yyscanner);
-
-                        /* Hexadecimal bit type.
-                         * At some point we should simply pass the string
-                         * forward to the parser and label it there.
-                         * In the meantime, place a leading "x" on the string
-                         * to mark it for the input routine as a hex string.
-                         */
-                        self.set_yylloc();
-                        self.begin(State::xh);
-                        self.literal.clear();
-                        self.addlitchar('x');
-                    }
-                }
-                14 => {
-                    yyerror!("unterminated hexadecimal string literal");
-                }
-                15 => {
-                    {
-                        // /* National character.
-                        //  * We will pass this along as a normal character string,
-                        //  * but preceded with an internally-generated "NCHAR".
-                        //  */
-                        // int		kwnum;
-                        //
-                        // SET_YYLLOC();
-                        // yyless(1);	/* eat only 'n' this time */
-                        //
-                        // kwnum = ScanKeywordLookup("nchar",
-                        // 						  yyextra->keywordlist);
-                        // if (kwnum >= 0)
-                        // {
-                        // 	yylval->keyword = GetScanKeyword(kwnum,
-                        // 									 yyextra->keywordlist);
-                        // 	return yyextra->keyword_tokens[kwnum];
-                        // }
-                        // else
-                        // {
-                        // 	/* If NCHAR isn't a keyword, just return "n" */
-                        // 	yylval->str = pstrdup("n");
-                        // 	yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 	return IDENT;
-                        // }
-
-                        self.set_yylloc();
-                        self.yyless(1); /* eat only 'n' this time */
-
-                        if let Some((kw, kw_token)) = self.get_keyword("nchar") {
-                            self.yylval = Yylval::Keyword(kw);
-                            return Ok(Some(TokenKind::KEYWORD(kw_token)));
-                        } else {
-                            /* If NCHAR isn't a keyword, just return "n" */
-                            self.yylval = Yylval::Str("n".to_string());
-                            self.set_yyllocend();
-                            return Ok(Some(TokenKind::IDENT));
-                        }
-                    }
-                }
-                16 => {
-                    {
-                        // yyextra->warn_on_first_escape = true;
-                        // yyextra->saw_non_ascii = false;
-                        // SET_YYLLOC();
-                        // if (yyextra->standard_conforming_strings)
-                        // 	BEGIN(xq);
-                        // else
-                        // 	BEGIN(xe);
-                        // startlit();
-
-                        self.warn_on_first_escape = true;
-                        self.saw_non_ascii = false;
-                        self.set_yylloc();
-                        if STANDARD_CONFORMING_STRINGS {
-                            self.begin(State::xq);
-                        } else {
-                            self.begin(State::xe);
-                        }
-                        self.literal.clear();
-                    }
-                }
-                17 => {
-                    {
-                        // yyextra->warn_on_first_escape = false;
-                        // yyextra->saw_non_ascii = false;
-                        // SET_YYLLOC();
-                        // BEGIN(xe);
-                        // startlit();
-
-                        self.warn_on_first_escape = false;
-                        self.saw_non_ascii = false;
-                        self.set_yylloc();
-                        self.begin(State::xe);
-                        self.literal.clear();
-                    }
-                }
-                18 => {
-                    {
-                        // SET_YYLLOC();
-                        // if (!yyextra->standard_conforming_strings)
-                        // 	ereport(ERROR,
-                        // 			(errcode(ERRCODE_FEATURE_NOT_SUPPORTED),
-                        // 			 errmsg("unsafe use of string constant with Unicode escapes"),
-                        // 			 errdetail("String constants with Unicode escapes cannot be used when \"standard_conforming_strings\" is off."),
-                        // 			 lexer_errposition()));
-                        // BEGIN(xus);
-                        // startlit();
-
-                        self.set_yylloc();
-                        if !STANDARD_CONFORMING_STRINGS {
-                            ereport!(self, ERROR,
-								(errcode(ERRCODE_FEATURE_NOT_SUPPORTED),
-								 errmsg("unsafe use of string constant with Unicode escapes"),
-								 errdetail("String constants with Unicode escapes cannot be used when standard_conforming_strings is off."),
-								 self.lexer_errposition()));
-                        }
-                        self.begin(State::xus);
-                        self.literal.clear();
-                    }
-                }
-                19 => {
-                    {
-                        // /*
-                        //  * When we are scanning a quoted string and see an end
-                        //  * quote, we must look ahead for a possible continuation.
-                        //  * If we don't see one, we know the end quote was in fact
-                        //  * the end of the string.  To reduce the lexer table size,
-                        //  * we use a single "xqs" state to do the lookahead for all
-                        //  * types of strings.
-                        //  */
-                        // yyextra->state_before_str_stop = YYSTATE;
-                        // BEGIN(xqs);
-
-                        /*
-                         * When we are scanning a quoted string and see an end
-                         * quote, we must look ahead for a possible continuation.
-                         * If we don't see one, we know the end quote was in fact
-                         * the end of the string.  To reduce the lexer table size,
-                         * we use a single "xqs" state to do the lookahead for all
-                         * types of strings.
-                         */
-                        self.state_before_str_stop = self.state;
-                        self.begin(State::xqs);
-                    }
-                }
-                20 => {
-                    {
-                        // /*
-                        //  * Found a quote continuation, so return to the in-quote
-                        //  * state and continue scanning the literal.  Nothing is
-                        //  * added to the literal's contents.
-                        //  */
-                        // BEGIN(yyextra->state_before_str_stop);
-
-                        /*
-                         * Found a quote continuation, so return to the in-quote
-                         * state and continue scanning the literal.  Nothing is
-                         * added to the literal's contents.
-                         */
-                        self.begin(self.state_before_str_stop);
-                    }
-                }
-                21 | 22 | 23 => {
-                    {
-                        // /*
-                        //  * Failed to see a quote continuation.  Throw back
-                        //  * everything after the end quote, and handle the string
-                        //  * according to the state we were in previously.
-                        //  */
-                        // yyless(0);
-                        // BEGIN(INITIAL);
-                        //
-                        // switch (yyextra->state_before_str_stop)
-                        // {
-                        // 	case xb:
-                        // 		yylval->str = litbufdup(yyscanner);
-                        // 		yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 		return BCONST;
-                        // 	case xh:
-                        // 		yylval->str = litbufdup(yyscanner);
-                        // 		yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 		return XCONST;
-                        // 	case xq:
-                        // 	case xe:
-                        // 		/*
-                        // 		 * Check that the data remains valid, if it might
-                        // 		 * have been made invalid by unescaping any chars.
-                        // 		 */
-                        // 		if (yyextra->saw_non_ascii)
-                        // 			pg_verifymbstr(yyextra->literalbuf,
-                        // 						   yyextra->literallen,
-                        // 						   false);
-                        // 		yylval->str = litbufdup(yyscanner);
-                        // 		yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 		return SCONST;
-                        // 	case xus:
-                        // 		yylval->str = litbufdup(yyscanner);
-                        // 		yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 		return USCONST;
-                        // 	default:
-                        // 		yyerror("unhandled previous state in xqs");
-                        // }
-
-                        /*
-                         * Failed to see a quote continuation.  Throw back
-                         * everything after the end quote, and handle the string
-                         * according to the state we were in previously.
-                         */
-                        self.yyless(0);
-                        self.begin(State::INITIAL);
-
-                        match self.state_before_str_stop {
-                            State::xb => {
-                                self.yylval = Yylval::Str(self.literal.clone());
-                                self.set_yyllocend();
-                                return Ok(Some(TokenKind::BCONST));
-                            }
-                            State::xh => {
-                                self.yylval = Yylval::Str(self.literal.clone());
-                                self.set_yyllocend();
-                                return Ok(Some(TokenKind::XCONST));
-                            }
-                            State::xq | State::xe => {
-                                /*
-                                 * Check that the data remains valid, if it might
-                                 * have been made invalid by unescaping any chars.
-                                 */
-                                // TODO verify
-                                // if (yyextra->saw_non_ascii)
-                                // 	pg_verifymbstr(yyextra->literalbuf,
-                                // 				   yyextra->literallen,
-                                // 				   false);
-                                self.yylval = Yylval::Str(self.literal.clone());
-                                self.set_yyllocend();
-                                return Ok(Some(TokenKind::SCONST));
-                            }
-                            State::xus => {
-                                self.yylval = Yylval::Str(self.literal.clone());
-                                self.set_yyllocend();
-                                return Ok(Some(TokenKind::USCONST));
-                            }
-                            _ => yyerror!("unhandled previous state in xqs"),
-                        }
-                    }
-                }
-                24 => {
-                    {
-                        // self.literal += '\'';
-
-                        self.addlitchar('\'');
-                    }
-                }
-                25 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                26 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                27 => {
-                    {
-                        // pg_wchar	c = strtoul(yytext + 2, NULL, 16);
-                        //
-                        // /*
-                        //  * For consistency with other productions, issue any
-                        //  * escape warning with cursor pointing to start of string.
-                        //  * We might want to change that, someday.
-                        //  */
-                        // check_escape_warning(yyscanner);
-                        //
-                        // /* Remember start of overall string token ... */
-                        // PUSH_YYLLOC();
-                        // /* ... and set the error cursor to point at this esc seq */
-                        // SET_YYLLOC();
-                        //
-                        // if (is_utf16_surrogate_first(c))
-                        // {
-                        // 	yyextra->utf16_first_part = c;
-                        // 	BEGIN(xeu);
-                        // }
-                        // else if (is_utf16_surrogate_second(c))
-                        // 	yyerror("invalid Unicode surrogate pair");
-                        // else
-                        // 	addunicode(c, yyscanner);
-                        //
-                        // /* Restore yylloc to be start of string token */
-                        // POP_YYLLOC();
-
-                        let c = u32::from_str_radix(
-                            &self.input[self.index_bytes + 2..self.index_bytes + self.yyleng],
-                            16,
-                        )
-                        .unwrap();
-
-                        self.push_yylloc();
-                        self.set_yylloc();
-
-                        if is_utf16_surrogate_first(c) {
-                            self.utf16_first_part = c;
-                            self.begin(State::xeu);
-                        } else if is_utf16_surrogate_second(c) {
-                            yyerror!("invalid Unicode surrogate pair");
-                        } else {
-                            self.addunicode(char::from_u32(c as u32).unwrap())?;
-                        }
-
-                        self.pop_yylloc();
-                    }
-                }
-                28 => {
-                    {
-                        // pg_wchar	c = strtoul(yytext + 2, NULL, 16);
-                        //
-                        // /* Remember start of overall string token ... */
-                        // PUSH_YYLLOC();
-                        // /* ... and set the error cursor to point at this esc seq */
-                        // SET_YYLLOC();
-                        //
-                        // if (!is_utf16_surrogate_second(c))
-                        // 	yyerror("invalid Unicode surrogate pair");
-                        //
-                        // c = surrogate_pair_to_codepoint(yyextra->utf16_first_part, c);
-                        //
-                        // addunicode(c, yyscanner);
-                        //
-                        // /* Restore yylloc to be start of string token */
-                        // POP_YYLLOC();
-                        //
-                        // BEGIN(xe);
-
-                        let c = u32::from_str_radix(
-                            &self.input[self.index_bytes + 2..self.index_bytes + self.yyleng],
-                            16,
-                        )
-                        .unwrap();
-
-                        self.push_yylloc();
-                        self.set_yylloc();
-
-                        if !is_utf16_surrogate_second(c) {
-                            yyerror!("invalid Unicode surrogate pair");
-                        }
-
-                        let c = surrogate_pair_to_codepoint(self.utf16_first_part, c);
-                        self.addunicode(c)?;
-
-                        self.pop_yylloc();
-                        self.begin(State::xe);
-                    }
-                }
-                29 | 30 | 31 => {
-                    {
-                        // /* Set the error cursor to point at missing esc seq */
-                        // SET_YYLLOC();
-                        // yyerror("invalid Unicode surrogate pair");
-
-                        /* Set the error cursor to point at missing esc seq */
-                        self.set_yylloc();
-                        yyerror!("invalid Unicode surrogate pair");
-                    }
-                }
-                32 => {
-                    {
-                        // /* Set the error cursor to point at malformed esc seq */
-                        // SET_YYLLOC();
-                        // ereport(ERROR,
-                        // 		(errcode(ERRCODE_INVALID_ESCAPE_SEQUENCE),
-                        // 		 errmsg("invalid Unicode escape"),
-                        // 		 errhint("Unicode escapes must be \\uXXXX or \\UXXXXXXXX."),
-                        // 		 lexer_errposition()));
-
-                        self.set_yylloc();
-                        ereport!(
-                            self,
-                            ERROR,
-                            (
-                                errcode(ERRCODE_INVALID_ESCAPE_SEQUENCE),
-                                errmsg("invalid Unicode escape"),
-                                errhint("Unicode escapes must be \\uXXXX or \\UXXXXXXXX."),
-                                self.lexer_errposition()
-                            )
-                        );
-                    }
-                }
-                33 => {
-                    {
-                        // if (yytext[1] == '\'')
-                        // {
-                        // 	if (yyextra->backslash_quote == BACKSLASH_QUOTE_OFF ||
-                        // 		(yyextra->backslash_quote == BACKSLASH_QUOTE_SAFE_ENCODING &&
-                        // 		 PG_ENCODING_IS_CLIENT_ONLY(pg_get_client_encoding())))
-                        // 		ereport(ERROR,
-                        // 				(errcode(ERRCODE_NONSTANDARD_USE_OF_ESCAPE_CHARACTER),
-                        // 				 errmsg("unsafe use of \\' in a string literal"),
-                        // 				 errhint("Use '' to write quotes in strings. \\' is insecure in client-only encodings."),
-                        // 				 lexer_errposition()));
-                        // }
-                        // check_string_escape_warning(yytext[1], yyscanner);
-                        // addlitchar(unescape_single_char(yytext[1], yyscanner),
-                        // 		   yyscanner);
-
-                        let c = self.yytext().chars().nth(1).unwrap();
-                        // if c == '\'' {
-                        // 	if self.backslash_quote == BACKSLASH_QUOTE_OFF ||
-                        // 		(self.backslash_quote == BACKSLASH_QUOTE_SAFE_ENCODING &&
-                        // 		 PG_ENCODING_IS_CLIENT_ONLY(pg_get_client_encoding())) {
-                        // 		ereport!(self, ERROR,
-                        // 				(errcode(ERRCODE_NONSTANDARD_USE_OF_ESCAPE_CHARACTER),
-                        // 				 errmsg("unsafe use of \\' in a string literal"),
-                        // 				 errhint("Use '' to write quotes in strings. \\' is insecure in client-only encodings."),
-                        // 				 self.lexer_errposition()));
-                        // 	}
-                        // }
-
-                        // self.check_string_escape_warning(c);
-                        let c = self.unescape_single_char(c);
-                        self.addlitchar(c);
-                    }
-                }
-                34 => {
-                    {
-                        // unsigned char c = strtoul(yytext + 1, NULL, 8);
-                        //
-                        // check_escape_warning(yyscanner);
-                        // addlitchar(c, yyscanner);
-                        // if (c == '\0' || IS_HIGHBIT_SET(c))
-                        // 	yyextra->saw_non_ascii = true;
-
-                        let c = u32::from_str_radix(
-                            &self.input[self.index_bytes + 1..self.index_bytes + self.yyleng],
-                            8,
-                        )
-                        .unwrap();
-                        let c = char::from_u32(c).unwrap();
-
-                        // self.check_escape_warning();
-                        self.addlitchar(c);
-                        if c == '\0' || is_highbit_set(c) != 0 {
-                            self.saw_non_ascii = true;
-                        }
-                    }
-                }
-                35 => {
-                    {
-                        // unsigned char c = strtoul(yytext + 2, NULL, 16);
-                        //
-                        // check_escape_warning(yyscanner);
-                        // addlitchar(c, yyscanner);
-                        // if (c == '\0' || IS_HIGHBIT_SET(c))
-                        // 	yyextra->saw_non_ascii = true;
-
-                        let c = u32::from_str_radix(
-                            &self.input[self.index_bytes + 1..self.index_bytes + self.yyleng],
-                            16,
-                        )
-                        .unwrap();
-                        let c = char::from_u32(c).unwrap();
-
-                        // self.check_escape_warning();
-                        self.addlitchar(c);
-                        if c == '\0' || is_highbit_set(c) != 0 {
-                            self.saw_non_ascii = true;
-                        }
-                    }
-                }
-                36 => {
-                    {
-                        // /* This is only needed for \ just before EOF */
-                        // addlitchar(yytext[0], yyscanner);
-
-                        /* This is only needed for \ just before EOF */
-                        let c = self.input[self.index_bytes..].chars().next().unwrap();
-                        self.addlitchar(c);
-                    }
-                }
-                37 => {
-                    yyerror!("unterminated quoted string");
-                }
-                38 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyextra->dolqstart = pstrdup(yytext);
-                        // BEGIN(xdolq);
-                        // startlit();
-
-                        self.set_yylloc();
-                        self.dolqstart = self.yytext();
-                        self.begin(State::xdolq);
-                        self.literal.clear();
-                    }
-                }
-                39 => {
-                    {
-                        // SET_YYLLOC();
-                        // /* throw back all but the initial "$" */
-                        // yyless(1);
-                        // /* and treat it as {other} */
-                        // return yytext[0];
-
-                        self.set_yylloc();
-                        /* throw back all but the initial "$" */
-                        self.yyless(1);
-                        /* and treat it as {other} */
-                        return Ok(Some(TokenKind::RAW(self.yytext())));
-                    }
-                }
-                40 => {
-                    {
-                        // if (strcmp(yytext, yyextra->dolqstart) == 0)
-                        // {
-                        // 	pfree(yyextra->dolqstart);
-                        // 	yyextra->dolqstart = NULL;
-                        // 	BEGIN(INITIAL);
-                        // 	yylval->str = litbufdup(yyscanner);
-                        // 	yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 	return SCONST;
-                        // }
-                        // else
-                        // {
-                        // 	/*
-                        // 	 * When we fail to match $...$ to dolqstart, transfer
-                        // 	 * the $... part to the output, but put back the final
-                        // 	 * $ for rescanning.  Consider $delim$...$junk$delim$
-                        // 	 */
-                        // 	addlit(yytext, yyleng - 1, yyscanner);
-                        // 	yyless(yyleng - 1);
-                        // }
-
-                        if self.yytext() == self.dolqstart {
-                            self.dolqstart = "".to_string();
-                            self.begin(State::INITIAL);
-                            self.yylval = Yylval::Str(self.literal.clone());
-                            self.set_yyllocend();
-                            return Ok(Some(TokenKind::SCONST));
-                        } else {
-                            /*
-                             * When we fail to match $...$ to dolqstart, transfer
-                             * the $... part to the output, but put back the final
-                             * $ for rescanning.  Consider $delim$...$junk$delim$
-                             */
-                            self.addlit(self.yyleng - 1);
-                            self.yyless(self.yyleng - 1);
-                        }
-                    }
-                }
-                41 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                42 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                43 => {
-                    {
-                        // /* This is only needed for $ inside the quoted text */
-                        // addlitchar(yytext[0], yyscanner);
-
-                        let c = self.yytext().chars().next().unwrap();
-                        self.addlitchar(c);
-                    }
-                }
-                44 => {
-                    yyerror!("unterminated dollar-quoted string");
-                }
-                45 => {
-                    {
-                        // SET_YYLLOC();
-                        // BEGIN(xd);
-                        // startlit();
-
-                        self.set_yylloc();
-                        self.begin(State::xd);
-                        self.literal.clear();
-                    }
-                }
-                46 => {
-                    {
-                        // SET_YYLLOC();
-                        // BEGIN(xui);
-                        // startlit();
-
-                        self.set_yylloc();
-                        self.begin(State::xui);
-                        self.literal.clear();
-                    }
-                }
-                47 => {
-                    {
-                        // char	   *ident;
-                        //
-                        // BEGIN(INITIAL);
-                        // if (yyextra->literallen == 0)
-                        // 	yyerror("zero-length delimited identifier");
-                        // ident = litbufdup(yyscanner);
-                        // if (yyextra->literallen >= NAMEDATALEN)
-                        // 	truncate_identifier(ident, yyextra->literallen, true);
-                        // yylval->str = ident;
-                        // yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // return IDENT;
-
-                        self.begin(State::INITIAL);
-                        if self.literal.len() == 0 {
-                            yyerror!("zero-length delimited identifier");
-                        }
-                        let ident = self.literal.clone();
-                        if self.literal.len() >= NAMEDATALEN {
-                            // TODO
-                            // panic!();
-                        }
-                        self.yylval = Yylval::Str(ident);
-                        self.set_yyllocend();
-                        return Ok(Some(TokenKind::IDENT));
-                    }
-                }
-                48 => {
-                    {
-                        // BEGIN(INITIAL);
-                        // if (yyextra->literallen == 0)
-                        // 	yyerror("zero-length delimited identifier");
-                        // /* can't truncate till after we de-escape the ident */
-                        // yylval->str = litbufdup(yyscanner);
-                        // yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // return UIDENT;
-
-                        self.begin(State::INITIAL);
-                        if self.literal.len() == 0 {
-                            yyerror!("zero-length delimited identifier");
-                        }
-                        /* can't truncate till after we de-escape the ident */
-                        self.yylval = Yylval::Str(self.yytext());
-                        self.set_yyllocend();
-                        return Ok(Some(TokenKind::UIDENT));
-                    }
-                }
-                49 => {
-                    {
-                        // addlitchar('"', yyscanner);
-
-                        self.addlitchar('"');
-                    }
-                }
-                50 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                51 => {
-                    yyerror!("unterminated quoted identifier");
-                }
-                52 => {
-                    {
-                        // char	   *ident;
-                        //
-                        // SET_YYLLOC();
-                        // /* throw back all but the initial u/U */
-                        // yyless(1);
-                        // /* and treat it as {identifier} */
-                        // ident = downcase_truncate_identifier(yytext, yyleng, true);
-                        // yylval->str = ident;
-                        // yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // return IDENT;
-
-                        self.set_yylloc();
-                        self.yyless(1);
-                        // FIXME
-                        // let ident = downcase_truncate_identifier(yytext, yyleng, true);
-                        let ident = self.yytext();
-                        self.yylval = Yylval::Str(ident);
-                        self.set_yyllocend();
-                        return Ok(Some(TokenKind::IDENT));
-                    }
-                }
-                53 => {
-                    {
-                        // SET_YYLLOC();
-                        // return TYPECAST;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::TYPECAST));
-                    }
-                }
-                54 => {
-                    {
-                        // SET_YYLLOC();
-                        // return DOT_DOT;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::DOT_DOT));
-                    }
-                }
-                55 => {
-                    {
-                        // SET_YYLLOC();
-                        // return COLON_EQUALS;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::COLON_EQUALS));
-                    }
-                }
-                56 => {
-                    {
-                        // SET_YYLLOC();
-                        // return EQUALS_GREATER;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::EQUALS_GREATER));
-                    }
-                }
-                57 => {
-                    {
-                        // SET_YYLLOC();
-                        // return LESS_EQUALS;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::LESS_EQUALS));
-                    }
-                }
-                58 => {
-                    {
-                        // SET_YYLLOC();
-                        // return GREATER_EQUALS;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::GREATER_EQUALS));
-                    }
-                }
-                59 => {
-                    {
-                        // /* We accept both "<>" and "!=" as meaning NOT_EQUALS */
-                        // SET_YYLLOC();
-                        // return NOT_EQUALS;
-
-                        /* We accept both "<>" and "!=" as meaning NOT_EQUALS */
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::NOT_EQUALS));
-                    }
-                }
-                60 => {
-                    {
-                        // /* We accept both "<>" and "!=" as meaning NOT_EQUALS */
-                        // SET_YYLLOC();
-                        // return NOT_EQUALS;
-
-                        /* We accept both "<>" and "!=" as meaning NOT_EQUALS */
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::NOT_EQUALS));
-                    }
-                }
-                61 => {
-                    {
-                        // SET_YYLLOC();
-                        // return yytext[0];
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::RAW(self.yytext()[0..1].to_string())));
-                    }
-                }
-                62 => {
-                    {
-                        // /*
-                        //  * Check for embedded slash-star or dash-dash; those
-                        //  * are comment starts, so operator must stop there.
-                        //  * Note that slash-star or dash-dash at the first
-                        //  * character will match a prior rule, not this one.
-                        //  */
-                        // int			nchars = yyleng;
-                        // char	   *slashstar = strstr(yytext, "/*");
-                        // char	   *dashdash = strstr(yytext, "--");
-                        //
-                        // if (slashstar && dashdash)
-                        // {
-                        // 	/* if both appear, take the first one */
-                        // 	if (slashstar > dashdash)
-                        // 		slashstar = dashdash;
-                        // }
-                        // else if (!slashstar)
-                        // 	slashstar = dashdash;
-                        // if (slashstar)
-                        // 	nchars = slashstar - yytext;
-                        //
-                        // /*
-                        //  * For SQL compatibility, '+' and '-' cannot be the
-                        //  * last char of a multi-char operator unless the operator
-                        //  * contains chars that are not in SQL operators.
-                        //  * The idea is to lex '=-' as two operators, but not
-                        //  * to forbid operator names like '?-' that could not be
-                        //  * sequences of SQL operators.
-                        //  */
-                        // if (nchars > 1 &&
-                        // 	(yytext[nchars - 1] == '+' ||
-                        // 	 yytext[nchars - 1] == '-'))
-                        // {
-                        // 	int			ic;
-                        //
-                        // 	for (ic = nchars - 2; ic >= 0; ic--)
-                        // 	{
-                        // 		char c = yytext[ic];
-                        // 		if (c == '~' || c == '!' || c == '@' ||
-                        // 			c == '#' || c == '^' || c == '&' ||
-                        // 			c == '|' || c == '`' || c == '?' ||
-                        // 			c == '%')
-                        // 			break;
-                        // 	}
-                        // 	if (ic < 0)
-                        // 	{
-                        // 		/*
-                        // 		 * didn't find a qualifying character, so remove
-                        // 		 * all trailing [+-]
-                        // 		 */
-                        // 		do {
-                        // 			nchars--;
-                        // 		} while (nchars > 1 &&
-                        // 			 (yytext[nchars - 1] == '+' ||
-                        // 			  yytext[nchars - 1] == '-'));
-                        // 	}
-                        // }
-                        //
-                        // SET_YYLLOC();
-                        //
-                        // if (nchars < yyleng)
-                        // {
-                        // 	/* Strip the unwanted chars from the token */
-                        // 	yyless(nchars);
-                        // 	/*
-                        // 	 * If what we have left is only one char, and it's
-                        // 	 * one of the characters matching "self", then
-                        // 	 * return it as a character token the same way
-                        // 	 * that the "self" rule would have.
-                        // 	 */
-                        // 	if (nchars == 1 &&
-                        // 		strchr(",()[].;:+-*/%^<>=", yytext[0]))
-                        // 		return yytext[0];
-                        // 	/*
-                        // 	 * Likewise, if what we have left is two chars, and
-                        // 	 * those match the tokens ">=", "<=", "=>", "<>" or
-                        // 	 * "!=", then we must return the appropriate token
-                        // 	 * rather than the generic Op.
-                        // 	 */
-                        // 	if (nchars == 2)
-                        // 	{
-                        // 		if (yytext[0] == '=' && yytext[1] == '>')
-                        // 			return EQUALS_GREATER;
-                        // 		if (yytext[0] == '>' && yytext[1] == '=')
-                        // 			return GREATER_EQUALS;
-                        // 		if (yytext[0] == '<' && yytext[1] == '=')
-                        // 			return LESS_EQUALS;
-                        // 		if (yytext[0] == '<' && yytext[1] == '>')
-                        // 			return NOT_EQUALS;
-                        // 		if (yytext[0] == '!' && yytext[1] == '=')
-                        // 			return NOT_EQUALS;
-                        // 	}
-                        // }
-                        //
-                        // /*
-                        //  * Complain if operator is too long.  Unlike the case
-                        //  * for identifiers, we make this an error not a notice-
-                        //  * and-truncate, because the odds are we are looking at
-                        //  * a syntactic mistake anyway.
-                        //  */
-                        // if (nchars >= NAMEDATALEN)
-                        // 	yyerror("operator too long");
-                        //
-                        // yylval->str = pstrdup(yytext);
-                        // return Op;
-
-                        /*
-                         * Check for embedded slash-star or dash-dash; those
-                         * are comment starts, so operator must stop there.
-                         * Note that slash-star or dash-dash at the first
-                         * character will match a prior rule, not this one.
-                         */
-                        let mut nchars = self.yyleng;
-                        let yytext = self.yytext();
-                        let mut slashstar = yytext.find("/*");
-                        let dashdash = yytext.find("--");
-
-                        let dashdash_first = match (&slashstar, &dashdash) {
-                            (Some(slashstar_index), Some(dashdash_index))
-                                if slashstar_index > dashdash_index =>
-                            {
-                                true
-                            }
-                            (None, Some(_)) => true,
-                            _ => false,
-                        };
-
-                        if dashdash_first {
-                            slashstar = dashdash;
-                        }
-
-                        if let Some(i) = slashstar {
-                            nchars = i;
-                        }
-
-                        /*
-                         * For SQL compatibility, '+' and '-' cannot be the
-                         * last char of a multi-char operator unless the operator
-                         * contains chars that are not in SQL operators.
-                         * The idea is to lex '=-' as two operators, but not
-                         * to forbid operator names like '?-' that could not be
-                         * sequences of SQL operators.
-                         */
-                        if nchars > 1
-                            && (get_char_by_byte_pos(&yytext, nchars - 1) == '+'
-                                || get_char_by_byte_pos(&yytext, nchars - 1) == '-')
-                        {
-                            let b = (0..nchars - 1).any(|ic| {
-                                matches!(
-                                    get_char_by_byte_pos(&yytext, ic),
-                                    '~' | '!' | '@' | '#' | '^' | '&' | '|' | '`' | '?' | '%'
-                                )
-                            });
-                            if !b {
-                                loop {
-                                    nchars -= 1;
-
-                                    if !(nchars > 1
-                                        && (get_char_by_byte_pos(&yytext, nchars - 1) == '+'
-                                            || get_char_by_byte_pos(&yytext, nchars - 1) == '-'))
-                                    {
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-
-                        self.set_yylloc();
-
-                        if nchars < self.yyleng {
-                            /* Strip the unwanted chars from the token */
-                            self.yyless(nchars);
-                            /*
-                             * If what we have left is only one char, and it's
-                             * one of the characters matching "self", then
-                             * return it as a character token the same way
-                             * that the "self" rule would have.
-                             */
-                            if nchars == 1
-                                && ",()[].;:+-*/%^<>="
-                                    .find(get_char_by_byte_pos(&yytext, 0))
-                                    .is_some()
-                            {
-                                return Ok(Some(TokenKind::RAW(yytext[0..1].to_string())));
-                            }
-                            /*
-                             * Likewise, if what we have left is two chars, and
-                             * those match the tokens ">=", "<=", "=>", "<>" or
-                             * "!=", then we must return the appropriate token
-                             * rather than the generic Op.
-                             */
-                            if nchars == 2 {
-                                if &yytext[0..2] == "=>" {
-                                    return Ok(Some(TokenKind::EQUALS_GREATER));
-                                }
-                                if &yytext[0..2] == ">=" {
-                                    return Ok(Some(TokenKind::GREATER_EQUALS));
-                                }
-                                if &yytext[0..2] == "<=" {
-                                    return Ok(Some(TokenKind::LESS_EQUALS));
-                                }
-                                if &yytext[0..2] == "<>" {
-                                    return Ok(Some(TokenKind::NOT_EQUALS));
-                                }
-                                if &yytext[0..2] == "!=" {
-                                    return Ok(Some(TokenKind::NOT_EQUALS));
-                                }
-                            }
-                        }
-
-                        /*
-                         * Complain if operator is too long.  Unlike the case
-                         * for identifiers, we make this an error not a notice-
-                         * and-truncate, because the odds are we are looking at
-                         * a syntactic mistake anyway.
-                         */
-                        if nchars >= NAMEDATALEN {
-                            yyerror!("operator too long");
-                        }
-
-                        self.yylval = Yylval::Str(yytext);
-                        return Ok(Some(TokenKind::Op));
-                    }
-                }
-                63 => {
-                    {
-                        // SET_YYLLOC();
-                        // yylval->ival = atol(yytext + 1);
-                        // return PARAM;
-
-                        self.set_yylloc();
-                        self.yylval =
-                            Yylval::I(i32::from_str_radix(&self.yytext()[1..], 10).unwrap());
-                        return Ok(Some(TokenKind::PARAM));
-                    }
-                }
-                64 => {
-                    {
-                        // SET_YYLLOC();
-                        // return process_integer_literal(yytext, yylval, 10);
-
-                        self.set_yylloc();
-                        return Ok(self.process_integer_literal(10));
-                    }
-                }
-                65 => {
-                    {
-                        // SET_YYLLOC();
-                        // return process_integer_literal(yytext, yylval, 16);
-
-                        self.set_yylloc();
-                        return Ok(self.process_integer_literal(16));
-                    }
-                }
-                66 => {
-                    {
-                        // SET_YYLLOC();
-                        // return process_integer_literal(yytext, yylval, 8);
-
-                        self.set_yylloc();
-                        return Ok(self.process_integer_literal(8));
-                    }
-                }
-                67 => {
-                    {
-                        // SET_YYLLOC();
-                        // return process_integer_literal(yytext, yylval, 2);
-
-                        self.set_yylloc();
-                        return Ok(self.process_integer_literal(2));
-                    }
-                }
-                68 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("invalid hexadecimal integer");
-
-                        self.set_yylloc();
-                        yyerror!("invalid hexadecimal integer");
-                    }
-                }
-                69 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("invalid octal integer");
-
-                        self.set_yylloc();
-                        yyerror!("invalid octal integer");
-                    }
-                }
-                70 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("invalid binary integer");
-
-                        self.set_yylloc();
-                        yyerror!("invalid binary integer");
-                    }
-                }
-                71 => {
-                    {
-                        // SET_YYLLOC();
-                        // yylval->str = pstrdup(yytext);
-                        // return FCONST;
-
-                        self.set_yylloc();
-                        self.yylval = Yylval::Str(self.yytext());
-                        return Ok(Some(TokenKind::FCONST));
-                    }
-                }
-                72 => {
-                    {
-                        // /* throw back the .., and treat as integer */
-                        // yyless(yyleng - 2);
-                        // SET_YYLLOC();
-                        // return process_integer_literal(yytext, yylval, 10);
-
-                        /* throw back the .., and treat as integer */
-                        self.yyless(self.yyleng - 2);
-                        self.set_yylloc();
-                        return Ok(self.process_integer_literal(10));
-                    }
-                }
-                73 => {
-                    {
-                        // SET_YYLLOC();
-                        // yylval->str = pstrdup(yytext);
-                        // return FCONST;
-
-                        self.set_yylloc();
-                        self.yylval = Yylval::Str(self.yytext());
-                        return Ok(Some(TokenKind::FCONST));
-                    }
-                }
-                74 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("trailing junk after numeric literal");
-
-                        self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
-                    }
-                }
-                75 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("trailing junk after numeric literal");
-
-                        self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
-                    }
-                }
-                76 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("trailing junk after numeric literal");
-
-                        self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
-                    }
-                }
-                77 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("trailing junk after numeric literal");
-
-                        self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
-                    }
-                }
-                78 => {
-                    {
-                        // int			kwnum;
-                        // char	   *ident;
-                        //
-                        // SET_YYLLOC();
-                        //
-                        // /* Is it a keyword? */
-                        // kwnum = ScanKeywordLookup(yytext,
-                        // 						  yyextra->keywordlist);
-                        // if (kwnum >= 0)
-                        // {
-                        // 	yylval->keyword = GetScanKeyword(kwnum,
-                        // 									 yyextra->keywordlist);
-                        // 	return yyextra->keyword_tokens[kwnum];
-                        // }
-                        //
-                        // /*
-                        //  * No.  Convert the identifier to lower case, and truncate
-                        //  * if necessary.
-                        //  */
-                        // ident = downcase_truncate_identifier(yytext, yyleng, true);
-                        // yylval->str = ident;
-                        // yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // return IDENT;
-
-                        self.set_yylloc();
-
-                        /* Is it a keyword? */
-                        let yytext = self.yytext();
-                        if let Some((kw, kw_token)) = self.get_keyword(&yytext) {
-                            self.yylval = Yylval::Keyword(kw);
-                            return Ok(Some(TokenKind::KEYWORD(kw_token)));
-                        }
-
-                        /*
-                         * No.  Convert the identifier to lower case, and truncate
-                         * if necessary.
-                         */
-                        let ident = self.downcase_truncate_identifier(self.yyleng, true);
-                        self.yylval = Yylval::Str(ident);
-                        self.set_yyllocend();
-                        return Ok(Some(TokenKind::IDENT));
-                    }
-                }
-                79 => {
-                    {
-                        // SET_YYLLOC();
-                        // return yytext[0];
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::RAW(yytext[0..1].to_string())));
-                    }
-                }
-                80 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyterminate();
-
-                        self.set_yylloc();
-                        yyterminate!();
-                    }
-                }
-                255 => {}
-                _ => {
-                    unreachable!()
-                }
-            }
-            self.advance();
-        }
-    }
-
-    #[cfg(feature = "regex-match")]
-    pub fn parse_token(&mut self) -> Result<Option<TokenKind>, ParserError> {
-        loop {
-            let (match_len, kind) = self.find_match_len();
-
-            self.yyleng = match_len;
-            let yytext = self.yytext();
-
-            match kind {
-                RuleKind::INITIAL1 => {
-                    { /* ignore */ }
-                }
-                RuleKind::INITIAL2 => {
-                    {
-                        // SET_YYLLOC();
-                        // return SQL_COMMENT;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::SQL_COMMENT));
-                    }
-                }
-                RuleKind::INITIAL3 => {
-                    {
-                        // /* Set location in case of syntax error in comment */
-                        // SET_YYLLOC();
-                        // yyextra->xcdepth = 0;
-                        // BEGIN(xc);
-                        // /* Put back any characters past slash-star; see above */
-                        // yyless(2);
-
-                        /* Set location in case of syntax error in comment */
-                        self.set_yylloc();
-                        self.xcdepth = 0;
-                        self.begin(State::xc);
-                        /* Put back any characters past slash-star; see above */
-                        self.yyless(2);
-                    }
-                }
-                RuleKind::xc1 => {
-                    {
-                        // (yyextra->xcdepth)++;
-                        // /* Put back any characters past slash-star; see above */
-                        // yyless(2);
-
-                        self.xcdepth += 1;
-                        /* Put back any characters past slash-star; see above */
-                        self.yyless(2);
-                    }
-                }
-                RuleKind::xc2 => {
-                    {
-                        // if (yyextra->xcdepth <= 0)
-                        // {
-                        // 	BEGIN(INITIAL);
-                        // 	yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 	return C_COMMENT;
-                        // }
-                        // else
-                        // 	(yyextra->xcdepth)--;
-
-                        if self.xcdepth <= 0 {
-                            self.begin(State::INITIAL);
-                            self.set_yyllocend();
-                            return Ok(Some(TokenKind::C_COMMENT));
-                        } else {
-                            self.xcdepth -= 1;
-                        }
-                    }
-                }
-                RuleKind::xc3 => {
-                    { /* ignore */ }
-                }
-                RuleKind::xc4 => {
-                    { /* ignore */ }
-                }
-                RuleKind::xc5 => {
-                    { /* ignore */ }
-                }
-                RuleKind::xc6 => {
-                    yyerror!("unterminated /* comment");
-                }
-                RuleKind::INITIAL4 => {
-                    {
-                        // /* Binary bit type.
-                        //  * At some point we should simply pass the string
-                        //  * forward to the parser and label it there.
-                        //  * In the meantime, place a leading "b" on the string
-                        //  * to mark it for the input routine as a binary string.
-                        //  */
-                        // SET_YYLLOC();
-                        // BEGIN(xb);
-                        // startlit();
-                        // addlitchar('b', yyscanner);
-
-                        /* Binary bit type.
-                         * At some point we should simply pass the string
-                         * forward to the parser and label it there.
-                         * In the meantime, place a leading "b" on the string
-                         * to mark it for the input routine as a binary string.
-                         */
-                        self.set_yylloc();
-                        self.begin(State::xb);
-                        self.literal.clear();
-                        self.addlitchar('b');
-                    }
-                }
-                RuleKind::xh1 | RuleKind::xb1 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                RuleKind::xb2 => {
-                    yyerror!("unterminated bit string literal");
-                }
-                RuleKind::INITIAL5 => {
-                    {
-                        // /* Hexadecimal bit type.
-                        //  * At some point we should simply pass the string
-                        //  * forward to the parser and label it there.
-                        //  * In the meantime, place a leading "x" on the string
-                        //  * to mark it for the input routine as a hex string.
-                        //  */
-                        // SET_YYLLOC();
-                        // BEGIN(xh);
-                        // startlit();
-                        // addlitchar('x', yyscanner);
-
-                        /* Hexadecimal bit type.
-                         * At some point we should simply pass the string
-                         * forward to the parser and label it there.
-                         * In the meantime, place a leading "x" on the string
-                         * to mark it for the input routine as a hex string.
-                         */
-                        self.set_yylloc();
-                        self.begin(State::xh);
-                        self.literal.clear();
-                        self.addlitchar('x');
-                    }
-                }
-                RuleKind::xh2 => {
-                    yyerror!("unterminated hexadecimal string literal");
-                }
-                RuleKind::INITIAL6 => {
-                    {
-                        // /* National character.
-                        //  * We will pass this along as a normal character string,
-                        //  * but preceded with an internally-generated "NCHAR".
-                        //  */
-                        // int		kwnum;
-                        //
-                        // SET_YYLLOC();
-                        // yyless(1);	/* eat only 'n' this time */
-                        //
-                        // kwnum = ScanKeywordLookup("nchar",
-                        // 						  yyextra->keywordlist);
-                        // if (kwnum >= 0)
-                        // {
-                        // 	yylval->keyword = GetScanKeyword(kwnum,
-                        // 									 yyextra->keywordlist);
-                        // 	return yyextra->keyword_tokens[kwnum];
-                        // }
-                        // else
-                        // {
-                        // 	/* If NCHAR isn't a keyword, just return "n" */
-                        // 	yylval->str = pstrdup("n");
-                        // 	yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 	return IDENT;
-                        // }
-
-                        self.set_yylloc();
-                        self.yyless(1); /* eat only 'n' this time */
-
-                        if let Some((kw, kw_token)) = self.get_keyword("nchar") {
-                            self.yylval = Yylval::Keyword(kw);
-                            return Ok(Some(TokenKind::KEYWORD(kw_token)));
-                        } else {
-                            /* If NCHAR isn't a keyword, just return "n" */
-                            self.yylval = Yylval::Str("n".to_string());
-                            self.set_yyllocend();
-                            return Ok(Some(TokenKind::IDENT));
-                        }
-                    }
-                }
-                RuleKind::INITIAL7 => {
-                    {
-                        // yyextra->warn_on_first_escape = true;
-                        // yyextra->saw_non_ascii = false;
-                        // SET_YYLLOC();
-                        // if (yyextra->standard_conforming_strings)
-                        // 	BEGIN(xq);
-                        // else
-                        // 	BEGIN(xe);
-                        // startlit();
-
-                        self.warn_on_first_escape = true;
-                        self.saw_non_ascii = false;
-                        self.set_yylloc();
-                        if STANDARD_CONFORMING_STRINGS {
-                            self.begin(State::xq);
-                        } else {
-                            self.begin(State::xe);
-                        }
-                        self.literal.clear();
-                    }
-                }
-                RuleKind::INITIAL8 => {
-                    {
-                        // yyextra->warn_on_first_escape = false;
-                        // yyextra->saw_non_ascii = false;
-                        // SET_YYLLOC();
-                        // BEGIN(xe);
-                        // startlit();
-
-                        self.warn_on_first_escape = false;
-                        self.saw_non_ascii = false;
-                        self.set_yylloc();
-                        self.begin(State::xe);
-                        self.literal.clear();
-                    }
-                }
-                RuleKind::INITIAL9 => {
-                    {
-                        // SET_YYLLOC();
-                        // if (!yyextra->standard_conforming_strings)
-                        // 	ereport(ERROR,
-                        // 			(errcode(ERRCODE_FEATURE_NOT_SUPPORTED),
-                        // 			 errmsg("unsafe use of string constant with Unicode escapes"),
-                        // 			 errdetail("String constants with Unicode escapes cannot be used when \"standard_conforming_strings\" is off."),
-                        // 			 lexer_errposition()));
-                        // BEGIN(xus);
-                        // startlit();
-
-                        self.set_yylloc();
-                        if !STANDARD_CONFORMING_STRINGS {
-                            ereport!(self, ERROR,
-								(errcode(ERRCODE_FEATURE_NOT_SUPPORTED),
-								 errmsg("unsafe use of string constant with Unicode escapes"),
-								 errdetail("String constants with Unicode escapes cannot be used when standard_conforming_strings is off."),
-								 self.lexer_errposition()));
-                        }
-                        self.begin(State::xus);
-                        self.literal.clear();
-                    }
-                }
-                RuleKind::xb3 => {
-                    {
-                        // /*
-                        //  * When we are scanning a quoted string and see an end
-                        //  * quote, we must look ahead for a possible continuation.
-                        //  * If we don't see one, we know the end quote was in fact
-                        //  * the end of the string.  To reduce the lexer table size,
-                        //  * we use a single "xqs" state to do the lookahead for all
-                        //  * types of strings.
-                        //  */
-                        // yyextra->state_before_str_stop = YYSTATE;
-                        // BEGIN(xqs);
-
-                        /*
-                         * When we are scanning a quoted string and see an end
-                         * quote, we must look ahead for a possible continuation.
-                         * If we don't see one, we know the end quote was in fact
-                         * the end of the string.  To reduce the lexer table size,
-                         * we use a single "xqs" state to do the lookahead for all
-                         * types of strings.
-                         */
-                        self.state_before_str_stop = self.state;
-                        self.begin(State::xqs);
-                    }
-                }
-                RuleKind::xh3 => {
-                    {
-                        // /*
-                        //  * When we are scanning a quoted string and see an end
-                        //  * quote, we must look ahead for a possible continuation.
-                        //  * If we don't see one, we know the end quote was in fact
-                        //  * the end of the string.  To reduce the lexer table size,
-                        //  * we use a single "xqs" state to do the lookahead for all
-                        //  * types of strings.
-                        //  */
-                        // yyextra->state_before_str_stop = YYSTATE;
-                        // BEGIN(xqs);
-
-                        /*
-                         * When we are scanning a quoted string and see an end
-                         * quote, we must look ahead for a possible continuation.
-                         * If we don't see one, we know the end quote was in fact
-                         * the end of the string.  To reduce the lexer table size,
-                         * we use a single "xqs" state to do the lookahead for all
-                         * types of strings.
-                         */
-                        self.state_before_str_stop = self.state;
-                        self.begin(State::xqs);
-                    }
-                }
-                RuleKind::xq1 => {
-                    {
-                        // /*
-                        //  * When we are scanning a quoted string and see an end
-                        //  * quote, we must look ahead for a possible continuation.
-                        //  * If we don't see one, we know the end quote was in fact
-                        //  * the end of the string.  To reduce the lexer table size,
-                        //  * we use a single "xqs" state to do the lookahead for all
-                        //  * types of strings.
-                        //  */
-                        // yyextra->state_before_str_stop = YYSTATE;
-                        // BEGIN(xqs);
-
-                        /*
-                         * When we are scanning a quoted string and see an end
-                         * quote, we must look ahead for a possible continuation.
-                         * If we don't see one, we know the end quote was in fact
-                         * the end of the string.  To reduce the lexer table size,
-                         * we use a single "xqs" state to do the lookahead for all
-                         * types of strings.
-                         */
-                        self.state_before_str_stop = self.state;
-                        self.begin(State::xqs);
-                    }
-                }
-                RuleKind::xe1 => {
-                    {
-                        // /*
-                        //  * When we are scanning a quoted string and see an end
-                        //  * quote, we must look ahead for a possible continuation.
-                        //  * If we don't see one, we know the end quote was in fact
-                        //  * the end of the string.  To reduce the lexer table size,
-                        //  * we use a single "xqs" state to do the lookahead for all
-                        //  * types of strings.
-                        //  */
-                        // yyextra->state_before_str_stop = YYSTATE;
-                        // BEGIN(xqs);
-
-                        /*
-                         * When we are scanning a quoted string and see an end
-                         * quote, we must look ahead for a possible continuation.
-                         * If we don't see one, we know the end quote was in fact
-                         * the end of the string.  To reduce the lexer table size,
-                         * we use a single "xqs" state to do the lookahead for all
-                         * types of strings.
-                         */
-                        self.state_before_str_stop = self.state;
-                        self.begin(State::xqs);
-                    }
-                }
-                RuleKind::xus1 => {
-                    {
-                        // /*
-                        //  * When we are scanning a quoted string and see an end
-                        //  * quote, we must look ahead for a possible continuation.
-                        //  * If we don't see one, we know the end quote was in fact
-                        //  * the end of the string.  To reduce the lexer table size,
-                        //  * we use a single "xqs" state to do the lookahead for all
-                        //  * types of strings.
-                        //  */
-                        // yyextra->state_before_str_stop = YYSTATE;
-                        // BEGIN(xqs);
-
-                        /*
-                         * When we are scanning a quoted string and see an end
-                         * quote, we must look ahead for a possible continuation.
-                         * If we don't see one, we know the end quote was in fact
-                         * the end of the string.  To reduce the lexer table size,
-                         * we use a single "xqs" state to do the lookahead for all
-                         * types of strings.
-                         */
-                        self.state_before_str_stop = self.state;
-                        self.begin(State::xqs);
-                    }
-                }
-                RuleKind::xqs1 => {
-                    {
-                        // /*
-                        //  * Found a quote continuation, so return to the in-quote
-                        //  * state and continue scanning the literal.  Nothing is
-                        //  * added to the literal's contents.
-                        //  */
-                        // BEGIN(yyextra->state_before_str_stop);
-
-                        /*
-                         * Found a quote continuation, so return to the in-quote
-                         * state and continue scanning the literal.  Nothing is
-                         * added to the literal's contents.
-                         */
-                        self.begin(self.state_before_str_stop);
-                    }
-                }
-                RuleKind::xqs2 | RuleKind::xqs3 | RuleKind::xqs4 => {
-                    {
-                        // /*
-                        //  * Failed to see a quote continuation.  Throw back
-                        //  * everything after the end quote, and handle the string
-                        //  * according to the state we were in previously.
-                        //  */
-                        // yyless(0);
-                        // BEGIN(INITIAL);
-                        //
-                        // switch (yyextra->state_before_str_stop)
-                        // {
-                        // 	case xb:
-                        // 		yylval->str = litbufdup(yyscanner);
-                        // 		yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 		return BCONST;
-                        // 	case xh:
-                        // 		yylval->str = litbufdup(yyscanner);
-                        // 		yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 		return XCONST;
-                        // 	case xq:
-                        // 	case xe:
-                        // 		/*
-                        // 		 * Check that the data remains valid, if it might
-                        // 		 * have been made invalid by unescaping any chars.
-                        // 		 */
-                        // 		if (yyextra->saw_non_ascii)
-                        // 			pg_verifymbstr(yyextra->literalbuf,
-                        // 						   yyextra->literallen,
-                        // 						   false);
-                        // 		yylval->str = litbufdup(yyscanner);
-                        // 		yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 		return SCONST;
-                        // 	case xus:
-                        // 		yylval->str = litbufdup(yyscanner);
-                        // 		yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 		return USCONST;
-                        // 	default:
-                        // 		yyerror("unhandled previous state in xqs");
-                        // }
-
-                        /*
-                         * Failed to see a quote continuation.  Throw back
-                         * everything after the end quote, and handle the string
-                         * according to the state we were in previously.
-                         */
-                        self.yyless(0);
-                        self.begin(State::INITIAL);
-
-                        match self.state_before_str_stop {
-                            State::xb => {
-                                self.yylval = Yylval::Str(self.literal.clone());
-                                self.set_yyllocend();
-                                return Ok(Some(TokenKind::BCONST));
-                            }
-                            State::xh => {
-                                self.yylval = Yylval::Str(self.literal.clone());
-                                self.set_yyllocend();
-                                return Ok(Some(TokenKind::XCONST));
-                            }
-                            State::xq | State::xe => {
-                                /*
-                                 * Check that the data remains valid, if it might
-                                 * have been made invalid by unescaping any chars.
-                                 */
-                                // TODO verify
-                                // if (yyextra->saw_non_ascii)
-                                // 	pg_verifymbstr(yyextra->literalbuf,
-                                // 				   yyextra->literallen,
-                                // 				   false);
-                                self.yylval = Yylval::Str(self.literal.clone());
-                                self.set_yyllocend();
-                                return Ok(Some(TokenKind::SCONST));
-                            }
-                            State::xus => {
-                                self.yylval = Yylval::Str(self.literal.clone());
-                                self.set_yyllocend();
-                                return Ok(Some(TokenKind::USCONST));
-                            }
-                            _ => yyerror!("unhandled previous state in xqs"),
-                        }
-                    }
-                }
-                RuleKind::xq2 => {
-                    {
-                        // self.literal += '\'';
-
-                        self.addlitchar('\'');
-                    }
-                }
-                RuleKind::xe2 => {
-                    {
-                        // self.literal += '\'';
-
-                        self.addlitchar('\'');
-                    }
-                }
-                RuleKind::xus2 => {
-                    {
-                        // self.literal += '\'';
-
-                        self.addlitchar('\'');
-                    }
-                }
-                RuleKind::xq3 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                RuleKind::xus3 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                RuleKind::xe3 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                RuleKind::xe4 => {
-                    {
-                        // pg_wchar	c = strtoul(yytext + 2, NULL, 16);
-                        //
-                        // /*
-                        //  * For consistency with other productions, issue any
-                        //  * escape warning with cursor pointing to start of string.
-                        //  * We might want to change that, someday.
-                        //  */
-                        // check_escape_warning(yyscanner);
-                        //
-                        // /* Remember start of overall string token ... */
-                        // PUSH_YYLLOC();
-                        // /* ... and set the error cursor to point at this esc seq */
-                        // SET_YYLLOC();
-                        //
-                        // if (is_utf16_surrogate_first(c))
-                        // {
-                        // 	yyextra->utf16_first_part = c;
-                        // 	BEGIN(xeu);
-                        // }
-                        // else if (is_utf16_surrogate_second(c))
-                        // 	yyerror("invalid Unicode surrogate pair");
-                        // else
-                        // 	addunicode(c, yyscanner);
-                        //
-                        // /* Restore yylloc to be start of string token */
-                        // POP_YYLLOC();
-
-                        let c = u32::from_str_radix(
-                            &self.input[self.index_bytes + 2..self.index_bytes + self.yyleng],
-                            16,
-                        )
-                        .unwrap();
-
-                        self.push_yylloc();
-                        self.set_yylloc();
-
-                        if is_utf16_surrogate_first(c) {
-                            self.utf16_first_part = c;
-                            self.begin(State::xeu);
-                        } else if is_utf16_surrogate_second(c) {
-                            yyerror!("invalid Unicode surrogate pair");
-                        } else {
-                            self.addunicode(char::from_u32(c as u32).unwrap())?;
-                        }
-
-                        self.pop_yylloc();
-                    }
-                }
-                RuleKind::xeu1 => {
-                    {
-                        // pg_wchar	c = strtoul(yytext + 2, NULL, 16);
-                        //
-                        // /* Remember start of overall string token ... */
-                        // PUSH_YYLLOC();
-                        // /* ... and set the error cursor to point at this esc seq */
-                        // SET_YYLLOC();
-                        //
-                        // if (!is_utf16_surrogate_second(c))
-                        // 	yyerror("invalid Unicode surrogate pair");
-                        //
-                        // c = surrogate_pair_to_codepoint(yyextra->utf16_first_part, c);
-                        //
-                        // addunicode(c, yyscanner);
-                        //
-                        // /* Restore yylloc to be start of string token */
-                        // POP_YYLLOC();
-                        //
-                        // BEGIN(xe);
-
-                        let c = u32::from_str_radix(
-                            &self.input[self.index_bytes + 2..self.index_bytes + self.yyleng],
-                            16,
-                        )
-                        .unwrap();
-
-                        self.push_yylloc();
-                        self.set_yylloc();
-
-                        if !is_utf16_surrogate_second(c) {
-                            yyerror!("invalid Unicode surrogate pair");
-                        }
-
-                        let c = surrogate_pair_to_codepoint(self.utf16_first_part, c);
-                        self.addunicode(c)?;
-
-                        self.pop_yylloc();
-                        self.begin(State::xe);
-                    }
-                }
-                RuleKind::xeu2 | RuleKind::xeu3 | RuleKind::xeu4 => {
-                    {
-                        // /* Set the error cursor to point at missing esc seq */
-                        // SET_YYLLOC();
-                        // yyerror("invalid Unicode surrogate pair");
-
-                        /* Set the error cursor to point at missing esc seq */
-                        self.set_yylloc();
-                        yyerror!("invalid Unicode surrogate pair");
-                    }
-                }
-                RuleKind::xe5 => {
-                    {
-                        // /* Set the error cursor to point at malformed esc seq */
-                        // SET_YYLLOC();
-                        // ereport(ERROR,
-                        // 		(errcode(ERRCODE_INVALID_ESCAPE_SEQUENCE),
-                        // 		 errmsg("invalid Unicode escape"),
-                        // 		 errhint("Unicode escapes must be \\uXXXX or \\UXXXXXXXX."),
-                        // 		 lexer_errposition()));
-
-                        self.set_yylloc();
-                        ereport!(
-                            self,
-                            ERROR,
-                            (
-                                errcode(ERRCODE_INVALID_ESCAPE_SEQUENCE),
-                                errmsg("invalid Unicode escape"),
-                                errhint("Unicode escapes must be \\uXXXX or \\UXXXXXXXX."),
-                                self.lexer_errposition()
-                            )
-                        );
-                    }
-                }
-                RuleKind::xeu5 => {
-                    {
-                        // /* Set the error cursor to point at malformed esc seq */
-                        // SET_YYLLOC();
-                        // ereport(ERROR,
-                        // 		(errcode(ERRCODE_INVALID_ESCAPE_SEQUENCE),
-                        // 		 errmsg("invalid Unicode escape"),
-                        // 		 errhint("Unicode escapes must be \\uXXXX or \\UXXXXXXXX."),
-                        // 		 lexer_errposition()));
-
-                        self.set_yylloc();
-                        ereport!(
-                            self,
-                            ERROR,
-                            (
-                                errcode(ERRCODE_INVALID_ESCAPE_SEQUENCE),
-                                errmsg("invalid Unicode escape"),
-                                errhint("Unicode escapes must be \\uXXXX or \\UXXXXXXXX."),
-                                self.lexer_errposition()
-                            )
-                        );
-                    }
-                }
-                RuleKind::xe6 => {
-                    {
-                        // if (yytext[1] == '\'')
-                        // {
-                        // 	if (yyextra->backslash_quote == BACKSLASH_QUOTE_OFF ||
-                        // 		(yyextra->backslash_quote == BACKSLASH_QUOTE_SAFE_ENCODING &&
-                        // 		 PG_ENCODING_IS_CLIENT_ONLY(pg_get_client_encoding())))
-                        // 		ereport(ERROR,
-                        // 				(errcode(ERRCODE_NONSTANDARD_USE_OF_ESCAPE_CHARACTER),
-                        // 				 errmsg("unsafe use of \\' in a string literal"),
-                        // 				 errhint("Use '' to write quotes in strings. \\' is insecure in client-only encodings."),
-                        // 				 lexer_errposition()));
-                        // }
-                        // check_string_escape_warning(yytext[1], yyscanner);
-                        // addlitchar(unescape_single_char(yytext[1], yyscanner),
-                        // 		   yyscanner);
-
-                        let c = self.yytext().chars().nth(1).unwrap();
-                        // if c == '\'' {
-                        // 	if self.backslash_quote == BACKSLASH_QUOTE_OFF ||
-                        // 		(self.backslash_quote == BACKSLASH_QUOTE_SAFE_ENCODING &&
-                        // 		 PG_ENCODING_IS_CLIENT_ONLY(pg_get_client_encoding())) {
-                        // 		ereport!(self, ERROR,
-                        // 				(errcode(ERRCODE_NONSTANDARD_USE_OF_ESCAPE_CHARACTER),
-                        // 				 errmsg("unsafe use of \\' in a string literal"),
-                        // 				 errhint("Use '' to write quotes in strings. \\' is insecure in client-only encodings."),
-                        // 				 self.lexer_errposition()));
-                        // 	}
-                        // }
-
-                        // self.check_string_escape_warning(c);
-                        let c = self.unescape_single_char(c);
-                        self.addlitchar(c);
-                    }
-                }
-                RuleKind::xe7 => {
-                    {
-                        // unsigned char c = strtoul(yytext + 1, NULL, 8);
-                        //
-                        // check_escape_warning(yyscanner);
-                        // addlitchar(c, yyscanner);
-                        // if (c == '\0' || IS_HIGHBIT_SET(c))
-                        // 	yyextra->saw_non_ascii = true;
-
-                        let c = u32::from_str_radix(
-                            &self.input[self.index_bytes + 1..self.index_bytes + self.yyleng],
-                            8,
-                        )
-                        .unwrap();
-                        let c = char::from_u32(c).unwrap();
-
-                        // self.check_escape_warning();
-                        self.addlitchar(c);
-                        if c == '\0' || is_highbit_set(c) != 0 {
-                            self.saw_non_ascii = true;
-                        }
-                    }
-                }
-                RuleKind::xe8 => {
-                    {
-                        // unsigned char c = strtoul(yytext + 2, NULL, 16);
-                        //
-                        // check_escape_warning(yyscanner);
-                        // addlitchar(c, yyscanner);
-                        // if (c == '\0' || IS_HIGHBIT_SET(c))
-                        // 	yyextra->saw_non_ascii = true;
-
-                        let c = u32::from_str_radix(
-                            &self.input[self.index_bytes + 1..self.index_bytes + self.yyleng],
-                            16,
-                        )
-                        .unwrap();
-                        let c = char::from_u32(c).unwrap();
-
-                        // self.check_escape_warning();
-                        self.addlitchar(c);
-                        if c == '\0' || is_highbit_set(c) != 0 {
-                            self.saw_non_ascii = true;
-                        }
-                    }
-                }
-                RuleKind::xe9 => {
-                    {
-                        // /* This is only needed for \ just before EOF */
-                        // addlitchar(yytext[0], yyscanner);
-
-                        /* This is only needed for \ just before EOF */
-                        let c = self.input[self.index_bytes..].chars().next().unwrap();
-                        self.addlitchar(c);
-                    }
-                }
-                RuleKind::xq4 => {
-                    yyerror!("unterminated quoted string");
-                }
-                RuleKind::xe10 => {
-                    yyerror!("unterminated quoted string");
-                }
-                RuleKind::xus4 => {
-                    yyerror!("unterminated quoted string");
-                }
-                RuleKind::INITIAL10 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyextra->dolqstart = pstrdup(yytext);
-                        // BEGIN(xdolq);
-                        // startlit();
-
-                        self.set_yylloc();
-                        self.dolqstart = self.yytext();
-                        self.begin(State::xdolq);
-                        self.literal.clear();
-                    }
-                }
-                RuleKind::INITIAL11 => {
-                    {
-                        // SET_YYLLOC();
-                        // /* throw back all but the initial "$" */
-                        // yyless(1);
-                        // /* and treat it as {other} */
-                        // return yytext[0];
-
-                        self.set_yylloc();
-                        /* throw back all but the initial "$" */
-                        self.yyless(1);
-                        /* and treat it as {other} */
-                        return Ok(Some(TokenKind::RAW(self.yytext())));
-                    }
-                }
-                RuleKind::xdolq1 => {
-                    {
-                        // if (strcmp(yytext, yyextra->dolqstart) == 0)
-                        // {
-                        // 	pfree(yyextra->dolqstart);
-                        // 	yyextra->dolqstart = NULL;
-                        // 	BEGIN(INITIAL);
-                        // 	yylval->str = litbufdup(yyscanner);
-                        // 	yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // 	return SCONST;
-                        // }
-                        // else
-                        // {
-                        // 	/*
-                        // 	 * When we fail to match $...$ to dolqstart, transfer
-                        // 	 * the $... part to the output, but put back the final
-                        // 	 * $ for rescanning.  Consider $delim$...$junk$delim$
-                        // 	 */
-                        // 	addlit(yytext, yyleng - 1, yyscanner);
-                        // 	yyless(yyleng - 1);
-                        // }
-
-                        if self.yytext() == self.dolqstart {
-                            self.dolqstart = "".to_string();
-                            self.begin(State::INITIAL);
-                            self.yylval = Yylval::Str(self.literal.clone());
-                            self.set_yyllocend();
-                            return Ok(Some(TokenKind::SCONST));
-                        } else {
-                            /*
-                             * When we fail to match $...$ to dolqstart, transfer
-                             * the $... part to the output, but put back the final
-                             * $ for rescanning.  Consider $delim$...$junk$delim$
-                             */
-                            self.addlit(self.yyleng - 1);
-                            self.yyless(self.yyleng - 1);
-                        }
-                    }
-                }
-                RuleKind::xdolq2 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                RuleKind::xdolq3 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                RuleKind::xdolq4 => {
-                    {
-                        // /* This is only needed for $ inside the quoted text */
-                        // addlitchar(yytext[0], yyscanner);
-
-                        let c = self.yytext().chars().next().unwrap();
-                        self.addlitchar(c);
-                    }
-                }
-                RuleKind::xdolq5 => {
-                    yyerror!("unterminated dollar-quoted string");
-                }
-                RuleKind::INITIAL12 => {
-                    {
-                        // SET_YYLLOC();
-                        // BEGIN(xd);
-                        // startlit();
-
-                        self.set_yylloc();
-                        self.begin(State::xd);
-                        self.literal.clear();
-                    }
-                }
-                RuleKind::INITIAL13 => {
-                    {
-                        // SET_YYLLOC();
-                        // BEGIN(xui);
-                        // startlit();
-
-                        self.set_yylloc();
-                        self.begin(State::xui);
-                        self.literal.clear();
-                    }
-                }
-                RuleKind::xd1 => {
-                    {
-                        // char	   *ident;
-                        //
-                        // BEGIN(INITIAL);
-                        // if (yyextra->literallen == 0)
-                        // 	yyerror("zero-length delimited identifier");
-                        // ident = litbufdup(yyscanner);
-                        // if (yyextra->literallen >= NAMEDATALEN)
-                        // 	truncate_identifier(ident, yyextra->literallen, true);
-                        // yylval->str = ident;
-                        // yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // return IDENT;
-
-                        self.begin(State::INITIAL);
-                        if self.literal.len() == 0 {
-                            yyerror!("zero-length delimited identifier");
-                        }
-                        let ident = self.literal.clone();
-                        if self.literal.len() >= NAMEDATALEN {
-                            // TODO
-                            // panic!();
-                        }
-                        self.yylval = Yylval::Str(ident);
-                        self.set_yyllocend();
-                        return Ok(Some(TokenKind::IDENT));
-                    }
-                }
-                RuleKind::xui1 => {
-                    {
-                        // BEGIN(INITIAL);
-                        // if (yyextra->literallen == 0)
-                        // 	yyerror("zero-length delimited identifier");
-                        // /* can't truncate till after we de-escape the ident */
-                        // yylval->str = litbufdup(yyscanner);
-                        // yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // return UIDENT;
-
-                        self.begin(State::INITIAL);
-                        if self.literal.len() == 0 {
-                            yyerror!("zero-length delimited identifier");
-                        }
-                        /* can't truncate till after we de-escape the ident */
-                        self.yylval = Yylval::Str(self.yytext());
-                        self.set_yyllocend();
-                        return Ok(Some(TokenKind::UIDENT));
-                    }
-                }
-                RuleKind::xd2 => {
-                    {
-                        // addlitchar('"', yyscanner);
-
-                        self.addlitchar('"');
-                    }
-                }
-                RuleKind::xui2 => {
-                    {
-                        // addlitchar('"', yyscanner);
-
-                        self.addlitchar('"');
-                    }
-                }
-                RuleKind::xd3 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                RuleKind::xui3 => {
-                    {
-                        // addlit(yytext, yyleng, yyscanner);
-
-                        self.addlit(self.yyleng);
-                    }
-                }
-                RuleKind::xd4 => {
-                    yyerror!("unterminated quoted identifier");
-                }
-                RuleKind::xui4 => {
-                    yyerror!("unterminated quoted identifier");
-                }
-                RuleKind::INITIAL14 => {
-                    {
-                        // char	   *ident;
-                        //
-                        // SET_YYLLOC();
-                        // /* throw back all but the initial u/U */
-                        // yyless(1);
-                        // /* and treat it as {identifier} */
-                        // ident = downcase_truncate_identifier(yytext, yyleng, true);
-                        // yylval->str = ident;
-                        // yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // return IDENT;
-
-                        self.set_yylloc();
-                        self.yyless(1);
-                        // FIXME
-                        // let ident = downcase_truncate_identifier(yytext, yyleng, true);
-                        let ident = self.yytext();
-                        self.yylval = Yylval::Str(ident);
-                        self.set_yyllocend();
-                        return Ok(Some(TokenKind::IDENT));
-                    }
-                }
-                RuleKind::INITIAL15 => {
-                    {
-                        // SET_YYLLOC();
-                        // return TYPECAST;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::TYPECAST));
-                    }
-                }
-                RuleKind::INITIAL16 => {
-                    {
-                        // SET_YYLLOC();
-                        // return DOT_DOT;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::DOT_DOT));
-                    }
-                }
-                RuleKind::INITIAL17 => {
-                    {
-                        // SET_YYLLOC();
-                        // return COLON_EQUALS;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::COLON_EQUALS));
-                    }
-                }
-                RuleKind::INITIAL18 => {
-                    {
-                        // SET_YYLLOC();
-                        // return EQUALS_GREATER;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::EQUALS_GREATER));
-                    }
-                }
-                RuleKind::INITIAL19 => {
-                    {
-                        // SET_YYLLOC();
-                        // return LESS_EQUALS;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::LESS_EQUALS));
-                    }
-                }
-                RuleKind::INITIAL20 => {
-                    {
-                        // SET_YYLLOC();
-                        // return GREATER_EQUALS;
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::GREATER_EQUALS));
-                    }
-                }
-                RuleKind::INITIAL21 => {
-                    {
-                        // /* We accept both "<>" and "!=" as meaning NOT_EQUALS */
-                        // SET_YYLLOC();
-                        // return NOT_EQUALS;
-
-                        /* We accept both "<>" and "!=" as meaning NOT_EQUALS */
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::NOT_EQUALS));
-                    }
-                }
-                RuleKind::INITIAL22 => {
-                    {
-                        // /* We accept both "<>" and "!=" as meaning NOT_EQUALS */
-                        // SET_YYLLOC();
-                        // return NOT_EQUALS;
-
-                        /* We accept both "<>" and "!=" as meaning NOT_EQUALS */
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::NOT_EQUALS));
-                    }
-                }
-                RuleKind::INITIAL23 => {
-                    {
-                        // SET_YYLLOC();
-                        // return yytext[0];
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::RAW(self.yytext()[0..1].to_string())));
-                    }
-                }
-                RuleKind::INITIAL24 => {
-                    {
-                        // /*
-                        //  * Check for embedded slash-star or dash-dash; those
-                        //  * are comment starts, so operator must stop there.
-                        //  * Note that slash-star or dash-dash at the first
-                        //  * character will match a prior rule, not this one.
-                        //  */
-                        // int			nchars = yyleng;
-                        // char	   *slashstar = strstr(yytext, "/*");
-                        // char	   *dashdash = strstr(yytext, "--");
-                        //
-                        // if (slashstar && dashdash)
-                        // {
-                        // 	/* if both appear, take the first one */
-                        // 	if (slashstar > dashdash)
-                        // 		slashstar = dashdash;
-                        // }
-                        // else if (!slashstar)
-                        // 	slashstar = dashdash;
-                        // if (slashstar)
-                        // 	nchars = slashstar - yytext;
-                        //
-                        // /*
-                        //  * For SQL compatibility, '+' and '-' cannot be the
-                        //  * last char of a multi-char operator unless the operator
-                        //  * contains chars that are not in SQL operators.
-                        //  * The idea is to lex '=-' as two operators, but not
-                        //  * to forbid operator names like '?-' that could not be
-                        //  * sequences of SQL operators.
-                        //  */
-                        // if (nchars > 1 &&
-                        // 	(yytext[nchars - 1] == '+' ||
-                        // 	 yytext[nchars - 1] == '-'))
-                        // {
-                        // 	int			ic;
-                        //
-                        // 	for (ic = nchars - 2; ic >= 0; ic--)
-                        // 	{
-                        // 		char c = yytext[ic];
-                        // 		if (c == '~' || c == '!' || c == '@' ||
-                        // 			c == '#' || c == '^' || c == '&' ||
-                        // 			c == '|' || c == '`' || c == '?' ||
-                        // 			c == '%')
-                        // 			break;
-                        // 	}
-                        // 	if (ic < 0)
-                        // 	{
-                        // 		/*
-                        // 		 * didn't find a qualifying character, so remove
-                        // 		 * all trailing [+-]
-                        // 		 */
-                        // 		do {
-                        // 			nchars--;
-                        // 		} while (nchars > 1 &&
-                        // 			 (yytext[nchars - 1] == '+' ||
-                        // 			  yytext[nchars - 1] == '-'));
-                        // 	}
-                        // }
-                        //
-                        // SET_YYLLOC();
-                        //
-                        // if (nchars < yyleng)
-                        // {
-                        // 	/* Strip the unwanted chars from the token */
-                        // 	yyless(nchars);
-                        // 	/*
-                        // 	 * If what we have left is only one char, and it's
-                        // 	 * one of the characters matching "self", then
-                        // 	 * return it as a character token the same way
-                        // 	 * that the "self" rule would have.
-                        // 	 */
-                        // 	if (nchars == 1 &&
-                        // 		strchr(",()[].;:+-*/%^<>=", yytext[0]))
-                        // 		return yytext[0];
-                        // 	/*
-                        // 	 * Likewise, if what we have left is two chars, and
-                        // 	 * those match the tokens ">=", "<=", "=>", "<>" or
-                        // 	 * "!=", then we must return the appropriate token
-                        // 	 * rather than the generic Op.
-                        // 	 */
-                        // 	if (nchars == 2)
-                        // 	{
-                        // 		if (yytext[0] == '=' && yytext[1] == '>')
-                        // 			return EQUALS_GREATER;
-                        // 		if (yytext[0] == '>' && yytext[1] == '=')
-                        // 			return GREATER_EQUALS;
-                        // 		if (yytext[0] == '<' && yytext[1] == '=')
-                        // 			return LESS_EQUALS;
-                        // 		if (yytext[0] == '<' && yytext[1] == '>')
-                        // 			return NOT_EQUALS;
-                        // 		if (yytext[0] == '!' && yytext[1] == '=')
-                        // 			return NOT_EQUALS;
-                        // 	}
-                        // }
-                        //
-                        // /*
-                        //  * Complain if operator is too long.  Unlike the case
-                        //  * for identifiers, we make this an error not a notice-
-                        //  * and-truncate, because the odds are we are looking at
-                        //  * a syntactic mistake anyway.
-                        //  */
-                        // if (nchars >= NAMEDATALEN)
-                        // 	yyerror("operator too long");
-                        //
-                        // yylval->str = pstrdup(yytext);
-                        // return Op;
-
-                        /*
-                         * Check for embedded slash-star or dash-dash; those
-                         * are comment starts, so operator must stop there.
-                         * Note that slash-star or dash-dash at the first
-                         * character will match a prior rule, not this one.
-                         */
-                        let mut nchars = self.yyleng;
-                        let yytext = self.yytext();
-                        let mut slashstar = yytext.find("/*");
-                        let dashdash = yytext.find("--");
-
-                        let dashdash_first = match (&slashstar, &dashdash) {
-                            (Some(slashstar_index), Some(dashdash_index))
-                                if slashstar_index > dashdash_index =>
-                            {
-                                true
-                            }
-                            (None, Some(_)) => true,
-                            _ => false,
-                        };
-
-                        if dashdash_first {
-                            slashstar = dashdash;
-                        }
-
-                        if let Some(i) = slashstar {
-                            nchars = i;
-                        }
-
-                        /*
-                         * For SQL compatibility, '+' and '-' cannot be the
-                         * last char of a multi-char operator unless the operator
-                         * contains chars that are not in SQL operators.
-                         * The idea is to lex '=-' as two operators, but not
-                         * to forbid operator names like '?-' that could not be
-                         * sequences of SQL operators.
-                         */
-                        if nchars > 1
-                            && (get_char_by_byte_pos(&yytext, nchars - 1) == '+'
-                                || get_char_by_byte_pos(&yytext, nchars - 1) == '-')
-                        {
-                            let b = (0..nchars - 1).any(|ic| {
-                                matches!(
-                                    get_char_by_byte_pos(&yytext, ic),
-                                    '~' | '!' | '@' | '#' | '^' | '&' | '|' | '`' | '?' | '%'
-                                )
-                            });
-                            if !b {
-                                loop {
-                                    nchars -= 1;
-
-                                    if !(nchars > 1
-                                        && (get_char_by_byte_pos(&yytext, nchars - 1) == '+'
-                                            || get_char_by_byte_pos(&yytext, nchars - 1) == '-'))
-                                    {
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-
-                        self.set_yylloc();
-
-                        if nchars < self.yyleng {
-                            /* Strip the unwanted chars from the token */
-                            self.yyless(nchars);
-                            /*
-                             * If what we have left is only one char, and it's
-                             * one of the characters matching "self", then
-                             * return it as a character token the same way
-                             * that the "self" rule would have.
-                             */
-                            if nchars == 1
-                                && ",()[].;:+-*/%^<>="
-                                    .find(get_char_by_byte_pos(&yytext, 0))
-                                    .is_some()
-                            {
-                                return Ok(Some(TokenKind::RAW(yytext[0..1].to_string())));
-                            }
-                            /*
-                             * Likewise, if what we have left is two chars, and
-                             * those match the tokens ">=", "<=", "=>", "<>" or
-                             * "!=", then we must return the appropriate token
-                             * rather than the generic Op.
-                             */
-                            if nchars == 2 {
-                                if &yytext[0..2] == "=>" {
-                                    return Ok(Some(TokenKind::EQUALS_GREATER));
-                                }
-                                if &yytext[0..2] == ">=" {
-                                    return Ok(Some(TokenKind::GREATER_EQUALS));
-                                }
-                                if &yytext[0..2] == "<=" {
-                                    return Ok(Some(TokenKind::LESS_EQUALS));
-                                }
-                                if &yytext[0..2] == "<>" {
-                                    return Ok(Some(TokenKind::NOT_EQUALS));
-                                }
-                                if &yytext[0..2] == "!=" {
-                                    return Ok(Some(TokenKind::NOT_EQUALS));
-                                }
-                            }
-                        }
-
-                        /*
-                         * Complain if operator is too long.  Unlike the case
-                         * for identifiers, we make this an error not a notice-
-                         * and-truncate, because the odds are we are looking at
-                         * a syntactic mistake anyway.
-                         */
-                        if nchars >= NAMEDATALEN {
-                            yyerror!("operator too long");
-                        }
-
-                        self.yylval = Yylval::Str(yytext);
-                        return Ok(Some(TokenKind::Op));
-                    }
-                }
-                RuleKind::INITIAL25 => {
-                    {
-                        // SET_YYLLOC();
-                        // yylval->ival = atol(yytext + 1);
-                        // return PARAM;
-
-                        self.set_yylloc();
-                        self.yylval =
-                            Yylval::I(i32::from_str_radix(&self.yytext()[1..], 10).unwrap());
-                        return Ok(Some(TokenKind::PARAM));
-                    }
-                }
-                RuleKind::INITIAL26 => {
-                    {
-                        // SET_YYLLOC();
-                        // return process_integer_literal(yytext, yylval, 10);
-
-                        self.set_yylloc();
-                        return Ok(self.process_integer_literal(10));
-                    }
-                }
-                RuleKind::INITIAL27 => {
-                    {
-                        // SET_YYLLOC();
-                        // return process_integer_literal(yytext, yylval, 16);
-
-                        self.set_yylloc();
-                        return Ok(self.process_integer_literal(16));
-                    }
-                }
-                RuleKind::INITIAL28 => {
-                    {
-                        // SET_YYLLOC();
-                        // return process_integer_literal(yytext, yylval, 8);
-
-                        self.set_yylloc();
-                        return Ok(self.process_integer_literal(8));
-                    }
-                }
-                RuleKind::INITIAL29 => {
-                    {
-                        // SET_YYLLOC();
-                        // return process_integer_literal(yytext, yylval, 2);
-
-                        self.set_yylloc();
-                        return Ok(self.process_integer_literal(2));
-                    }
-                }
-                RuleKind::INITIAL30 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("invalid hexadecimal integer");
-
-                        self.set_yylloc();
-                        yyerror!("invalid hexadecimal integer");
-                    }
-                }
-                RuleKind::INITIAL31 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("invalid octal integer");
-
-                        self.set_yylloc();
-                        yyerror!("invalid octal integer");
-                    }
-                }
-                RuleKind::INITIAL32 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("invalid binary integer");
-
-                        self.set_yylloc();
-                        yyerror!("invalid binary integer");
-                    }
-                }
-                RuleKind::INITIAL33 => {
-                    {
-                        // SET_YYLLOC();
-                        // yylval->str = pstrdup(yytext);
-                        // return FCONST;
-
-                        self.set_yylloc();
-                        self.yylval = Yylval::Str(self.yytext());
-                        return Ok(Some(TokenKind::FCONST));
-                    }
-                }
-                RuleKind::INITIAL34 => {
-                    {
-                        // /* throw back the .., and treat as integer */
-                        // yyless(yyleng - 2);
-                        // SET_YYLLOC();
-                        // return process_integer_literal(yytext, yylval, 10);
-
-                        /* throw back the .., and treat as integer */
-                        self.yyless(self.yyleng - 2);
-                        self.set_yylloc();
-                        return Ok(self.process_integer_literal(10));
-                    }
-                }
-                RuleKind::INITIAL35 => {
-                    {
-                        // SET_YYLLOC();
-                        // yylval->str = pstrdup(yytext);
-                        // return FCONST;
-
-                        self.set_yylloc();
-                        self.yylval = Yylval::Str(self.yytext());
-                        return Ok(Some(TokenKind::FCONST));
-                    }
-                }
-                RuleKind::INITIAL36 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("trailing junk after numeric literal");
-
-                        self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
-                    }
-                }
-                RuleKind::INITIAL37 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("trailing junk after numeric literal");
-
-                        self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
-                    }
-                }
-                RuleKind::INITIAL38 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("trailing junk after numeric literal");
-
-                        self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
-                    }
-                }
-                RuleKind::INITIAL39 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyerror("trailing junk after numeric literal");
-
-                        self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
-                    }
-                }
-                RuleKind::INITIAL40 => {
-                    {
-                        // int			kwnum;
-                        // char	   *ident;
-                        //
-                        // SET_YYLLOC();
-                        //
-                        // /* Is it a keyword? */
-                        // kwnum = ScanKeywordLookup(yytext,
-                        // 						  yyextra->keywordlist);
-                        // if (kwnum >= 0)
-                        // {
-                        // 	yylval->keyword = GetScanKeyword(kwnum,
-                        // 									 yyextra->keywordlist);
-                        // 	return yyextra->keyword_tokens[kwnum];
-                        // }
-                        //
-                        // /*
-                        //  * No.  Convert the identifier to lower case, and truncate
-                        //  * if necessary.
-                        //  */
-                        // ident = downcase_truncate_identifier(yytext, yyleng, true);
-                        // yylval->str = ident;
-                        // yyextra->yyllocend = yytext - yyextra->scanbuf + yyleng;
-                        // return IDENT;
-
-                        self.set_yylloc();
-
-                        /* Is it a keyword? */
-                        let yytext = self.yytext();
-                        if let Some((kw, kw_token)) = self.get_keyword(&yytext) {
-                            self.yylval = Yylval::Keyword(kw);
-                            return Ok(Some(TokenKind::KEYWORD(kw_token)));
-                        }
-
-                        /*
-                         * No.  Convert the identifier to lower case, and truncate
-                         * if necessary.
-                         */
-                        let ident = self.downcase_truncate_identifier(self.yyleng, true);
-                        self.yylval = Yylval::Str(ident);
-                        self.set_yyllocend();
-                        return Ok(Some(TokenKind::IDENT));
-                    }
-                }
-                RuleKind::INITIAL41 => {
-                    {
-                        // SET_YYLLOC();
-                        // return yytext[0];
-
-                        self.set_yylloc();
-                        return Ok(Some(TokenKind::RAW(yytext[0..1].to_string())));
-                    }
-                }
-                RuleKind::INITIAL42 => {
-                    {
-                        // SET_YYLLOC();
-                        // yyterminate();
-
-                        self.set_yylloc();
-                        yyterminate!();
-                    }
-                }
-            }
-            self.advance();
-        }
-    }
-}
-
-#[cfg(feature = "regex-match")]
-pub fn get_rules() -> Vec<crate::lexer::Rule> {
-    use super::generated::RuleKind;
-    use crate::lexer::Rule;
-
-    vec![
-                // {whitespace}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((([ \t\n\r\f\v])+)))"#).unwrap(),
-                    kind: RuleKind::INITIAL1,
-                    eof: false,
-                },
-
-                // {comment}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((--([^\n\r])*)))"#).unwrap(),
-                    kind: RuleKind::INITIAL2,
-                    eof: false,
-                },
-
-                // {xcstart}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((\/\*([\~\!\@\#\^\&\|\`\?\+\-\*\/\%<>\=])*))"#).unwrap(),
-                    kind: RuleKind::INITIAL3,
-                    eof: false,
-                },
-
-                // {xcstart}
-                Rule {
-                    state: State::xc,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((\/\*([\~\!\@\#\^\&\|\`\?\+\-\*\/\%<>\=])*))"#).unwrap(),
-                    kind: RuleKind::xc1,
-                    eof: false,
-                },
-
-                // {xcstop}
-                Rule {
-                    state: State::xc,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((\*+\/))"#).unwrap(),
-                    kind: RuleKind::xc2,
-                    eof: false,
-                },
-
-                // {xcinside}
-                Rule {
-                    state: State::xc,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([^*/]+))"#).unwrap(),
-                    kind: RuleKind::xc3,
-                    eof: false,
-                },
-
-                // {op_chars}
-                Rule {
-                    state: State::xc,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([\~\!\@\#\^\&\|\`\?\+\-\*\/\%<>\=]))"#).unwrap(),
-                    kind: RuleKind::xc4,
-                    eof: false,
-                },
-
-                // \*+
-                Rule {
-                    state: State::xc,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(\*+)"#).unwrap(),
-                    kind: RuleKind::xc5,
-                    eof: false,
-                },
-
-                // <<EOF>>
-                Rule {
-                    state: State::xc,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(^$)"#).unwrap(),
-                    kind: RuleKind::xc6,
-                    eof: true,
-                },
-
-                // {xbstart}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([bB](')))"#).unwrap(),
-                    kind: RuleKind::INITIAL4,
-                    eof: false,
-                },
-
-                // {xhinside}
-                Rule {
-                    state: State::xh,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([^']*))"#).unwrap(),
-                    kind: RuleKind::xh1,
-                    eof: false,
-                },
-
-                // {xbinside}
-                Rule {
-                    state: State::xb,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([^']*))"#).unwrap(),
-                    kind: RuleKind::xb1,
-                    eof: false,
-                },
-
-                // <<EOF>>
-                Rule {
-                    state: State::xb,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(^$)"#).unwrap(),
-                    kind: RuleKind::xb2,
-                    eof: true,
-                },
-
-                // {xhstart}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([xX](')))"#).unwrap(),
-                    kind: RuleKind::INITIAL5,
-                    eof: false,
-                },
-
-                // <<EOF>>
-                Rule {
-                    state: State::xh,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(^$)"#).unwrap(),
-                    kind: RuleKind::xh2,
-                    eof: true,
-                },
-
-                // {xnstart}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([nN](')))"#).unwrap(),
-                    kind: RuleKind::INITIAL6,
-                    eof: false,
-                },
-
-                // {xqstart}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((')))"#).unwrap(),
-                    kind: RuleKind::INITIAL7,
-                    eof: false,
-                },
-
-                // {xestart}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([eE](')))"#).unwrap(),
-                    kind: RuleKind::INITIAL8,
-                    eof: false,
-                },
-
-                // {xusstart}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([uU]&(')))"#).unwrap(),
-                    kind: RuleKind::INITIAL9,
-                    eof: false,
-                },
-
-                // {quote}
-                Rule {
-                    state: State::xb,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(('))"#).unwrap(),
-                    kind: RuleKind::xb3,
-                    eof: false,
-                },
-
-                // {quote}
-                Rule {
-                    state: State::xh,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(('))"#).unwrap(),
-                    kind: RuleKind::xh3,
-                    eof: false,
-                },
-
-                // {quote}
-                Rule {
-                    state: State::xq,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(('))"#).unwrap(),
-                    kind: RuleKind::xq1,
-                    eof: false,
-                },
-
-                // {quote}
-                Rule {
-                    state: State::xe,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(('))"#).unwrap(),
-                    kind: RuleKind::xe1,
-                    eof: false,
-                },
-
-                // {quote}
-                Rule {
-                    state: State::xus,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(('))"#).unwrap(),
-                    kind: RuleKind::xus1,
-                    eof: false,
-                },
-
-                // {quotecontinue}
-                Rule {
-                    state: State::xqs,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((((((([ \t\f\v])))*([\n\r])((([ \t\n\r\f\v])+))*))(')))"#).unwrap(),
-                    kind: RuleKind::xqs1,
-                    eof: false,
-                },
-
-                // {quotecontinuefail}
-                Rule {
-                    state: State::xqs,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((((([ \t\n\r\f\v])+))*-?))"#).unwrap(),
-                    kind: RuleKind::xqs2,
-                    eof: false,
-                },
-
-                // {other}
-                Rule {
-                    state: State::xqs,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((.))"#).unwrap(),
-                    kind: RuleKind::xqs3,
-                    eof: false,
-                },
-
-                // <<EOF>>
-                Rule {
-                    state: State::xqs,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(^$)"#).unwrap(),
-                    kind: RuleKind::xqs4,
-                    eof: true,
-                },
-
-                // {xqdouble}
-                Rule {
-                    state: State::xq,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((')(')))"#).unwrap(),
-                    kind: RuleKind::xq2,
-                    eof: false,
-                },
-
-                // {xqdouble}
-                Rule {
-                    state: State::xe,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((')(')))"#).unwrap(),
-                    kind: RuleKind::xe2,
-                    eof: false,
-                },
-
-                // {xqdouble}
-                Rule {
-                    state: State::xus,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((')(')))"#).unwrap(),
-                    kind: RuleKind::xus2,
-                    eof: false,
-                },
-
-                // {xqinside}
-                Rule {
-                    state: State::xq,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([^']+))"#).unwrap(),
-                    kind: RuleKind::xq3,
-                    eof: false,
-                },
-
-                // {xqinside}
-                Rule {
-                    state: State::xus,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([^']+))"#).unwrap(),
-                    kind: RuleKind::xus3,
-                    eof: false,
-                },
-
-                // {xeinside}
-                Rule {
-                    state: State::xe,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([^\\']+))"#).unwrap(),
-                    kind: RuleKind::xe3,
-                    eof: false,
-                },
-
-                // {xeunicode}
-                Rule {
-                    state: State::xe,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([\\](u[0-9A-Fa-f]{4}|U[0-9A-Fa-f]{8})))"#).unwrap(),
-                    kind: RuleKind::xe4,
-                    eof: false,
-                },
-
-                // {xeunicode}
-                Rule {
-                    state: State::xeu,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([\\](u[0-9A-Fa-f]{4}|U[0-9A-Fa-f]{8})))"#).unwrap(),
-                    kind: RuleKind::xeu1,
-                    eof: false,
-                },
-
-                // .
-                Rule {
-                    state: State::xeu,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(.)"#).unwrap(),
-                    kind: RuleKind::xeu2,
-                    eof: false,
-                },
-
-                // \n
-                Rule {
-                    state: State::xeu,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(\n)"#).unwrap(),
-                    kind: RuleKind::xeu3,
-                    eof: false,
-                },
-
-                // <<EOF>>
-                Rule {
-                    state: State::xeu,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(^$)"#).unwrap(),
-                    kind: RuleKind::xeu4,
-                    eof: true,
-                },
-
-                // {xeunicodefail}
-                Rule {
-                    state: State::xe,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([\\](u[0-9A-Fa-f]{0,3}|U[0-9A-Fa-f]{0,7})))"#).unwrap(),
-                    kind: RuleKind::xe5,
-                    eof: false,
-                },
-
-                // {xeunicodefail}
-                Rule {
-                    state: State::xeu,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([\\](u[0-9A-Fa-f]{0,3}|U[0-9A-Fa-f]{0,7})))"#).unwrap(),
-                    kind: RuleKind::xeu5,
-                    eof: false,
-                },
-
-                // {xeescape}
-                Rule {
-                    state: State::xe,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([\\][^0-7]))"#).unwrap(),
-                    kind: RuleKind::xe6,
-                    eof: false,
-                },
-
-                // {xeoctesc}
-                Rule {
-                    state: State::xe,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([\\][0-7]{1,3}))"#).unwrap(),
-                    kind: RuleKind::xe7,
-                    eof: false,
-                },
-
-                // {xehexesc}
-                Rule {
-                    state: State::xe,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([\\]x[0-9A-Fa-f]{1,2}))"#).unwrap(),
-                    kind: RuleKind::xe8,
-                    eof: false,
-                },
-
-                // .
-                Rule {
-                    state: State::xe,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(.)"#).unwrap(),
-                    kind: RuleKind::xe9,
-                    eof: false,
-                },
-
-                // <<EOF>>
-                Rule {
-                    state: State::xq,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(^$)"#).unwrap(),
-                    kind: RuleKind::xq4,
-                    eof: true,
-                },
-
-                // <<EOF>>
-                Rule {
-                    state: State::xe,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(^$)"#).unwrap(),
-                    kind: RuleKind::xe10,
-                    eof: true,
-                },
-
-                // <<EOF>>
-                Rule {
-                    state: State::xus,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(^$)"#).unwrap(),
-                    kind: RuleKind::xus4,
-                    eof: true,
-                },
-
-                // {dolqdelim}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((\$(([A-Za-z\x80-\xFF_])([A-Za-z\x80-\xFF_0-9])*)?\$))"#).unwrap(),
-                    kind: RuleKind::INITIAL10,
-                    eof: false,
-                },
-
-                // {dolqfailed}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((\$([A-Za-z\x80-\xFF_])([A-Za-z\x80-\xFF_0-9])*))"#).unwrap(),
-                    kind: RuleKind::INITIAL11,
-                    eof: false,
-                },
-
-                // {dolqdelim}
-                Rule {
-                    state: State::xdolq,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((\$(([A-Za-z\x80-\xFF_])([A-Za-z\x80-\xFF_0-9])*)?\$))"#).unwrap(),
-                    kind: RuleKind::xdolq1,
-                    eof: false,
-                },
-
-                // {dolqinside}
-                Rule {
-                    state: State::xdolq,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([^$]+))"#).unwrap(),
-                    kind: RuleKind::xdolq2,
-                    eof: false,
-                },
-
-                // {dolqfailed}
-                Rule {
-                    state: State::xdolq,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((\$([A-Za-z\x80-\xFF_])([A-Za-z\x80-\xFF_0-9])*))"#).unwrap(),
-                    kind: RuleKind::xdolq3,
-                    eof: false,
-                },
-
-                // .
-                Rule {
-                    state: State::xdolq,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(.)"#).unwrap(),
-                    kind: RuleKind::xdolq4,
-                    eof: false,
-                },
-
-                // <<EOF>>
-                Rule {
-                    state: State::xdolq,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(^$)"#).unwrap(),
-                    kind: RuleKind::xdolq5,
-                    eof: true,
-                },
-
-                // {xdstart}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((\")))"#).unwrap(),
-                    kind: RuleKind::INITIAL12,
-                    eof: false,
-                },
-
-                // {xuistart}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([uU]&(\")))"#).unwrap(),
-                    kind: RuleKind::INITIAL13,
-                    eof: false,
-                },
-
-                // {xdstop}
-                Rule {
-                    state: State::xd,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((\")))"#).unwrap(),
-                    kind: RuleKind::xd1,
-                    eof: false,
-                },
-
-                // {dquote}
-                Rule {
-                    state: State::xui,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((\"))"#).unwrap(),
-                    kind: RuleKind::xui1,
-                    eof: false,
-                },
-
-                // {xddouble}
-                Rule {
-                    state: State::xd,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((\")(\")))"#).unwrap(),
-                    kind: RuleKind::xd2,
-                    eof: false,
-                },
-
-                // {xddouble}
-                Rule {
-                    state: State::xui,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((\")(\")))"#).unwrap(),
-                    kind: RuleKind::xui2,
-                    eof: false,
-                },
-
-                // {xdinside}
-                Rule {
-                    state: State::xd,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([^"]+))"#).unwrap(),
-                    kind: RuleKind::xd3,
-                    eof: false,
-                },
-
-                // {xdinside}
-                Rule {
-                    state: State::xui,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([^"]+))"#).unwrap(),
-                    kind: RuleKind::xui3,
-                    eof: false,
-                },
-
-                // <<EOF>>
-                Rule {
-                    state: State::xd,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(^$)"#).unwrap(),
-                    kind: RuleKind::xd4,
-                    eof: true,
-                },
-
-                // <<EOF>>
-                Rule {
-                    state: State::xui,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(^$)"#).unwrap(),
-                    kind: RuleKind::xui4,
-                    eof: true,
-                },
-
-                // {xufailed}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([uU]&))"#).unwrap(),
-                    kind: RuleKind::INITIAL14,
-                    eof: false,
-                },
-
-                // {typecast}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((::))"#).unwrap(),
-                    kind: RuleKind::INITIAL15,
-                    eof: false,
-                },
-
-                // {dot_dot}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((\.\.))"#).unwrap(),
-                    kind: RuleKind::INITIAL16,
-                    eof: false,
-                },
-
-                // {colon_equals}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((:=))"#).unwrap(),
-                    kind: RuleKind::INITIAL17,
-                    eof: false,
-                },
-
-                // {equals_greater}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((=>))"#).unwrap(),
-                    kind: RuleKind::INITIAL18,
-                    eof: false,
-                },
-
-                // {less_equals}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((<=))"#).unwrap(),
-                    kind: RuleKind::INITIAL19,
-                    eof: false,
-                },
-
-                // {greater_equals}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((>=))"#).unwrap(),
-                    kind: RuleKind::INITIAL20,
-                    eof: false,
-                },
-
-                // {less_greater}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((<>))"#).unwrap(),
-                    kind: RuleKind::INITIAL21,
-                    eof: false,
-                },
-
-                // {not_equals}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((!=))"#).unwrap(),
-                    kind: RuleKind::INITIAL22,
-                    eof: false,
-                },
-
-                // {self}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(([,()\[\].;\:\+\-\*\/\%\^<>\=]))"#).unwrap(),
-                    kind: RuleKind::INITIAL23,
-                    eof: false,
-                },
-
-                // {operator}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((([\~\!\@\#\^\&\|\`\?\+\-\*\/\%<>\=])+))"#).unwrap(),
-                    kind: RuleKind::INITIAL24,
-                    eof: false,
-                },
-
-                // {param}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((\$([0-9])+))"#).unwrap(),
-                    kind: RuleKind::INITIAL25,
-                    eof: false,
-                },
-
-                // {decinteger}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((([0-9])(_?([0-9]))*))"#).unwrap(),
-                    kind: RuleKind::INITIAL26,
-                    eof: false,
-                },
-
-                // {hexinteger}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((0[xX](_?([0-9A-Fa-f]))+))"#).unwrap(),
-                    kind: RuleKind::INITIAL27,
-                    eof: false,
-                },
-
-                // {octinteger}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((0[oO](_?([0-7]))+))"#).unwrap(),
-                    kind: RuleKind::INITIAL28,
-                    eof: false,
-                },
-
-                // {bininteger}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((0[bB](_?([0-1]))+))"#).unwrap(),
-                    kind: RuleKind::INITIAL29,
-                    eof: false,
-                },
-
-                // {hexfail}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((0[xX]_?))"#).unwrap(),
-                    kind: RuleKind::INITIAL30,
-                    eof: false,
-                },
-
-                // {octfail}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((0[oO]_?))"#).unwrap(),
-                    kind: RuleKind::INITIAL31,
-                    eof: false,
-                },
-
-                // {binfail}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((0[bB]_?))"#).unwrap(),
-                    kind: RuleKind::INITIAL32,
-                    eof: false,
-                },
-
-                // {numeric}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((((([0-9])(_?([0-9]))*)\.(([0-9])(_?([0-9]))*)?)|(\.(([0-9])(_?([0-9]))*)))))"#).unwrap(),
-                    kind: RuleKind::INITIAL33,
-                    eof: false,
-                },
-
-                // {numericfail}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((([0-9])(_?([0-9]))*)\.\.))"#).unwrap(),
-                    kind: RuleKind::INITIAL34,
-                    eof: false,
-                },
-
-                // {real}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((((([0-9])(_?([0-9]))*)|((((([0-9])(_?([0-9]))*)\.(([0-9])(_?([0-9]))*)?)|(\.(([0-9])(_?([0-9]))*)))))[Ee][-+]?(([0-9])(_?([0-9]))*)))"#).unwrap(),
-                    kind: RuleKind::INITIAL35,
-                    eof: false,
-                },
-
-                // {realfail}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((((([0-9])(_?([0-9]))*)|((((([0-9])(_?([0-9]))*)\.(([0-9])(_?([0-9]))*)?)|(\.(([0-9])(_?([0-9]))*)))))[Ee][-+]))"#).unwrap(),
-                    kind: RuleKind::INITIAL36,
-                    eof: false,
-                },
-
-                // {integer_junk}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((([0-9])(_?([0-9]))*)(([A-Za-z\x80-\xFF_])([A-Za-z\x80-\xFF_0-9\$])*)))"#).unwrap(),
-                    kind: RuleKind::INITIAL37,
-                    eof: false,
-                },
-
-                // {numeric_junk}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((((((([0-9])(_?([0-9]))*)\.(([0-9])(_?([0-9]))*)?)|(\.(([0-9])(_?([0-9]))*))))(([A-Za-z\x80-\xFF_])([A-Za-z\x80-\xFF_0-9\$])*)))"#).unwrap(),
-                    kind: RuleKind::INITIAL38,
-                    eof: false,
-                },
-
-                // {real_junk}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(((((([0-9])(_?([0-9]))*)|((((([0-9])(_?([0-9]))*)\.(([0-9])(_?([0-9]))*)?)|(\.(([0-9])(_?([0-9]))*)))))[Ee][-+]?(([0-9])(_?([0-9]))*))(([A-Za-z\x80-\xFF_])([A-Za-z\x80-\xFF_0-9\$])*)))"#).unwrap(),
-                    kind: RuleKind::INITIAL39,
-                    eof: false,
-                },
-
-                // {identifier}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((([A-Za-z\x80-\xFF_])([A-Za-z\x80-\xFF_0-9\$])*))"#).unwrap(),
-                    kind: RuleKind::INITIAL40,
-                    eof: false,
-                },
-
-                // {other}
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^((.))"#).unwrap(),
-                    kind: RuleKind::INITIAL41,
-                    eof: false,
-                },
-
-                // <<EOF>>
-                Rule {
-                    state: State::INITIAL,
-                    pattern: regex::bytes::Regex::new(r#"(?-u)^(^$)"#).unwrap(),
-                    kind: RuleKind::INITIAL42,
-                    eof: true,
-                }]
-}
-
-pub fn get_keyword_map() -> HashMap<&'static str, &'static str> {
-    let mut map = HashMap::new();
-    for (kw, tok) in [
-        ("abort", "ABORT_P"),
-        ("absent", "ABSENT"),
-        ("absolute", "ABSOLUTE_P"),
-        ("access", "ACCESS"),
-        ("action", "ACTION"),
-        ("add", "ADD_P"),
-        ("admin", "ADMIN"),
-        ("after", "AFTER"),
-        ("aggregate", "AGGREGATE"),
-        ("all", "ALL"),
-        ("also", "ALSO"),
-        ("alter", "ALTER"),
-        ("always", "ALWAYS"),
-        ("analyse", "ANALYSE"),
-        ("analyze", "ANALYZE"),
-        ("and", "AND"),
-        ("any", "ANY"),
-        ("array", "ARRAY"),
-        ("as", "AS"),
-        ("asc", "ASC"),
-        ("asensitive", "ASENSITIVE"),
-        ("assertion", "ASSERTION"),
-        ("assignment", "ASSIGNMENT"),
-        ("asymmetric", "ASYMMETRIC"),
-        ("at", "AT"),
-        ("atomic", "ATOMIC"),
-        ("attach", "ATTACH"),
-        ("attribute", "ATTRIBUTE"),
-        ("authorization", "AUTHORIZATION"),
-        ("backward", "BACKWARD"),
-        ("before", "BEFORE"),
-        ("begin", "BEGIN_P"),
-        ("between", "BETWEEN"),
-        ("bigint", "BIGINT"),
-        ("binary", "BINARY"),
-        ("bit", "BIT"),
-        ("boolean", "BOOLEAN_P"),
-        ("both", "BOTH"),
-        ("breadth", "BREADTH"),
-        ("by", "BY"),
-        ("cache", "CACHE"),
-        ("call", "CALL"),
-        ("called", "CALLED"),
-        ("cascade", "CASCADE"),
-        ("cascaded", "CASCADED"),
-        ("case", "CASE"),
-        ("cast", "CAST"),
-        ("catalog", "CATALOG_P"),
-        ("chain", "CHAIN"),
-        ("char", "CHAR_P"),
-        ("character", "CHARACTER"),
-        ("characteristics", "CHARACTERISTICS"),
-        ("check", "CHECK"),
-        ("checkpoint", "CHECKPOINT"),
-        ("class", "CLASS"),
-        ("close", "CLOSE"),
-        ("cluster", "CLUSTER"),
-        ("coalesce", "COALESCE"),
-        ("collate", "COLLATE"),
-        ("collation", "COLLATION"),
-        ("column", "COLUMN"),
-        ("columns", "COLUMNS"),
-        ("comment", "COMMENT"),
-        ("comments", "COMMENTS"),
-        ("commit", "COMMIT"),
-        ("committed", "COMMITTED"),
-        ("compression", "COMPRESSION"),
-        ("concurrently", "CONCURRENTLY"),
-        ("conditional", "CONDITIONAL"),
-        ("configuration", "CONFIGURATION"),
-        ("conflict", "CONFLICT"),
-        ("connection", "CONNECTION"),
-        ("constraint", "CONSTRAINT"),
-        ("constraints", "CONSTRAINTS"),
-        ("content", "CONTENT_P"),
-        ("continue", "CONTINUE_P"),
-        ("conversion", "CONVERSION_P"),
-        ("copy", "COPY"),
-        ("cost", "COST"),
-        ("create", "CREATE"),
-        ("cross", "CROSS"),
-        ("csv", "CSV"),
-        ("cube", "CUBE"),
-        ("current", "CURRENT_P"),
-        ("current_catalog", "CURRENT_CATALOG"),
-        ("current_date", "CURRENT_DATE"),
-        ("current_role", "CURRENT_ROLE"),
-        ("current_schema", "CURRENT_SCHEMA"),
-        ("current_time", "CURRENT_TIME"),
-        ("current_timestamp", "CURRENT_TIMESTAMP"),
-        ("current_user", "CURRENT_USER"),
-        ("cursor", "CURSOR"),
-        ("cycle", "CYCLE"),
-        ("data", "DATA_P"),
-        ("database", "DATABASE"),
-        ("day", "DAY_P"),
-        ("deallocate", "DEALLOCATE"),
-        ("dec", "DEC"),
-        ("decimal", "DECIMAL_P"),
-        ("declare", "DECLARE"),
-        ("default", "DEFAULT"),
-        ("defaults", "DEFAULTS"),
-        ("deferrable", "DEFERRABLE"),
-        ("deferred", "DEFERRED"),
-        ("definer", "DEFINER"),
-        ("delete", "DELETE_P"),
-        ("delimiter", "DELIMITER"),
-        ("delimiters", "DELIMITERS"),
-        ("depends", "DEPENDS"),
-        ("depth", "DEPTH"),
-        ("desc", "DESC"),
-        ("detach", "DETACH"),
-        ("dictionary", "DICTIONARY"),
-        ("disable", "DISABLE_P"),
-        ("discard", "DISCARD"),
-        ("distinct", "DISTINCT"),
-        ("do", "DO"),
-        ("document", "DOCUMENT_P"),
-        ("domain", "DOMAIN_P"),
-        ("double", "DOUBLE_P"),
-        ("drop", "DROP"),
-        ("each", "EACH"),
-        ("else", "ELSE"),
-        ("empty", "EMPTY_P"),
-        ("enable", "ENABLE_P"),
-        ("encoding", "ENCODING"),
-        ("encrypted", "ENCRYPTED"),
-        ("end", "END_P"),
-        ("enum", "ENUM_P"),
-        ("error", "ERROR_P"),
-        ("escape", "ESCAPE"),
-        ("event", "EVENT"),
-        ("except", "EXCEPT"),
-        ("exclude", "EXCLUDE"),
-        ("excluding", "EXCLUDING"),
-        ("exclusive", "EXCLUSIVE"),
-        ("execute", "EXECUTE"),
-        ("exists", "EXISTS"),
-        ("explain", "EXPLAIN"),
-        ("expression", "EXPRESSION"),
-        ("extension", "EXTENSION"),
-        ("external", "EXTERNAL"),
-        ("extract", "EXTRACT"),
-        ("false", "FALSE_P"),
-        ("family", "FAMILY"),
-        ("fetch", "FETCH"),
-        ("filter", "FILTER"),
-        ("finalize", "FINALIZE"),
-        ("first", "FIRST_P"),
-        ("float", "FLOAT_P"),
-        ("following", "FOLLOWING"),
-        ("for", "FOR"),
-        ("force", "FORCE"),
-        ("foreign", "FOREIGN"),
-        ("format", "FORMAT"),
-        ("forward", "FORWARD"),
-        ("freeze", "FREEZE"),
-        ("from", "FROM"),
-        ("full", "FULL"),
-        ("function", "FUNCTION"),
-        ("functions", "FUNCTIONS"),
-        ("generated", "GENERATED"),
-        ("global", "GLOBAL"),
-        ("grant", "GRANT"),
-        ("granted", "GRANTED"),
-        ("greatest", "GREATEST"),
-        ("group", "GROUP_P"),
-        ("grouping", "GROUPING"),
-        ("groups", "GROUPS"),
-        ("handler", "HANDLER"),
-        ("having", "HAVING"),
-        ("header", "HEADER_P"),
-        ("hold", "HOLD"),
-        ("hour", "HOUR_P"),
-        ("identity", "IDENTITY_P"),
-        ("if", "IF_P"),
-        ("ilike", "ILIKE"),
-        ("immediate", "IMMEDIATE"),
-        ("immutable", "IMMUTABLE"),
-        ("implicit", "IMPLICIT_P"),
-        ("import", "IMPORT_P"),
-        ("in", "IN_P"),
-        ("include", "INCLUDE"),
-        ("including", "INCLUDING"),
-        ("increment", "INCREMENT"),
-        ("indent", "INDENT"),
-        ("index", "INDEX"),
-        ("indexes", "INDEXES"),
-        ("inherit", "INHERIT"),
-        ("inherits", "INHERITS"),
-        ("initially", "INITIALLY"),
-        ("inline", "INLINE_P"),
-        ("inner", "INNER_P"),
-        ("inout", "INOUT"),
-        ("input", "INPUT_P"),
-        ("insensitive", "INSENSITIVE"),
-        ("insert", "INSERT"),
-        ("instead", "INSTEAD"),
-        ("int", "INT_P"),
-        ("integer", "INTEGER"),
-        ("intersect", "INTERSECT"),
-        ("interval", "INTERVAL"),
-        ("into", "INTO"),
-        ("invoker", "INVOKER"),
-        ("is", "IS"),
-        ("isnull", "ISNULL"),
-        ("isolation", "ISOLATION"),
-        ("join", "JOIN"),
-        ("json", "JSON"),
-        ("json_array", "JSON_ARRAY"),
-        ("json_arrayagg", "JSON_ARRAYAGG"),
-        ("json_exists", "JSON_EXISTS"),
-        ("json_object", "JSON_OBJECT"),
-        ("json_objectagg", "JSON_OBJECTAGG"),
-        ("json_query", "JSON_QUERY"),
-        ("json_scalar", "JSON_SCALAR"),
-        ("json_serialize", "JSON_SERIALIZE"),
-        ("json_table", "JSON_TABLE"),
-        ("json_value", "JSON_VALUE"),
-        ("keep", "KEEP"),
-        ("key", "KEY"),
-        ("keys", "KEYS"),
-        ("label", "LABEL"),
-        ("language", "LANGUAGE"),
-        ("large", "LARGE_P"),
-        ("last", "LAST_P"),
-        ("lateral", "LATERAL_P"),
-        ("leading", "LEADING"),
-        ("leakproof", "LEAKPROOF"),
-        ("least", "LEAST"),
-        ("left", "LEFT"),
-        ("level", "LEVEL"),
-        ("like", "LIKE"),
-        ("limit", "LIMIT"),
-        ("listen", "LISTEN"),
-        ("load", "LOAD"),
-        ("local", "LOCAL"),
-        ("localtime", "LOCALTIME"),
-        ("localtimestamp", "LOCALTIMESTAMP"),
-        ("location", "LOCATION"),
-        ("lock", "LOCK_P"),
-        ("locked", "LOCKED"),
-        ("logged", "LOGGED"),
-        ("mapping", "MAPPING"),
-        ("match", "MATCH"),
-        ("matched", "MATCHED"),
-        ("materialized", "MATERIALIZED"),
-        ("maxvalue", "MAXVALUE"),
-        ("merge", "MERGE"),
-        ("merge_action", "MERGE_ACTION"),
-        ("method", "METHOD"),
-        ("minute", "MINUTE_P"),
-        ("minvalue", "MINVALUE"),
-        ("mode", "MODE"),
-        ("month", "MONTH_P"),
-        ("move", "MOVE"),
-        ("name", "NAME_P"),
-        ("names", "NAMES"),
-        ("national", "NATIONAL"),
-        ("natural", "NATURAL"),
-        ("nchar", "NCHAR"),
-        ("nested", "NESTED"),
-        ("new", "NEW"),
-        ("next", "NEXT"),
-        ("nfc", "NFC"),
-        ("nfd", "NFD"),
-        ("nfkc", "NFKC"),
-        ("nfkd", "NFKD"),
-        ("no", "NO"),
-        ("none", "NONE"),
-        ("normalize", "NORMALIZE"),
-        ("normalized", "NORMALIZED"),
-        ("not", "NOT"),
-        ("nothing", "NOTHING"),
-        ("notify", "NOTIFY"),
-        ("notnull", "NOTNULL"),
-        ("nowait", "NOWAIT"),
-        ("null", "NULL_P"),
-        ("nullif", "NULLIF"),
-        ("nulls", "NULLS_P"),
-        ("numeric", "NUMERIC"),
-        ("object", "OBJECT_P"),
-        ("of", "OF"),
-        ("off", "OFF"),
-        ("offset", "OFFSET"),
-        ("oids", "OIDS"),
-        ("old", "OLD"),
-        ("omit", "OMIT"),
-        ("on", "ON"),
-        ("only", "ONLY"),
-        ("operator", "OPERATOR"),
-        ("option", "OPTION"),
-        ("options", "OPTIONS"),
-        ("or", "OR"),
-        ("order", "ORDER"),
-        ("ordinality", "ORDINALITY"),
-        ("others", "OTHERS"),
-        ("out", "OUT_P"),
-        ("outer", "OUTER_P"),
-        ("over", "OVER"),
-        ("overlaps", "OVERLAPS"),
-        ("overlay", "OVERLAY"),
-        ("overriding", "OVERRIDING"),
-        ("owned", "OWNED"),
-        ("owner", "OWNER"),
-        ("parallel", "PARALLEL"),
-        ("parameter", "PARAMETER"),
-        ("parser", "PARSER"),
-        ("partial", "PARTIAL"),
-        ("partition", "PARTITION"),
-        ("passing", "PASSING"),
-        ("password", "PASSWORD"),
-        ("path", "PATH"),
-        ("placing", "PLACING"),
-        ("plan", "PLAN"),
-        ("plans", "PLANS"),
-        ("policy", "POLICY"),
-        ("position", "POSITION"),
-        ("preceding", "PRECEDING"),
-        ("precision", "PRECISION"),
-        ("prepare", "PREPARE"),
-        ("prepared", "PREPARED"),
-        ("preserve", "PRESERVE"),
-        ("primary", "PRIMARY"),
-        ("prior", "PRIOR"),
-        ("privileges", "PRIVILEGES"),
-        ("procedural", "PROCEDURAL"),
-        ("procedure", "PROCEDURE"),
-        ("procedures", "PROCEDURES"),
-        ("program", "PROGRAM"),
-        ("publication", "PUBLICATION"),
-        ("quote", "QUOTE"),
-        ("quotes", "QUOTES"),
-        ("range", "RANGE"),
-        ("read", "READ"),
-        ("real", "REAL"),
-        ("reassign", "REASSIGN"),
-        ("recheck", "RECHECK"),
-        ("recursive", "RECURSIVE"),
-        ("ref", "REF_P"),
-        ("references", "REFERENCES"),
-        ("referencing", "REFERENCING"),
-        ("refresh", "REFRESH"),
-        ("reindex", "REINDEX"),
-        ("relative", "RELATIVE_P"),
-        ("release", "RELEASE"),
-        ("rename", "RENAME"),
-        ("repeatable", "REPEATABLE"),
-        ("replace", "REPLACE"),
-        ("replica", "REPLICA"),
-        ("reset", "RESET"),
-        ("restart", "RESTART"),
-        ("restrict", "RESTRICT"),
-        ("return", "RETURN"),
-        ("returning", "RETURNING"),
-        ("returns", "RETURNS"),
-        ("revoke", "REVOKE"),
-        ("right", "RIGHT"),
-        ("role", "ROLE"),
-        ("rollback", "ROLLBACK"),
-        ("rollup", "ROLLUP"),
-        ("routine", "ROUTINE"),
-        ("routines", "ROUTINES"),
-        ("row", "ROW"),
-        ("rows", "ROWS"),
-        ("rule", "RULE"),
-        ("savepoint", "SAVEPOINT"),
-        ("scalar", "SCALAR"),
-        ("schema", "SCHEMA"),
-        ("schemas", "SCHEMAS"),
-        ("scroll", "SCROLL"),
-        ("search", "SEARCH"),
-        ("second", "SECOND_P"),
-        ("security", "SECURITY"),
-        ("select", "SELECT"),
-        ("sequence", "SEQUENCE"),
-        ("sequences", "SEQUENCES"),
-        ("serializable", "SERIALIZABLE"),
-        ("server", "SERVER"),
-        ("session", "SESSION"),
-        ("session_user", "SESSION_USER"),
-        ("set", "SET"),
-        ("setof", "SETOF"),
-        ("sets", "SETS"),
-        ("share", "SHARE"),
-        ("show", "SHOW"),
-        ("similar", "SIMILAR"),
-        ("simple", "SIMPLE"),
-        ("skip", "SKIP"),
-        ("smallint", "SMALLINT"),
-        ("snapshot", "SNAPSHOT"),
-        ("some", "SOME"),
-        ("source", "SOURCE"),
-        ("sql", "SQL_P"),
-        ("stable", "STABLE"),
-        ("standalone", "STANDALONE_P"),
-        ("start", "START"),
-        ("statement", "STATEMENT"),
-        ("statistics", "STATISTICS"),
-        ("stdin", "STDIN"),
-        ("stdout", "STDOUT"),
-        ("storage", "STORAGE"),
-        ("stored", "STORED"),
-        ("strict", "STRICT_P"),
-        ("string", "STRING_P"),
-        ("strip", "STRIP_P"),
-        ("subscription", "SUBSCRIPTION"),
-        ("substring", "SUBSTRING"),
-        ("support", "SUPPORT"),
-        ("symmetric", "SYMMETRIC"),
-        ("sysid", "SYSID"),
-        ("system", "SYSTEM_P"),
-        ("system_user", "SYSTEM_USER"),
-        ("table", "TABLE"),
-        ("tables", "TABLES"),
-        ("tablesample", "TABLESAMPLE"),
-        ("tablespace", "TABLESPACE"),
-        ("target", "TARGET"),
-        ("temp", "TEMP"),
-        ("template", "TEMPLATE"),
-        ("temporary", "TEMPORARY"),
-        ("text", "TEXT_P"),
-        ("then", "THEN"),
-        ("ties", "TIES"),
-        ("time", "TIME"),
-        ("timestamp", "TIMESTAMP"),
-        ("to", "TO"),
-        ("trailing", "TRAILING"),
-        ("transaction", "TRANSACTION"),
-        ("transform", "TRANSFORM"),
-        ("treat", "TREAT"),
-        ("trigger", "TRIGGER"),
-        ("trim", "TRIM"),
-        ("true", "TRUE_P"),
-        ("truncate", "TRUNCATE"),
-        ("trusted", "TRUSTED"),
-        ("type", "TYPE_P"),
-        ("types", "TYPES_P"),
-        ("uescape", "UESCAPE"),
-        ("unbounded", "UNBOUNDED"),
-        ("uncommitted", "UNCOMMITTED"),
-        ("unconditional", "UNCONDITIONAL"),
-        ("unencrypted", "UNENCRYPTED"),
-        ("union", "UNION"),
-        ("unique", "UNIQUE"),
-        ("unknown", "UNKNOWN"),
-        ("unlisten", "UNLISTEN"),
-        ("unlogged", "UNLOGGED"),
-        ("until", "UNTIL"),
-        ("update", "UPDATE"),
-        ("user", "USER"),
-        ("using", "USING"),
-        ("vacuum", "VACUUM"),
-        ("valid", "VALID"),
-        ("validate", "VALIDATE"),
-        ("validator", "VALIDATOR"),
-        ("value", "VALUE_P"),
-        ("values", "VALUES"),
-        ("varchar", "VARCHAR"),
-        ("variadic", "VARIADIC"),
-        ("varying", "VARYING"),
-        ("verbose", "VERBOSE"),
-        ("version", "VERSION_P"),
-        ("view", "VIEW"),
-        ("views", "VIEWS"),
-        ("volatile", "VOLATILE"),
-        ("when", "WHEN"),
-        ("where", "WHERE"),
-        ("whitespace", "WHITESPACE_P"),
-        ("window", "WINDOW"),
-        ("with", "WITH"),
-        ("within", "WITHIN"),
-        ("without", "WITHOUT"),
-        ("work", "WORK"),
-        ("wrapper", "WRAPPER"),
-        ("write", "WRITE"),
-        ("xml", "XML_P"),
-        ("xmlattributes", "XMLATTRIBUTES"),
-        ("xmlconcat", "XMLCONCAT"),
-        ("xmlelement", "XMLELEMENT"),
-        ("xmlexists", "XMLEXISTS"),
-        ("xmlforest", "XMLFOREST"),
-        ("xmlnamespaces", "XMLNAMESPACES"),
-        ("xmlparse", "XMLPARSE"),
-        ("xmlpi", "XMLPI"),
-        ("xmlroot", "XMLROOT"),
-        ("xmlserialize", "XMLSERIALIZE"),
-        ("xmltable", "XMLTABLE"),
-        ("year", "YEAR_P"),
-        ("yes", "YES_P"),
-        ("zone", "ZONE"),
-    ] {
-        map.insert(kw, tok);
-    }
-    map
-}
@@ -1,6 +1,10 @@
 mod utils;
 
-use postgresql_cst_parser::parse;
+use postgresql_cst_parser::{
+    highlight::{highlight, HighlightClass},
+    line_index::{LineIndex, PositionEncoding},
+    parse,
+};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -12,8 +16,42 @@ extern "C" {
 pub fn parse_sql(sql: &str) -> String {
     match parse(sql) {
         Ok(resolved_root) => format!("{:#?}", resolved_root),
-        Err(e) => {
-            return format!("Error: {:?}", e);
-        }
+        Err(e) => format!("Error: {:?}", e),
     }
 }
+
+/// Returns highlight ranges as a flat array of `[start, end, class, ...]` triples.
+///
+/// `start` and `end` are offsets in UTF-16 code units, so they can be used as indices of the JavaScript string, and `class` is an index into the array returned by `highlight_class_names`.
+/// An empty array is returned if the SQL cannot be parsed.
+#[wasm_bindgen]
+pub fn highlight_sql(sql: &str) -> Vec<u32> {
+    utils::set_panic_hook();
+
+    let Ok(root) = parse(sql) else {
+        return vec![];
+    };
+
+    let index = LineIndex::new(sql);
+    let offset =
+        |offset: u32| index.encoded_offset(offset as usize, PositionEncoding::Utf16) as u32;
+
+    highlight(&root)
+        .into_iter()
+        .flat_map(|(range, class)| {
+            [
+                offset(range.start().into()),
+                offset(range.end().into()),
+                class.index() as u32,
+            ]
+        })
+        .collect()
+}
+
+#[wasm_bindgen]
+pub fn highlight_class_names() -> Vec<String> {
+    HighlightClass::ALL
+        .iter()
+        .map(|class| class.as_str().to_string())
+        .collect()
+}
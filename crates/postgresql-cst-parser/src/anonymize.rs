@@ -0,0 +1,319 @@
+//! Anonymization of SQL for sharing it safely
+//!
+//! [`anonymize`] replaces constants with placeholders of the same type, and optionally names with pseudonyms.
+//! The rest of the source, including whitespace, is kept as it is, so the output is parseable and keeps the formatting.
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::{anonymize::{anonymize, AnonymizeOptions}, parse};
+//!
+//! let root = parse("select name from users u where u.email = 'a@example.com' and age > 30 -- vip\n;").unwrap();
+//!
+//! assert_eq!(
+//!     anonymize(&root, &AnonymizeOptions::default()),
+//!     "select name from users u where u.email = '?' and age > 0 \n;"
+//! );
+//!
+//! let options = AnonymizeOptions { identifiers: true, keep_comments: true };
+//! assert_eq!(
+//!     anonymize(&root, &options),
+//!     "select c1 from t1 t2 where t2.c2 = '?' and c3 > 0 -- vip\n;"
+//! );
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    syntax_kind::SyntaxKind,
+    value::{TokenValues, Value},
+    LexerOptions, ResolvedNode, ResolvedToken,
+};
+
+/// Settings of [`anonymize`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AnonymizeOptions {
+    /// Whether to replace the names of tables, columns and aliases with pseudonyms
+    ///
+    /// Names of tables and their aliases become `t1`, `t2`, ... and the other names become `c1`, `c2`, ....
+    /// The same name always gets the same pseudonym. Names of functions and types are kept.
+    pub identifiers: bool,
+    /// Whether to keep comments, which may contain data and are removed by default
+    pub keep_comments: bool,
+}
+
+/// Returns the source of `root` with the constants, and optionally names and comments, replaced
+///
+/// String, integer, numeric, bit string and hexadecimal constants become `'?'`, `0`, `0.0`, `B'0'` and `X'00'`.
+/// Type modifiers such as `varchar(10)` and positions in `ORDER BY` and `GROUP BY` are kept.
+pub fn anonymize(root: &ResolvedNode, options: &AnonymizeOptions) -> String {
+    let pseudonyms = options.identifiers.then(|| Pseudonyms::new(root));
+
+    let tokens: Vec<_> = root
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .collect();
+    let mut output = String::new();
+    for (i, token) in tokens.iter().enumerate() {
+        match token.kind() {
+            SyntaxKind::C_COMMENT | SyntaxKind::SQL_COMMENT if !options.keep_comments => {
+                // Keep the tokens around the comment apart, as in `a/* comment */b`
+                let separated = output.ends_with(char::is_whitespace)
+                    || tokens
+                        .get(i + 1)
+                        .is_none_or(|next| next.kind() == SyntaxKind::Whitespace);
+                if !separated {
+                    output.push(' ');
+                }
+            }
+            SyntaxKind::SCONST if !is_kept_constant(token) => output.push_str("'?'"),
+            SyntaxKind::ICONST if !is_kept_constant(token) => output.push('0'),
+            SyntaxKind::FCONST => output.push_str("0.0"),
+            SyntaxKind::BCONST => {
+                output.push_str(&token.text()[..1]);
+                output.push_str("'0'");
+            }
+            SyntaxKind::XCONST => {
+                output.push_str(&token.text()[..1]);
+                output.push_str("'00'");
+            }
+            _ => match pseudonyms.as_ref().and_then(|p| p.get(token)) {
+                Some(pseudonym) => output.push_str(pseudonym),
+                None => output.push_str(token.text()),
+            },
+        }
+    }
+    output
+}
+
+/// Pseudonyms of the names in a tree, assigned in the order of the source
+struct Pseudonyms {
+    names: HashMap<String, String>,
+    values: TokenValues,
+}
+
+impl Pseudonyms {
+    fn new(root: &ResolvedNode) -> Self {
+        // The names are compared as written if the source cannot be lexed with the default settings
+        let values = TokenValues::new(&root.text().to_string(), &LexerOptions::default())
+            .unwrap_or_default();
+        let identifiers: Vec<_> = root
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+            .filter_map(|token| Some((identifier_role(token)?, folded(token, &values))))
+            .collect();
+
+        // A name used for a table is a table name everywhere, such as `t` in `t.a`
+        let tables: HashSet<_> = identifiers
+            .iter()
+            .filter(|(role, _)| *role == Role::Table)
+            .map(|(_, name)| name.clone())
+            .collect();
+
+        let mut names = HashMap::new();
+        let (mut table_count, mut column_count) = (0, 0);
+        for (_, name) in identifiers {
+            if names.contains_key(&name) {
+                continue;
+            }
+            let pseudonym = if tables.contains(&name) {
+                table_count += 1;
+                format!("t{table_count}")
+            } else {
+                column_count += 1;
+                format!("c{column_count}")
+            };
+            names.insert(name, pseudonym);
+        }
+        Self { names, values }
+    }
+
+    fn get(&self, token: &ResolvedToken) -> Option<&str> {
+        identifier_role(token)?;
+        self.names
+            .get(&folded(token, &self.values))
+            .map(String::as_str)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    /// A table, or an alias or `WITH` query used like a table
+    Table,
+    /// A column or any other name
+    Column,
+}
+
+/// Returns the role of a token that is a name to be replaced, or `None` for other tokens
+fn identifier_role(token: &ResolvedToken) -> Option<Role> {
+    let parent = token.parent();
+    let is_name = token.kind() == SyntaxKind::IDENT
+        || (matches!(
+            parent.kind(),
+            SyntaxKind::unreserved_keyword
+                | SyntaxKind::col_name_keyword
+                | SyntaxKind::type_func_name_keyword
+                | SyntaxKind::reserved_keyword
+                | SyntaxKind::bare_label_keyword
+        ) && parent.parent().is_some_and(|grandparent| {
+            matches!(
+                grandparent.kind(),
+                SyntaxKind::ColId
+                    | SyntaxKind::ColLabel
+                    | SyntaxKind::BareColLabel
+                    | SyntaxKind::type_function_name
+                    | SyntaxKind::NonReservedWord
+            )
+        }));
+    if !is_name {
+        return None;
+    }
+
+    let mut role = Role::Column;
+    let mut child = parent;
+    for ancestor in parent.ancestors() {
+        match ancestor.kind() {
+            // Names of functions, types, settings and options are not data
+            SyntaxKind::func_name
+            | SyntaxKind::Typename
+            | SyntaxKind::var_name
+            | SyntaxKind::utility_option_name
+            | SyntaxKind::copy_generic_opt_elem
+            | SyntaxKind::reloption_elem
+            | SyntaxKind::def_elem
+            | SyntaxKind::opt_collate_clause
+            | SyntaxKind::VariableSetStmt
+            | SyntaxKind::VariableShowStmt
+            | SyntaxKind::VariableResetStmt => return None,
+            // `COLLATE name`
+            SyntaxKind::any_name
+                if ancestor
+                    .parent()
+                    .is_some_and(|parent| parent.kind() == SyntaxKind::a_expr) =>
+            {
+                return None
+            }
+            SyntaxKind::qualified_name | SyntaxKind::relation_expr | SyntaxKind::any_name => {
+                role = Role::Table
+            }
+            SyntaxKind::alias_clause if child.kind() == SyntaxKind::ColId => role = Role::Table,
+            SyntaxKind::common_table_expr if child.kind() == SyntaxKind::name => role = Role::Table,
+            _ => {}
+        }
+        child = ancestor;
+    }
+    Some(role)
+}
+
+/// Returns the name with the case folded as PostgreSQL does
+fn folded(token: &ResolvedToken, values: &TokenValues) -> String {
+    match values.get(token) {
+        Some(Value::Identifier(name) | Value::Keyword(name)) => name.clone(),
+        _ => token.text().to_string(),
+    }
+}
+
+/// Returns whether the constant is not data: a type modifier, or a position in `ORDER BY` or `GROUP BY`
+fn is_kept_constant(token: &ResolvedToken) -> bool {
+    let mut ancestors = token.parent().ancestors();
+    if ancestors.any(|ancestor| ancestor.kind() == SyntaxKind::Typename) {
+        return true;
+    }
+    if token.kind() != SyntaxKind::ICONST {
+        return false;
+    }
+
+    // ICONST in AexprConst in c_expr in a_expr
+    let expr = token
+        .parent()
+        .ancestors()
+        .find(|ancestor| ancestor.kind() == SyntaxKind::a_expr);
+    expr.filter(|expr| expr.text().to_string().trim() == token.text())
+        .and_then(|expr| expr.parent())
+        .is_some_and(|parent| {
+            matches!(
+                parent.kind(),
+                SyntaxKind::sortby | SyntaxKind::group_by_item
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+
+    use super::{anonymize, AnonymizeOptions};
+
+    fn anonymize_all(src: &str) -> String {
+        let root = parse(src).unwrap();
+        let output = anonymize(
+            &root,
+            &AnonymizeOptions {
+                identifiers: true,
+                keep_comments: false,
+            },
+        );
+        assert!(parse(&output).is_ok(), "{output}");
+        output
+    }
+
+    #[test]
+    fn constants() {
+        let root = parse(
+            "select 'it''s', e'\\n', $$x$$, -1, 1.5, 12345678901, b'01', X'ff', v::varchar(10)
+             from t group by 1 order by 2, a + 3 limit 10;",
+        )
+        .unwrap();
+        assert_eq!(
+            anonymize(&root, &AnonymizeOptions::default()),
+            "select '?', '?', '?', -0, 0.0, 0.0, b'0', X'00', v::varchar(10)
+             from t group by 1 order by 2, a + 0 limit 0;"
+        );
+    }
+
+    #[test]
+    fn identifiers() {
+        assert_eq!(
+            anonymize_all(
+                r#"with recent as (select "Id", name as n from public.orders o where o.at > now())
+                   select r."Id", count(*) from recent as r (id) join items on items.id = r.id
+                   where r.name collate "C" = 'x' group by r."Id";"#
+            ),
+            r#"with t1 as (select c1, c2 as c3 from t2.t3 t4 where t4.c4 > now())
+                   select t5.c1, count(*) from t1 as t5 (c5) join t6 on t6.c5 = t5.c5
+                   where t5.c2 collate "C" = '?' group by t5.c1;"#
+        );
+        assert_eq!(
+            anonymize_all(
+                "insert into Accounts (id, balance) values (1, 2.5) on conflict do nothing;"
+            ),
+            "insert into t1 (c1, c2) values (0, 0.0) on conflict do nothing;"
+        );
+        assert_eq!(
+            anonymize_all(
+                "create table t (a int not null, b text default 'x'); set search_path = s;"
+            ),
+            "create table t1 (c1 int not null, c2 text default '?'); set search_path = s;"
+        );
+    }
+
+    #[test]
+    fn comments() {
+        let src = "select a/* x */+ 1 -- y\nfrom t;";
+        let root = parse(src).unwrap();
+        assert_eq!(
+            anonymize(&root, &AnonymizeOptions::default()),
+            "select a + 0 \nfrom t;"
+        );
+        assert_eq!(
+            anonymize(
+                &root,
+                &AnonymizeOptions {
+                    identifiers: false,
+                    keep_comments: true
+                }
+            ),
+            "select a/* x */+ 0 -- y\nfrom t;"
+        );
+    }
+}
@@ -1,11 +1,129 @@
-use std::io::Read;
+use std::io::{IsTerminal, Read};
 
+use postgresql_cst_parser::{
+    diagnostic::{Diagnostic, Renderer},
+    diff::{diff, unified},
+    logparse::{extract, LogFormat},
+    ParserError, ResolvedNode,
+};
+
+const USAGE: &str = "usage: parse < SQL
+       parse diff OLD NEW
+       parse logparse [--csv] LOG";
+
+/// Prints the syntax tree of the SQL read from stdin, or runs a subcommand
+///
+/// - `diff OLD NEW` compares two SQL files, exiting with 0 if they are the same, 1 if they differ and 2 on errors like diff(1)
+/// - `logparse [--csv] LOG` parses the statements in a PostgreSQL log, exiting with 0 if all of them parse,
+///   1 if some do not and 2 on errors. Files ending with `.csv` are read in the csvlog format,
+///   and the others in the stderr format unless `--csv` is given.
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.split_first() {
+        None => print_tree(),
+        Some((command, args)) if command == "diff" => diff_files(args),
+        Some((command, args)) if command == "logparse" => parse_log(args),
+        Some(_) => {
+            eprintln!("{USAGE}");
+            std::process::exit(2);
+        }
+    }
+}
+
+fn print_tree() {
     let mut sql = String::new();
     std::io::stdin().read_to_string(&mut sql).unwrap();
 
     match postgresql_cst_parser::parse(&sql) {
         Ok(tree) => println!("{tree:#?}"),
-        Err(e) => eprintln!("{e:#?}"),
+        Err(e) => {
+            eprint!("{}", render_error(&sql, &e));
+            std::process::exit(1);
+        }
+    }
+}
+
+fn diff_files(args: &[String]) {
+    let [old_path, new_path] = args else {
+        eprintln!("{USAGE}");
+        std::process::exit(2);
+    };
+
+    let old = parse_file(old_path);
+    let new = parse_file(new_path);
+    let changes = diff(&old, &new);
+    if changes.is_empty() {
+        return;
+    }
+
+    println!("--- {old_path}");
+    println!("+++ {new_path}");
+    print!("{}", unified(&old, &new, &changes));
+    std::process::exit(1);
+}
+
+fn parse_log(args: &[String]) {
+    let (csv, path) = match args {
+        [flag, path] if flag == "--csv" => (true, path),
+        [path] => (path.ends_with(".csv"), path),
+        _ => {
+            eprintln!("{USAGE}");
+            std::process::exit(2);
+        }
+    };
+
+    let log = std::fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("{path}: {e}");
+        std::process::exit(2);
+    });
+    let format = if csv {
+        LogFormat::Csv
+    } else {
+        LogFormat::Stderr
+    };
+
+    let renderer = if std::io::stdout().is_terminal() {
+        Renderer::ansi()
+    } else {
+        Renderer::plain()
+    };
+    let statements = extract(&log, format);
+    let mut failed = 0;
+    for statement in &statements {
+        if let Err(e) = statement.parse() {
+            failed += 1;
+            print!(
+                "{path}:{}: {}",
+                statement.line,
+                renderer.render(&statement.sql, &Diagnostic::from(&e))
+            );
+        }
+    }
+
+    println!("{} statements, {failed} failed to parse", statements.len());
+    if failed > 0 {
+        std::process::exit(1);
     }
 }
+
+fn parse_file(path: &str) -> ResolvedNode {
+    let sql = std::fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("{path}: {e}");
+        std::process::exit(2);
+    });
+
+    postgresql_cst_parser::parse(&sql).unwrap_or_else(|e| {
+        eprint!("{path}: {}", render_error(&sql, &e));
+        std::process::exit(2);
+    })
+}
+
+/// Renders an error for stderr, with colors if it is a terminal
+fn render_error(sql: &str, error: &ParserError) -> String {
+    let renderer = if std::io::stderr().is_terminal() {
+        Renderer::ansi()
+    } else {
+        Renderer::plain()
+    };
+    renderer.render(sql, &Diagnostic::from(error))
+}
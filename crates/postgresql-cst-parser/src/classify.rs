@@ -0,0 +1,369 @@
+//! Classification of statements
+//!
+//! [`classify`] tells the [`Category`] of a statement, whether it can run on a read-only standby,
+//! and whether it can run inside a transaction block.
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::{classify::{classify, Category}, parse, syntax_kind::SyntaxKind};
+//!
+//! let root = parse("select * from t for update; create index concurrently on t (a);").unwrap();
+//! let infos: Vec<_> = root
+//!     .descendants()
+//!     .filter(|node| node.kind() == SyntaxKind::toplevel_stmt)
+//!     .filter_map(classify)
+//!     .map(|info| (info.category, info.read_only, info.transaction_block))
+//!     .collect();
+//!
+//! assert_eq!(infos, [(Category::Dml, false, true), (Category::Ddl, false, false)]);
+//! ```
+
+use crate::{
+    cst_util::{child, tokens},
+    syntax_kind::SyntaxKind,
+    value::{token_value, Value},
+    ResolvedNode,
+};
+
+/// Category of a statement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    /// Data definition, such as `CREATE TABLE`, `ALTER TABLE` and `TRUNCATE`
+    Ddl,
+    /// Data manipulation, such as `SELECT`, `INSERT` and `COPY`
+    Dml,
+    /// Data control, such as `GRANT`, `REVOKE` and `CREATE ROLE`
+    Dcl,
+    /// Transaction control, such as `BEGIN`, `COMMIT` and `SAVEPOINT`
+    Tcl,
+    /// Other statements, such as `EXPLAIN`, `VACUUM` and `SET`
+    Utility,
+}
+
+/// Properties of a statement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementInfo {
+    /// Kind of the statement node, such as `SelectStmt`
+    pub kind: SyntaxKind,
+    pub category: Category,
+    /// Whether the statement can run on a read-only standby
+    ///
+    /// `false` if unknown, such as `EXECUTE` of a prepared statement.
+    pub read_only: bool,
+    /// Whether the statement can run inside a transaction block
+    pub transaction_block: bool,
+}
+
+/// Functions that write or need a transaction id, and fail on a standby
+const WRITING_FUNCTIONS: &[&str] = &[
+    "lo_creat",
+    "lo_create",
+    "lo_from_bytea",
+    "lo_import",
+    "lo_put",
+    "lo_unlink",
+    "nextval",
+    "pg_create_restore_point",
+    "pg_current_xact_id",
+    "pg_notify",
+    "pg_switch_wal",
+    "setval",
+    "txid_current",
+];
+
+/// Classifies a statement
+///
+/// `stmt` is a `toplevel_stmt` or `stmt` node, or the statement node itself such as `SelectStmt`.
+/// Returns `None` if it is none of them.
+pub fn classify(stmt: &ResolvedNode) -> Option<StatementInfo> {
+    let stmt = unwrap_stmt(stmt)?;
+    let kind = stmt.kind();
+    if !is_statement(kind) {
+        return None;
+    }
+
+    Some(StatementInfo {
+        kind,
+        category: category(kind),
+        read_only: is_read_only(stmt),
+        transaction_block: allows_transaction_block(stmt),
+    })
+}
+
+/// Descends `toplevel_stmt` and `stmt` to the statement node
+fn unwrap_stmt(node: &ResolvedNode) -> Option<&ResolvedNode> {
+    let mut node = node;
+    while matches!(node.kind(), SyntaxKind::toplevel_stmt | SyntaxKind::stmt) {
+        node = node.children().next()?;
+    }
+    Some(node)
+}
+
+fn is_statement(kind: SyntaxKind) -> bool {
+    let name = format!("{kind:?}");
+    name.ends_with("Stmt") || kind == SyntaxKind::TransactionStmtLegacy
+}
+
+fn category(kind: SyntaxKind) -> Category {
+    use SyntaxKind::*;
+
+    match kind {
+        SelectStmt | InsertStmt | UpdateStmt | DeleteStmt | MergeStmt | CopyStmt | CallStmt => {
+            Category::Dml
+        }
+        GrantStmt
+        | RevokeStmt
+        | GrantRoleStmt
+        | RevokeRoleStmt
+        | AlterDefaultPrivilegesStmt
+        | CreateRoleStmt
+        | CreateUserStmt
+        | CreateGroupStmt
+        | AlterRoleStmt
+        | AlterRoleSetStmt
+        | AlterGroupStmt
+        | DropRoleStmt => Category::Dcl,
+        TransactionStmt | TransactionStmtLegacy | ConstraintsSetStmt => Category::Tcl,
+        ExplainStmt | VacuumStmt | AnalyzeStmt | ClusterStmt | ReindexStmt | CheckPointStmt
+        | LoadStmt | ListenStmt | NotifyStmt | UnlistenStmt | DiscardStmt | PrepareStmt
+        | ExecuteStmt | DeallocateStmt | DeclareCursorStmt | FetchStmt | ClosePortalStmt
+        | VariableSetStmt | VariableShowStmt | VariableResetStmt | LockStmt | DoStmt
+        | AlterSystemStmt | ReturnStmt | PLAssignStmt => Category::Utility,
+        _ => Category::Ddl,
+    }
+}
+
+fn is_read_only(node: &ResolvedNode) -> bool {
+    use SyntaxKind::*;
+
+    match node.kind() {
+        SelectStmt => is_read_only_select(node),
+        CopyStmt => {
+            child(node, copy_from).is_some_and(|from| first_token_is(from, SyntaxKind::TO))
+                && child(node, PreparableStmt)
+                    .and_then(|query| query.children().next())
+                    .is_none_or(is_read_only)
+        }
+        ExplainStmt => {
+            let explained =
+                child(node, ExplainableStmt).and_then(|explained| explained.children().next());
+            !explain_analyzes(node) || explained.is_some_and(is_read_only)
+        }
+        PrepareStmt => child(node, PreparableStmt)
+            .and_then(|query| query.children().next())
+            .is_some_and(is_read_only),
+        DeclareCursorStmt => child(node, SelectStmt).is_some_and(is_read_only),
+        // `PREPARE TRANSACTION`, `COMMIT PREPARED` and `ROLLBACK PREPARED` are not allowed on a standby
+        TransactionStmt | TransactionStmtLegacy => !tokens(node)
+            .any(|token| matches!(token.kind(), SyntaxKind::PREPARE | SyntaxKind::PREPARED)),
+        ConstraintsSetStmt | VariableSetStmt | VariableShowStmt | VariableResetStmt | FetchStmt
+        | ClosePortalStmt | DeallocateStmt | DiscardStmt | UnlistenStmt | LoadStmt => true,
+        _ => false,
+    }
+}
+
+/// Returns whether the `SELECT` neither locks rows, creates a table, modifies data in `WITH` nor calls a writing function
+fn is_read_only_select(select: &ResolvedNode) -> bool {
+    let mut nodes = select
+        .descendants()
+        .filter(|node| !node.text_range().is_empty());
+    !nodes.any(|node| match node.kind() {
+        // `FOR READ ONLY` is also a locking clause
+        SyntaxKind::for_locking_clause => {
+            !tokens(node).any(|token| token.kind() == SyntaxKind::READ)
+        }
+        SyntaxKind::into_clause => true,
+        SyntaxKind::PreparableStmt => node
+            .children()
+            .next()
+            .is_some_and(|query| query.kind() != SyntaxKind::SelectStmt),
+        SyntaxKind::func_name => {
+            let name = tokens(node)
+                .last()
+                .map(|token| match token_value(token) {
+                    Some(Value::Identifier(name) | Value::Keyword(name)) => name,
+                    _ => token.text().to_string(),
+                })
+                .unwrap_or_default();
+            WRITING_FUNCTIONS.contains(&name.as_str())
+        }
+        _ => false,
+    })
+}
+
+/// Returns whether `EXPLAIN` runs the statement, with `ANALYZE` or `(ANALYZE [true])`
+fn explain_analyzes(explain: &ResolvedNode) -> bool {
+    if child(explain, SyntaxKind::analyze_keyword).is_some() {
+        return true;
+    }
+
+    explain
+        .descendants()
+        .filter(|node| node.kind() == SyntaxKind::utility_option_elem)
+        .filter(|elem| {
+            child(elem, SyntaxKind::utility_option_name)
+                .is_some_and(|name| child(name, SyntaxKind::analyze_keyword).is_some())
+        })
+        .any(|elem| {
+            child(elem, SyntaxKind::utility_option_arg).is_none_or(|arg| {
+                let arg = arg.text().to_string().trim().to_ascii_lowercase();
+                !matches!(arg.as_str(), "false" | "off" | "0" | "'false'" | "'off'")
+            })
+        })
+}
+
+fn allows_transaction_block(node: &ResolvedNode) -> bool {
+    use SyntaxKind::*;
+
+    match node.kind() {
+        VacuumStmt
+        | CreatedbStmt
+        | DropdbStmt
+        | CreateTableSpaceStmt
+        | DropTableSpaceStmt
+        | AlterSystemStmt
+        | CreateSubscriptionStmt
+        | DropSubscriptionStmt => false,
+        // `CREATE INDEX CONCURRENTLY`, `DROP INDEX CONCURRENTLY` and `DETACH PARTITION ... CONCURRENTLY`
+        IndexStmt | DropStmt | AlterTableStmt => {
+            !tokens(node).any(|token| token.kind() == SyntaxKind::CONCURRENTLY)
+        }
+        // `REINDEX ... CONCURRENTLY`, `REINDEX DATABASE` and `REINDEX SYSTEM`
+        ReindexStmt => {
+            child(node, reindex_target_all).is_none()
+                && !tokens(node).any(|token| token.kind() == SyntaxKind::CONCURRENTLY)
+        }
+        // `CLUSTER` without a table
+        ClusterStmt => child(node, qualified_name).is_some(),
+        DiscardStmt => !tokens(node).any(|token| token.kind() == SyntaxKind::ALL),
+        // `COMMIT PREPARED` and `ROLLBACK PREPARED`
+        TransactionStmt => !tokens(node).any(|token| token.kind() == SyntaxKind::PREPARED),
+        // `ALTER DATABASE ... SET TABLESPACE`
+        AlterDatabaseStmt => !tokens(node).any(|token| token.kind() == SyntaxKind::TABLESPACE),
+        _ => true,
+    }
+}
+
+fn first_token_is(node: &ResolvedNode, kind: SyntaxKind) -> bool {
+    tokens(node)
+        .next()
+        .is_some_and(|token| token.kind() == kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, syntax_kind::SyntaxKind};
+
+    use super::{classify, Category};
+
+    /// Returns the category, read-only flag and transaction block flag of each statement
+    fn classify_all(src: &str) -> Vec<(Category, bool, bool)> {
+        parse(src)
+            .unwrap()
+            .descendants()
+            .filter(|node| node.kind() == SyntaxKind::toplevel_stmt)
+            .filter(|node| !node.text_range().is_empty())
+            .map(|stmt| {
+                let info = classify(stmt).unwrap();
+                (info.category, info.read_only, info.transaction_block)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn select() {
+        assert_eq!(
+            classify_all(
+                "select a from t where b in (select c from u);
+                 select a from t for update;
+                 select a from t for read only;
+                 with d as (delete from t returning *) select * from d;
+                 with s as (select 1) select * from s;
+                 select nextval('s'), now();
+                 select 1 into u;"
+            ),
+            [
+                (Category::Dml, true, true),
+                (Category::Dml, false, true),
+                (Category::Dml, true, true),
+                (Category::Dml, false, true),
+                (Category::Dml, true, true),
+                (Category::Dml, false, true),
+                (Category::Dml, false, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn categories() {
+        assert_eq!(
+            classify_all(
+                "insert into t values (1);
+                 copy t to stdout;
+                 copy t from stdin;
+                 create table t (a int);
+                 truncate t;
+                 grant select on t to r;
+                 begin;
+                 start transaction read only;
+                 commit prepared 'x';
+                 set search_path = s;
+                 explain delete from t;
+                 explain analyze delete from t;
+                 explain (analyze false) delete from t;"
+            ),
+            [
+                (Category::Dml, false, true),
+                (Category::Dml, true, true),
+                (Category::Dml, false, true),
+                (Category::Ddl, false, true),
+                (Category::Ddl, false, true),
+                (Category::Dcl, false, true),
+                (Category::Tcl, true, true),
+                (Category::Tcl, true, true),
+                (Category::Tcl, false, false),
+                (Category::Utility, true, true),
+                (Category::Utility, true, true),
+                (Category::Utility, false, true),
+                (Category::Utility, true, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn transaction_block() {
+        let transaction_block: Vec<_> = classify_all(
+            "create index concurrently on t (a);
+             create index on t (a);
+             drop index concurrently i;
+             reindex table concurrently t;
+             reindex database d;
+             reindex table t;
+             vacuum t;
+             create database d;
+             cluster;
+             cluster t;
+             discard all;
+             refresh materialized view concurrently v;",
+        )
+        .into_iter()
+        .map(|(_, _, transaction_block)| transaction_block)
+        .collect();
+        assert_eq!(
+            transaction_block,
+            [false, true, false, false, false, true, false, false, false, true, false, true]
+        );
+    }
+
+    #[test]
+    fn not_a_statement() {
+        let root = parse("select 1;").unwrap();
+        assert_eq!(classify(&root), None);
+        let select = root
+            .descendants()
+            .find(|node| node.kind() == SyntaxKind::SelectStmt)
+            .unwrap();
+        assert_eq!(classify(select).unwrap().kind, SyntaxKind::SelectStmt);
+    }
+}
@@ -4,12 +4,19 @@ pub(crate) mod lr_parse_state;
 pub(crate) use extra::*;
 pub(crate) use lr_parse_state::*;
 
+use std::collections::HashSet;
+
 use cstree::{
     build::GreenNodeBuilder, green::GreenNode, interning::Resolver, RawSyntaxKind, Syntax,
 };
 
 use crate::{
-    lexer::{lex, lexer_ported::init_tokens, parser_error::ParserError, TokenKind},
+    lexer::{
+        is_psql_variable, lex_with_options,
+        lexer_ported::init_tokens,
+        parser_error::{ParserError, ScanReport},
+        LexerOptions, TokenKind,
+    },
     parser::{
         end_rule_id, end_rule_kind, num_terminal_symbol, rule_name_to_component_id,
         token_kind_to_component_id, Action, ACTION_CHECK_TABLE, ACTION_DEF_RULE_TABLE,
@@ -30,6 +37,8 @@ pub(crate) struct Node {
     children: Vec<Node>,
     start_byte_pos: usize,
     end_byte_pos: usize,
+    /// A subtree of a previous syntax tree copied as is, see [`reparse`]
+    reused: Option<ResolvedNode>,
 }
 
 impl From<&Node> for SyntaxKind {
@@ -100,7 +109,15 @@ impl Parser {
         }
 
         let kind: SyntaxKind = SyntaxKind::from_raw(RawSyntaxKind(node.component_id));
-        if let Some(token) = &node.token {
+        if let Some(reused) = &node.reused {
+            // The extras inside the reused node are copied together with it
+            self.copy_rec(reused);
+        } else if let Some(token) = &node.token {
+            let kind = if is_psql_variable(token) {
+                SyntaxKind::PSQL_VARIABLE
+            } else {
+                kind
+            };
             self.builder.token(kind, &token.value);
         } else {
             self.builder.start_node(kind);
@@ -111,6 +128,19 @@ impl Parser {
         }
     }
 
+    fn copy_rec(&mut self, node: &ResolvedNode) {
+        self.builder.start_node(node.kind());
+        for child in node.children_with_tokens() {
+            match child {
+                cstree::util::NodeOrToken::Node(node) => self.copy_rec(node),
+                cstree::util::NodeOrToken::Token(token) => {
+                    self.builder.token(token.kind(), token.text())
+                }
+            }
+        }
+        self.builder.finish_node();
+    }
+
     fn parse(mut self, nodes: &Vec<&Node>, extras: Vec<Extra>) -> (GreenNode, impl Resolver) {
         let mut peekable = extras.into_iter().peekable();
 
@@ -132,6 +162,35 @@ impl Parser {
     }
 }
 
+/// Returns the kind of a token not passed to the parser, such as comments and psql meta-commands
+fn trivia_kind(kind: &TokenKind) -> Option<SyntaxKind> {
+    match kind {
+        TokenKind::C_COMMENT => Some(SyntaxKind::C_COMMENT),
+        TokenKind::SQL_COMMENT => Some(SyntaxKind::SQL_COMMENT),
+        TokenKind::PSQL_META_COMMAND => Some(SyntaxKind::PSQL_META_COMMAND),
+        TokenKind::COPY_DATA => Some(SyntaxKind::COPY_DATA),
+        _ => None,
+    }
+}
+
+/// Returns a syntax error at a token, worded like PostgreSQL's
+fn syntax_error(input: &str, start_byte_pos: usize, end_byte_pos: usize) -> ParserError {
+    let message = if start_byte_pos >= input.len() {
+        "syntax error at end of input".to_string()
+    } else {
+        format!(
+            "syntax error at or near \"{}\"",
+            &input[start_byte_pos..end_byte_pos]
+        )
+    };
+
+    ParserError::ParseError {
+        message,
+        start_byte_pos,
+        end_byte_pos,
+    }
+}
+
 pub(crate) fn lookup_parser_action(state: u32, cid: u32) -> i16 {
     let state = state as usize;
     let cid = cid as usize;
@@ -170,11 +229,106 @@ pub fn parse_with_transformer(
     input: &str,
     transformers: &[&dyn ParseTransformer],
 ) -> Result<ResolvedNode, ParserError> {
-    let mut tokens = lex(input)?;
+    parse_with_warnings(input, transformers).map(|parsed| parsed.root)
+}
+
+/// The syntax tree and the non-fatal reports of the lexer
+#[derive(Debug)]
+pub struct Parsed {
+    pub root: ResolvedNode,
+    /// Warnings and notices, such as `nonstandard use of \\ in a string literal` and identifier truncation
+    pub warnings: Vec<ScanReport>,
+}
+
+/// Settings for parsing
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    pub lexer: LexerOptions,
+}
+
+/// Same as [`parse_with_transformer`], but also returns the warnings of the lexer
+pub fn parse_with_warnings(
+    input: &str,
+    transformers: &[&dyn ParseTransformer],
+) -> Result<Parsed, ParserError> {
+    parse_with_options(input, transformers, &ParseOptions::default())
+}
+
+/// Same as [`parse_with_warnings`], but with the given settings
+pub fn parse_with_options(
+    input: &str,
+    transformers: &[&dyn ParseTransformer],
+    options: &ParseOptions,
+) -> Result<Parsed, ParserError> {
+    parse_reusing(input, transformers, options, None)
+}
+
+/// Parses the edited source of a tree, copying the leading statements that are not changed
+///
+/// The statements are reused up to the last `;` before the first changed byte,
+/// because the lexer and the parser never look past a `;` when they handle the text before it.
+/// `old` must have been parsed from `old_input` with the same transformers and settings.
+#[cfg(feature = "tree-sitter-like")]
+pub(crate) fn reparse(
+    old: &ResolvedNode,
+    old_input: &str,
+    input: &str,
+    transformers: &[&dyn ParseTransformer],
+    options: &ParseOptions,
+) -> Result<ResolvedNode, ParserError> {
+    let unchanged = old_input
+        .bytes()
+        .zip(input.bytes())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let reused = reusable_statements(old, unchanged);
+    parse_reusing(input, transformers, options, reused).map(|parsed| parsed.root)
+}
+
+/// Returns the largest `stmtmulti` of `old` followed by a `;` that ends within the first `unchanged` bytes
+#[cfg(feature = "tree-sitter-like")]
+fn reusable_statements(old: &ResolvedNode, unchanged: usize) -> Option<&ResolvedNode> {
+    let parse_toplevel = old
+        .children()
+        .find(|node| node.kind() == SyntaxKind::parse_toplevel)?;
+    let mut stmtmulti = parse_toplevel
+        .first_child()
+        .filter(|node| node.kind() == SyntaxKind::stmtmulti)?;
 
-    if !tokens.is_empty() {
-        init_tokens(&mut tokens);
+    loop {
+        // stmtmulti: stmtmulti ';' toplevel_stmt | toplevel_stmt
+        let inner = stmtmulti
+            .first_child()
+            .filter(|node| node.kind() == SyntaxKind::stmtmulti)?;
+        let semicolon = stmtmulti
+            .children_with_tokens()
+            .find(|child| child.kind() == SyntaxKind::Semicolon)?;
+
+        if usize::from(semicolon.text_range().end()) <= unchanged {
+            return Some(inner);
+        }
+        stmtmulti = inner;
     }
+}
+
+fn parse_reusing(
+    input: &str,
+    transformers: &[&dyn ParseTransformer],
+    options: &ParseOptions,
+    reused: Option<&ResolvedNode>,
+) -> Result<Parsed, ParserError> {
+    let (mut tokens, mut warnings) = lex_with_options(input, &options.lexer)?;
+
+    // The tokens of `UESCAPE 'c'` clauses, which are added to the tree like comments
+    let uescape_clauses: HashSet<usize> = if tokens.is_empty() {
+        HashSet::new()
+    } else {
+        init_tokens(&mut tokens, &options.lexer, &mut warnings)?
+            .into_iter()
+            .map(|i| tokens[i].start_byte_pos)
+            .collect()
+    };
 
     tokens.push(Token {
         kind: end_rule_kind(),
@@ -218,6 +372,58 @@ pub fn parse_with_transformer(
     }
 
     let mut stack: Vec<(u32, Node)> = Vec::new();
+    let mut last_pos = 0;
+    let mut extras: Vec<Extra> = Vec::new();
+
+    // Only the comments before the reused statements are left, the others are copied with the statements
+    let reused = reused.map(|reused| {
+        let start_byte_pos = usize::from(reused.text_range().start());
+        let end_byte_pos = usize::from(reused.text_range().end());
+
+        for token in tokens
+            .iter()
+            .take_while(|token| token.end_byte_pos <= start_byte_pos)
+        {
+            if let Some(kind) = trivia_kind(&token.kind) {
+                if last_pos < token.start_byte_pos {
+                    extras.push(Extra {
+                        kind: SyntaxKind::Whitespace,
+                        start_byte_pos: last_pos,
+                        end_byte_pos: token.start_byte_pos,
+                        comment: &input[last_pos..token.start_byte_pos],
+                    });
+                }
+                last_pos = token.end_byte_pos;
+
+                extras.push(Extra {
+                    kind,
+                    start_byte_pos: token.start_byte_pos,
+                    end_byte_pos: token.end_byte_pos,
+                    comment: &input[token.start_byte_pos..token.end_byte_pos],
+                });
+            }
+        }
+        if last_pos < start_byte_pos {
+            extras.push(Extra {
+                kind: SyntaxKind::Whitespace,
+                start_byte_pos: last_pos,
+                end_byte_pos: start_byte_pos,
+                comment: &input[last_pos..start_byte_pos],
+            });
+        }
+        last_pos = end_byte_pos;
+        tokens.retain(|token| token.start_byte_pos >= end_byte_pos);
+
+        Node {
+            token: None,
+            component_id: SyntaxKind::stmtmulti as u32,
+            children: Vec::new(),
+            start_byte_pos,
+            end_byte_pos,
+            reused: Some(reused.clone()),
+        }
+    });
+
     let mut tokens = TokenQueue::new(tokens);
 
     stack.push((
@@ -228,28 +434,30 @@ pub fn parse_with_transformer(
             children: Vec::new(),
             start_byte_pos: 0,
             end_byte_pos: 0,
+            reused: None,
         },
     ));
 
-    let mut last_pos = 0;
-    let mut extras: Vec<Extra> = Vec::new();
+    if let Some(node) = reused {
+        let goto = lookup_goto_state(0, node.component_id - num_terminal_symbol());
+        stack.push((goto as u32, node));
+    }
 
     loop {
         let state = stack.last().unwrap().0;
         let mut token = match tokens.peek() {
             Some(token) => token.clone(),
             None => {
-                return Err(ParserError::ParseError {
-                    message: "unexpected end of input".to_string(),
-                    start_byte_pos: input.len(),
-                    end_byte_pos: input.len(),
-                });
+                return Err(syntax_error(input, input.len(), input.len()));
             }
         };
 
-        let mut cid = token_kind_to_component_id(&token.kind);
-
-        if matches!(token.kind, TokenKind::C_COMMENT | TokenKind::SQL_COMMENT) {
+        let kind = trivia_kind(&token.kind).or_else(|| {
+            uescape_clauses.contains(&token.start_byte_pos).then(|| {
+                SyntaxKind::from_raw(RawSyntaxKind(token_kind_to_component_id(&token.kind)))
+            })
+        });
+        if let Some(kind) = kind {
             if last_pos < token.start_byte_pos {
                 extras.push(Extra {
                     kind: SyntaxKind::Whitespace,
@@ -261,7 +469,6 @@ pub fn parse_with_transformer(
 
             last_pos = token.end_byte_pos;
 
-            let kind = SyntaxKind::from_raw(RawSyntaxKind(cid));
             extras.push(Extra {
                 kind,
                 start_byte_pos: token.start_byte_pos,
@@ -273,6 +480,8 @@ pub fn parse_with_transformer(
             continue;
         }
 
+        let mut cid = token_kind_to_component_id(&token.kind);
+
         let mut action = match lookup_parser_action(state, cid) {
             0x7FFF => Action::Error,
             v if v > 0 => Action::Shift((v - 1) as usize),
@@ -349,6 +558,7 @@ pub fn parse_with_transformer(
                     children: Vec::new(),
                     start_byte_pos: token.start_byte_pos,
                     end_byte_pos: token.end_byte_pos,
+                    reused: None,
                 };
 
                 if last_pos < token.start_byte_pos {
@@ -400,6 +610,7 @@ pub fn parse_with_transformer(
                     children,
                     start_byte_pos,
                     end_byte_pos,
+                    reused: None,
                 };
 
                 let next_state = stack.last().unwrap().0;
@@ -410,14 +621,11 @@ pub fn parse_with_transformer(
                         stack.push((next_state as u32, node));
                     }
                     _ => {
-                        return Err(ParserError::ParseError {
-                            message: format!(
-                                "syntax error at byte position {}",
-                                token.start_byte_pos
-                            ),
-                            start_byte_pos: token.start_byte_pos,
-                            end_byte_pos: token.end_byte_pos,
-                        });
+                        return Err(syntax_error(
+                            input,
+                            token.start_byte_pos,
+                            token.end_byte_pos,
+                        ));
                     }
                 }
             }
@@ -425,14 +633,11 @@ pub fn parse_with_transformer(
                 break;
             }
             Action::Error => {
-                return Err(ParserError::ParseError {
-                    message: format!(
-                        "Action::Error: syntax error at byte position {}",
-                        token.start_byte_pos
-                    ),
-                    start_byte_pos: token.start_byte_pos,
-                    end_byte_pos: token.end_byte_pos,
-                });
+                return Err(syntax_error(
+                    input,
+                    token.start_byte_pos,
+                    token.end_byte_pos,
+                ));
             }
         }
     }
@@ -454,8 +659,9 @@ pub fn parse_with_transformer(
             break;
         }
 
-        let cid = token_kind_to_component_id(&token.kind);
-        let kind = SyntaxKind::from_raw(RawSyntaxKind(cid));
+        let kind = trivia_kind(&token.kind).unwrap_or_else(|| {
+            SyntaxKind::from_raw(RawSyntaxKind(token_kind_to_component_id(&token.kind)))
+        });
         extras.push(Extra {
             kind,
             start_byte_pos: token.start_byte_pos,
@@ -470,5 +676,8 @@ pub fn parse_with_transformer(
     let root: Vec<&Node> = stack[1..].iter().map(|s| &s.1).collect();
     let (ast, resolver) = parser.parse(&root, extras);
 
-    Ok(SyntaxNode::new_root_with_resolver(ast, resolver))
+    Ok(Parsed {
+        root: SyntaxNode::new_root_with_resolver(ast, resolver),
+        warnings,
+    })
 }
@@ -0,0 +1,54 @@
+//! Helpers for walking the syntax tree, shared by the analyses of this crate
+
+use std::ops::Range;
+
+use cstree::text::TextRange;
+
+use crate::{syntax_kind::SyntaxKind, ResolvedNode, ResolvedToken};
+
+/// Returns whether tokens of the kind are comments, psql meta-commands or `COPY` data, which the grammar does not see
+pub(crate) fn is_extra(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::C_COMMENT
+            | SyntaxKind::SQL_COMMENT
+            | SyntaxKind::PSQL_META_COMMAND
+            | SyntaxKind::COPY_DATA
+    )
+}
+
+/// Returns whether tokens of the kind are whitespace or [extras](is_extra)
+pub(crate) fn is_trivia(kind: SyntaxKind) -> bool {
+    kind == SyntaxKind::Whitespace || is_extra(kind)
+}
+
+/// Returns the child of the kind, ignoring the empty nodes kept without `remove-empty-node`
+pub(crate) fn child(node: &ResolvedNode, kind: SyntaxKind) -> Option<&ResolvedNode> {
+    node.children()
+        .find(|child| child.kind() == kind && !child.text_range().is_empty())
+}
+
+/// Returns the tokens of `node` other than trivia and empty tokens
+pub(crate) fn tokens(node: &ResolvedNode) -> impl Iterator<Item = &ResolvedToken> {
+    node.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| !is_trivia(token.kind()) && !token.text().is_empty())
+}
+
+/// Returns the range of `node` without leading and trailing trivia
+pub(crate) fn trimmed_range(node: &ResolvedNode) -> TextRange {
+    let mut tokens = tokens(node).map(|token| token.text_range());
+    match tokens.next() {
+        Some(first) => first.cover(tokens.last().unwrap_or(first)),
+        None => node.text_range(),
+    }
+}
+
+/// Returns [`trimmed_range`] as a byte range
+pub(crate) fn range(node: &ResolvedNode) -> Range<usize> {
+    byte_range(trimmed_range(node))
+}
+
+pub(crate) fn byte_range(range: TextRange) -> Range<usize> {
+    usize::from(range.start())..usize::from(range.end())
+}
@@ -0,0 +1,408 @@
+//! Rendering of errors as source snippets
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::{diagnostic::{Diagnostic, Renderer}, parse};
+//!
+//! let src = "select a,\n  from t;";
+//! let error = parse(src).unwrap_err();
+//!
+//! let rendered = Renderer::plain().render(src, &Diagnostic::from(&error));
+//! assert_eq!(
+//!     rendered,
+//!     "\
+//! error: syntax error at or near \"from\"
+//!  --> 2:3
+//!   |
+//! 2 |   from t;
+//!   |   ^^^^
+//! "
+//! );
+//! ```
+
+use std::{fmt::Write, ops::Range};
+
+use crate::{
+    line_index::{LineIndex, PositionEncoding},
+    ParserError, ReportLevel, ScanReport,
+};
+
+/// Severity of a [`Diagnostic`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    Error,
+    Warning,
+    Notice,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Notice => "notice",
+        }
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A message about a byte range of the source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub range: Range<usize>,
+    /// PostgreSQL's `DETAIL` text
+    pub detail: Option<String>,
+    /// PostgreSQL's `HINT` text
+    pub hint: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: impl Into<String>, range: Range<usize>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            range,
+            detail: None,
+            hint: None,
+        }
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+}
+
+impl From<&ParserError> for Diagnostic {
+    fn from(error: &ParserError) -> Self {
+        match error {
+            ParserError::ScanReport(report) => Diagnostic::from(report),
+            _ => Diagnostic::new(Severity::Error, error.message(), error.byte_range()),
+        }
+    }
+}
+
+/// Converts a [`ScanReport`], e.g. a warning returned by [`crate::parse_with_warnings`]
+///
+/// The severity follows [`ScanReport::level`].
+impl From<&ScanReport> for Diagnostic {
+    fn from(report: &ScanReport) -> Self {
+        let position = report.position_in_bytes;
+        let severity = match report.level {
+            ReportLevel::Error => Severity::Error,
+            ReportLevel::Warning => Severity::Warning,
+            ReportLevel::Notice => Severity::Notice,
+        };
+        Diagnostic {
+            detail: Some(report.detail.clone()).filter(|s| !s.is_empty()),
+            hint: Some(report.hint.clone()).filter(|s| !s.is_empty()),
+            ..Diagnostic::new(severity, &report.message, position..position)
+        }
+    }
+}
+
+/// Number of columns a tab occupies in the snippet
+const TAB_WIDTH: usize = 4;
+
+/// Number of lines shown at each end of a snippet spanning many lines
+const CONTEXT_LINES: usize = 2;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const BLUE: &str = "\x1b[1;34m";
+
+/// Renders diagnostics as snippets of the source with the line number and an underline
+///
+/// The format follows `codespan-reporting`. Columns in the location are 1-based and count characters, like PostgreSQL's error cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Renderer {
+    color: bool,
+}
+
+impl Renderer {
+    /// A renderer for plain text
+    pub fn plain() -> Self {
+        Self { color: false }
+    }
+
+    /// A renderer using ANSI escape sequences for colors
+    pub fn ansi() -> Self {
+        Self { color: true }
+    }
+
+    pub fn render(&self, src: &str, diagnostic: &Diagnostic) -> String {
+        let index = LineIndex::new(src);
+        let start = index.line_col(diagnostic.range.start, PositionEncoding::Utf8);
+        let mut end = index.line_col(diagnostic.range.end, PositionEncoding::Utf8);
+        // A range ending at the start of a line does not cover the line
+        if end.line > start.line && end.col == 0 {
+            end.line -= 1;
+            end.col = index.line_range(end.line).unwrap().len();
+        }
+
+        let severity_color = match diagnostic.severity {
+            Severity::Error => "\x1b[1;31m",
+            Severity::Warning => "\x1b[1;33m",
+            Severity::Notice => BLUE,
+        };
+
+        let gutter_width = (end.line + 1).to_string().len();
+        let gutter = " ".repeat(gutter_width);
+        let mut out = String::new();
+
+        writeln!(
+            out,
+            "{}: {}",
+            self.paint(severity_color, diagnostic.severity.as_str()),
+            self.paint(BOLD, &diagnostic.message)
+        )
+        .unwrap();
+
+        let location = index.line_col(diagnostic.range.start, PositionEncoding::Utf32);
+        writeln!(
+            out,
+            "{gutter}{} {}:{}",
+            self.paint(BLUE, "-->"),
+            location.line + 1,
+            location.col + 1
+        )
+        .unwrap();
+        writeln!(out, "{gutter} {}", self.paint(BLUE, "|")).unwrap();
+
+        let lines: Vec<usize> = if end.line - start.line + 1 > CONTEXT_LINES * 2 + 1 {
+            (start.line..start.line + CONTEXT_LINES)
+                .chain(end.line + 1 - CONTEXT_LINES..=end.line)
+                .collect()
+        } else {
+            (start.line..=end.line).collect()
+        };
+
+        for (i, &line) in lines.iter().enumerate() {
+            if i > 0 && lines[i - 1] + 1 != line {
+                writeln!(out, "{}", self.paint(BLUE, "...")).unwrap();
+            }
+
+            let line_range = index.line_range(line).unwrap();
+            let text = &src[line_range.clone()];
+            let underline_start = if line == start.line { start.col } else { 0 };
+            let underline_end = if line == end.line {
+                end.col
+            } else {
+                text.len()
+            };
+
+            let number = format!("{:>gutter_width$} |", line + 1);
+            writeln!(
+                out,
+                "{}{}",
+                self.paint(BLUE, &number),
+                if text.is_empty() {
+                    String::new()
+                } else {
+                    format!(" {}", expand_tabs(text))
+                }
+            )
+            .unwrap();
+
+            let padding = display_width(&text[..underline_start]);
+            let width = display_width(&text[underline_start..underline_end.max(underline_start)]);
+            writeln!(
+                out,
+                "{gutter} {} {}{}",
+                self.paint(BLUE, "|"),
+                " ".repeat(padding),
+                self.paint(severity_color, &"^".repeat(width.max(1)))
+            )
+            .unwrap();
+        }
+
+        for (label, text) in [("DETAIL", &diagnostic.detail), ("HINT", &diagnostic.hint)] {
+            if let Some(text) = text {
+                writeln!(
+                    out,
+                    "{gutter} {} {}: {text}",
+                    self.paint(BLUE, "="),
+                    self.paint(BOLD, label)
+                )
+                .unwrap();
+            }
+        }
+
+        out
+    }
+
+    fn paint(&self, style: &str, text: &str) -> String {
+        if self.color {
+            format!("{style}{text}{RESET}")
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+fn expand_tabs(text: &str) -> String {
+    text.replace('\t', &" ".repeat(TAB_WIDTH))
+}
+
+/// Returns the number of terminal columns `text` occupies
+///
+/// East Asian wide characters take two columns. This is an approximation for the common ranges.
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c {
+            '\t' => TAB_WIDTH,
+            '\u{1100}'..='\u{115F}'
+            | '\u{2E80}'..='\u{303E}'
+            | '\u{3041}'..='\u{33FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{A000}'..='\u{A4CF}'
+            | '\u{AC00}'..='\u{D7A3}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{FE30}'..='\u{FE4F}'
+            | '\u{FF00}'..='\u{FF60}'
+            | '\u{FFE0}'..='\u{FFE6}'
+            | '\u{1F300}'..='\u{1F64F}'
+            | '\u{1F900}'..='\u{1F9FF}'
+            | '\u{20000}'..='\u{3FFFD}' => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+
+    use super::{Diagnostic, Renderer, Severity};
+
+    #[test]
+    fn parse_error_after_wide_characters() {
+        let src = "select 'あいう' as \"列\"\n\tfrom;";
+        let error = parse(src).unwrap_err();
+
+        assert_eq!(
+            Renderer::plain().render(src, &Diagnostic::from(&error)),
+            "\
+error: syntax error at or near \";\"
+ --> 2:6
+  |
+2 |     from;
+  |         ^
+"
+        );
+
+        let diagnostic = Diagnostic::new(Severity::Warning, "column alias", 22..27);
+        assert_eq!(
+            Renderer::plain().render(src, &diagnostic),
+            "\
+warning: column alias
+ --> 1:17
+  |
+1 | select 'あいう' as \"列\"
+  |                    ^^^^
+"
+        );
+    }
+
+    #[test]
+    fn scan_report_with_hint() {
+        let src = r#"select e'\uD80"#;
+        let error = parse(src).unwrap_err();
+
+        assert_eq!(
+            Renderer::plain().render(src, &Diagnostic::from(&error)),
+            "\
+error: invalid Unicode escape
+ --> 1:10
+  |
+1 | select e'\\uD80
+  |          ^
+  = HINT: Unicode escapes must be \\uXXXX or \\UXXXXXXXX.
+"
+        );
+    }
+
+    #[test]
+    fn notice() {
+        let src = format!("select {};", "a".repeat(64));
+        let parsed = crate::parse_with_warnings(&src).unwrap();
+
+        let rendered = Renderer::plain().render(&src, &Diagnostic::from(&parsed.warnings[0]));
+        assert!(rendered.starts_with("notice: identifier"));
+        assert!(rendered.contains("\n --> 1:8\n"));
+        assert!(rendered.ends_with("\n  |        ^\n"));
+    }
+
+    #[test]
+    fn multiline_range() {
+        let src = "select 'a\nb\nc\n\nd\ne;";
+        let error = parse(src).unwrap_err();
+
+        assert_eq!(
+            Renderer::plain().render(src, &Diagnostic::from(&error)),
+            "\
+error: unterminated quoted string
+ --> 1:8
+  |
+1 | select 'a
+  |        ^^
+2 | b
+  | ^
+...
+5 | d
+  | ^
+6 | e;
+  | ^^
+"
+        );
+
+        let diagnostic = Diagnostic::new(Severity::Error, "two lines", 0..src.rfind('c').unwrap())
+            .with_detail("ends at the start of a line");
+        assert_eq!(
+            Renderer::plain().render(src, &diagnostic),
+            "\
+error: two lines
+ --> 1:1
+  |
+1 | select 'a
+  | ^^^^^^^^^
+2 | b
+  | ^
+  = DETAIL: ends at the start of a line
+"
+        );
+    }
+
+    #[test]
+    fn ansi() {
+        let src = "select a,\n  from t;";
+        let diagnostic = Diagnostic::from(&parse(src).unwrap_err());
+
+        let plain = Renderer::plain().render(src, &diagnostic);
+        let ansi = Renderer::ansi().render(src, &diagnostic);
+        assert!(ansi.starts_with("\x1b[1;31merror\x1b[0m: \x1b[1m"));
+
+        let mut stripped = String::new();
+        let mut rest = ansi.as_str();
+        while let Some(i) = rest.find('\x1b') {
+            stripped += &rest[..i];
+            rest = &rest[i + rest[i..].find('m').unwrap() + 1..];
+        }
+        stripped += rest;
+        assert_eq!(stripped, plain);
+    }
+}
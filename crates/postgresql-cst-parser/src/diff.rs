@@ -0,0 +1,593 @@
+//! Syntax-aware diff of two SQL documents
+//!
+//! [`diff`] compares two trees instead of their lines.
+//! Whitespace, comments and the case of keywords and unquoted identifiers are ignored,
+//! and lists such as `target_list` are compared item by item.
+//!
+//! The children of two nodes are aligned by their content.
+//! Children left over are matched as a [`Change::Move`] if the same content appears elsewhere among the siblings,
+//! and otherwise paired by kind and compared recursively.
+//! A pair of nodes with nothing in common is reported as a single [`Change::Update`].
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::{diff::{diff, Change}, parse, syntax_kind::SyntaxKind};
+//!
+//! let old = parse("select a, b from t; delete from u;").unwrap();
+//! let new = parse("SELECT a, /* new */ c FROM t;\ndelete from u;").unwrap();
+//!
+//! let changes = diff(&old, &new);
+//! assert_eq!(changes.len(), 1);
+//! assert!(matches!(changes[0], Change::Update { kind: SyntaxKind::IDENT, .. }));
+//! assert_eq!(&new.text().to_string()[changes[0].new_range().unwrap()], "c");
+//! ```
+
+use std::fmt::Write;
+
+use cstree::text::TextRange;
+
+use crate::{
+    cst_util::{self, is_trivia, trimmed_range},
+    keywords::lookup,
+    line_index::{LineIndex, PositionEncoding},
+    syntax_kind::SyntaxKind,
+    NodeOrToken, ResolvedNode, ResolvedToken,
+};
+
+/// A difference between two documents
+///
+/// `kind` is the kind of the changed node or token.
+/// `old` and `new` are the ranges in the old and the new document.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Change {
+    /// An element only in the new document
+    Insert { kind: SyntaxKind, new: TextRange },
+    /// An element only in the old document
+    Delete { kind: SyntaxKind, old: TextRange },
+    /// An element whose content is unchanged, at another position among its siblings
+    Move {
+        kind: SyntaxKind,
+        old: TextRange,
+        new: TextRange,
+    },
+    /// An element whose content changed
+    Update {
+        kind: SyntaxKind,
+        old: TextRange,
+        new: TextRange,
+    },
+}
+
+impl Change {
+    /// Returns the kind of the changed node or token
+    pub fn kind(&self) -> SyntaxKind {
+        match self {
+            Change::Insert { kind, .. }
+            | Change::Delete { kind, .. }
+            | Change::Move { kind, .. }
+            | Change::Update { kind, .. } => *kind,
+        }
+    }
+
+    /// Returns the range in the old document, if the element is there
+    pub fn old_range(&self) -> Option<TextRange> {
+        match self {
+            Change::Insert { .. } => None,
+            Change::Delete { old, .. } | Change::Move { old, .. } | Change::Update { old, .. } => {
+                Some(*old)
+            }
+        }
+    }
+
+    /// Returns the range in the new document, if the element is there
+    pub fn new_range(&self) -> Option<TextRange> {
+        match self {
+            Change::Delete { .. } => None,
+            Change::Insert { new, .. } | Change::Move { new, .. } | Change::Update { new, .. } => {
+                Some(*new)
+            }
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Change::Insert { .. } => "insert",
+            Change::Delete { .. } => "delete",
+            Change::Move { .. } => "move",
+            Change::Update { .. } => "update",
+        }
+    }
+}
+
+/// Returns the changes from `old` to `new` in the order of the documents
+pub fn diff(old: &ResolvedNode, new: &ResolvedNode) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let old = Item::new(NodeOrToken::Node(old));
+    let new = Item::new(NodeOrToken::Node(new));
+    if old.key != new.key {
+        diff_pair(&old, &new, &mut changes);
+    }
+    changes
+}
+
+/// Formats the changes like a unified diff
+///
+/// Each change has a header with its one-based line and column in the old and the new document,
+/// followed by the old text prefixed with `-` and the new text prefixed with `+`.
+///
+/// # Examples
+///
+/// ```
+/// use postgresql_cst_parser::{diff::{diff, unified}, parse};
+///
+/// let old = parse("select a from t;").unwrap();
+/// let new = parse("select a\nfrom t\nwhere b;").unwrap();
+///
+/// assert_eq!(
+///     unified(&old, &new, &diff(&old, &new)),
+///     "@@ +3:1 @@ insert where_clause\n+where b\n"
+/// );
+/// ```
+pub fn unified(old: &ResolvedNode, new: &ResolvedNode, changes: &[Change]) -> String {
+    let old_src = old.text().to_string();
+    let new_src = new.text().to_string();
+    let old_index = LineIndex::new(&old_src);
+    let new_index = LineIndex::new(&new_src);
+
+    let position = |index: &LineIndex, range: TextRange| {
+        let line_col = index.line_col(range.start().into(), PositionEncoding::Utf32);
+        format!("{}:{}", line_col.line + 1, line_col.col + 1)
+    };
+
+    let mut output = String::new();
+    for change in changes {
+        output += "@@";
+        if let Some(range) = change.old_range() {
+            write!(output, " -{}", position(&old_index, range)).unwrap();
+        }
+        if let Some(range) = change.new_range() {
+            write!(output, " +{}", position(&new_index, range)).unwrap();
+        }
+        writeln!(output, " @@ {} {:?}", change.name(), change.kind()).unwrap();
+
+        if let Some(range) = change.old_range() {
+            for line in old_src[range].lines() {
+                writeln!(output, "-{line}").unwrap();
+            }
+        }
+        if let Some(range) = change.new_range() {
+            for line in new_src[range].lines() {
+                writeln!(output, "+{line}").unwrap();
+            }
+        }
+    }
+    output
+}
+
+/// A node or token with its normalized content
+struct Item<'a> {
+    element: NodeOrToken<'a>,
+    key: String,
+}
+
+impl<'a> Item<'a> {
+    fn new(element: NodeOrToken<'a>) -> Self {
+        // Statements are reported by their own kind, such as `SelectStmt`
+        let mut element = element;
+        while let NodeOrToken::Node(node) = element {
+            if !matches!(node.kind(), SyntaxKind::toplevel_stmt | SyntaxKind::stmt) {
+                break;
+            }
+            match node.children().next() {
+                Some(child) => element = NodeOrToken::Node(child),
+                None => break,
+            }
+        }
+
+        let key = tokens(element)
+            .map(|token| normalize(token.kind(), token.text()))
+            .collect::<Vec<_>>()
+            .join("\0");
+        Self { element, key }
+    }
+
+    fn kind(&self) -> SyntaxKind {
+        self.element.kind()
+    }
+
+    fn range(&self) -> TextRange {
+        range(self.element)
+    }
+}
+
+/// Returns the range of `element` without leading and trailing comments
+fn range(element: NodeOrToken<'_>) -> TextRange {
+    match element {
+        NodeOrToken::Node(node) => trimmed_range(node),
+        NodeOrToken::Token(token) => token.text_range(),
+    }
+}
+
+/// Returns the tokens of `element` other than whitespace and comments
+fn tokens<'a>(element: NodeOrToken<'a>) -> Box<dyn Iterator<Item = &'a ResolvedToken> + 'a> {
+    match element {
+        NodeOrToken::Node(node) => Box::new(cst_util::tokens(node)),
+        NodeOrToken::Token(token) => {
+            Box::new(std::iter::once(token).filter(|token| !is_ignored(token)))
+        }
+    }
+}
+
+fn is_ignored(token: &ResolvedToken) -> bool {
+    is_trivia(token.kind()) || token.text().is_empty()
+}
+
+/// Lowercases keywords and unquoted identifiers, which PostgreSQL folds to lowercase
+fn normalize(kind: SyntaxKind, text: &str) -> String {
+    let folded = match kind {
+        SyntaxKind::IDENT => !text.contains('"'),
+        _ => lookup(text).is_some(),
+    };
+    if folded {
+        text.to_ascii_lowercase()
+    } else {
+        text.to_string()
+    }
+}
+
+fn significant_children(node: &ResolvedNode) -> impl Iterator<Item = NodeOrToken<'_>> {
+    node.children_with_tokens().filter(|child| match child {
+        NodeOrToken::Node(node) => !node.text_range().is_empty(),
+        NodeOrToken::Token(token) => !is_ignored(token),
+    })
+}
+
+/// Returns the children of `node`, with the items of a nested list such as `target_list` flattened
+fn items(node: &ResolvedNode) -> Vec<Item<'_>> {
+    let mut items = Vec::new();
+    for child in significant_children(node) {
+        match child {
+            NodeOrToken::Node(child) if child.kind() == node.kind() => {
+                items.extend(self::items(child))
+            }
+            _ => items.push(Item::new(child)),
+        }
+    }
+    items
+}
+
+/// Compares elements of the same kind with different content
+///
+/// Returns whether they have anything in common.
+fn diff_pair(old: &Item, new: &Item, changes: &mut Vec<Change>) -> bool {
+    let mut inner = Vec::new();
+    let related = match (old.element, new.element) {
+        (NodeOrToken::Node(old), NodeOrToken::Node(new)) => {
+            diff_items(&items(old), &items(new), &mut inner)
+        }
+        _ => false,
+    };
+
+    if related {
+        changes.extend(inner);
+    } else {
+        // Report the innermost elements, such as the `ICONST` of `a_expr` > `c_expr` > `AexprConst` > `Iconst`
+        let (mut old, mut new) = (old.element, new.element);
+        while let (Some(old_child), Some(new_child)) = (only_child(old), only_child(new)) {
+            if old_child.kind() != new_child.kind() {
+                break;
+            }
+            (old, new) = (old_child, new_child);
+        }
+        changes.push(Change::Update {
+            kind: old.kind(),
+            old: range(old),
+            new: range(new),
+        });
+    }
+    related
+}
+
+fn only_child(element: NodeOrToken<'_>) -> Option<NodeOrToken<'_>> {
+    let NodeOrToken::Node(node) = element else {
+        return None;
+    };
+    let mut children = significant_children(node);
+    match (children.next(), children.next()) {
+        (Some(child), None) => Some(child),
+        _ => None,
+    }
+}
+
+/// Compares two lists of siblings, returning whether anything is unchanged, moved or partly changed
+fn diff_items(old: &[Item], new: &[Item], changes: &mut Vec<Change>) -> bool {
+    let anchors = lcs(old, new, |old, new| old.key == new.key);
+    let mut related = !anchors.is_empty();
+
+    // Unaligned items with the same content are moved
+    let mut old_moved = vec![false; old.len()];
+    let mut new_moved = vec![None; new.len()];
+    let old_unaligned: Vec<_> = gaps(&anchors, old.len(), new.len())
+        .flat_map(|(old_gap, _)| old_gap)
+        .collect();
+    for j in gaps(&anchors, old.len(), new.len()).flat_map(|(_, new_gap)| new_gap) {
+        if let Some(&i) = old_unaligned
+            .iter()
+            .find(|&&i| !old_moved[i] && old[i].key == new[j].key)
+        {
+            old_moved[i] = true;
+            new_moved[j] = Some(i);
+            related = true;
+        }
+    }
+
+    for (old_gap, new_gap) in gaps(&anchors, old.len(), new.len()) {
+        let old_rest: Vec<_> = old_gap.filter(|&i| !old_moved[i]).collect();
+        let new_rest: Vec<_> = new_gap
+            .clone()
+            .filter(|&j| new_moved[j].is_none())
+            .collect();
+
+        // Pair the rest by kind, and report the items in the order of the documents
+        let mut pairs = lcs(&old_rest, &new_rest, |&i, &j| {
+            old[i].kind() == new[j].kind()
+        });
+        pairs.push((old_rest.len(), new_rest.len()));
+
+        let (mut next_old, mut next_new) = (0, new_gap.start);
+        for (pi, pj) in pairs {
+            for &i in &old_rest[next_old..pi] {
+                changes.push(Change::Delete {
+                    kind: old[i].kind(),
+                    old: old[i].range(),
+                });
+            }
+
+            let paired = new_rest.get(pj).copied();
+            for j in next_new..paired.unwrap_or(new_gap.end) {
+                changes.push(match new_moved[j] {
+                    Some(i) => Change::Move {
+                        kind: new[j].kind(),
+                        old: old[i].range(),
+                        new: new[j].range(),
+                    },
+                    None => Change::Insert {
+                        kind: new[j].kind(),
+                        new: new[j].range(),
+                    },
+                });
+            }
+
+            if let Some(j) = paired {
+                related |= diff_pair(&old[old_rest[pi]], &new[j], changes);
+                next_new = j + 1;
+            }
+            next_old = pi + 1;
+        }
+    }
+
+    related
+}
+
+/// Returns the ranges of indices between the aligned pairs, including before the first and after the last
+fn gaps(
+    anchors: &[(usize, usize)],
+    old_len: usize,
+    new_len: usize,
+) -> impl Iterator<Item = (std::ops::Range<usize>, std::ops::Range<usize>)> + '_ {
+    let starts = [(0, 0)]
+        .into_iter()
+        .chain(anchors.iter().map(|&(i, j)| (i + 1, j + 1)));
+    let ends = anchors.iter().copied().chain([(old_len, new_len)]);
+    starts
+        .zip(ends)
+        .map(|((old_start, new_start), (old_end, new_end))| {
+            (old_start..old_end, new_start..new_end)
+        })
+}
+
+/// Returns the indices of a longest common subsequence of `old` and `new`
+fn lcs<T, U>(old: &[T], new: &[U], eq: impl Fn(&T, &U) -> bool) -> Vec<(usize, usize)> {
+    // Common prefix and suffix are aligned without the search
+    let prefix = old
+        .iter()
+        .zip(new)
+        .take_while(|(old, new)| eq(old, new))
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| eq(old, new))
+        .count();
+    let (old_mid, new_mid) = (
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    );
+
+    let mut pairs: Vec<_> = (0..prefix).map(|i| (i, i)).collect();
+    hirschberg(old_mid, new_mid, &eq, (prefix, prefix), &mut pairs);
+    pairs.extend((0..suffix).map(|k| (old.len() - suffix + k, new.len() - suffix + k)));
+    pairs
+}
+
+/// Appends the indices of a longest common subsequence of `old` and `new`, shifted by `offset`
+///
+/// This is Hirschberg's algorithm, which takes space linear in the lengths.
+fn hirschberg<T, U>(
+    old: &[T],
+    new: &[U],
+    eq: &impl Fn(&T, &U) -> bool,
+    offset: (usize, usize),
+    pairs: &mut Vec<(usize, usize)>,
+) {
+    match old {
+        [] => return,
+        [item] => {
+            if let Some(j) = new.iter().position(|new| eq(item, new)) {
+                pairs.push((offset.0, offset.1 + j));
+            }
+            return;
+        }
+        _ if new.is_empty() => return,
+        _ => {}
+    }
+
+    // Split `new` where the LCS of the halves of `old` with the parts of `new` is the longest
+    let mid = old.len() / 2;
+    let forward = lcs_lengths(old[..mid].iter(), new.iter(), eq);
+    let backward = lcs_lengths(old[mid..].iter().rev(), new.iter().rev(), eq);
+    let split = (0..=new.len())
+        .max_by_key(|&j| forward[j] + backward[new.len() - j])
+        .unwrap();
+
+    hirschberg(&old[..mid], &new[..split], eq, offset, pairs);
+    hirschberg(
+        &old[mid..],
+        &new[split..],
+        eq,
+        (offset.0 + mid, offset.1 + split),
+        pairs,
+    );
+}
+
+/// Returns the lengths of the longest common subsequences of `old` and each prefix of `new`
+fn lcs_lengths<'a, T: 'a, U: 'a>(
+    old: impl Iterator<Item = &'a T>,
+    new: impl Iterator<Item = &'a U> + Clone,
+    eq: &impl Fn(&T, &U) -> bool,
+) -> Vec<u32> {
+    let mut row = vec![0u32; new.clone().count() + 1];
+    for old in old {
+        // The length for the previous item of `old` and the previous prefix of `new`
+        let mut diagonal = 0;
+        for (j, new) in new.clone().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if eq(old, new) {
+                diagonal + 1
+            } else {
+                above.max(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, syntax_kind::SyntaxKind};
+
+    use super::{diff, lcs, unified};
+
+    /// Returns the changes as the kind of the change, the kind of the element and the old and new texts
+    fn changes(old: &str, new: &str) -> Vec<(&'static str, SyntaxKind, String, String)> {
+        let (old_root, new_root) = (parse(old).unwrap(), parse(new).unwrap());
+        diff(&old_root, &new_root)
+            .into_iter()
+            .map(|change| {
+                let text = |src: &str, range: Option<cstree::text::TextRange>| {
+                    range.map_or(String::new(), |range| src[range].to_string())
+                };
+                (
+                    change.name(),
+                    change.kind(),
+                    text(old, change.old_range()),
+                    text(new, change.new_range()),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn longest_common_subsequence() {
+        for (old, new, len) in [
+            ("ABCBDAB", "BDCABA", 4),
+            ("xABCy", "xACy", 4),
+            ("", "ABC", 0),
+            ("ABC", "DEF", 0),
+            ("AAAA", "AA", 2),
+        ] {
+            let (old, new): (Vec<_>, Vec<_>) = (old.chars().collect(), new.chars().collect());
+            let pairs = lcs(&old, &new, |old, new| old == new);
+            assert_eq!(pairs.len(), len, "{old:?} {new:?}");
+            assert!(pairs.iter().all(|&(i, j)| old[i] == new[j]));
+            assert!(pairs.windows(2).all(|w| w[0].0 < w[1].0 && w[0].1 < w[1].1));
+        }
+    }
+
+    #[test]
+    fn ignored() {
+        assert!(changes(
+            "select a, b from t where x = 1;",
+            "SELECT A,\n  b -- comment\nFrom T where x=1;"
+        )
+        .is_empty());
+
+        // Quoted identifiers are case sensitive
+        assert_eq!(
+            changes(r#"select "a";"#, r#"select "A";"#),
+            [(
+                "update",
+                SyntaxKind::IDENT,
+                r#""a""#.to_string(),
+                r#""A""#.to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn list_items() {
+        assert_eq!(
+            changes("select a, b, c from t;", "select c, a, d + 1, e from t;"),
+            [
+                ("move", SyntaxKind::target_el, "c".into(), "c".into()),
+                ("insert", SyntaxKind::Comma, "".into(), ",".into()),
+                ("update", SyntaxKind::a_expr, "b".into(), "d + 1".into()),
+                ("insert", SyntaxKind::target_el, "".into(), "e".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn statements() {
+        assert_eq!(
+            changes(
+                "select 1; update t set a = 1; delete from t;",
+                "delete from t; update t set a = 2; insert into t values (1);"
+            ),
+            [
+                (
+                    "delete",
+                    SyntaxKind::SelectStmt,
+                    "select 1".into(),
+                    "".into()
+                ),
+                (
+                    "move",
+                    SyntaxKind::DeleteStmt,
+                    "delete from t".into(),
+                    "delete from t".into()
+                ),
+                ("update", SyntaxKind::ICONST, "1".into(), "2".into()),
+                (
+                    "insert",
+                    SyntaxKind::InsertStmt,
+                    "".into(),
+                    "insert into t values (1)".into()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unified_output() {
+        let old = parse("select a,\n  b\nfrom t;").unwrap();
+        let new = parse("select a,\n  c\nfrom t;").unwrap();
+        assert_eq!(
+            unified(&old, &new, &diff(&old, &new)),
+            "@@ -2:3 +2:3 @@ update IDENT\n-b\n+c\n"
+        );
+    }
+}
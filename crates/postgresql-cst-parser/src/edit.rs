@@ -0,0 +1,634 @@
+//! Rewriting of the source through the syntax tree
+//!
+//! [`EditBuilder`] collects changes addressed by nodes and tokens of the tree,
+//! and returns them as text edits or as the new source.
+//! Everything outside the changed ranges, including whitespace and comments, is kept as it was.
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::{edit::EditBuilder, parse, syntax_kind::SyntaxKind};
+//!
+//! let src = "select a /* first */, b from t where x = 1 or y = 2;";
+//! let root = parse(src).unwrap();
+//! let find = |kind| root.descendants().find(|n| n.kind() == kind).unwrap();
+//!
+//! let mut builder = EditBuilder::new(&root);
+//! builder.push_list_item(find(SyntaxKind::target_list), "c")?;
+//! builder.push_list_item(find(SyntaxKind::from_list), "u")?;
+//! builder.add_condition(find(SyntaxKind::simple_select), "t.id = u.id")?;
+//!
+//! assert_eq!(
+//!     builder.apply()?,
+//!     "select a /* first */, b, c from t, u where (x = 1 or y = 2) and t.id = u.id;"
+//! );
+//! # Ok::<(), postgresql_cst_parser::edit::EditError>(())
+//! ```
+
+use std::ops::Range;
+
+use crate::{
+    cst_util::{byte_range, is_trivia},
+    keywords::quote_ident,
+    parse,
+    syntax_kind::SyntaxKind,
+    value::{token_value, Value},
+    ParserError, ResolvedNode, ResolvedToken,
+};
+
+/// A change of the source: `range` is replaced with `text`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextEdit {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+impl TextEdit {
+    fn insert(offset: usize, text: impl Into<String>) -> Self {
+        Self {
+            range: offset..offset,
+            text: text.into(),
+        }
+    }
+}
+
+/// Errors of [`EditBuilder`]
+#[derive(Debug, PartialEq)]
+pub enum EditError {
+    /// The node or token is not of the kind the operation expects
+    UnexpectedKind {
+        expected: &'static str,
+        found: SyntaxKind,
+    },
+    /// The only item of a list cannot be removed
+    OnlyItem,
+    /// Two edits change overlapping ranges
+    Overlap {
+        first: Range<usize>,
+        second: Range<usize>,
+    },
+    /// The source after the edits is not valid SQL
+    Parse(ParserError),
+}
+
+impl std::fmt::Display for EditError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EditError::UnexpectedKind { expected, found } => {
+                write!(f, "expected {expected}, but found {found:?}")
+            }
+            EditError::OnlyItem => f.write_str("cannot remove the only item of a list"),
+            EditError::Overlap { first, second } => {
+                write!(f, "edits of {first:?} and {second:?} overlap")
+            }
+            EditError::Parse(error) => write!(f, "the edited source is invalid: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for EditError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EditError::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Collects edits of a parsed source
+///
+/// The nodes and tokens given to the methods must belong to the tree passed to [`EditBuilder::new`].
+/// Edits are applied to the original source, so a node cannot be addressed after it has been replaced.
+pub struct EditBuilder {
+    source: String,
+    edits: Vec<TextEdit>,
+}
+
+impl EditBuilder {
+    /// Creates a builder for the source of the tree containing `node`
+    pub fn new(node: &ResolvedNode) -> Self {
+        let root = node.ancestors().last().unwrap_or(node);
+        Self {
+            source: root.text().to_string(),
+            edits: Vec::new(),
+        }
+    }
+
+    /// Replaces the text of `node` with `sql`
+    pub fn replace(&mut self, node: &ResolvedNode, sql: &str) -> &mut Self {
+        self.edits.push(TextEdit {
+            range: byte_range(node.text_range()),
+            text: sql.to_string(),
+        });
+        self
+    }
+
+    /// Replaces the text of `token` with `text`
+    pub fn replace_token(&mut self, token: &ResolvedToken, text: &str) -> &mut Self {
+        self.edits.push(TextEdit {
+            range: byte_range(token.text_range()),
+            text: text.to_string(),
+        });
+        self
+    }
+
+    /// Adds an edit of the source
+    pub fn push(&mut self, edit: TextEdit) -> &mut Self {
+        self.edits.push(edit);
+        self
+    }
+
+    /// Renames an identifier, quoting the new name if needed
+    ///
+    /// The token must be an identifier or a keyword used as an identifier.
+    pub fn rename(
+        &mut self,
+        token: &ResolvedToken,
+        new_name: &str,
+    ) -> Result<&mut Self, EditError> {
+        match token_value(token) {
+            Some(Value::Identifier(_) | Value::Keyword(_)) => {
+                Ok(self.replace_token(token, &quote_ident(new_name)))
+            }
+            _ => Err(EditError::UnexpectedKind {
+                expected: "an identifier",
+                found: token.kind(),
+            }),
+        }
+    }
+
+    /// Inserts `sql` as the `index`-th item of a comma-separated list such as `target_list` or `from_list`
+    ///
+    /// `list` may be any of the nested list nodes; the whole list is used.
+    /// Appends the item if `index` is the number of the items.
+    pub fn insert_list_item(
+        &mut self,
+        list: &ResolvedNode,
+        index: usize,
+        sql: &str,
+    ) -> Result<&mut Self, EditError> {
+        let items = list_items(outermost_list(list)?);
+        let edit = match items.get(index) {
+            Some(item) => TextEdit::insert(byte_range(item.text_range()).start, format!("{sql}, ")),
+            None => match items.last() {
+                Some(last) => {
+                    TextEdit::insert(byte_range(last.text_range()).end, format!(", {sql}"))
+                }
+                None => {
+                    return Err(EditError::UnexpectedKind {
+                        expected: "a non-empty list",
+                        found: list.kind(),
+                    })
+                }
+            },
+        };
+        self.edits.push(edit);
+        Ok(self)
+    }
+
+    /// Appends `sql` to a comma-separated list
+    pub fn push_list_item(
+        &mut self,
+        list: &ResolvedNode,
+        sql: &str,
+    ) -> Result<&mut Self, EditError> {
+        let len = list_items(outermost_list(list)?).len();
+        self.insert_list_item(list, len, sql)
+    }
+
+    /// Removes an item of a comma-separated list together with its separator
+    pub fn remove_list_item(&mut self, item: &ResolvedNode) -> Result<&mut Self, EditError> {
+        let list =
+            item.parent()
+                .filter(|parent| is_list(parent))
+                .ok_or(EditError::UnexpectedKind {
+                    expected: "an item of a list",
+                    found: item.kind(),
+                })?;
+        let items = list_items(outermost_list(list)?);
+        let index = items
+            .iter()
+            .position(|i| i.text_range() == item.text_range())
+            .ok_or(EditError::UnexpectedKind {
+                expected: "an item of a list",
+                found: item.kind(),
+            })?;
+
+        let range = if let Some(next) = items.get(index + 1) {
+            // `item, next` -> `next`
+            byte_range(item.text_range()).start..byte_range(next.text_range()).start
+        } else if let Some(prev) = index.checked_sub(1).map(|i| &items[i]) {
+            // `prev, item` -> `prev`
+            byte_range(prev.text_range()).end..byte_range(item.text_range()).end
+        } else {
+            return Err(EditError::OnlyItem);
+        };
+
+        self.edits.push(TextEdit {
+            range,
+            text: String::new(),
+        });
+        Ok(self)
+    }
+
+    /// Adds `condition` to the `WHERE` clause of a statement with `AND`, adding the clause if there is none
+    ///
+    /// `stmt` is a `simple_select`, `UpdateStmt` or `DeleteStmt`.
+    /// Operands of the lower precedence `OR` are parenthesized.
+    pub fn add_condition(
+        &mut self,
+        stmt: &ResolvedNode,
+        condition: &str,
+    ) -> Result<&mut Self, EditError> {
+        if !matches!(
+            stmt.kind(),
+            SyntaxKind::simple_select | SyntaxKind::UpdateStmt | SyntaxKind::DeleteStmt
+        ) {
+            return Err(EditError::UnexpectedKind {
+                expected: "simple_select, UpdateStmt or DeleteStmt",
+                found: stmt.kind(),
+            });
+        }
+
+        let uppercase = first_keyword_is_uppercase(stmt);
+        let keyword = |kw: &str| {
+            if uppercase {
+                kw.to_ascii_uppercase()
+            } else {
+                kw.to_string()
+            }
+        };
+
+        let condition = if condition_has_or(condition) {
+            format!("({condition})")
+        } else {
+            condition.to_string()
+        };
+
+        // Without `remove-empty-node`, a missing clause is an empty node
+        let where_clause = stmt.children().find(|child| {
+            matches!(
+                child.kind(),
+                SyntaxKind::where_clause | SyntaxKind::where_or_current_clause
+            ) && !child.text_range().is_empty()
+        });
+        let expr = where_clause.and_then(|clause| {
+            clause
+                .children()
+                .find(|child| child.kind() == SyntaxKind::a_expr)
+        });
+
+        match expr {
+            Some(expr) => {
+                let range = byte_range(expr.text_range());
+                if has_child_token(expr, SyntaxKind::OR) {
+                    self.edits.push(TextEdit::insert(range.start, "("));
+                    self.edits.push(TextEdit::insert(range.end, ")"));
+                }
+                self.edits.push(TextEdit::insert(
+                    range.end,
+                    format!(" {} {condition}", keyword("and")),
+                ));
+            }
+            None => {
+                // `WHERE CURRENT OF` or a missing clause
+                if let Some(clause) = where_clause {
+                    return Err(EditError::UnexpectedKind {
+                        expected: "a WHERE clause with an expression",
+                        found: clause.kind(),
+                    });
+                }
+
+                // Insert after the clauses preceding the WHERE clause in the grammar
+                let following = |kind| {
+                    matches!(
+                        kind,
+                        SyntaxKind::group_clause
+                            | SyntaxKind::having_clause
+                            | SyntaxKind::window_clause
+                            | SyntaxKind::returning_clause
+                    )
+                };
+                let offset = stmt
+                    .children_with_tokens()
+                    .take_while(|child| !following(child.kind()))
+                    .filter(|child| !is_trivia(child.kind()))
+                    .last()
+                    .map(|child| usize::from(child.text_range().end()))
+                    .unwrap_or(byte_range(stmt.text_range()).end);
+                self.edits.push(TextEdit::insert(
+                    offset,
+                    format!(" {} {condition}", keyword("where")),
+                ));
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the edits sorted by position
+    ///
+    /// Insertions at the same position are kept in the order they were added.
+    pub fn edits(&self) -> Result<Vec<TextEdit>, EditError> {
+        let mut edits = self.edits.clone();
+        // The sort is stable
+        edits.sort_by_key(|edit| (edit.range.start, edit.range.end));
+
+        for pair in edits.windows(2) {
+            let (first, second) = (&pair[0], &pair[1]);
+            if first.range.end > second.range.start
+                || (first.range == second.range && !first.range.is_empty())
+            {
+                return Err(EditError::Overlap {
+                    first: first.range.clone(),
+                    second: second.range.clone(),
+                });
+            }
+        }
+
+        Ok(edits)
+    }
+
+    /// Applies the edits and returns the new source, checking that it can be parsed
+    pub fn apply(&self) -> Result<String, EditError> {
+        let result = self.apply_unchecked()?;
+        parse(&result).map_err(EditError::Parse)?;
+        Ok(result)
+    }
+
+    /// Applies the edits and returns the new source without parsing it
+    pub fn apply_unchecked(&self) -> Result<String, EditError> {
+        let mut result = String::with_capacity(self.source.len());
+        let mut pos = 0;
+        for edit in self.edits()? {
+            result += &self.source[pos..edit.range.start];
+            result += &edit.text;
+            pos = edit.range.end;
+        }
+        result += &self.source[pos..];
+        Ok(result)
+    }
+}
+
+/// Comma-separated lists that are accepted even with a single item
+const COMMA_SEPARATED_LISTS: &[SyntaxKind] = &[
+    SyntaxKind::target_list,
+    SyntaxKind::from_list,
+    SyntaxKind::expr_list,
+    SyntaxKind::name_list,
+    SyntaxKind::columnList,
+    SyntaxKind::qualified_name_list,
+    SyntaxKind::any_name_list,
+    SyntaxKind::set_clause_list,
+    SyntaxKind::set_target_list,
+    SyntaxKind::sortby_list,
+    SyntaxKind::group_by_list,
+    SyntaxKind::func_arg_list,
+    SyntaxKind::TableElementList,
+    SyntaxKind::relation_expr_list,
+    SyntaxKind::insert_column_list,
+    SyntaxKind::cte_list,
+];
+
+/// Returns whether `node` is a comma-separated list
+///
+/// Other left-recursive lists count only if a node of the list has a `,` separator, which excludes binary
+/// expressions such as `a_expr: a_expr '+' a_expr`.
+fn is_list(node: &ResolvedNode) -> bool {
+    COMMA_SEPARATED_LISTS.contains(&node.kind())
+        || std::iter::successors(Some(node), |list| {
+            list.parent().filter(|parent| parent.kind() == node.kind())
+        })
+        .any(|list| {
+            list.children()
+                .next()
+                .is_some_and(|first| first.kind() == list.kind())
+                && has_child_token(list, SyntaxKind::Comma)
+        })
+}
+
+fn outermost_list(list: &ResolvedNode) -> Result<&ResolvedNode, EditError> {
+    if !is_list(list) {
+        return Err(EditError::UnexpectedKind {
+            expected: "a list",
+            found: list.kind(),
+        });
+    }
+
+    let mut list = list;
+    while let Some(parent) = list.parent().filter(|parent| parent.kind() == list.kind()) {
+        list = parent;
+    }
+    Ok(list)
+}
+
+/// Returns the items of a left-recursive list such as `target_list: target_el | target_list ',' target_el`
+pub(crate) fn list_items(list: &ResolvedNode) -> Vec<&ResolvedNode> {
+    let mut items = Vec::new();
+    for child in list.children() {
+        if child.kind() == list.kind() {
+            items.extend(list_items(child));
+        } else {
+            items.push(child);
+        }
+    }
+    items
+}
+
+fn has_child_token(node: &ResolvedNode, kind: SyntaxKind) -> bool {
+    node.children_with_tokens()
+        .any(|child| child.as_token().is_some_and(|token| token.kind() == kind))
+}
+
+fn first_keyword_is_uppercase(node: &ResolvedNode) -> bool {
+    node.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .find(|token| !is_trivia(token.kind()))
+        .is_some_and(|token| token.text().chars().any(|c| c.is_ascii_uppercase()))
+}
+
+/// Returns whether `condition` is an `OR` expression, which needs parentheses as an operand of `AND`
+fn condition_has_or(condition: &str) -> bool {
+    let Ok(root) = parse(&format!("select where {condition}")) else {
+        return true;
+    };
+    let has_or = root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::where_clause)
+        .and_then(|clause| {
+            clause
+                .children()
+                .find(|child| child.kind() == SyntaxKind::a_expr)
+        })
+        .is_some_and(|expr| has_child_token(expr, SyntaxKind::OR));
+    has_or
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, syntax_kind::SyntaxKind, ResolvedNode};
+
+    use super::{EditBuilder, EditError, TextEdit};
+
+    fn find(root: &ResolvedNode, kind: SyntaxKind) -> &ResolvedNode {
+        root.descendants().find(|n| n.kind() == kind).unwrap()
+    }
+
+    #[test]
+    fn list_items() {
+        let src = "select a, /* b */ b, c from t;";
+        let root = parse(src).unwrap();
+        // the innermost list
+        let list = root
+            .descendants()
+            .filter(|n| n.kind() == SyntaxKind::target_list)
+            .last()
+            .unwrap();
+
+        let mut builder = EditBuilder::new(&root);
+        builder.insert_list_item(list, 0, "x").unwrap();
+        builder.insert_list_item(list, 2, "y").unwrap();
+        assert_eq!(
+            builder.apply().unwrap(),
+            "select x, a, /* b */ b, y, c from t;"
+        );
+
+        let items: Vec<_> = root
+            .descendants()
+            .filter(|n| n.kind() == SyntaxKind::target_el)
+            .collect();
+
+        let mut builder = EditBuilder::new(&root);
+        builder.remove_list_item(items[1]).unwrap();
+        assert_eq!(builder.apply().unwrap(), "select a, /* b */ c from t;");
+
+        let mut builder = EditBuilder::new(&root);
+        builder.remove_list_item(items[2]).unwrap();
+        assert_eq!(builder.apply().unwrap(), "select a, /* b */ b from t;");
+
+        let root = parse("select a from t;").unwrap();
+        let mut builder = EditBuilder::new(&root);
+        assert_eq!(
+            builder
+                .remove_list_item(find(&root, SyntaxKind::target_el))
+                .err(),
+            Some(EditError::OnlyItem)
+        );
+        assert!(matches!(
+            builder.push_list_item(find(&root, SyntaxKind::simple_select), "b"),
+            Err(EditError::UnexpectedKind { .. })
+        ));
+
+        // An operand of a binary expression is not a list item
+        let root = parse("select a + b from t;").unwrap();
+        let operand = root
+            .descendants()
+            .filter(|n| n.kind() == SyntaxKind::a_expr)
+            .nth(1)
+            .unwrap();
+        assert_eq!(operand.text().to_string(), "a");
+        assert!(matches!(
+            EditBuilder::new(&root).remove_list_item(operand),
+            Err(EditError::UnexpectedKind { .. })
+        ));
+    }
+
+    #[test]
+    fn add_condition() {
+        for (src, condition, expected) in [
+            ("select * from t;", "a = 1", "select * from t where a = 1;"),
+            (
+                "SELECT * FROM t GROUP BY a;",
+                "b OR c",
+                "SELECT * FROM t WHERE (b OR c) GROUP BY a;",
+            ),
+            (
+                "select * from t where a and b -- comment\n order by a;",
+                "c",
+                "select * from t where a and b and c -- comment\n order by a;",
+            ),
+            (
+                "update t set a = 1 where a = 2 or a = 3 returning a;",
+                "b",
+                "update t set a = 1 where (a = 2 or a = 3) and b returning a;",
+            ),
+            (
+                "delete from t returning a;",
+                "b",
+                "delete from t where b returning a;",
+            ),
+        ] {
+            let root = parse(src).unwrap();
+            let stmt = root
+                .descendants()
+                .find(|n| {
+                    matches!(
+                        n.kind(),
+                        SyntaxKind::simple_select | SyntaxKind::UpdateStmt | SyntaxKind::DeleteStmt
+                    )
+                })
+                .unwrap();
+
+            let mut builder = EditBuilder::new(&root);
+            builder.add_condition(stmt, condition).unwrap();
+            assert_eq!(builder.apply().unwrap(), expected, "{src}");
+        }
+    }
+
+    #[test]
+    fn replace_and_rename() {
+        let src = "select a + 1 from t;";
+        let root = parse(src).unwrap();
+        let ident = |text: &str| {
+            root.descendants_with_tokens()
+                .filter_map(|e| e.into_token())
+                .find(|t| t.text() == text)
+                .unwrap()
+        };
+
+        let mut builder = EditBuilder::new(&root);
+        builder.rename(ident("a"), "Col").unwrap();
+        builder.rename(ident("t"), "select").unwrap();
+        builder.replace(
+            find(&root, SyntaxKind::a_expr).children().last().unwrap(),
+            "2 * b",
+        );
+        assert_eq!(
+            builder.apply().unwrap(),
+            r#"select "Col" + 2 * b from "select";"#
+        );
+        assert_eq!(
+            builder.edits().unwrap()[0],
+            TextEdit {
+                range: 7..8,
+                text: r#""Col""#.to_string()
+            }
+        );
+
+        assert!(matches!(
+            builder.rename(ident("1"), "b"),
+            Err(EditError::UnexpectedKind { .. })
+        ));
+    }
+
+    #[test]
+    fn errors() {
+        let root = parse("select a from t;").unwrap();
+        let mut builder = EditBuilder::new(&root);
+        let target = find(&root, SyntaxKind::target_el);
+        builder.replace(target, "b");
+        builder.replace(target, "c");
+        assert_eq!(
+            builder.apply(),
+            Err(EditError::Overlap {
+                first: 7..8,
+                second: 7..8
+            })
+        );
+
+        let mut builder = EditBuilder::new(&root);
+        builder.replace(target, "from");
+        assert!(matches!(builder.apply(), Err(EditError::Parse(_))));
+        assert_eq!(builder.apply_unchecked().unwrap(), "select from from t;");
+    }
+}
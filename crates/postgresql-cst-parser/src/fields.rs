@@ -0,0 +1,193 @@
+//! Field names of child nodes
+//!
+//! The field table is generated by the parser generator from `resources/fields.txt`.
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::{fields, syntax_kind::SyntaxKind};
+//!
+//! let root = postgresql_cst_parser::parse("select a from t where a > 1;").unwrap();
+//! let select = root
+//!     .descendants()
+//!     .find(|node| node.kind() == SyntaxKind::simple_select)
+//!     .unwrap();
+//!
+//! let where_clause = fields::child_by_field_name(select, "where").unwrap();
+//! assert_eq!(where_clause.kind(), SyntaxKind::where_clause);
+//! ```
+
+mod generated;
+
+use generated::FIELDS;
+
+use crate::{cst_util::is_trivia, syntax_kind::SyntaxKind, NodeOrToken, ResolvedNode};
+
+/// Returns the field name of a `child` at `position` of a `parent` node
+///
+/// `position` counts the children of the parent except whitespace and comments.
+pub fn field_name(parent: SyntaxKind, child: SyntaxKind, position: usize) -> Option<&'static str> {
+    FIELDS
+        .iter()
+        .find(|(p, c, pos, _)| *p == parent && *c == child && pos.is_none_or(|pos| pos == position))
+        .map(|(_, _, _, field)| *field)
+}
+
+/// Returns the field names of a sequence of children of a `parent` node
+pub(crate) fn field_names(
+    parent: SyntaxKind,
+    children: impl IntoIterator<Item = SyntaxKind>,
+) -> impl Iterator<Item = Option<&'static str>> {
+    let mut position = 0;
+    children.into_iter().map(move |child| {
+        if is_trivia(child) {
+            return None;
+        }
+        let field = field_name(parent, child, position);
+        position += 1;
+        field
+    })
+}
+
+/// Returns whether some node has a child with the field name
+#[cfg(feature = "tree-sitter-like")]
+pub(crate) fn is_field_name(name: &str) -> bool {
+    FIELDS.iter().any(|(_, _, _, field)| *field == name)
+}
+
+/// Returns the first child of `node` with the given field name
+///
+/// Empty nodes, which are kept without the `remove-empty-node` feature, are not returned.
+pub fn child_by_field_name<'a>(
+    node: &'a ResolvedNode,
+    field_name: &str,
+) -> Option<NodeOrToken<'a>> {
+    let fields = field_names(node.kind(), node.children_with_tokens().map(|c| c.kind()));
+    node.children_with_tokens()
+        .zip(fields)
+        .find(|(child, field)| {
+            *field == Some(field_name)
+                && child
+                    .as_node()
+                    .is_none_or(|node| !node.text_range().is_empty())
+        })
+        .map(|(child, _)| child)
+}
+
+/// Returns the children of `node` with the given field name
+pub fn children_by_field_name<'a>(
+    node: &'a ResolvedNode,
+    field_name: &'a str,
+) -> impl Iterator<Item = NodeOrToken<'a>> + 'a {
+    let fields = field_names(node.kind(), node.children_with_tokens().map(|c| c.kind()));
+    node.children_with_tokens()
+        .zip(fields)
+        .filter(move |(_, field)| *field == Some(field_name))
+        .map(|(child, _)| child)
+}
+
+/// Returns the field name of the child of `node` at `child_index`
+///
+/// `child_index` is the index in [`ResolvedNode::children_with_tokens`].
+pub fn field_name_for_child(node: &ResolvedNode, child_index: usize) -> Option<&'static str> {
+    field_names(node.kind(), node.children_with_tokens().map(|c| c.kind()))
+        .nth(child_index)
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cst, syntax_kind::SyntaxKind, ResolvedNode};
+
+    use super::{child_by_field_name, children_by_field_name, field_name_for_child};
+
+    fn find(root: &ResolvedNode, kind: SyntaxKind) -> &ResolvedNode {
+        root.descendants().find(|node| node.kind() == kind).unwrap()
+    }
+
+    #[test]
+    fn select_clauses() {
+        let root =
+            cst::parse("select distinct a from t where a > 1 group by a having a > 2;").unwrap();
+        let select = find(&root, SyntaxKind::simple_select);
+
+        for (field, kind) in [
+            ("distinct", SyntaxKind::distinct_clause),
+            ("targets", SyntaxKind::target_list),
+            ("from", SyntaxKind::from_clause),
+            ("where", SyntaxKind::where_clause),
+            ("group_by", SyntaxKind::group_clause),
+            ("having", SyntaxKind::having_clause),
+        ] {
+            assert_eq!(child_by_field_name(select, field).unwrap().kind(), kind);
+        }
+        assert!(child_by_field_name(select, "window").is_none());
+    }
+
+    #[test]
+    fn binary_and_unary_operands() {
+        let root = cst::parse("select a + b, not c, d is null;").unwrap();
+        let mut exprs = root
+            .descendants()
+            .filter(|node| node.kind() == SyntaxKind::target_el)
+            .map(|target_el| target_el.first_child().unwrap());
+
+        let plus = exprs.next().unwrap();
+        assert_eq!(child_by_field_name(plus, "left").unwrap().to_string(), "a");
+        assert_eq!(child_by_field_name(plus, "right").unwrap().to_string(), "b");
+
+        let not = exprs.next().unwrap();
+        assert!(child_by_field_name(not, "left").is_none());
+        assert_eq!(child_by_field_name(not, "right").unwrap().to_string(), "c");
+
+        let is_null = exprs.next().unwrap();
+        assert_eq!(
+            child_by_field_name(is_null, "left").unwrap().to_string(),
+            "d"
+        );
+        assert!(child_by_field_name(is_null, "right").is_none());
+    }
+
+    #[test]
+    fn field_name_for_child_index() {
+        let root = cst::parse("select case when a then b end;").unwrap();
+        let when_clause = find(&root, SyntaxKind::when_clause);
+
+        let fields: Vec<_> = (0..when_clause.children_with_tokens().count())
+            .map(|i| field_name_for_child(when_clause, i))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                None,
+                None,
+                Some("condition"),
+                None,
+                None,
+                None,
+                Some("result")
+            ]
+        );
+    }
+
+    #[test]
+    fn multiple_children() {
+        let root = cst::parse("select * from a join b on true;").unwrap();
+        let joined_table = find(&root, SyntaxKind::joined_table);
+
+        assert_eq!(children_by_field_name(joined_table, "left").count(), 1);
+        assert_eq!(children_by_field_name(joined_table, "right").count(), 1);
+        assert_eq!(
+            child_by_field_name(joined_table, "right")
+                .unwrap()
+                .to_string(),
+            "b"
+        );
+        assert_eq!(
+            child_by_field_name(joined_table, "condition")
+                .unwrap()
+                .kind(),
+            SyntaxKind::join_qual
+        );
+    }
+}
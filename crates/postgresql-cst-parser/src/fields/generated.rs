@@ -0,0 +1,496 @@
+use crate::syntax_kind::SyntaxKind;
+
+/// (parent, child, position of the child, field name)
+pub(crate) const FIELDS: &[(SyntaxKind, SyntaxKind, Option<usize>, &str)] = &[
+    (
+        SyntaxKind::simple_select,
+        SyntaxKind::distinct_clause,
+        None,
+        "distinct",
+    ),
+    (
+        SyntaxKind::simple_select,
+        SyntaxKind::opt_target_list,
+        None,
+        "targets",
+    ),
+    (
+        SyntaxKind::simple_select,
+        SyntaxKind::target_list,
+        None,
+        "targets",
+    ),
+    (
+        SyntaxKind::simple_select,
+        SyntaxKind::into_clause,
+        None,
+        "into",
+    ),
+    (
+        SyntaxKind::simple_select,
+        SyntaxKind::from_clause,
+        None,
+        "from",
+    ),
+    (
+        SyntaxKind::simple_select,
+        SyntaxKind::where_clause,
+        None,
+        "where",
+    ),
+    (
+        SyntaxKind::simple_select,
+        SyntaxKind::group_clause,
+        None,
+        "group_by",
+    ),
+    (
+        SyntaxKind::simple_select,
+        SyntaxKind::having_clause,
+        None,
+        "having",
+    ),
+    (
+        SyntaxKind::simple_select,
+        SyntaxKind::window_clause,
+        None,
+        "window",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::with_clause,
+        None,
+        "with",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::opt_sort_clause,
+        None,
+        "order_by",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::sort_clause,
+        None,
+        "order_by",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::for_locking_clause,
+        None,
+        "locking",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::distinct_clause,
+        None,
+        "distinct",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::target_list,
+        None,
+        "targets",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::into_clause,
+        None,
+        "into",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::from_clause,
+        None,
+        "from",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::where_clause,
+        None,
+        "where",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::group_clause,
+        None,
+        "group_by",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::having_clause,
+        None,
+        "having",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::window_clause,
+        None,
+        "window",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::limit_clause,
+        None,
+        "limit",
+    ),
+    (
+        SyntaxKind::select_no_parens,
+        SyntaxKind::offset_clause,
+        None,
+        "offset",
+    ),
+    (
+        SyntaxKind::select_limit,
+        SyntaxKind::limit_clause,
+        None,
+        "limit",
+    ),
+    (
+        SyntaxKind::select_limit,
+        SyntaxKind::offset_clause,
+        None,
+        "offset",
+    ),
+    (
+        SyntaxKind::limit_clause,
+        SyntaxKind::select_limit_value,
+        None,
+        "value",
+    ),
+    (
+        SyntaxKind::offset_clause,
+        SyntaxKind::select_offset_value,
+        None,
+        "value",
+    ),
+    (SyntaxKind::target_el, SyntaxKind::a_expr, None, "value"),
+    (SyntaxKind::target_el, SyntaxKind::ColLabel, None, "alias"),
+    (
+        SyntaxKind::target_el,
+        SyntaxKind::BareColLabel,
+        None,
+        "alias",
+    ),
+    (
+        SyntaxKind::common_table_expr,
+        SyntaxKind::name,
+        None,
+        "name",
+    ),
+    (
+        SyntaxKind::common_table_expr,
+        SyntaxKind::opt_name_list,
+        None,
+        "columns",
+    ),
+    (
+        SyntaxKind::common_table_expr,
+        SyntaxKind::PreparableStmt,
+        None,
+        "query",
+    ),
+    (
+        SyntaxKind::table_ref,
+        SyntaxKind::relation_expr,
+        None,
+        "relation",
+    ),
+    (
+        SyntaxKind::table_ref,
+        SyntaxKind::opt_alias_clause,
+        None,
+        "alias",
+    ),
+    (
+        SyntaxKind::relation_expr,
+        SyntaxKind::qualified_name,
+        None,
+        "name",
+    ),
+    (
+        SyntaxKind::joined_table,
+        SyntaxKind::table_ref,
+        Some(0),
+        "left",
+    ),
+    (
+        SyntaxKind::joined_table,
+        SyntaxKind::table_ref,
+        None,
+        "right",
+    ),
+    (
+        SyntaxKind::joined_table,
+        SyntaxKind::join_type,
+        None,
+        "type",
+    ),
+    (
+        SyntaxKind::joined_table,
+        SyntaxKind::join_qual,
+        None,
+        "condition",
+    ),
+    (SyntaxKind::join_qual, SyntaxKind::a_expr, None, "condition"),
+    (
+        SyntaxKind::join_qual,
+        SyntaxKind::name_list,
+        None,
+        "columns",
+    ),
+    (SyntaxKind::sortby, SyntaxKind::a_expr, None, "expression"),
+    (
+        SyntaxKind::sortby,
+        SyntaxKind::opt_asc_desc,
+        None,
+        "direction",
+    ),
+    (
+        SyntaxKind::sortby,
+        SyntaxKind::opt_nulls_order,
+        None,
+        "nulls",
+    ),
+    (
+        SyntaxKind::where_clause,
+        SyntaxKind::a_expr,
+        None,
+        "condition",
+    ),
+    (
+        SyntaxKind::having_clause,
+        SyntaxKind::a_expr,
+        None,
+        "condition",
+    ),
+    (
+        SyntaxKind::where_or_current_clause,
+        SyntaxKind::a_expr,
+        None,
+        "condition",
+    ),
+    (
+        SyntaxKind::InsertStmt,
+        SyntaxKind::opt_with_clause,
+        None,
+        "with",
+    ),
+    (
+        SyntaxKind::InsertStmt,
+        SyntaxKind::insert_target,
+        None,
+        "target",
+    ),
+    (
+        SyntaxKind::InsertStmt,
+        SyntaxKind::insert_rest,
+        None,
+        "rest",
+    ),
+    (
+        SyntaxKind::InsertStmt,
+        SyntaxKind::opt_on_conflict,
+        None,
+        "on_conflict",
+    ),
+    (
+        SyntaxKind::InsertStmt,
+        SyntaxKind::returning_clause,
+        None,
+        "returning",
+    ),
+    (
+        SyntaxKind::insert_target,
+        SyntaxKind::qualified_name,
+        None,
+        "name",
+    ),
+    (SyntaxKind::insert_target, SyntaxKind::ColId, None, "alias"),
+    (
+        SyntaxKind::insert_rest,
+        SyntaxKind::insert_column_list,
+        None,
+        "columns",
+    ),
+    (
+        SyntaxKind::insert_rest,
+        SyntaxKind::SelectStmt,
+        None,
+        "query",
+    ),
+    (
+        SyntaxKind::UpdateStmt,
+        SyntaxKind::opt_with_clause,
+        None,
+        "with",
+    ),
+    (
+        SyntaxKind::UpdateStmt,
+        SyntaxKind::relation_expr_opt_alias,
+        None,
+        "target",
+    ),
+    (
+        SyntaxKind::UpdateStmt,
+        SyntaxKind::set_clause_list,
+        None,
+        "set",
+    ),
+    (
+        SyntaxKind::UpdateStmt,
+        SyntaxKind::from_clause,
+        None,
+        "from",
+    ),
+    (
+        SyntaxKind::UpdateStmt,
+        SyntaxKind::where_or_current_clause,
+        None,
+        "where",
+    ),
+    (
+        SyntaxKind::UpdateStmt,
+        SyntaxKind::returning_clause,
+        None,
+        "returning",
+    ),
+    (
+        SyntaxKind::set_clause,
+        SyntaxKind::set_target,
+        None,
+        "target",
+    ),
+    (
+        SyntaxKind::set_clause,
+        SyntaxKind::set_target_list,
+        None,
+        "targets",
+    ),
+    (SyntaxKind::set_clause, SyntaxKind::a_expr, None, "value"),
+    (
+        SyntaxKind::DeleteStmt,
+        SyntaxKind::opt_with_clause,
+        None,
+        "with",
+    ),
+    (
+        SyntaxKind::DeleteStmt,
+        SyntaxKind::relation_expr_opt_alias,
+        None,
+        "target",
+    ),
+    (
+        SyntaxKind::DeleteStmt,
+        SyntaxKind::using_clause,
+        None,
+        "using",
+    ),
+    (
+        SyntaxKind::DeleteStmt,
+        SyntaxKind::where_or_current_clause,
+        None,
+        "where",
+    ),
+    (
+        SyntaxKind::DeleteStmt,
+        SyntaxKind::returning_clause,
+        None,
+        "returning",
+    ),
+    (
+        SyntaxKind::relation_expr_opt_alias,
+        SyntaxKind::relation_expr,
+        None,
+        "relation",
+    ),
+    (
+        SyntaxKind::relation_expr_opt_alias,
+        SyntaxKind::ColId,
+        None,
+        "alias",
+    ),
+    (
+        SyntaxKind::CreateStmt,
+        SyntaxKind::qualified_name,
+        None,
+        "name",
+    ),
+    (
+        SyntaxKind::CreateStmt,
+        SyntaxKind::OptTableElementList,
+        None,
+        "elements",
+    ),
+    (
+        SyntaxKind::CreateStmt,
+        SyntaxKind::OptInherit,
+        None,
+        "inherits",
+    ),
+    (SyntaxKind::columnDef, SyntaxKind::ColId, None, "name"),
+    (SyntaxKind::columnDef, SyntaxKind::Typename, None, "type"),
+    (
+        SyntaxKind::columnDef,
+        SyntaxKind::ColQualList,
+        None,
+        "constraints",
+    ),
+    (SyntaxKind::a_expr, SyntaxKind::a_expr, Some(0), "left"),
+    (SyntaxKind::a_expr, SyntaxKind::a_expr, None, "right"),
+    (SyntaxKind::a_expr, SyntaxKind::qual_Op, None, "operator"),
+    (SyntaxKind::a_expr, SyntaxKind::Typename, None, "type"),
+    (SyntaxKind::b_expr, SyntaxKind::b_expr, Some(0), "left"),
+    (SyntaxKind::b_expr, SyntaxKind::b_expr, None, "right"),
+    (SyntaxKind::b_expr, SyntaxKind::qual_Op, None, "operator"),
+    (SyntaxKind::b_expr, SyntaxKind::Typename, None, "type"),
+    (
+        SyntaxKind::case_expr,
+        SyntaxKind::case_arg,
+        None,
+        "argument",
+    ),
+    (
+        SyntaxKind::case_expr,
+        SyntaxKind::when_clause_list,
+        None,
+        "when",
+    ),
+    (
+        SyntaxKind::case_expr,
+        SyntaxKind::case_default,
+        None,
+        "default",
+    ),
+    (
+        SyntaxKind::when_clause,
+        SyntaxKind::a_expr,
+        Some(1),
+        "condition",
+    ),
+    (
+        SyntaxKind::when_clause,
+        SyntaxKind::a_expr,
+        Some(3),
+        "result",
+    ),
+    (
+        SyntaxKind::func_application,
+        SyntaxKind::func_name,
+        None,
+        "function",
+    ),
+    (
+        SyntaxKind::func_application,
+        SyntaxKind::func_arg_list,
+        None,
+        "arguments",
+    ),
+    (
+        SyntaxKind::func_arg_expr,
+        SyntaxKind::param_name,
+        None,
+        "name",
+    ),
+    (SyntaxKind::func_arg_expr, SyntaxKind::a_expr, None, "value"),
+];
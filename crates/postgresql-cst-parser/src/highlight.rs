@@ -0,0 +1,401 @@
+use cstree::text::TextRange;
+
+use crate::{
+    cst_util::is_trivia, parser::num_terminal_symbol, syntax_kind::SyntaxKind, ResolvedNode,
+    ResolvedToken,
+};
+
+/// Classification of a token for syntax highlighting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HighlightClass {
+    /// Reserved or unreserved keyword
+    Keyword,
+    Identifier,
+    /// Double-quoted identifier such as `"Foo"`
+    QuotedIdentifier,
+    FunctionName,
+    TypeName,
+    String,
+    Number,
+    Operator,
+    /// Positional parameter such as `$1`
+    Parameter,
+    Comment,
+    /// 2Way SQL directive such as `/*param*/`, `/*#tbl*/` or `/*IF cond*/`
+    DirectiveComment,
+}
+
+impl HighlightClass {
+    /// All classes, in declaration order
+    pub const ALL: [HighlightClass; 11] = [
+        HighlightClass::Keyword,
+        HighlightClass::Identifier,
+        HighlightClass::QuotedIdentifier,
+        HighlightClass::FunctionName,
+        HighlightClass::TypeName,
+        HighlightClass::String,
+        HighlightClass::Number,
+        HighlightClass::Operator,
+        HighlightClass::Parameter,
+        HighlightClass::Comment,
+        HighlightClass::DirectiveComment,
+    ];
+
+    /// Position of the class in [`HighlightClass::ALL`], for encoding the class as a number
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// Name of the class, suitable for a CSS class or a semantic token type
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HighlightClass::Keyword => "keyword",
+            HighlightClass::Identifier => "identifier",
+            HighlightClass::QuotedIdentifier => "quoted-identifier",
+            HighlightClass::FunctionName => "function",
+            HighlightClass::TypeName => "type",
+            HighlightClass::String => "string",
+            HighlightClass::Number => "number",
+            HighlightClass::Operator => "operator",
+            HighlightClass::Parameter => "parameter",
+            HighlightClass::Comment => "comment",
+            HighlightClass::DirectiveComment => "directive-comment",
+        }
+    }
+}
+
+impl std::fmt::Display for HighlightClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Classifies every token in the tree for syntax highlighting.
+///
+/// The class is determined from the surrounding nodes rather than the token kind alone,
+/// so a keyword used as a column name is reported as an identifier and the name in `func_name` as a function name.
+/// Whitespace, punctuation and tokens complemented by the 2Way SQL transformers are not reported.
+///
+/// # Examples
+///
+/// ```
+/// use postgresql_cst_parser::{highlight::{highlight, HighlightClass}, parse};
+///
+/// let root = parse("select count(name) from t").unwrap();
+/// let classes: Vec<_> = highlight(&root).into_iter().map(|(_, class)| class).collect();
+///
+/// assert_eq!(
+///     classes,
+///     [
+///         HighlightClass::Keyword,
+///         HighlightClass::FunctionName,
+///         HighlightClass::Identifier,
+///         HighlightClass::Keyword,
+///         HighlightClass::Identifier,
+///     ]
+/// );
+/// ```
+pub fn highlight(root: &ResolvedNode) -> Vec<(TextRange, HighlightClass)> {
+    root.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| !token.text_range().is_empty())
+        .filter_map(|token| classify_token(token).map(|class| (token.text_range(), class)))
+        .collect()
+}
+
+fn classify_token(token: &ResolvedToken) -> Option<HighlightClass> {
+    let class = match token.kind() {
+        SyntaxKind::Whitespace
+        | SyntaxKind::Comma
+        | SyntaxKind::Semicolon
+        | SyntaxKind::Colon
+        | SyntaxKind::Dot
+        | SyntaxKind::LParen
+        | SyntaxKind::RParen
+        | SyntaxKind::LBracket
+        | SyntaxKind::RBracket => return None,
+
+        SyntaxKind::SQL_COMMENT | SyntaxKind::PSQL_META_COMMAND | SyntaxKind::COPY_DATA => {
+            HighlightClass::Comment
+        }
+        SyntaxKind::C_COMMENT if is_2way_directive(token.text()) => {
+            HighlightClass::DirectiveComment
+        }
+        SyntaxKind::C_COMMENT => HighlightClass::Comment,
+
+        SyntaxKind::SCONST | SyntaxKind::BCONST | SyntaxKind::XCONST => HighlightClass::String,
+        SyntaxKind::ICONST | SyntaxKind::FCONST => HighlightClass::Number,
+        SyntaxKind::PARAM => HighlightClass::Parameter,
+
+        // `*` is only an operator inside expressions (not in `SELECT *` or `t.*`)
+        SyntaxKind::Star => match token.parent().kind() {
+            SyntaxKind::a_expr | SyntaxKind::b_expr | SyntaxKind::MathOp => {
+                HighlightClass::Operator
+            }
+            _ => return None,
+        },
+        SyntaxKind::Op
+        | SyntaxKind::Plus
+        | SyntaxKind::Minus
+        | SyntaxKind::Slash
+        | SyntaxKind::Percent
+        | SyntaxKind::Caret
+        | SyntaxKind::Less
+        | SyntaxKind::Greater
+        | SyntaxKind::Equals
+        | SyntaxKind::TYPECAST
+        | SyntaxKind::COLON_EQUALS
+        | SyntaxKind::EQUALS_GREATER
+        | SyntaxKind::LESS_EQUALS
+        | SyntaxKind::GREATER_EQUALS
+        | SyntaxKind::NOT_EQUALS => HighlightClass::Operator,
+
+        SyntaxKind::IDENT => classify_name(token),
+
+        kind if (kind as u32) < num_terminal_symbol() => {
+            if matches!(
+                token.parent().kind(),
+                SyntaxKind::unreserved_keyword
+                    | SyntaxKind::col_name_keyword
+                    | SyntaxKind::type_func_name_keyword
+                    | SyntaxKind::reserved_keyword
+                    | SyntaxKind::bare_label_keyword
+            ) {
+                // The keyword is used as a name
+                classify_name(token)
+            } else {
+                classify_keyword(token)
+            }
+        }
+
+        _ => return None,
+    };
+
+    Some(class)
+}
+
+/// Classifies an identifier (or a keyword used as an identifier) based on its ancestors
+fn classify_name(token: &ResolvedToken) -> HighlightClass {
+    for ancestor in token.ancestors() {
+        match ancestor.kind() {
+            // Typed literals such as `date '2000-01-01'` have the type name in `func_name`
+            SyntaxKind::func_name
+                if ancestor.parent().map(|p| p.kind()) == Some(SyntaxKind::AexprConst) =>
+            {
+                return HighlightClass::TypeName;
+            }
+            // Only the last part of a qualified name is the function or type name itself
+            SyntaxKind::func_name if is_last_token(ancestor, token) => {
+                return HighlightClass::FunctionName;
+            }
+            SyntaxKind::GenericType if is_last_name_of_generic_type(ancestor, token) => {
+                return HighlightClass::TypeName;
+            }
+            SyntaxKind::func_name | SyntaxKind::GenericType => break,
+            SyntaxKind::Typename
+            | SyntaxKind::SimpleTypename
+            | SyntaxKind::ConstTypename
+            | SyntaxKind::func_type => return HighlightClass::TypeName,
+            SyntaxKind::a_expr | SyntaxKind::b_expr | SyntaxKind::c_expr => break,
+            _ => (),
+        }
+    }
+
+    if token.kind() == SyntaxKind::IDENT && token.text().starts_with('"') {
+        HighlightClass::QuotedIdentifier
+    } else {
+        HighlightClass::Identifier
+    }
+}
+
+fn classify_keyword(token: &ResolvedToken) -> HighlightClass {
+    // e.g. `coalesce(a, b)`, `extract(year from d)`
+    if token.parent().kind() == SyntaxKind::func_expr_common_subexpr
+        && !matches!(token.kind(), SyntaxKind::CAST | SyntaxKind::TREAT)
+        && is_first_token(token.parent(), token)
+        && next_significant_token(token).map(|t| t.kind()) == Some(SyntaxKind::LParen)
+    {
+        return HighlightClass::FunctionName;
+    }
+
+    // e.g. `int`, `double precision`, `timestamp with time zone`
+    if !matches!(token.kind(), SyntaxKind::SETOF | SyntaxKind::ARRAY) {
+        for ancestor in token.ancestors() {
+            match ancestor.kind() {
+                SyntaxKind::Typename
+                | SyntaxKind::SimpleTypename
+                | SyntaxKind::ConstTypename
+                | SyntaxKind::ConstInterval => return HighlightClass::TypeName,
+                SyntaxKind::a_expr | SyntaxKind::b_expr | SyntaxKind::c_expr => break,
+                _ => (),
+            }
+        }
+    }
+
+    HighlightClass::Keyword
+}
+
+/// Whether the comment is a 2Way SQL bind variable, replacement string or directive
+///
+/// Ordinary comments are written with a space after `/*` (e.g. `/* comment */`), while 2Way SQL directives are not.
+fn is_2way_directive(comment: &str) -> bool {
+    let Some(body) = comment
+        .strip_prefix("/*")
+        .and_then(|s| s.strip_suffix("*/"))
+    else {
+        return false;
+    };
+
+    !body.contains('\n')
+        && body
+            .chars()
+            .next()
+            .is_some_and(|c| !c.is_whitespace() && c != '*')
+}
+
+fn is_first_token(node: &ResolvedNode, token: &ResolvedToken) -> bool {
+    node.first_token()
+        .is_some_and(|t| t.text_range() == token.text_range())
+}
+
+fn is_last_token(node: &ResolvedNode, token: &ResolvedToken) -> bool {
+    node.last_token()
+        .is_some_and(|t| t.text_range() == token.text_range())
+}
+
+/// GenericType: type_function_name opt_type_modifiers | type_function_name attrs opt_type_modifiers
+fn is_last_name_of_generic_type(node: &ResolvedNode, token: &ResolvedToken) -> bool {
+    node.children()
+        .filter(|child| {
+            matches!(
+                child.kind(),
+                SyntaxKind::type_function_name | SyntaxKind::attrs
+            )
+        })
+        .last()
+        .is_some_and(|name| is_last_token(name, token))
+}
+
+fn next_significant_token(token: &ResolvedToken) -> Option<&ResolvedToken> {
+    let mut next = token.next_token();
+    while let Some(t) = next {
+        if !is_trivia(t.kind()) {
+            return Some(t);
+        }
+        next = t.next_token();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{highlight, HighlightClass};
+    use crate::{parse, parse_2way};
+
+    fn classes(input: &str, root: &crate::ResolvedNode) -> Vec<(String, HighlightClass)> {
+        highlight(root)
+            .into_iter()
+            .map(|(range, class)| (input[range].to_string(), class))
+            .collect()
+    }
+
+    fn assert_class(actual: &[(String, HighlightClass)], text: &str, expected: HighlightClass) {
+        let found = actual
+            .iter()
+            .find(|(t, _)| t == text)
+            .unwrap_or_else(|| panic!("token {text:?} is not highlighted: {actual:?}"));
+        assert_eq!(found.1, expected, "unexpected class for {text:?}");
+    }
+
+    #[test]
+    fn indices() {
+        for (i, class) in HighlightClass::ALL.iter().enumerate() {
+            assert_eq!(class.index(), i);
+        }
+    }
+
+    #[test]
+    fn keywords_and_identifiers() {
+        let input = r#"SELECT name, "Quoted" FROM users WHERE id = $1 AND flag -- comment
+;"#;
+        let actual = classes(input, &parse(input).unwrap());
+
+        assert_class(&actual, "SELECT", HighlightClass::Keyword);
+        // `name` is an unreserved keyword used as a column name
+        assert_class(&actual, "name", HighlightClass::Identifier);
+        assert_class(&actual, "\"Quoted\"", HighlightClass::QuotedIdentifier);
+        assert_class(&actual, "users", HighlightClass::Identifier);
+        assert_class(&actual, "=", HighlightClass::Operator);
+        assert_class(&actual, "$1", HighlightClass::Parameter);
+        assert_class(&actual, "AND", HighlightClass::Keyword);
+        assert_class(&actual, "-- comment", HighlightClass::Comment);
+        assert!(actual.iter().all(|(t, _)| t != "," && t != ";"));
+    }
+
+    #[test]
+    fn literals() {
+        let input = "select 1, 1.5, 'a', x'ff', b'01', e'\\n';";
+        let actual = classes(input, &parse(input).unwrap());
+
+        assert_class(&actual, "1", HighlightClass::Number);
+        assert_class(&actual, "1.5", HighlightClass::Number);
+        assert_class(&actual, "'a'", HighlightClass::String);
+        assert_class(&actual, "x'ff'", HighlightClass::String);
+        assert_class(&actual, "b'01'", HighlightClass::String);
+        assert_class(&actual, "e'\\n'", HighlightClass::String);
+    }
+
+    #[test]
+    fn function_names() {
+        let input = "select pg_catalog.now(), coalesce(a, b), cast(c as int), current_date;";
+        let actual = classes(input, &parse(input).unwrap());
+
+        assert_class(&actual, "pg_catalog", HighlightClass::Identifier);
+        assert_class(&actual, "now", HighlightClass::FunctionName);
+        assert_class(&actual, "coalesce", HighlightClass::FunctionName);
+        assert_class(&actual, "cast", HighlightClass::Keyword);
+        assert_class(&actual, "current_date", HighlightClass::Keyword);
+    }
+
+    #[test]
+    fn type_names() {
+        let input = "create table t (a int, b varchar(10), c double precision, d my_schema.my_type, e text[]);\nselect a::timestamp with time zone, date '2000-01-01';";
+        let actual = classes(input, &parse(input).unwrap());
+
+        assert_class(&actual, "int", HighlightClass::TypeName);
+        assert_class(&actual, "varchar", HighlightClass::TypeName);
+        assert_class(&actual, "10", HighlightClass::Number);
+        assert_class(&actual, "double", HighlightClass::TypeName);
+        assert_class(&actual, "precision", HighlightClass::TypeName);
+        assert_class(&actual, "my_schema", HighlightClass::Identifier);
+        assert_class(&actual, "my_type", HighlightClass::TypeName);
+        assert_class(&actual, "text", HighlightClass::TypeName);
+        assert_class(&actual, "::", HighlightClass::Operator);
+        assert_class(&actual, "timestamp", HighlightClass::TypeName);
+        assert_class(&actual, "zone", HighlightClass::TypeName);
+        assert_class(&actual, "date", HighlightClass::TypeName);
+    }
+
+    #[test]
+    fn star() {
+        let input = "select *, t.*, 2 * 3 from t;";
+        let actual = classes(input, &parse(input).unwrap());
+
+        let stars: Vec<_> = actual.iter().filter(|(t, _)| t == "*").collect();
+        assert_eq!(stars.len(), 1);
+        assert_eq!(stars[0].1, HighlightClass::Operator);
+    }
+
+    #[test]
+    fn directive_comments() {
+        let input =
+            "select /* comment */ /*param*/'a' from /*#tbl*/ where /*IF cond*/ a = 1 /*END*/;";
+        let actual = classes(input, &parse_2way(input).unwrap());
+
+        assert_class(&actual, "/* comment */", HighlightClass::Comment);
+        assert_class(&actual, "/*param*/", HighlightClass::DirectiveComment);
+        assert_class(&actual, "/*#tbl*/", HighlightClass::DirectiveComment);
+        assert_class(&actual, "/*IF cond*/", HighlightClass::DirectiveComment);
+        assert_class(&actual, "/*END*/", HighlightClass::DirectiveComment);
+    }
+}
@@ -0,0 +1,168 @@
+//! Keywords of PostgreSQL
+//!
+//! The keyword list is generated by the lexer generator from `resources/kwlist.h`.
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::keywords::{keyword_category, quote_ident, KeywordCategory};
+//!
+//! assert_eq!(keyword_category("select"), Some(KeywordCategory::Reserved));
+//! assert_eq!(keyword_category("Name"), Some(KeywordCategory::Unreserved));
+//! assert_eq!(keyword_category("foo"), None);
+//!
+//! assert_eq!(quote_ident("name"), "name");
+//! assert_eq!(quote_ident("user"), "\"user\"");
+//! assert_eq!(quote_ident("Foo"), "\"Foo\"");
+//! ```
+
+use std::borrow::Cow;
+
+use crate::lexer::KEYWORD_LIST;
+
+/// Category of a keyword, which determines where it can be used as an identifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KeywordCategory {
+    /// `UNRESERVED_KEYWORD`: can be used as any name
+    Unreserved,
+    /// `COL_NAME_KEYWORD`: can be used as a column name, but not as a function or type name
+    ColName,
+    /// `TYPE_FUNC_NAME_KEYWORD`: can be used as a function or type name, but not as a column name
+    TypeFuncName,
+    /// `RESERVED_KEYWORD`: can be used only as a column label with `AS`
+    Reserved,
+}
+
+/// A keyword of `kwlist.h`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Keyword {
+    /// Lowercase name, such as `select`
+    pub name: &'static str,
+    /// Name of the token in the grammar, such as `SELECT` or `ABORT_P`
+    pub token: &'static str,
+    pub category: KeywordCategory,
+    /// `BARE_LABEL`: whether the keyword can be a column label without `AS`
+    pub bare_label: bool,
+}
+
+impl Keyword {
+    fn from_entry(
+        &(name, token, category, bare_label): &(&'static str, &'static str, KeywordCategory, bool),
+    ) -> Self {
+        Self {
+            name,
+            token,
+            category,
+            bare_label,
+        }
+    }
+}
+
+/// Returns all keywords in alphabetical order
+pub fn keywords() -> impl ExactSizeIterator<Item = Keyword> {
+    KEYWORD_LIST.iter().map(Keyword::from_entry)
+}
+
+/// Returns the keyword `name`, ignoring ASCII case
+pub fn lookup(name: &str) -> Option<Keyword> {
+    let name = name.to_ascii_lowercase();
+    KEYWORD_LIST
+        .binary_search_by(|(kw, _, _, _)| (*kw).cmp(name.as_str()))
+        .ok()
+        .map(|i| Keyword::from_entry(&KEYWORD_LIST[i]))
+}
+
+/// Returns the category of the keyword `name`, ignoring ASCII case
+pub fn keyword_category(name: &str) -> Option<KeywordCategory> {
+    lookup(name).map(|keyword| keyword.category)
+}
+
+/// Returns whether `ident` must be double-quoted to be used as an identifier
+///
+/// This is the same condition as PostgreSQL's `quote_identifier()` with `quote_all_identifiers` off:
+/// an identifier is left unquoted only if it consists of lowercase ASCII letters, digits and underscores,
+/// does not start with a digit and is not a keyword other than an unreserved one.
+pub fn needs_quoting(ident: &str) -> bool {
+    let safe = ident.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+        && ident
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_');
+
+    // We quote keywords except for unreserved ones
+    !safe || lookup(ident).is_some_and(|keyword| keyword.category != KeywordCategory::Unreserved)
+}
+
+/// Quotes `ident` if needed, like PostgreSQL's `quote_ident()`
+pub fn quote_ident(ident: &str) -> Cow<'_, str> {
+    if needs_quoting(ident) {
+        Cow::Owned(format!("\"{}\"", ident.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(ident)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        keyword_category, keywords, lookup, needs_quoting, quote_ident, Keyword, KeywordCategory,
+    };
+
+    #[test]
+    fn sorted() {
+        assert!(keywords()
+            .collect::<Vec<_>>()
+            .windows(2)
+            .all(|w| w[0].name < w[1].name));
+    }
+
+    #[test]
+    fn categories() {
+        assert_eq!(keyword_category("abort"), Some(KeywordCategory::Unreserved));
+        assert_eq!(keyword_category("BETWEEN"), Some(KeywordCategory::ColName));
+        assert_eq!(
+            keyword_category("left"),
+            Some(KeywordCategory::TypeFuncName)
+        );
+        assert_eq!(keyword_category("Where"), Some(KeywordCategory::Reserved));
+        assert_eq!(keyword_category("not_la"), None);
+        assert_eq!(keyword_category(""), None);
+    }
+
+    #[test]
+    fn bare_label() {
+        assert_eq!(
+            lookup("ABORT"),
+            Some(Keyword {
+                name: "abort",
+                token: "ABORT_P",
+                category: KeywordCategory::Unreserved,
+                bare_label: true,
+            })
+        );
+        assert!(!lookup("as").unwrap().bare_label);
+        assert!(!lookup("year").unwrap().bare_label);
+        assert_eq!(keywords().len(), 491);
+    }
+
+    #[test]
+    fn quoting() {
+        for (ident, quoted) in [
+            ("foo", "foo"),
+            ("_foo1", "_foo1"),
+            ("name", "name"),
+            ("Foo", r#""Foo""#),
+            ("1foo", r#""1foo""#),
+            ("", r#""""#),
+            ("foo bar", r#""foo bar""#),
+            (r#"a"b"#, r#""a""b""#),
+            ("café", r#""café""#),
+            // column name, type or function name and reserved keywords
+            ("int", r#""int""#),
+            ("left", r#""left""#),
+            ("select", r#""select""#),
+        ] {
+            assert_eq!(quote_ident(ident), quoted, "{ident}");
+            assert_eq!(needs_quoting(ident), ident != quoted, "{ident}");
+        }
+    }
+}
@@ -2,16 +2,64 @@
 mod generated;
 pub mod lexer_ported;
 pub mod parser_error;
+mod psql;
 mod util;
-
 use std::collections::HashMap;
 
 use parser_error::{ParserError, ScanReport};
 
+pub(crate) use self::psql::{is_psql_variable, PsqlState};
+
 use self::generated::State;
+pub(crate) use self::generated::KEYWORD_LIST;
 
 pub const NAMEDATALEN: usize = 64;
 
+/// Values of `backslash_quote` of PostgreSQL
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BackslashQuote {
+    /// `\'` is allowed in a string literal
+    On,
+    /// `\'` is rejected
+    Off,
+    /// `\'` is allowed only if the client encoding does not allow ASCII `\` within a multibyte character.
+    /// The input of the lexer is UTF-8, so this is the same as [`BackslashQuote::On`].
+    #[default]
+    SafeEncoding,
+}
+
+/// Settings of PostgreSQL that change the behavior of the lexer
+///
+/// The default values are the same as PostgreSQL 17.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LexerOptions {
+    /// `standard_conforming_strings`: whether backslashes are ordinary characters in `'...'` strings
+    pub standard_conforming_strings: bool,
+    /// `backslash_quote`: whether `\'` can be used in a string literal
+    pub backslash_quote: BackslashQuote,
+    /// `escape_string_warning`: whether backslash escapes in `'...'` strings are warned when `standard_conforming_strings` is off
+    pub escape_string_warning: bool,
+    /// Whether to accept psql scripts
+    ///
+    /// Backslash meta-commands such as `\set x 1` are [`TokenKind::PSQL_META_COMMAND`] up to the end of the line,
+    /// and those sending the query buffer such as `\g` also end the statement.
+    /// Variable references `:var`, `:'var'` and `:"var"` are identifiers or strings for the parser
+    /// and [`crate::syntax_kind::SyntaxKind::PSQL_VARIABLE`] in the tree.
+    /// The data after `COPY ... FROM STDIN` up to the line `\.` is [`TokenKind::COPY_DATA`].
+    pub psql: bool,
+}
+
+impl Default for LexerOptions {
+    fn default() -> Self {
+        Self {
+            standard_conforming_strings: true,
+            backslash_quote: BackslashQuote::SafeEncoding,
+            escape_string_warning: true,
+            psql: false,
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum Yylval {
@@ -23,6 +71,7 @@ pub enum Yylval {
 
 pub struct Lexer {
     pub input: String,
+    pub options: LexerOptions,
     pub index_bytes: usize,
     pub state: State,
     pub yyleng: usize,
@@ -47,6 +96,7 @@ pub struct Lexer {
     pub rules: Vec<Rule>,
     pub keyword_map: HashMap<&'static str, &'static str>,
     pub reports: Vec<ScanReport>,
+    pub psql: PsqlState,
 }
 
 #[cfg(feature = "regex-match")]
@@ -66,6 +116,10 @@ pub enum TokenKind {
     KEYWORD(String),
     C_COMMENT,
     SQL_COMMENT,
+    /// A psql meta-command such as `\set x 1`, up to the end of the line
+    PSQL_META_COMMAND,
+    /// The data of `COPY ... FROM STDIN` in a psql script, including the terminating `\.`
+    COPY_DATA,
     EOF,
     BCONST,
     XCONST,
@@ -94,6 +148,8 @@ impl TokenKind {
             TokenKind::KEYWORD(s) => s.to_string(),
             TokenKind::C_COMMENT => "C_COMMENT".to_string(),
             TokenKind::SQL_COMMENT => "SQL_COMMENT".to_string(),
+            TokenKind::PSQL_META_COMMAND => "PSQL_META_COMMAND".to_string(),
+            TokenKind::COPY_DATA => "COPY_DATA".to_string(),
             TokenKind::EOF => "EOF".to_string(),
             TokenKind::BCONST => "BCONST".to_string(),
             TokenKind::XCONST => "XCONST".to_string(),
@@ -124,6 +180,8 @@ where
             "IDENT" => TokenKind::IDENT,
             "C_COMMENT" => TokenKind::C_COMMENT,
             "SQL_COMMENT" => TokenKind::SQL_COMMENT,
+            "PSQL_META_COMMAND" => TokenKind::PSQL_META_COMMAND,
+            "COPY_DATA" => TokenKind::COPY_DATA,
             "EOF" => TokenKind::EOF,
             "BCONST" => TokenKind::BCONST,
             "XCONST" => TokenKind::XCONST,
@@ -157,38 +215,78 @@ pub struct Token {
 }
 
 pub fn lex(input: &str) -> Result<Vec<Token>, ParserError> {
-    let mut lexer = Lexer::new(input);
+    lex_with_warnings(input).map(|(tokens, _)| tokens)
+}
+
+/// Same as [`lex`], but also returns the non-fatal reports of the lexer, such as `WARNING` and `NOTICE` of PostgreSQL
+pub fn lex_with_warnings(input: &str) -> Result<(Vec<Token>, Vec<ScanReport>), ParserError> {
+    lex_with_options(input, &LexerOptions::default())
+}
+
+/// Same as [`lex_with_warnings`], but with the given settings
+pub fn lex_with_options(
+    input: &str,
+    options: &LexerOptions,
+) -> Result<(Vec<Token>, Vec<ScanReport>), ParserError> {
+    let mut lexer = Lexer::new(input, options.clone());
 
     let mut tokens = vec![];
-    while let Some(kind) = lexer.parse_token()? {
+    while let Some((token, _)) = lexer.next_token()? {
+        tokens.push(token);
+    }
+
+    // dbg!(&tokens);
+    Ok((tokens, lexer.reports))
+}
+
+impl Lexer {
+    /// Returns the next token and its semantic value, or `None` at the end of the input
+    pub(crate) fn next_token(&mut self) -> Result<Option<(Token, Yylval)>, ParserError> {
+        let psql_token = if self.options.psql {
+            self.psql_token()
+        } else {
+            None
+        };
+        let kind = match psql_token {
+            Some(kind) => kind,
+            None => match self.parse_token()? {
+                Some(kind) => kind,
+                None => return Ok(None),
+            },
+        };
         // dbg!(&kind);
         if kind == TokenKind::EOF {
-            break;
+            return Ok(None);
         }
 
-        let start_byte_pos = lexer.yylloc_bytes;
+        let start_byte_pos = self.yylloc_bytes;
         let end_byte_pos = if matches!(
             kind,
             TokenKind::SCONST
+                | TokenKind::USCONST
                 | TokenKind::BCONST
                 | TokenKind::XCONST
                 | TokenKind::IDENT
+                | TokenKind::UIDENT
                 | TokenKind::C_COMMENT
         ) {
-            lexer.yyllocend_bytes
+            self.yyllocend_bytes
         } else {
-            lexer.yylloc_bytes + lexer.yyleng
+            self.yylloc_bytes + self.yyleng
         };
 
-        tokens.push(Token {
+        let token = Token {
             start_byte_pos,
             end_byte_pos,
             kind,
-            value: input[start_byte_pos..end_byte_pos].to_string(),
-        });
-        lexer.advance();
-    }
+            value: self.input[start_byte_pos..end_byte_pos].to_string(),
+        };
+        let yylval = std::mem::replace(&mut self.yylval, Yylval::Uninitialized);
+        self.advance();
+        if self.options.psql {
+            self.psql.track(&token.kind);
+        }
 
-    // dbg!(&tokens);
-    Ok(tokens)
+        Ok(Some((token, yylval)))
+    }
 }
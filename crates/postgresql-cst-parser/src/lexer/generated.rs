@@ -4,12 +4,14 @@
 /// This file contains ported sources from PostgreSQL
 use std::collections::HashMap;
 
+use crate::keywords::KeywordCategory;
+
 use super::{
     lexer_ported::{
         get_char_by_byte_pos, is_highbit_set, is_utf16_surrogate_first, is_utf16_surrogate_second,
         surrogate_pair_to_codepoint,
     },
-    Lexer, ParserError, TokenKind, Yylval, NAMEDATALEN,
+    BackslashQuote, Lexer, ParserError, ScanReport, TokenKind, Yylval, NAMEDATALEN,
 };
 
 macro_rules! ereport {
@@ -20,21 +22,29 @@ macro_rules! ereport {
             $err_position,
         ));
     };
-    ($lexer:expr, ERROR, (errcode($err_code:expr), errmsg($err_msg:expr), errhint($err_detail:expr), $err_position:expr)) => {
-        return Err(ParserError::new_report(
+    ($lexer:expr, ERROR, (errcode($err_code:expr), errmsg($err_msg:expr), errhint($err_hint:expr), $err_position:expr)) => {
+        return Err(ParserError::ScanReport(ScanReport::new_hint(
             $err_msg,
-            $err_detail,
+            $err_hint,
             $err_position,
-        ));
+        )));
     };
     ($lexer:expr, WARNING, (errcode($err_code:expr), errmsg($err_msg:expr), errdetail($err_detail:expr), $err_position:expr)) => {
-        $lexer.add_warning(ScanReport::new($err_msg, $err_detail, $err_position));
+        $lexer.add_warning(
+            ScanReport::new($err_msg, $err_detail, $err_position).with_level(ReportLevel::Warning),
+        );
+    };
+    ($lexer:expr, WARNING, (errcode($err_code:expr), errmsg($err_msg:expr), errhint($err_hint:expr), $err_position:expr)) => {
+        $lexer.add_warning(
+            ScanReport::new_hint($err_msg, $err_hint, $err_position)
+                .with_level(ReportLevel::Warning),
+        );
     };
 }
 
 macro_rules! yyerror {
-    ($msg:expr) => {
-        return Err(ParserError::new_error($msg))
+    ($lexer:expr, $msg:expr) => {
+        return Err($lexer.yyerror($msg))
     };
 }
 
@@ -3788,8 +3798,6 @@ pub enum State {
     xeu,
 }
 
-const STANDARD_CONFORMING_STRINGS: bool = true;
-
 impl Lexer {
     #[cfg(not(feature = "regex-match"))]
     pub fn parse_token(&mut self) -> Result<Option<TokenKind>, ParserError> {
@@ -3870,7 +3878,7 @@ impl Lexer {
                     { /* ignore */ }
                 }
                 8 => {
-                    yyerror!("unterminated /* comment");
+                    yyerror!(self, "unterminated /* comment");
                 }
                 9 => {
                     {
@@ -3905,7 +3913,7 @@ impl Lexer {
                     }
                 }
                 12 => {
-                    yyerror!("unterminated bit string literal");
+                    yyerror!(self, "unterminated bit string literal");
                 }
                 13 => {
                     {
@@ -3933,7 +3941,7 @@ impl Lexer {
                     }
                 }
                 14 => {
-                    yyerror!("unterminated hexadecimal string literal");
+                    yyerror!(self, "unterminated hexadecimal string literal");
                 }
                 15 => {
                     {
@@ -3990,7 +3998,7 @@ impl Lexer {
                         self.warn_on_first_escape = true;
                         self.saw_non_ascii = false;
                         self.set_yylloc();
-                        if STANDARD_CONFORMING_STRINGS {
+                        if self.options.standard_conforming_strings {
                             self.begin(State::xq);
                         } else {
                             self.begin(State::xe);
@@ -4026,7 +4034,7 @@ impl Lexer {
                         // startlit();
 
                         self.set_yylloc();
-                        if !STANDARD_CONFORMING_STRINGS {
+                        if !self.options.standard_conforming_strings {
                             ereport!(self, ERROR,
 								(errcode(ERRCODE_FEATURE_NOT_SUPPORTED),
 								 errmsg("unsafe use of string constant with Unicode escapes"),
@@ -4158,7 +4166,7 @@ impl Lexer {
                                 self.set_yyllocend();
                                 return Ok(Some(TokenKind::USCONST));
                             }
-                            _ => yyerror!("unhandled previous state in xqs"),
+                            _ => yyerror!(self, "unhandled previous state in xqs"),
                         }
                     }
                 }
@@ -4225,7 +4233,7 @@ impl Lexer {
                             self.utf16_first_part = c;
                             self.begin(State::xeu);
                         } else if is_utf16_surrogate_second(c) {
-                            yyerror!("invalid Unicode surrogate pair");
+                            yyerror!(self, "invalid Unicode surrogate pair");
                         } else {
                             self.addunicode(char::from_u32(c as u32).unwrap())?;
                         }
@@ -4264,7 +4272,7 @@ impl Lexer {
                         self.set_yylloc();
 
                         if !is_utf16_surrogate_second(c) {
-                            yyerror!("invalid Unicode surrogate pair");
+                            yyerror!(self, "invalid Unicode surrogate pair");
                         }
 
                         let c = surrogate_pair_to_codepoint(self.utf16_first_part, c);
@@ -4282,7 +4290,7 @@ impl Lexer {
 
                         /* Set the error cursor to point at missing esc seq */
                         self.set_yylloc();
-                        yyerror!("invalid Unicode surrogate pair");
+                        yyerror!(self, "invalid Unicode surrogate pair");
                     }
                 }
                 32 => {
@@ -4326,19 +4334,16 @@ impl Lexer {
                         // 		   yyscanner);
 
                         let c = self.yytext().chars().nth(1).unwrap();
-                        // if c == '\'' {
-                        // 	if self.backslash_quote == BACKSLASH_QUOTE_OFF ||
-                        // 		(self.backslash_quote == BACKSLASH_QUOTE_SAFE_ENCODING &&
-                        // 		 PG_ENCODING_IS_CLIENT_ONLY(pg_get_client_encoding())) {
-                        // 		ereport!(self, ERROR,
-                        // 				(errcode(ERRCODE_NONSTANDARD_USE_OF_ESCAPE_CHARACTER),
-                        // 				 errmsg("unsafe use of \\' in a string literal"),
-                        // 				 errhint("Use '' to write quotes in strings. \\' is insecure in client-only encodings."),
-                        // 				 self.lexer_errposition()));
-                        // 	}
-                        // }
+                        // The input is UTF-8, which is not a client-only encoding
+                        if c == '\'' && self.options.backslash_quote == BackslashQuote::Off {
+                            ereport!(self, ERROR,
+								(errcode(ERRCODE_NONSTANDARD_USE_OF_ESCAPE_CHARACTER),
+								 errmsg("unsafe use of \\' in a string literal"),
+								 errhint("Use '' to write quotes in strings. \\' is insecure in client-only encodings."),
+								 self.lexer_errposition()));
+                        }
 
-                        // self.check_string_escape_warning(c);
+                        self.check_string_escape_warning(c);
                         let c = self.unescape_single_char(c);
                         self.addlitchar(c);
                     }
@@ -4359,7 +4364,7 @@ impl Lexer {
                         .unwrap();
                         let c = char::from_u32(c).unwrap();
 
-                        // self.check_escape_warning();
+                        self.check_escape_warning();
                         self.addlitchar(c);
                         if c == '\0' || is_highbit_set(c) != 0 {
                             self.saw_non_ascii = true;
@@ -4376,13 +4381,13 @@ impl Lexer {
                         // 	yyextra->saw_non_ascii = true;
 
                         let c = u32::from_str_radix(
-                            &self.input[self.index_bytes + 1..self.index_bytes + self.yyleng],
+                            &self.input[self.index_bytes + 2..self.index_bytes + self.yyleng],
                             16,
                         )
                         .unwrap();
                         let c = char::from_u32(c).unwrap();
 
-                        // self.check_escape_warning();
+                        self.check_escape_warning();
                         self.addlitchar(c);
                         if c == '\0' || is_highbit_set(c) != 0 {
                             self.saw_non_ascii = true;
@@ -4400,7 +4405,7 @@ impl Lexer {
                     }
                 }
                 37 => {
-                    yyerror!("unterminated quoted string");
+                    yyerror!(self, "unterminated quoted string");
                 }
                 38 => {
                     {
@@ -4493,7 +4498,7 @@ impl Lexer {
                     }
                 }
                 44 => {
-                    yyerror!("unterminated dollar-quoted string");
+                    yyerror!(self, "unterminated dollar-quoted string");
                 }
                 45 => {
                     {
@@ -4533,13 +4538,9 @@ impl Lexer {
 
                         self.begin(State::INITIAL);
                         if self.literal.len() == 0 {
-                            yyerror!("zero-length delimited identifier");
-                        }
-                        let ident = self.literal.clone();
-                        if self.literal.len() >= NAMEDATALEN {
-                            // TODO
-                            // panic!();
+                            yyerror!(self, "zero-length delimited identifier");
                         }
+                        let ident = self.truncate_identifier(self.literal.clone(), true);
                         self.yylval = Yylval::Str(ident);
                         self.set_yyllocend();
                         return Ok(Some(TokenKind::IDENT));
@@ -4557,10 +4558,10 @@ impl Lexer {
 
                         self.begin(State::INITIAL);
                         if self.literal.len() == 0 {
-                            yyerror!("zero-length delimited identifier");
+                            yyerror!(self, "zero-length delimited identifier");
                         }
                         /* can't truncate till after we de-escape the ident */
-                        self.yylval = Yylval::Str(self.yytext());
+                        self.yylval = Yylval::Str(self.literal.clone());
                         self.set_yyllocend();
                         return Ok(Some(TokenKind::UIDENT));
                     }
@@ -4580,7 +4581,7 @@ impl Lexer {
                     }
                 }
                 51 => {
-                    yyerror!("unterminated quoted identifier");
+                    yyerror!(self, "unterminated quoted identifier");
                 }
                 52 => {
                     {
@@ -4909,7 +4910,7 @@ impl Lexer {
                          * a syntactic mistake anyway.
                          */
                         if nchars >= NAMEDATALEN {
-                            yyerror!("operator too long");
+                            yyerror!(self, "operator too long");
                         }
 
                         self.yylval = Yylval::Str(yytext);
@@ -4970,7 +4971,7 @@ impl Lexer {
                         // yyerror("invalid hexadecimal integer");
 
                         self.set_yylloc();
-                        yyerror!("invalid hexadecimal integer");
+                        yyerror!(self, "invalid hexadecimal integer");
                     }
                 }
                 69 => {
@@ -4979,7 +4980,7 @@ impl Lexer {
                         // yyerror("invalid octal integer");
 
                         self.set_yylloc();
-                        yyerror!("invalid octal integer");
+                        yyerror!(self, "invalid octal integer");
                     }
                 }
                 70 => {
@@ -4988,7 +4989,7 @@ impl Lexer {
                         // yyerror("invalid binary integer");
 
                         self.set_yylloc();
-                        yyerror!("invalid binary integer");
+                        yyerror!(self, "invalid binary integer");
                     }
                 }
                 71 => {
@@ -5032,7 +5033,7 @@ impl Lexer {
                         // yyerror("trailing junk after numeric literal");
 
                         self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
+                        yyerror!(self, "trailing junk after numeric literal");
                     }
                 }
                 75 => {
@@ -5041,7 +5042,7 @@ impl Lexer {
                         // yyerror("trailing junk after numeric literal");
 
                         self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
+                        yyerror!(self, "trailing junk after numeric literal");
                     }
                 }
                 76 => {
@@ -5050,7 +5051,7 @@ impl Lexer {
                         // yyerror("trailing junk after numeric literal");
 
                         self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
+                        yyerror!(self, "trailing junk after numeric literal");
                     }
                 }
                 77 => {
@@ -5059,7 +5060,7 @@ impl Lexer {
                         // yyerror("trailing junk after numeric literal");
 
                         self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
+                        yyerror!(self, "trailing junk after numeric literal");
                     }
                 }
                 78 => {
@@ -5213,7 +5214,7 @@ impl Lexer {
                     { /* ignore */ }
                 }
                 RuleKind::xc6 => {
-                    yyerror!("unterminated /* comment");
+                    yyerror!(self, "unterminated /* comment");
                 }
                 RuleKind::INITIAL4 => {
                     {
@@ -5248,7 +5249,7 @@ impl Lexer {
                     }
                 }
                 RuleKind::xb2 => {
-                    yyerror!("unterminated bit string literal");
+                    yyerror!(self, "unterminated bit string literal");
                 }
                 RuleKind::INITIAL5 => {
                     {
@@ -5276,7 +5277,7 @@ impl Lexer {
                     }
                 }
                 RuleKind::xh2 => {
-                    yyerror!("unterminated hexadecimal string literal");
+                    yyerror!(self, "unterminated hexadecimal string literal");
                 }
                 RuleKind::INITIAL6 => {
                     {
@@ -5333,7 +5334,7 @@ impl Lexer {
                         self.warn_on_first_escape = true;
                         self.saw_non_ascii = false;
                         self.set_yylloc();
-                        if STANDARD_CONFORMING_STRINGS {
+                        if self.options.standard_conforming_strings {
                             self.begin(State::xq);
                         } else {
                             self.begin(State::xe);
@@ -5369,7 +5370,7 @@ impl Lexer {
                         // startlit();
 
                         self.set_yylloc();
-                        if !STANDARD_CONFORMING_STRINGS {
+                        if !self.options.standard_conforming_strings {
                             ereport!(self, ERROR,
 								(errcode(ERRCODE_FEATURE_NOT_SUPPORTED),
 								 errmsg("unsafe use of string constant with Unicode escapes"),
@@ -5601,7 +5602,7 @@ impl Lexer {
                                 self.set_yyllocend();
                                 return Ok(Some(TokenKind::USCONST));
                             }
-                            _ => yyerror!("unhandled previous state in xqs"),
+                            _ => yyerror!(self, "unhandled previous state in xqs"),
                         }
                     }
                 }
@@ -5689,7 +5690,7 @@ impl Lexer {
                             self.utf16_first_part = c;
                             self.begin(State::xeu);
                         } else if is_utf16_surrogate_second(c) {
-                            yyerror!("invalid Unicode surrogate pair");
+                            yyerror!(self, "invalid Unicode surrogate pair");
                         } else {
                             self.addunicode(char::from_u32(c as u32).unwrap())?;
                         }
@@ -5728,7 +5729,7 @@ impl Lexer {
                         self.set_yylloc();
 
                         if !is_utf16_surrogate_second(c) {
-                            yyerror!("invalid Unicode surrogate pair");
+                            yyerror!(self, "invalid Unicode surrogate pair");
                         }
 
                         let c = surrogate_pair_to_codepoint(self.utf16_first_part, c);
@@ -5746,7 +5747,7 @@ impl Lexer {
 
                         /* Set the error cursor to point at missing esc seq */
                         self.set_yylloc();
-                        yyerror!("invalid Unicode surrogate pair");
+                        yyerror!(self, "invalid Unicode surrogate pair");
                     }
                 }
                 RuleKind::xe5 => {
@@ -5813,19 +5814,16 @@ impl Lexer {
                         // 		   yyscanner);
 
                         let c = self.yytext().chars().nth(1).unwrap();
-                        // if c == '\'' {
-                        // 	if self.backslash_quote == BACKSLASH_QUOTE_OFF ||
-                        // 		(self.backslash_quote == BACKSLASH_QUOTE_SAFE_ENCODING &&
-                        // 		 PG_ENCODING_IS_CLIENT_ONLY(pg_get_client_encoding())) {
-                        // 		ereport!(self, ERROR,
-                        // 				(errcode(ERRCODE_NONSTANDARD_USE_OF_ESCAPE_CHARACTER),
-                        // 				 errmsg("unsafe use of \\' in a string literal"),
-                        // 				 errhint("Use '' to write quotes in strings. \\' is insecure in client-only encodings."),
-                        // 				 self.lexer_errposition()));
-                        // 	}
-                        // }
+                        // The input is UTF-8, which is not a client-only encoding
+                        if c == '\'' && self.options.backslash_quote == BackslashQuote::Off {
+                            ereport!(self, ERROR,
+								(errcode(ERRCODE_NONSTANDARD_USE_OF_ESCAPE_CHARACTER),
+								 errmsg("unsafe use of \\' in a string literal"),
+								 errhint("Use '' to write quotes in strings. \\' is insecure in client-only encodings."),
+								 self.lexer_errposition()));
+                        }
 
-                        // self.check_string_escape_warning(c);
+                        self.check_string_escape_warning(c);
                         let c = self.unescape_single_char(c);
                         self.addlitchar(c);
                     }
@@ -5846,7 +5844,7 @@ impl Lexer {
                         .unwrap();
                         let c = char::from_u32(c).unwrap();
 
-                        // self.check_escape_warning();
+                        self.check_escape_warning();
                         self.addlitchar(c);
                         if c == '\0' || is_highbit_set(c) != 0 {
                             self.saw_non_ascii = true;
@@ -5863,13 +5861,13 @@ impl Lexer {
                         // 	yyextra->saw_non_ascii = true;
 
                         let c = u32::from_str_radix(
-                            &self.input[self.index_bytes + 1..self.index_bytes + self.yyleng],
+                            &self.input[self.index_bytes + 2..self.index_bytes + self.yyleng],
                             16,
                         )
                         .unwrap();
                         let c = char::from_u32(c).unwrap();
 
-                        // self.check_escape_warning();
+                        self.check_escape_warning();
                         self.addlitchar(c);
                         if c == '\0' || is_highbit_set(c) != 0 {
                             self.saw_non_ascii = true;
@@ -5887,13 +5885,13 @@ impl Lexer {
                     }
                 }
                 RuleKind::xq4 => {
-                    yyerror!("unterminated quoted string");
+                    yyerror!(self, "unterminated quoted string");
                 }
                 RuleKind::xe10 => {
-                    yyerror!("unterminated quoted string");
+                    yyerror!(self, "unterminated quoted string");
                 }
                 RuleKind::xus4 => {
-                    yyerror!("unterminated quoted string");
+                    yyerror!(self, "unterminated quoted string");
                 }
                 RuleKind::INITIAL10 => {
                     {
@@ -5986,7 +5984,7 @@ impl Lexer {
                     }
                 }
                 RuleKind::xdolq5 => {
-                    yyerror!("unterminated dollar-quoted string");
+                    yyerror!(self, "unterminated dollar-quoted string");
                 }
                 RuleKind::INITIAL12 => {
                     {
@@ -6026,13 +6024,9 @@ impl Lexer {
 
                         self.begin(State::INITIAL);
                         if self.literal.len() == 0 {
-                            yyerror!("zero-length delimited identifier");
-                        }
-                        let ident = self.literal.clone();
-                        if self.literal.len() >= NAMEDATALEN {
-                            // TODO
-                            // panic!();
+                            yyerror!(self, "zero-length delimited identifier");
                         }
+                        let ident = self.truncate_identifier(self.literal.clone(), true);
                         self.yylval = Yylval::Str(ident);
                         self.set_yyllocend();
                         return Ok(Some(TokenKind::IDENT));
@@ -6050,10 +6044,10 @@ impl Lexer {
 
                         self.begin(State::INITIAL);
                         if self.literal.len() == 0 {
-                            yyerror!("zero-length delimited identifier");
+                            yyerror!(self, "zero-length delimited identifier");
                         }
                         /* can't truncate till after we de-escape the ident */
-                        self.yylval = Yylval::Str(self.yytext());
+                        self.yylval = Yylval::Str(self.literal.clone());
                         self.set_yyllocend();
                         return Ok(Some(TokenKind::UIDENT));
                     }
@@ -6087,10 +6081,10 @@ impl Lexer {
                     }
                 }
                 RuleKind::xd4 => {
-                    yyerror!("unterminated quoted identifier");
+                    yyerror!(self, "unterminated quoted identifier");
                 }
                 RuleKind::xui4 => {
-                    yyerror!("unterminated quoted identifier");
+                    yyerror!(self, "unterminated quoted identifier");
                 }
                 RuleKind::INITIAL14 => {
                     {
@@ -6419,7 +6413,7 @@ impl Lexer {
                          * a syntactic mistake anyway.
                          */
                         if nchars >= NAMEDATALEN {
-                            yyerror!("operator too long");
+                            yyerror!(self, "operator too long");
                         }
 
                         self.yylval = Yylval::Str(yytext);
@@ -6480,7 +6474,7 @@ impl Lexer {
                         // yyerror("invalid hexadecimal integer");
 
                         self.set_yylloc();
-                        yyerror!("invalid hexadecimal integer");
+                        yyerror!(self, "invalid hexadecimal integer");
                     }
                 }
                 RuleKind::INITIAL31 => {
@@ -6489,7 +6483,7 @@ impl Lexer {
                         // yyerror("invalid octal integer");
 
                         self.set_yylloc();
-                        yyerror!("invalid octal integer");
+                        yyerror!(self, "invalid octal integer");
                     }
                 }
                 RuleKind::INITIAL32 => {
@@ -6498,7 +6492,7 @@ impl Lexer {
                         // yyerror("invalid binary integer");
 
                         self.set_yylloc();
-                        yyerror!("invalid binary integer");
+                        yyerror!(self, "invalid binary integer");
                     }
                 }
                 RuleKind::INITIAL33 => {
@@ -6542,7 +6536,7 @@ impl Lexer {
                         // yyerror("trailing junk after numeric literal");
 
                         self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
+                        yyerror!(self, "trailing junk after numeric literal");
                     }
                 }
                 RuleKind::INITIAL37 => {
@@ -6551,7 +6545,7 @@ impl Lexer {
                         // yyerror("trailing junk after numeric literal");
 
                         self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
+                        yyerror!(self, "trailing junk after numeric literal");
                     }
                 }
                 RuleKind::INITIAL38 => {
@@ -6560,7 +6554,7 @@ impl Lexer {
                         // yyerror("trailing junk after numeric literal");
 
                         self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
+                        yyerror!(self, "trailing junk after numeric literal");
                     }
                 }
                 RuleKind::INITIAL39 => {
@@ -6569,7 +6563,7 @@ impl Lexer {
                         // yyerror("trailing junk after numeric literal");
 
                         self.set_yylloc();
-                        yyerror!("trailing junk after numeric literal");
+                        yyerror!(self, "trailing junk after numeric literal");
                     }
                 }
                 RuleKind::INITIAL40 => {
@@ -7400,501 +7394,784 @@ pub fn get_rules() -> Vec<crate::lexer::Rule> {
                 }]
 }
 
+/// Keywords of kwlist.h: the name, the token, the category and whether it can be a bare column label
+pub static KEYWORD_LIST: &[(&str, &str, KeywordCategory, bool)] = &[
+    ("abort", "ABORT_P", KeywordCategory::Unreserved, true),
+    ("absent", "ABSENT", KeywordCategory::Unreserved, true),
+    ("absolute", "ABSOLUTE_P", KeywordCategory::Unreserved, true),
+    ("access", "ACCESS", KeywordCategory::Unreserved, true),
+    ("action", "ACTION", KeywordCategory::Unreserved, true),
+    ("add", "ADD_P", KeywordCategory::Unreserved, true),
+    ("admin", "ADMIN", KeywordCategory::Unreserved, true),
+    ("after", "AFTER", KeywordCategory::Unreserved, true),
+    ("aggregate", "AGGREGATE", KeywordCategory::Unreserved, true),
+    ("all", "ALL", KeywordCategory::Reserved, true),
+    ("also", "ALSO", KeywordCategory::Unreserved, true),
+    ("alter", "ALTER", KeywordCategory::Unreserved, true),
+    ("always", "ALWAYS", KeywordCategory::Unreserved, true),
+    ("analyse", "ANALYSE", KeywordCategory::Reserved, true),
+    ("analyze", "ANALYZE", KeywordCategory::Reserved, true),
+    ("and", "AND", KeywordCategory::Reserved, true),
+    ("any", "ANY", KeywordCategory::Reserved, true),
+    ("array", "ARRAY", KeywordCategory::Reserved, false),
+    ("as", "AS", KeywordCategory::Reserved, false),
+    ("asc", "ASC", KeywordCategory::Reserved, true),
+    (
+        "asensitive",
+        "ASENSITIVE",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("assertion", "ASSERTION", KeywordCategory::Unreserved, true),
+    (
+        "assignment",
+        "ASSIGNMENT",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("asymmetric", "ASYMMETRIC", KeywordCategory::Reserved, true),
+    ("at", "AT", KeywordCategory::Unreserved, true),
+    ("atomic", "ATOMIC", KeywordCategory::Unreserved, true),
+    ("attach", "ATTACH", KeywordCategory::Unreserved, true),
+    ("attribute", "ATTRIBUTE", KeywordCategory::Unreserved, true),
+    (
+        "authorization",
+        "AUTHORIZATION",
+        KeywordCategory::TypeFuncName,
+        true,
+    ),
+    ("backward", "BACKWARD", KeywordCategory::Unreserved, true),
+    ("before", "BEFORE", KeywordCategory::Unreserved, true),
+    ("begin", "BEGIN_P", KeywordCategory::Unreserved, true),
+    ("between", "BETWEEN", KeywordCategory::ColName, true),
+    ("bigint", "BIGINT", KeywordCategory::ColName, true),
+    ("binary", "BINARY", KeywordCategory::TypeFuncName, true),
+    ("bit", "BIT", KeywordCategory::ColName, true),
+    ("boolean", "BOOLEAN_P", KeywordCategory::ColName, true),
+    ("both", "BOTH", KeywordCategory::Reserved, true),
+    ("breadth", "BREADTH", KeywordCategory::Unreserved, true),
+    ("by", "BY", KeywordCategory::Unreserved, true),
+    ("cache", "CACHE", KeywordCategory::Unreserved, true),
+    ("call", "CALL", KeywordCategory::Unreserved, true),
+    ("called", "CALLED", KeywordCategory::Unreserved, true),
+    ("cascade", "CASCADE", KeywordCategory::Unreserved, true),
+    ("cascaded", "CASCADED", KeywordCategory::Unreserved, true),
+    ("case", "CASE", KeywordCategory::Reserved, true),
+    ("cast", "CAST", KeywordCategory::Reserved, true),
+    ("catalog", "CATALOG_P", KeywordCategory::Unreserved, true),
+    ("chain", "CHAIN", KeywordCategory::Unreserved, true),
+    ("char", "CHAR_P", KeywordCategory::ColName, false),
+    ("character", "CHARACTER", KeywordCategory::ColName, false),
+    (
+        "characteristics",
+        "CHARACTERISTICS",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("check", "CHECK", KeywordCategory::Reserved, true),
+    (
+        "checkpoint",
+        "CHECKPOINT",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("class", "CLASS", KeywordCategory::Unreserved, true),
+    ("close", "CLOSE", KeywordCategory::Unreserved, true),
+    ("cluster", "CLUSTER", KeywordCategory::Unreserved, true),
+    ("coalesce", "COALESCE", KeywordCategory::ColName, true),
+    ("collate", "COLLATE", KeywordCategory::Reserved, true),
+    (
+        "collation",
+        "COLLATION",
+        KeywordCategory::TypeFuncName,
+        true,
+    ),
+    ("column", "COLUMN", KeywordCategory::Reserved, true),
+    ("columns", "COLUMNS", KeywordCategory::Unreserved, true),
+    ("comment", "COMMENT", KeywordCategory::Unreserved, true),
+    ("comments", "COMMENTS", KeywordCategory::Unreserved, true),
+    ("commit", "COMMIT", KeywordCategory::Unreserved, true),
+    ("committed", "COMMITTED", KeywordCategory::Unreserved, true),
+    (
+        "compression",
+        "COMPRESSION",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    (
+        "concurrently",
+        "CONCURRENTLY",
+        KeywordCategory::TypeFuncName,
+        true,
+    ),
+    (
+        "conditional",
+        "CONDITIONAL",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    (
+        "configuration",
+        "CONFIGURATION",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("conflict", "CONFLICT", KeywordCategory::Unreserved, true),
+    (
+        "connection",
+        "CONNECTION",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("constraint", "CONSTRAINT", KeywordCategory::Reserved, true),
+    (
+        "constraints",
+        "CONSTRAINTS",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("content", "CONTENT_P", KeywordCategory::Unreserved, true),
+    ("continue", "CONTINUE_P", KeywordCategory::Unreserved, true),
+    (
+        "conversion",
+        "CONVERSION_P",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("copy", "COPY", KeywordCategory::Unreserved, true),
+    ("cost", "COST", KeywordCategory::Unreserved, true),
+    ("create", "CREATE", KeywordCategory::Reserved, false),
+    ("cross", "CROSS", KeywordCategory::TypeFuncName, true),
+    ("csv", "CSV", KeywordCategory::Unreserved, true),
+    ("cube", "CUBE", KeywordCategory::Unreserved, true),
+    ("current", "CURRENT_P", KeywordCategory::Unreserved, true),
+    (
+        "current_catalog",
+        "CURRENT_CATALOG",
+        KeywordCategory::Reserved,
+        true,
+    ),
+    (
+        "current_date",
+        "CURRENT_DATE",
+        KeywordCategory::Reserved,
+        true,
+    ),
+    (
+        "current_role",
+        "CURRENT_ROLE",
+        KeywordCategory::Reserved,
+        true,
+    ),
+    (
+        "current_schema",
+        "CURRENT_SCHEMA",
+        KeywordCategory::TypeFuncName,
+        true,
+    ),
+    (
+        "current_time",
+        "CURRENT_TIME",
+        KeywordCategory::Reserved,
+        true,
+    ),
+    (
+        "current_timestamp",
+        "CURRENT_TIMESTAMP",
+        KeywordCategory::Reserved,
+        true,
+    ),
+    (
+        "current_user",
+        "CURRENT_USER",
+        KeywordCategory::Reserved,
+        true,
+    ),
+    ("cursor", "CURSOR", KeywordCategory::Unreserved, true),
+    ("cycle", "CYCLE", KeywordCategory::Unreserved, true),
+    ("data", "DATA_P", KeywordCategory::Unreserved, true),
+    ("database", "DATABASE", KeywordCategory::Unreserved, true),
+    ("day", "DAY_P", KeywordCategory::Unreserved, false),
+    (
+        "deallocate",
+        "DEALLOCATE",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("dec", "DEC", KeywordCategory::ColName, true),
+    ("decimal", "DECIMAL_P", KeywordCategory::ColName, true),
+    ("declare", "DECLARE", KeywordCategory::Unreserved, true),
+    ("default", "DEFAULT", KeywordCategory::Reserved, true),
+    ("defaults", "DEFAULTS", KeywordCategory::Unreserved, true),
+    ("deferrable", "DEFERRABLE", KeywordCategory::Reserved, true),
+    ("deferred", "DEFERRED", KeywordCategory::Unreserved, true),
+    ("definer", "DEFINER", KeywordCategory::Unreserved, true),
+    ("delete", "DELETE_P", KeywordCategory::Unreserved, true),
+    ("delimiter", "DELIMITER", KeywordCategory::Unreserved, true),
+    (
+        "delimiters",
+        "DELIMITERS",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("depends", "DEPENDS", KeywordCategory::Unreserved, true),
+    ("depth", "DEPTH", KeywordCategory::Unreserved, true),
+    ("desc", "DESC", KeywordCategory::Reserved, true),
+    ("detach", "DETACH", KeywordCategory::Unreserved, true),
+    (
+        "dictionary",
+        "DICTIONARY",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("disable", "DISABLE_P", KeywordCategory::Unreserved, true),
+    ("discard", "DISCARD", KeywordCategory::Unreserved, true),
+    ("distinct", "DISTINCT", KeywordCategory::Reserved, true),
+    ("do", "DO", KeywordCategory::Reserved, true),
+    ("document", "DOCUMENT_P", KeywordCategory::Unreserved, true),
+    ("domain", "DOMAIN_P", KeywordCategory::Unreserved, true),
+    ("double", "DOUBLE_P", KeywordCategory::Unreserved, true),
+    ("drop", "DROP", KeywordCategory::Unreserved, true),
+    ("each", "EACH", KeywordCategory::Unreserved, true),
+    ("else", "ELSE", KeywordCategory::Reserved, true),
+    ("empty", "EMPTY_P", KeywordCategory::Unreserved, true),
+    ("enable", "ENABLE_P", KeywordCategory::Unreserved, true),
+    ("encoding", "ENCODING", KeywordCategory::Unreserved, true),
+    ("encrypted", "ENCRYPTED", KeywordCategory::Unreserved, true),
+    ("end", "END_P", KeywordCategory::Reserved, true),
+    ("enum", "ENUM_P", KeywordCategory::Unreserved, true),
+    ("error", "ERROR_P", KeywordCategory::Unreserved, true),
+    ("escape", "ESCAPE", KeywordCategory::Unreserved, true),
+    ("event", "EVENT", KeywordCategory::Unreserved, true),
+    ("except", "EXCEPT", KeywordCategory::Reserved, false),
+    ("exclude", "EXCLUDE", KeywordCategory::Unreserved, true),
+    ("excluding", "EXCLUDING", KeywordCategory::Unreserved, true),
+    ("exclusive", "EXCLUSIVE", KeywordCategory::Unreserved, true),
+    ("execute", "EXECUTE", KeywordCategory::Unreserved, true),
+    ("exists", "EXISTS", KeywordCategory::ColName, true),
+    ("explain", "EXPLAIN", KeywordCategory::Unreserved, true),
+    (
+        "expression",
+        "EXPRESSION",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("extension", "EXTENSION", KeywordCategory::Unreserved, true),
+    ("external", "EXTERNAL", KeywordCategory::Unreserved, true),
+    ("extract", "EXTRACT", KeywordCategory::ColName, true),
+    ("false", "FALSE_P", KeywordCategory::Reserved, true),
+    ("family", "FAMILY", KeywordCategory::Unreserved, true),
+    ("fetch", "FETCH", KeywordCategory::Reserved, false),
+    ("filter", "FILTER", KeywordCategory::Unreserved, false),
+    ("finalize", "FINALIZE", KeywordCategory::Unreserved, true),
+    ("first", "FIRST_P", KeywordCategory::Unreserved, true),
+    ("float", "FLOAT_P", KeywordCategory::ColName, true),
+    ("following", "FOLLOWING", KeywordCategory::Unreserved, true),
+    ("for", "FOR", KeywordCategory::Reserved, false),
+    ("force", "FORCE", KeywordCategory::Unreserved, true),
+    ("foreign", "FOREIGN", KeywordCategory::Reserved, true),
+    ("format", "FORMAT", KeywordCategory::Unreserved, true),
+    ("forward", "FORWARD", KeywordCategory::Unreserved, true),
+    ("freeze", "FREEZE", KeywordCategory::TypeFuncName, true),
+    ("from", "FROM", KeywordCategory::Reserved, false),
+    ("full", "FULL", KeywordCategory::TypeFuncName, true),
+    ("function", "FUNCTION", KeywordCategory::Unreserved, true),
+    ("functions", "FUNCTIONS", KeywordCategory::Unreserved, true),
+    ("generated", "GENERATED", KeywordCategory::Unreserved, true),
+    ("global", "GLOBAL", KeywordCategory::Unreserved, true),
+    ("grant", "GRANT", KeywordCategory::Reserved, false),
+    ("granted", "GRANTED", KeywordCategory::Unreserved, true),
+    ("greatest", "GREATEST", KeywordCategory::ColName, true),
+    ("group", "GROUP_P", KeywordCategory::Reserved, false),
+    ("grouping", "GROUPING", KeywordCategory::ColName, true),
+    ("groups", "GROUPS", KeywordCategory::Unreserved, true),
+    ("handler", "HANDLER", KeywordCategory::Unreserved, true),
+    ("having", "HAVING", KeywordCategory::Reserved, false),
+    ("header", "HEADER_P", KeywordCategory::Unreserved, true),
+    ("hold", "HOLD", KeywordCategory::Unreserved, true),
+    ("hour", "HOUR_P", KeywordCategory::Unreserved, false),
+    ("identity", "IDENTITY_P", KeywordCategory::Unreserved, true),
+    ("if", "IF_P", KeywordCategory::Unreserved, true),
+    ("ilike", "ILIKE", KeywordCategory::TypeFuncName, true),
+    ("immediate", "IMMEDIATE", KeywordCategory::Unreserved, true),
+    ("immutable", "IMMUTABLE", KeywordCategory::Unreserved, true),
+    ("implicit", "IMPLICIT_P", KeywordCategory::Unreserved, true),
+    ("import", "IMPORT_P", KeywordCategory::Unreserved, true),
+    ("in", "IN_P", KeywordCategory::Reserved, true),
+    ("include", "INCLUDE", KeywordCategory::Unreserved, true),
+    ("including", "INCLUDING", KeywordCategory::Unreserved, true),
+    ("increment", "INCREMENT", KeywordCategory::Unreserved, true),
+    ("indent", "INDENT", KeywordCategory::Unreserved, true),
+    ("index", "INDEX", KeywordCategory::Unreserved, true),
+    ("indexes", "INDEXES", KeywordCategory::Unreserved, true),
+    ("inherit", "INHERIT", KeywordCategory::Unreserved, true),
+    ("inherits", "INHERITS", KeywordCategory::Unreserved, true),
+    ("initially", "INITIALLY", KeywordCategory::Reserved, true),
+    ("inline", "INLINE_P", KeywordCategory::Unreserved, true),
+    ("inner", "INNER_P", KeywordCategory::TypeFuncName, true),
+    ("inout", "INOUT", KeywordCategory::ColName, true),
+    ("input", "INPUT_P", KeywordCategory::Unreserved, true),
+    (
+        "insensitive",
+        "INSENSITIVE",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("insert", "INSERT", KeywordCategory::Unreserved, true),
+    ("instead", "INSTEAD", KeywordCategory::Unreserved, true),
+    ("int", "INT_P", KeywordCategory::ColName, true),
+    ("integer", "INTEGER", KeywordCategory::ColName, true),
+    ("intersect", "INTERSECT", KeywordCategory::Reserved, false),
+    ("interval", "INTERVAL", KeywordCategory::ColName, true),
+    ("into", "INTO", KeywordCategory::Reserved, false),
+    ("invoker", "INVOKER", KeywordCategory::Unreserved, true),
+    ("is", "IS", KeywordCategory::TypeFuncName, true),
+    ("isnull", "ISNULL", KeywordCategory::TypeFuncName, false),
+    ("isolation", "ISOLATION", KeywordCategory::Unreserved, true),
+    ("join", "JOIN", KeywordCategory::TypeFuncName, true),
+    ("json", "JSON", KeywordCategory::ColName, true),
+    ("json_array", "JSON_ARRAY", KeywordCategory::ColName, true),
+    (
+        "json_arrayagg",
+        "JSON_ARRAYAGG",
+        KeywordCategory::ColName,
+        true,
+    ),
+    ("json_exists", "JSON_EXISTS", KeywordCategory::ColName, true),
+    ("json_object", "JSON_OBJECT", KeywordCategory::ColName, true),
+    (
+        "json_objectagg",
+        "JSON_OBJECTAGG",
+        KeywordCategory::ColName,
+        true,
+    ),
+    ("json_query", "JSON_QUERY", KeywordCategory::ColName, true),
+    ("json_scalar", "JSON_SCALAR", KeywordCategory::ColName, true),
+    (
+        "json_serialize",
+        "JSON_SERIALIZE",
+        KeywordCategory::ColName,
+        true,
+    ),
+    ("json_table", "JSON_TABLE", KeywordCategory::ColName, true),
+    ("json_value", "JSON_VALUE", KeywordCategory::ColName, true),
+    ("keep", "KEEP", KeywordCategory::Unreserved, true),
+    ("key", "KEY", KeywordCategory::Unreserved, true),
+    ("keys", "KEYS", KeywordCategory::Unreserved, true),
+    ("label", "LABEL", KeywordCategory::Unreserved, true),
+    ("language", "LANGUAGE", KeywordCategory::Unreserved, true),
+    ("large", "LARGE_P", KeywordCategory::Unreserved, true),
+    ("last", "LAST_P", KeywordCategory::Unreserved, true),
+    ("lateral", "LATERAL_P", KeywordCategory::Reserved, true),
+    ("leading", "LEADING", KeywordCategory::Reserved, true),
+    ("leakproof", "LEAKPROOF", KeywordCategory::Unreserved, true),
+    ("least", "LEAST", KeywordCategory::ColName, true),
+    ("left", "LEFT", KeywordCategory::TypeFuncName, true),
+    ("level", "LEVEL", KeywordCategory::Unreserved, true),
+    ("like", "LIKE", KeywordCategory::TypeFuncName, true),
+    ("limit", "LIMIT", KeywordCategory::Reserved, false),
+    ("listen", "LISTEN", KeywordCategory::Unreserved, true),
+    ("load", "LOAD", KeywordCategory::Unreserved, true),
+    ("local", "LOCAL", KeywordCategory::Unreserved, true),
+    ("localtime", "LOCALTIME", KeywordCategory::Reserved, true),
+    (
+        "localtimestamp",
+        "LOCALTIMESTAMP",
+        KeywordCategory::Reserved,
+        true,
+    ),
+    ("location", "LOCATION", KeywordCategory::Unreserved, true),
+    ("lock", "LOCK_P", KeywordCategory::Unreserved, true),
+    ("locked", "LOCKED", KeywordCategory::Unreserved, true),
+    ("logged", "LOGGED", KeywordCategory::Unreserved, true),
+    ("mapping", "MAPPING", KeywordCategory::Unreserved, true),
+    ("match", "MATCH", KeywordCategory::Unreserved, true),
+    ("matched", "MATCHED", KeywordCategory::Unreserved, true),
+    (
+        "materialized",
+        "MATERIALIZED",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("maxvalue", "MAXVALUE", KeywordCategory::Unreserved, true),
+    ("merge", "MERGE", KeywordCategory::Unreserved, true),
+    (
+        "merge_action",
+        "MERGE_ACTION",
+        KeywordCategory::ColName,
+        true,
+    ),
+    ("method", "METHOD", KeywordCategory::Unreserved, true),
+    ("minute", "MINUTE_P", KeywordCategory::Unreserved, false),
+    ("minvalue", "MINVALUE", KeywordCategory::Unreserved, true),
+    ("mode", "MODE", KeywordCategory::Unreserved, true),
+    ("month", "MONTH_P", KeywordCategory::Unreserved, false),
+    ("move", "MOVE", KeywordCategory::Unreserved, true),
+    ("name", "NAME_P", KeywordCategory::Unreserved, true),
+    ("names", "NAMES", KeywordCategory::Unreserved, true),
+    ("national", "NATIONAL", KeywordCategory::ColName, true),
+    ("natural", "NATURAL", KeywordCategory::TypeFuncName, true),
+    ("nchar", "NCHAR", KeywordCategory::ColName, true),
+    ("nested", "NESTED", KeywordCategory::Unreserved, true),
+    ("new", "NEW", KeywordCategory::Unreserved, true),
+    ("next", "NEXT", KeywordCategory::Unreserved, true),
+    ("nfc", "NFC", KeywordCategory::Unreserved, true),
+    ("nfd", "NFD", KeywordCategory::Unreserved, true),
+    ("nfkc", "NFKC", KeywordCategory::Unreserved, true),
+    ("nfkd", "NFKD", KeywordCategory::Unreserved, true),
+    ("no", "NO", KeywordCategory::Unreserved, true),
+    ("none", "NONE", KeywordCategory::ColName, true),
+    ("normalize", "NORMALIZE", KeywordCategory::ColName, true),
+    (
+        "normalized",
+        "NORMALIZED",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("not", "NOT", KeywordCategory::Reserved, true),
+    ("nothing", "NOTHING", KeywordCategory::Unreserved, true),
+    ("notify", "NOTIFY", KeywordCategory::Unreserved, true),
+    ("notnull", "NOTNULL", KeywordCategory::TypeFuncName, false),
+    ("nowait", "NOWAIT", KeywordCategory::Unreserved, true),
+    ("null", "NULL_P", KeywordCategory::Reserved, true),
+    ("nullif", "NULLIF", KeywordCategory::ColName, true),
+    ("nulls", "NULLS_P", KeywordCategory::Unreserved, true),
+    ("numeric", "NUMERIC", KeywordCategory::ColName, true),
+    ("object", "OBJECT_P", KeywordCategory::Unreserved, true),
+    ("of", "OF", KeywordCategory::Unreserved, true),
+    ("off", "OFF", KeywordCategory::Unreserved, true),
+    ("offset", "OFFSET", KeywordCategory::Reserved, false),
+    ("oids", "OIDS", KeywordCategory::Unreserved, true),
+    ("old", "OLD", KeywordCategory::Unreserved, true),
+    ("omit", "OMIT", KeywordCategory::Unreserved, true),
+    ("on", "ON", KeywordCategory::Reserved, false),
+    ("only", "ONLY", KeywordCategory::Reserved, true),
+    ("operator", "OPERATOR", KeywordCategory::Unreserved, true),
+    ("option", "OPTION", KeywordCategory::Unreserved, true),
+    ("options", "OPTIONS", KeywordCategory::Unreserved, true),
+    ("or", "OR", KeywordCategory::Reserved, true),
+    ("order", "ORDER", KeywordCategory::Reserved, false),
+    (
+        "ordinality",
+        "ORDINALITY",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("others", "OTHERS", KeywordCategory::Unreserved, true),
+    ("out", "OUT_P", KeywordCategory::ColName, true),
+    ("outer", "OUTER_P", KeywordCategory::TypeFuncName, true),
+    ("over", "OVER", KeywordCategory::Unreserved, false),
+    ("overlaps", "OVERLAPS", KeywordCategory::TypeFuncName, false),
+    ("overlay", "OVERLAY", KeywordCategory::ColName, true),
+    (
+        "overriding",
+        "OVERRIDING",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("owned", "OWNED", KeywordCategory::Unreserved, true),
+    ("owner", "OWNER", KeywordCategory::Unreserved, true),
+    ("parallel", "PARALLEL", KeywordCategory::Unreserved, true),
+    ("parameter", "PARAMETER", KeywordCategory::Unreserved, true),
+    ("parser", "PARSER", KeywordCategory::Unreserved, true),
+    ("partial", "PARTIAL", KeywordCategory::Unreserved, true),
+    ("partition", "PARTITION", KeywordCategory::Unreserved, true),
+    ("passing", "PASSING", KeywordCategory::Unreserved, true),
+    ("password", "PASSWORD", KeywordCategory::Unreserved, true),
+    ("path", "PATH", KeywordCategory::Unreserved, true),
+    ("placing", "PLACING", KeywordCategory::Reserved, true),
+    ("plan", "PLAN", KeywordCategory::Unreserved, true),
+    ("plans", "PLANS", KeywordCategory::Unreserved, true),
+    ("policy", "POLICY", KeywordCategory::Unreserved, true),
+    ("position", "POSITION", KeywordCategory::ColName, true),
+    ("preceding", "PRECEDING", KeywordCategory::Unreserved, true),
+    ("precision", "PRECISION", KeywordCategory::ColName, false),
+    ("prepare", "PREPARE", KeywordCategory::Unreserved, true),
+    ("prepared", "PREPARED", KeywordCategory::Unreserved, true),
+    ("preserve", "PRESERVE", KeywordCategory::Unreserved, true),
+    ("primary", "PRIMARY", KeywordCategory::Reserved, true),
+    ("prior", "PRIOR", KeywordCategory::Unreserved, true),
+    (
+        "privileges",
+        "PRIVILEGES",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    (
+        "procedural",
+        "PROCEDURAL",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("procedure", "PROCEDURE", KeywordCategory::Unreserved, true),
+    (
+        "procedures",
+        "PROCEDURES",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("program", "PROGRAM", KeywordCategory::Unreserved, true),
+    (
+        "publication",
+        "PUBLICATION",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("quote", "QUOTE", KeywordCategory::Unreserved, true),
+    ("quotes", "QUOTES", KeywordCategory::Unreserved, true),
+    ("range", "RANGE", KeywordCategory::Unreserved, true),
+    ("read", "READ", KeywordCategory::Unreserved, true),
+    ("real", "REAL", KeywordCategory::ColName, true),
+    ("reassign", "REASSIGN", KeywordCategory::Unreserved, true),
+    ("recheck", "RECHECK", KeywordCategory::Unreserved, true),
+    ("recursive", "RECURSIVE", KeywordCategory::Unreserved, true),
+    ("ref", "REF_P", KeywordCategory::Unreserved, true),
+    ("references", "REFERENCES", KeywordCategory::Reserved, true),
+    (
+        "referencing",
+        "REFERENCING",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("refresh", "REFRESH", KeywordCategory::Unreserved, true),
+    ("reindex", "REINDEX", KeywordCategory::Unreserved, true),
+    ("relative", "RELATIVE_P", KeywordCategory::Unreserved, true),
+    ("release", "RELEASE", KeywordCategory::Unreserved, true),
+    ("rename", "RENAME", KeywordCategory::Unreserved, true),
+    (
+        "repeatable",
+        "REPEATABLE",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("replace", "REPLACE", KeywordCategory::Unreserved, true),
+    ("replica", "REPLICA", KeywordCategory::Unreserved, true),
+    ("reset", "RESET", KeywordCategory::Unreserved, true),
+    ("restart", "RESTART", KeywordCategory::Unreserved, true),
+    ("restrict", "RESTRICT", KeywordCategory::Unreserved, true),
+    ("return", "RETURN", KeywordCategory::Unreserved, true),
+    ("returning", "RETURNING", KeywordCategory::Reserved, false),
+    ("returns", "RETURNS", KeywordCategory::Unreserved, true),
+    ("revoke", "REVOKE", KeywordCategory::Unreserved, true),
+    ("right", "RIGHT", KeywordCategory::TypeFuncName, true),
+    ("role", "ROLE", KeywordCategory::Unreserved, true),
+    ("rollback", "ROLLBACK", KeywordCategory::Unreserved, true),
+    ("rollup", "ROLLUP", KeywordCategory::Unreserved, true),
+    ("routine", "ROUTINE", KeywordCategory::Unreserved, true),
+    ("routines", "ROUTINES", KeywordCategory::Unreserved, true),
+    ("row", "ROW", KeywordCategory::ColName, true),
+    ("rows", "ROWS", KeywordCategory::Unreserved, true),
+    ("rule", "RULE", KeywordCategory::Unreserved, true),
+    ("savepoint", "SAVEPOINT", KeywordCategory::Unreserved, true),
+    ("scalar", "SCALAR", KeywordCategory::Unreserved, true),
+    ("schema", "SCHEMA", KeywordCategory::Unreserved, true),
+    ("schemas", "SCHEMAS", KeywordCategory::Unreserved, true),
+    ("scroll", "SCROLL", KeywordCategory::Unreserved, true),
+    ("search", "SEARCH", KeywordCategory::Unreserved, true),
+    ("second", "SECOND_P", KeywordCategory::Unreserved, false),
+    ("security", "SECURITY", KeywordCategory::Unreserved, true),
+    ("select", "SELECT", KeywordCategory::Reserved, true),
+    ("sequence", "SEQUENCE", KeywordCategory::Unreserved, true),
+    ("sequences", "SEQUENCES", KeywordCategory::Unreserved, true),
+    (
+        "serializable",
+        "SERIALIZABLE",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("server", "SERVER", KeywordCategory::Unreserved, true),
+    ("session", "SESSION", KeywordCategory::Unreserved, true),
+    (
+        "session_user",
+        "SESSION_USER",
+        KeywordCategory::Reserved,
+        true,
+    ),
+    ("set", "SET", KeywordCategory::Unreserved, true),
+    ("setof", "SETOF", KeywordCategory::ColName, true),
+    ("sets", "SETS", KeywordCategory::Unreserved, true),
+    ("share", "SHARE", KeywordCategory::Unreserved, true),
+    ("show", "SHOW", KeywordCategory::Unreserved, true),
+    ("similar", "SIMILAR", KeywordCategory::TypeFuncName, true),
+    ("simple", "SIMPLE", KeywordCategory::Unreserved, true),
+    ("skip", "SKIP", KeywordCategory::Unreserved, true),
+    ("smallint", "SMALLINT", KeywordCategory::ColName, true),
+    ("snapshot", "SNAPSHOT", KeywordCategory::Unreserved, true),
+    ("some", "SOME", KeywordCategory::Reserved, true),
+    ("source", "SOURCE", KeywordCategory::Unreserved, true),
+    ("sql", "SQL_P", KeywordCategory::Unreserved, true),
+    ("stable", "STABLE", KeywordCategory::Unreserved, true),
+    (
+        "standalone",
+        "STANDALONE_P",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("start", "START", KeywordCategory::Unreserved, true),
+    ("statement", "STATEMENT", KeywordCategory::Unreserved, true),
+    (
+        "statistics",
+        "STATISTICS",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("stdin", "STDIN", KeywordCategory::Unreserved, true),
+    ("stdout", "STDOUT", KeywordCategory::Unreserved, true),
+    ("storage", "STORAGE", KeywordCategory::Unreserved, true),
+    ("stored", "STORED", KeywordCategory::Unreserved, true),
+    ("strict", "STRICT_P", KeywordCategory::Unreserved, true),
+    ("string", "STRING_P", KeywordCategory::Unreserved, true),
+    ("strip", "STRIP_P", KeywordCategory::Unreserved, true),
+    (
+        "subscription",
+        "SUBSCRIPTION",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("substring", "SUBSTRING", KeywordCategory::ColName, true),
+    ("support", "SUPPORT", KeywordCategory::Unreserved, true),
+    ("symmetric", "SYMMETRIC", KeywordCategory::Reserved, true),
+    ("sysid", "SYSID", KeywordCategory::Unreserved, true),
+    ("system", "SYSTEM_P", KeywordCategory::Unreserved, true),
+    (
+        "system_user",
+        "SYSTEM_USER",
+        KeywordCategory::Reserved,
+        true,
+    ),
+    ("table", "TABLE", KeywordCategory::Reserved, true),
+    ("tables", "TABLES", KeywordCategory::Unreserved, true),
+    (
+        "tablesample",
+        "TABLESAMPLE",
+        KeywordCategory::TypeFuncName,
+        true,
+    ),
+    (
+        "tablespace",
+        "TABLESPACE",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("target", "TARGET", KeywordCategory::Unreserved, true),
+    ("temp", "TEMP", KeywordCategory::Unreserved, true),
+    ("template", "TEMPLATE", KeywordCategory::Unreserved, true),
+    ("temporary", "TEMPORARY", KeywordCategory::Unreserved, true),
+    ("text", "TEXT_P", KeywordCategory::Unreserved, true),
+    ("then", "THEN", KeywordCategory::Reserved, true),
+    ("ties", "TIES", KeywordCategory::Unreserved, true),
+    ("time", "TIME", KeywordCategory::ColName, true),
+    ("timestamp", "TIMESTAMP", KeywordCategory::ColName, true),
+    ("to", "TO", KeywordCategory::Reserved, false),
+    ("trailing", "TRAILING", KeywordCategory::Reserved, true),
+    (
+        "transaction",
+        "TRANSACTION",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("transform", "TRANSFORM", KeywordCategory::Unreserved, true),
+    ("treat", "TREAT", KeywordCategory::ColName, true),
+    ("trigger", "TRIGGER", KeywordCategory::Unreserved, true),
+    ("trim", "TRIM", KeywordCategory::ColName, true),
+    ("true", "TRUE_P", KeywordCategory::Reserved, true),
+    ("truncate", "TRUNCATE", KeywordCategory::Unreserved, true),
+    ("trusted", "TRUSTED", KeywordCategory::Unreserved, true),
+    ("type", "TYPE_P", KeywordCategory::Unreserved, true),
+    ("types", "TYPES_P", KeywordCategory::Unreserved, true),
+    ("uescape", "UESCAPE", KeywordCategory::Unreserved, true),
+    ("unbounded", "UNBOUNDED", KeywordCategory::Unreserved, true),
+    (
+        "uncommitted",
+        "UNCOMMITTED",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    (
+        "unconditional",
+        "UNCONDITIONAL",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    (
+        "unencrypted",
+        "UNENCRYPTED",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("union", "UNION", KeywordCategory::Reserved, false),
+    ("unique", "UNIQUE", KeywordCategory::Reserved, true),
+    ("unknown", "UNKNOWN", KeywordCategory::Unreserved, true),
+    ("unlisten", "UNLISTEN", KeywordCategory::Unreserved, true),
+    ("unlogged", "UNLOGGED", KeywordCategory::Unreserved, true),
+    ("until", "UNTIL", KeywordCategory::Unreserved, true),
+    ("update", "UPDATE", KeywordCategory::Unreserved, true),
+    ("user", "USER", KeywordCategory::Reserved, true),
+    ("using", "USING", KeywordCategory::Reserved, true),
+    ("vacuum", "VACUUM", KeywordCategory::Unreserved, true),
+    ("valid", "VALID", KeywordCategory::Unreserved, true),
+    ("validate", "VALIDATE", KeywordCategory::Unreserved, true),
+    ("validator", "VALIDATOR", KeywordCategory::Unreserved, true),
+    ("value", "VALUE_P", KeywordCategory::Unreserved, true),
+    ("values", "VALUES", KeywordCategory::ColName, true),
+    ("varchar", "VARCHAR", KeywordCategory::ColName, true),
+    ("variadic", "VARIADIC", KeywordCategory::Reserved, true),
+    ("varying", "VARYING", KeywordCategory::Unreserved, false),
+    ("verbose", "VERBOSE", KeywordCategory::TypeFuncName, true),
+    ("version", "VERSION_P", KeywordCategory::Unreserved, true),
+    ("view", "VIEW", KeywordCategory::Unreserved, true),
+    ("views", "VIEWS", KeywordCategory::Unreserved, true),
+    ("volatile", "VOLATILE", KeywordCategory::Unreserved, true),
+    ("when", "WHEN", KeywordCategory::Reserved, true),
+    ("where", "WHERE", KeywordCategory::Reserved, false),
+    (
+        "whitespace",
+        "WHITESPACE_P",
+        KeywordCategory::Unreserved,
+        true,
+    ),
+    ("window", "WINDOW", KeywordCategory::Reserved, false),
+    ("with", "WITH", KeywordCategory::Reserved, false),
+    ("within", "WITHIN", KeywordCategory::Unreserved, false),
+    ("without", "WITHOUT", KeywordCategory::Unreserved, false),
+    ("work", "WORK", KeywordCategory::Unreserved, true),
+    ("wrapper", "WRAPPER", KeywordCategory::Unreserved, true),
+    ("write", "WRITE", KeywordCategory::Unreserved, true),
+    ("xml", "XML_P", KeywordCategory::Unreserved, true),
+    (
+        "xmlattributes",
+        "XMLATTRIBUTES",
+        KeywordCategory::ColName,
+        true,
+    ),
+    ("xmlconcat", "XMLCONCAT", KeywordCategory::ColName, true),
+    ("xmlelement", "XMLELEMENT", KeywordCategory::ColName, true),
+    ("xmlexists", "XMLEXISTS", KeywordCategory::ColName, true),
+    ("xmlforest", "XMLFOREST", KeywordCategory::ColName, true),
+    (
+        "xmlnamespaces",
+        "XMLNAMESPACES",
+        KeywordCategory::ColName,
+        true,
+    ),
+    ("xmlparse", "XMLPARSE", KeywordCategory::ColName, true),
+    ("xmlpi", "XMLPI", KeywordCategory::ColName, true),
+    ("xmlroot", "XMLROOT", KeywordCategory::ColName, true),
+    (
+        "xmlserialize",
+        "XMLSERIALIZE",
+        KeywordCategory::ColName,
+        true,
+    ),
+    ("xmltable", "XMLTABLE", KeywordCategory::ColName, true),
+    ("year", "YEAR_P", KeywordCategory::Unreserved, false),
+    ("yes", "YES_P", KeywordCategory::Unreserved, true),
+    ("zone", "ZONE", KeywordCategory::Unreserved, true),
+];
+
 pub fn get_keyword_map() -> HashMap<&'static str, &'static str> {
     let mut map = HashMap::new();
-    for (kw, tok) in [
-        ("abort", "ABORT_P"),
-        ("absent", "ABSENT"),
-        ("absolute", "ABSOLUTE_P"),
-        ("access", "ACCESS"),
-        ("action", "ACTION"),
-        ("add", "ADD_P"),
-        ("admin", "ADMIN"),
-        ("after", "AFTER"),
-        ("aggregate", "AGGREGATE"),
-        ("all", "ALL"),
-        ("also", "ALSO"),
-        ("alter", "ALTER"),
-        ("always", "ALWAYS"),
-        ("analyse", "ANALYSE"),
-        ("analyze", "ANALYZE"),
-        ("and", "AND"),
-        ("any", "ANY"),
-        ("array", "ARRAY"),
-        ("as", "AS"),
-        ("asc", "ASC"),
-        ("asensitive", "ASENSITIVE"),
-        ("assertion", "ASSERTION"),
-        ("assignment", "ASSIGNMENT"),
-        ("asymmetric", "ASYMMETRIC"),
-        ("at", "AT"),
-        ("atomic", "ATOMIC"),
-        ("attach", "ATTACH"),
-        ("attribute", "ATTRIBUTE"),
-        ("authorization", "AUTHORIZATION"),
-        ("backward", "BACKWARD"),
-        ("before", "BEFORE"),
-        ("begin", "BEGIN_P"),
-        ("between", "BETWEEN"),
-        ("bigint", "BIGINT"),
-        ("binary", "BINARY"),
-        ("bit", "BIT"),
-        ("boolean", "BOOLEAN_P"),
-        ("both", "BOTH"),
-        ("breadth", "BREADTH"),
-        ("by", "BY"),
-        ("cache", "CACHE"),
-        ("call", "CALL"),
-        ("called", "CALLED"),
-        ("cascade", "CASCADE"),
-        ("cascaded", "CASCADED"),
-        ("case", "CASE"),
-        ("cast", "CAST"),
-        ("catalog", "CATALOG_P"),
-        ("chain", "CHAIN"),
-        ("char", "CHAR_P"),
-        ("character", "CHARACTER"),
-        ("characteristics", "CHARACTERISTICS"),
-        ("check", "CHECK"),
-        ("checkpoint", "CHECKPOINT"),
-        ("class", "CLASS"),
-        ("close", "CLOSE"),
-        ("cluster", "CLUSTER"),
-        ("coalesce", "COALESCE"),
-        ("collate", "COLLATE"),
-        ("collation", "COLLATION"),
-        ("column", "COLUMN"),
-        ("columns", "COLUMNS"),
-        ("comment", "COMMENT"),
-        ("comments", "COMMENTS"),
-        ("commit", "COMMIT"),
-        ("committed", "COMMITTED"),
-        ("compression", "COMPRESSION"),
-        ("concurrently", "CONCURRENTLY"),
-        ("conditional", "CONDITIONAL"),
-        ("configuration", "CONFIGURATION"),
-        ("conflict", "CONFLICT"),
-        ("connection", "CONNECTION"),
-        ("constraint", "CONSTRAINT"),
-        ("constraints", "CONSTRAINTS"),
-        ("content", "CONTENT_P"),
-        ("continue", "CONTINUE_P"),
-        ("conversion", "CONVERSION_P"),
-        ("copy", "COPY"),
-        ("cost", "COST"),
-        ("create", "CREATE"),
-        ("cross", "CROSS"),
-        ("csv", "CSV"),
-        ("cube", "CUBE"),
-        ("current", "CURRENT_P"),
-        ("current_catalog", "CURRENT_CATALOG"),
-        ("current_date", "CURRENT_DATE"),
-        ("current_role", "CURRENT_ROLE"),
-        ("current_schema", "CURRENT_SCHEMA"),
-        ("current_time", "CURRENT_TIME"),
-        ("current_timestamp", "CURRENT_TIMESTAMP"),
-        ("current_user", "CURRENT_USER"),
-        ("cursor", "CURSOR"),
-        ("cycle", "CYCLE"),
-        ("data", "DATA_P"),
-        ("database", "DATABASE"),
-        ("day", "DAY_P"),
-        ("deallocate", "DEALLOCATE"),
-        ("dec", "DEC"),
-        ("decimal", "DECIMAL_P"),
-        ("declare", "DECLARE"),
-        ("default", "DEFAULT"),
-        ("defaults", "DEFAULTS"),
-        ("deferrable", "DEFERRABLE"),
-        ("deferred", "DEFERRED"),
-        ("definer", "DEFINER"),
-        ("delete", "DELETE_P"),
-        ("delimiter", "DELIMITER"),
-        ("delimiters", "DELIMITERS"),
-        ("depends", "DEPENDS"),
-        ("depth", "DEPTH"),
-        ("desc", "DESC"),
-        ("detach", "DETACH"),
-        ("dictionary", "DICTIONARY"),
-        ("disable", "DISABLE_P"),
-        ("discard", "DISCARD"),
-        ("distinct", "DISTINCT"),
-        ("do", "DO"),
-        ("document", "DOCUMENT_P"),
-        ("domain", "DOMAIN_P"),
-        ("double", "DOUBLE_P"),
-        ("drop", "DROP"),
-        ("each", "EACH"),
-        ("else", "ELSE"),
-        ("empty", "EMPTY_P"),
-        ("enable", "ENABLE_P"),
-        ("encoding", "ENCODING"),
-        ("encrypted", "ENCRYPTED"),
-        ("end", "END_P"),
-        ("enum", "ENUM_P"),
-        ("error", "ERROR_P"),
-        ("escape", "ESCAPE"),
-        ("event", "EVENT"),
-        ("except", "EXCEPT"),
-        ("exclude", "EXCLUDE"),
-        ("excluding", "EXCLUDING"),
-        ("exclusive", "EXCLUSIVE"),
-        ("execute", "EXECUTE"),
-        ("exists", "EXISTS"),
-        ("explain", "EXPLAIN"),
-        ("expression", "EXPRESSION"),
-        ("extension", "EXTENSION"),
-        ("external", "EXTERNAL"),
-        ("extract", "EXTRACT"),
-        ("false", "FALSE_P"),
-        ("family", "FAMILY"),
-        ("fetch", "FETCH"),
-        ("filter", "FILTER"),
-        ("finalize", "FINALIZE"),
-        ("first", "FIRST_P"),
-        ("float", "FLOAT_P"),
-        ("following", "FOLLOWING"),
-        ("for", "FOR"),
-        ("force", "FORCE"),
-        ("foreign", "FOREIGN"),
-        ("format", "FORMAT"),
-        ("forward", "FORWARD"),
-        ("freeze", "FREEZE"),
-        ("from", "FROM"),
-        ("full", "FULL"),
-        ("function", "FUNCTION"),
-        ("functions", "FUNCTIONS"),
-        ("generated", "GENERATED"),
-        ("global", "GLOBAL"),
-        ("grant", "GRANT"),
-        ("granted", "GRANTED"),
-        ("greatest", "GREATEST"),
-        ("group", "GROUP_P"),
-        ("grouping", "GROUPING"),
-        ("groups", "GROUPS"),
-        ("handler", "HANDLER"),
-        ("having", "HAVING"),
-        ("header", "HEADER_P"),
-        ("hold", "HOLD"),
-        ("hour", "HOUR_P"),
-        ("identity", "IDENTITY_P"),
-        ("if", "IF_P"),
-        ("ilike", "ILIKE"),
-        ("immediate", "IMMEDIATE"),
-        ("immutable", "IMMUTABLE"),
-        ("implicit", "IMPLICIT_P"),
-        ("import", "IMPORT_P"),
-        ("in", "IN_P"),
-        ("include", "INCLUDE"),
-        ("including", "INCLUDING"),
-        ("increment", "INCREMENT"),
-        ("indent", "INDENT"),
-        ("index", "INDEX"),
-        ("indexes", "INDEXES"),
-        ("inherit", "INHERIT"),
-        ("inherits", "INHERITS"),
-        ("initially", "INITIALLY"),
-        ("inline", "INLINE_P"),
-        ("inner", "INNER_P"),
-        ("inout", "INOUT"),
-        ("input", "INPUT_P"),
-        ("insensitive", "INSENSITIVE"),
-        ("insert", "INSERT"),
-        ("instead", "INSTEAD"),
-        ("int", "INT_P"),
-        ("integer", "INTEGER"),
-        ("intersect", "INTERSECT"),
-        ("interval", "INTERVAL"),
-        ("into", "INTO"),
-        ("invoker", "INVOKER"),
-        ("is", "IS"),
-        ("isnull", "ISNULL"),
-        ("isolation", "ISOLATION"),
-        ("join", "JOIN"),
-        ("json", "JSON"),
-        ("json_array", "JSON_ARRAY"),
-        ("json_arrayagg", "JSON_ARRAYAGG"),
-        ("json_exists", "JSON_EXISTS"),
-        ("json_object", "JSON_OBJECT"),
-        ("json_objectagg", "JSON_OBJECTAGG"),
-        ("json_query", "JSON_QUERY"),
-        ("json_scalar", "JSON_SCALAR"),
-        ("json_serialize", "JSON_SERIALIZE"),
-        ("json_table", "JSON_TABLE"),
-        ("json_value", "JSON_VALUE"),
-        ("keep", "KEEP"),
-        ("key", "KEY"),
-        ("keys", "KEYS"),
-        ("label", "LABEL"),
-        ("language", "LANGUAGE"),
-        ("large", "LARGE_P"),
-        ("last", "LAST_P"),
-        ("lateral", "LATERAL_P"),
-        ("leading", "LEADING"),
-        ("leakproof", "LEAKPROOF"),
-        ("least", "LEAST"),
-        ("left", "LEFT"),
-        ("level", "LEVEL"),
-        ("like", "LIKE"),
-        ("limit", "LIMIT"),
-        ("listen", "LISTEN"),
-        ("load", "LOAD"),
-        ("local", "LOCAL"),
-        ("localtime", "LOCALTIME"),
-        ("localtimestamp", "LOCALTIMESTAMP"),
-        ("location", "LOCATION"),
-        ("lock", "LOCK_P"),
-        ("locked", "LOCKED"),
-        ("logged", "LOGGED"),
-        ("mapping", "MAPPING"),
-        ("match", "MATCH"),
-        ("matched", "MATCHED"),
-        ("materialized", "MATERIALIZED"),
-        ("maxvalue", "MAXVALUE"),
-        ("merge", "MERGE"),
-        ("merge_action", "MERGE_ACTION"),
-        ("method", "METHOD"),
-        ("minute", "MINUTE_P"),
-        ("minvalue", "MINVALUE"),
-        ("mode", "MODE"),
-        ("month", "MONTH_P"),
-        ("move", "MOVE"),
-        ("name", "NAME_P"),
-        ("names", "NAMES"),
-        ("national", "NATIONAL"),
-        ("natural", "NATURAL"),
-        ("nchar", "NCHAR"),
-        ("nested", "NESTED"),
-        ("new", "NEW"),
-        ("next", "NEXT"),
-        ("nfc", "NFC"),
-        ("nfd", "NFD"),
-        ("nfkc", "NFKC"),
-        ("nfkd", "NFKD"),
-        ("no", "NO"),
-        ("none", "NONE"),
-        ("normalize", "NORMALIZE"),
-        ("normalized", "NORMALIZED"),
-        ("not", "NOT"),
-        ("nothing", "NOTHING"),
-        ("notify", "NOTIFY"),
-        ("notnull", "NOTNULL"),
-        ("nowait", "NOWAIT"),
-        ("null", "NULL_P"),
-        ("nullif", "NULLIF"),
-        ("nulls", "NULLS_P"),
-        ("numeric", "NUMERIC"),
-        ("object", "OBJECT_P"),
-        ("of", "OF"),
-        ("off", "OFF"),
-        ("offset", "OFFSET"),
-        ("oids", "OIDS"),
-        ("old", "OLD"),
-        ("omit", "OMIT"),
-        ("on", "ON"),
-        ("only", "ONLY"),
-        ("operator", "OPERATOR"),
-        ("option", "OPTION"),
-        ("options", "OPTIONS"),
-        ("or", "OR"),
-        ("order", "ORDER"),
-        ("ordinality", "ORDINALITY"),
-        ("others", "OTHERS"),
-        ("out", "OUT_P"),
-        ("outer", "OUTER_P"),
-        ("over", "OVER"),
-        ("overlaps", "OVERLAPS"),
-        ("overlay", "OVERLAY"),
-        ("overriding", "OVERRIDING"),
-        ("owned", "OWNED"),
-        ("owner", "OWNER"),
-        ("parallel", "PARALLEL"),
-        ("parameter", "PARAMETER"),
-        ("parser", "PARSER"),
-        ("partial", "PARTIAL"),
-        ("partition", "PARTITION"),
-        ("passing", "PASSING"),
-        ("password", "PASSWORD"),
-        ("path", "PATH"),
-        ("placing", "PLACING"),
-        ("plan", "PLAN"),
-        ("plans", "PLANS"),
-        ("policy", "POLICY"),
-        ("position", "POSITION"),
-        ("preceding", "PRECEDING"),
-        ("precision", "PRECISION"),
-        ("prepare", "PREPARE"),
-        ("prepared", "PREPARED"),
-        ("preserve", "PRESERVE"),
-        ("primary", "PRIMARY"),
-        ("prior", "PRIOR"),
-        ("privileges", "PRIVILEGES"),
-        ("procedural", "PROCEDURAL"),
-        ("procedure", "PROCEDURE"),
-        ("procedures", "PROCEDURES"),
-        ("program", "PROGRAM"),
-        ("publication", "PUBLICATION"),
-        ("quote", "QUOTE"),
-        ("quotes", "QUOTES"),
-        ("range", "RANGE"),
-        ("read", "READ"),
-        ("real", "REAL"),
-        ("reassign", "REASSIGN"),
-        ("recheck", "RECHECK"),
-        ("recursive", "RECURSIVE"),
-        ("ref", "REF_P"),
-        ("references", "REFERENCES"),
-        ("referencing", "REFERENCING"),
-        ("refresh", "REFRESH"),
-        ("reindex", "REINDEX"),
-        ("relative", "RELATIVE_P"),
-        ("release", "RELEASE"),
-        ("rename", "RENAME"),
-        ("repeatable", "REPEATABLE"),
-        ("replace", "REPLACE"),
-        ("replica", "REPLICA"),
-        ("reset", "RESET"),
-        ("restart", "RESTART"),
-        ("restrict", "RESTRICT"),
-        ("return", "RETURN"),
-        ("returning", "RETURNING"),
-        ("returns", "RETURNS"),
-        ("revoke", "REVOKE"),
-        ("right", "RIGHT"),
-        ("role", "ROLE"),
-        ("rollback", "ROLLBACK"),
-        ("rollup", "ROLLUP"),
-        ("routine", "ROUTINE"),
-        ("routines", "ROUTINES"),
-        ("row", "ROW"),
-        ("rows", "ROWS"),
-        ("rule", "RULE"),
-        ("savepoint", "SAVEPOINT"),
-        ("scalar", "SCALAR"),
-        ("schema", "SCHEMA"),
-        ("schemas", "SCHEMAS"),
-        ("scroll", "SCROLL"),
-        ("search", "SEARCH"),
-        ("second", "SECOND_P"),
-        ("security", "SECURITY"),
-        ("select", "SELECT"),
-        ("sequence", "SEQUENCE"),
-        ("sequences", "SEQUENCES"),
-        ("serializable", "SERIALIZABLE"),
-        ("server", "SERVER"),
-        ("session", "SESSION"),
-        ("session_user", "SESSION_USER"),
-        ("set", "SET"),
-        ("setof", "SETOF"),
-        ("sets", "SETS"),
-        ("share", "SHARE"),
-        ("show", "SHOW"),
-        ("similar", "SIMILAR"),
-        ("simple", "SIMPLE"),
-        ("skip", "SKIP"),
-        ("smallint", "SMALLINT"),
-        ("snapshot", "SNAPSHOT"),
-        ("some", "SOME"),
-        ("source", "SOURCE"),
-        ("sql", "SQL_P"),
-        ("stable", "STABLE"),
-        ("standalone", "STANDALONE_P"),
-        ("start", "START"),
-        ("statement", "STATEMENT"),
-        ("statistics", "STATISTICS"),
-        ("stdin", "STDIN"),
-        ("stdout", "STDOUT"),
-        ("storage", "STORAGE"),
-        ("stored", "STORED"),
-        ("strict", "STRICT_P"),
-        ("string", "STRING_P"),
-        ("strip", "STRIP_P"),
-        ("subscription", "SUBSCRIPTION"),
-        ("substring", "SUBSTRING"),
-        ("support", "SUPPORT"),
-        ("symmetric", "SYMMETRIC"),
-        ("sysid", "SYSID"),
-        ("system", "SYSTEM_P"),
-        ("system_user", "SYSTEM_USER"),
-        ("table", "TABLE"),
-        ("tables", "TABLES"),
-        ("tablesample", "TABLESAMPLE"),
-        ("tablespace", "TABLESPACE"),
-        ("target", "TARGET"),
-        ("temp", "TEMP"),
-        ("template", "TEMPLATE"),
-        ("temporary", "TEMPORARY"),
-        ("text", "TEXT_P"),
-        ("then", "THEN"),
-        ("ties", "TIES"),
-        ("time", "TIME"),
-        ("timestamp", "TIMESTAMP"),
-        ("to", "TO"),
-        ("trailing", "TRAILING"),
-        ("transaction", "TRANSACTION"),
-        ("transform", "TRANSFORM"),
-        ("treat", "TREAT"),
-        ("trigger", "TRIGGER"),
-        ("trim", "TRIM"),
-        ("true", "TRUE_P"),
-        ("truncate", "TRUNCATE"),
-        ("trusted", "TRUSTED"),
-        ("type", "TYPE_P"),
-        ("types", "TYPES_P"),
-        ("uescape", "UESCAPE"),
-        ("unbounded", "UNBOUNDED"),
-        ("uncommitted", "UNCOMMITTED"),
-        ("unconditional", "UNCONDITIONAL"),
-        ("unencrypted", "UNENCRYPTED"),
-        ("union", "UNION"),
-        ("unique", "UNIQUE"),
-        ("unknown", "UNKNOWN"),
-        ("unlisten", "UNLISTEN"),
-        ("unlogged", "UNLOGGED"),
-        ("until", "UNTIL"),
-        ("update", "UPDATE"),
-        ("user", "USER"),
-        ("using", "USING"),
-        ("vacuum", "VACUUM"),
-        ("valid", "VALID"),
-        ("validate", "VALIDATE"),
-        ("validator", "VALIDATOR"),
-        ("value", "VALUE_P"),
-        ("values", "VALUES"),
-        ("varchar", "VARCHAR"),
-        ("variadic", "VARIADIC"),
-        ("varying", "VARYING"),
-        ("verbose", "VERBOSE"),
-        ("version", "VERSION_P"),
-        ("view", "VIEW"),
-        ("views", "VIEWS"),
-        ("volatile", "VOLATILE"),
-        ("when", "WHEN"),
-        ("where", "WHERE"),
-        ("whitespace", "WHITESPACE_P"),
-        ("window", "WINDOW"),
-        ("with", "WITH"),
-        ("within", "WITHIN"),
-        ("without", "WITHOUT"),
-        ("work", "WORK"),
-        ("wrapper", "WRAPPER"),
-        ("write", "WRITE"),
-        ("xml", "XML_P"),
-        ("xmlattributes", "XMLATTRIBUTES"),
-        ("xmlconcat", "XMLCONCAT"),
-        ("xmlelement", "XMLELEMENT"),
-        ("xmlexists", "XMLEXISTS"),
-        ("xmlforest", "XMLFOREST"),
-        ("xmlnamespaces", "XMLNAMESPACES"),
-        ("xmlparse", "XMLPARSE"),
-        ("xmlpi", "XMLPI"),
-        ("xmlroot", "XMLROOT"),
-        ("xmlserialize", "XMLSERIALIZE"),
-        ("xmltable", "XMLTABLE"),
-        ("year", "YEAR_P"),
-        ("yes", "YES_P"),
-        ("zone", "ZONE"),
-    ] {
+    for &(kw, tok, _, _) in KEYWORD_LIST {
         map.insert(kw, tok);
     }
     map
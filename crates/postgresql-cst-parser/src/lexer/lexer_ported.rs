@@ -1,5 +1,8 @@
 /// Ported sources from PostgreSQL
-use super::{Lexer, Token, TokenKind, Yylval, parser_error::ParserError};
+use super::{
+    parser_error::{ParserError, ReportLevel, ScanReport},
+    Lexer, LexerOptions, Token, TokenKind, Yylval, NAMEDATALEN,
+};
 
 pub fn is_highbit_set(c: char) -> u8 {
     (c as u8) & 0x80
@@ -66,10 +69,10 @@ impl Lexer {
 
     pub fn addunicode(&mut self, c: char) -> Result<(), ParserError> {
         if !is_valid_unicode_codepoint(c) {
-            yyerror!("invalid Unicode escape value");
+            yyerror!(self, "invalid Unicode escape value");
         }
 
-        self.addlit(c.len_utf8());
+        self.addlitchar(c);
         Ok(())
     }
 
@@ -119,10 +122,11 @@ impl Lexer {
             _ => (),
         }
 
+        // pg_strtoint32_safe() accepts underscores between digits
+        let digits = yytext.replace('_', "");
         let res_parse_as_i32 = match radix {
-            8 => i32::from_str_radix(&yytext[2..], 8),
-            10 => yytext.parse::<i32>(),
-            16 => i32::from_str_radix(&yytext[2..], 16),
+            10 => digits.parse::<i32>(),
+            2 | 8 | 16 => i32::from_str_radix(&digits[2..], radix as u32),
             _ => unreachable!(),
         };
 
@@ -141,22 +145,333 @@ impl Lexer {
         }
     }
 
-    pub fn downcase_truncate_identifier(&self, yyleng: usize, _warn: bool) -> String {
-        self.yytext()[..yyleng].to_ascii_lowercase()
+    pub fn downcase_truncate_identifier(&mut self, yyleng: usize, warn: bool) -> String {
+        let ident = self.yytext()[..yyleng].to_ascii_lowercase();
+        self.truncate_identifier(ident, warn)
     }
+
+    pub fn truncate_identifier(&mut self, mut ident: String, warn: bool) -> String {
+        if let Some(report) = truncate_identifier(&mut ident, self.yylloc_bytes) {
+            if warn {
+                self.add_warning(report);
+            }
+        }
+        ident
+    }
+
+    pub fn check_string_escape_warning(&mut self, ychar: char) {
+        // if (ychar == '\'')
+        // {
+        // 	if (yyextra->warn_on_first_escape && yyextra->escape_string_warning)
+        // 		ereport(WARNING, ...
+        // 	yyextra->warn_on_first_escape = false;	/* warn only once per string */
+        // }
+        // else if (ychar == '\\')
+        // { ...
+        // }
+        // else
+        // 	check_escape_warning(yyscanner);
+
+        if ychar == '\'' {
+            if self.warn_on_first_escape && self.options.escape_string_warning {
+                ereport!(
+                    self,
+                    WARNING,
+                    (
+                        errcode(ERRCODE_NONSTANDARD_USE_OF_ESCAPE_CHARACTER),
+                        errmsg("nonstandard use of \\' in a string literal"),
+                        errhint(
+                            "Use '' to write quotes in strings, or use the escape string syntax (E'...')."
+                        ),
+                        self.lexer_errposition()
+                    )
+                );
+            }
+            self.warn_on_first_escape = false; /* warn only once per string */
+        } else if ychar == '\\' {
+            if self.warn_on_first_escape && self.options.escape_string_warning {
+                ereport!(
+                    self,
+                    WARNING,
+                    (
+                        errcode(ERRCODE_NONSTANDARD_USE_OF_ESCAPE_CHARACTER),
+                        errmsg("nonstandard use of \\\\ in a string literal"),
+                        errhint("Use the escape string syntax for backslashes, e.g., E'\\\\'."),
+                        self.lexer_errposition()
+                    )
+                );
+            }
+            self.warn_on_first_escape = false; /* warn only once per string */
+        } else {
+            self.check_escape_warning();
+        }
+    }
+
+    pub fn check_escape_warning(&mut self) {
+        if self.warn_on_first_escape && self.options.escape_string_warning {
+            ereport!(
+                self,
+                WARNING,
+                (
+                    errcode(ERRCODE_NONSTANDARD_USE_OF_ESCAPE_CHARACTER),
+                    errmsg("nonstandard use of escape in a string literal"),
+                    errhint("Use the escape string syntax for escapes, e.g., E'\\r\\n'."),
+                    self.lexer_errposition()
+                )
+            );
+        }
+        self.warn_on_first_escape = false; /* warn only once per string */
+    }
+}
+
+/// Truncates an identifier longer than `NAMEDATALEN - 1` bytes, returning the notice for it
+///
+/// `position` is the byte position of the identifier, which is used for the position of the notice.
+/// ref: https://github.com/postgres/postgres/blob/REL_17_STABLE/src/backend/parser/scansup.c
+pub fn truncate_identifier(ident: &mut String, position: usize) -> Option<ScanReport> {
+    // if (len >= NAMEDATALEN)
+    // {
+    // 	len = pg_mbcliplen(ident, len, NAMEDATALEN - 1);
+    // 	if (warn)
+    // 		ereport(NOTICE,
+    // 				(errcode(ERRCODE_NAME_TOO_LONG),
+    // 				 errmsg("identifier \"%s\" will be truncated to \"%.*s\"",
+    // 						ident, len, ident)));
+    // 	ident[len] = '\0';
+    // }
+
+    if ident.len() < NAMEDATALEN {
+        return None;
+    }
+
+    let mut len = NAMEDATALEN - 1;
+    while !ident.is_char_boundary(len) {
+        len -= 1;
+    }
+    let message = format!(
+        "identifier \"{ident}\" will be truncated to \"{}\"",
+        &ident[..len]
+    );
+    ident.truncate(len);
+    Some(ScanReport::new(&message, "", position).with_level(ReportLevel::Notice))
+}
+
+/// Processes the Unicode escapes of the literal of `UIDENT` and `USCONST`
+///
+/// `position` is the byte position of the token, which is used for the position of errors.
+/// ref: https://github.com/postgres/postgres/blob/REL_17_STABLE/src/backend/parser/parser.c
+pub fn str_udeescape(s: &str, escape: char, position: usize) -> Result<String, ParserError> {
+    fn invalid_pair(position: usize) -> ParserError {
+        ParserError::new_report("invalid Unicode surrogate pair", "", position)
+    }
+
+    fn hex_value(s: &str, len: usize) -> Option<u32> {
+        let digits = s.get(..len)?;
+        if digits.len() == len && digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            u32::from_str_radix(digits, 16).ok()
+        } else {
+            None
+        }
+    }
+
+    let mut new = String::with_capacity(s.len());
+    let mut pair_first = 0;
+    let mut in_pos = 0;
+
+    while let Some(c) = s[in_pos..].chars().next() {
+        if c != escape {
+            if pair_first != 0 {
+                return Err(invalid_pair(in_pos + position + 3));
+            }
+            new.push(c);
+            in_pos += c.len_utf8();
+            continue;
+        }
+
+        // Any errors reported while processing this escape sequence will have
+        // an error cursor pointing at the escape.
+        let errpos = in_pos + position + 3; /* 3 for U&" */
+        let rest = &s[in_pos + escape.len_utf8()..];
+
+        let (unicode, len) = if rest.starts_with(escape) {
+            if pair_first != 0 {
+                return Err(invalid_pair(errpos));
+            }
+            new.push(escape);
+            in_pos += escape.len_utf8() * 2;
+            continue;
+        } else if let Some(unicode) = hex_value(rest, 4) {
+            (unicode, 4)
+        } else if let Some(unicode) = rest.strip_prefix('+').and_then(|rest| hex_value(rest, 6)) {
+            (unicode, 7)
+        } else {
+            return Err(ParserError::ScanReport(ScanReport::new_hint(
+                "invalid Unicode escape",
+                "Unicode escapes must be \\XXXX or \\+XXXXXX.",
+                errpos,
+            )));
+        };
+
+        // check_unicode_value()
+        if !(1..=0x10FFFF).contains(&unicode) {
+            return Err(ParserError::new_report(
+                "invalid Unicode escape value",
+                "",
+                errpos,
+            ));
+        }
+
+        let unicode = if pair_first != 0 {
+            if !is_utf16_surrogate_second(unicode) {
+                return Err(invalid_pair(errpos));
+            }
+            let first = pair_first;
+            pair_first = 0;
+            u32::from(surrogate_pair_to_codepoint(first, unicode))
+        } else if is_utf16_surrogate_second(unicode) {
+            return Err(invalid_pair(errpos));
+        } else {
+            unicode
+        };
+
+        if is_utf16_surrogate_first(unicode) {
+            pair_first = unicode;
+        } else {
+            new.push(char::from_u32(unicode).unwrap());
+        }
+        in_pos += escape.len_utf8() + len;
+    }
+
+    /* unfinished surrogate pair? */
+    if pair_first != 0 {
+        return Err(invalid_pair(in_pos + position + 3));
+    }
+
+    Ok(new)
+}
+
+/// Returns whether the token is not passed to the parser, like a comment
+fn is_comment(kind: &TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::C_COMMENT
+            | TokenKind::SQL_COMMENT
+            | TokenKind::PSQL_META_COMMAND
+            | TokenKind::COPY_DATA
+    )
+}
+
+fn next_token_index(tokens: &[Token], i: usize) -> Option<usize> {
+    (i + 1..tokens.len()).find(|&j| !is_comment(&tokens[j].kind))
+}
+
+/// Returns the literal of a string or identifier token, which is the text without the quotes
+///
+/// `options` must be the settings the token was lexed with, because the literal of a string depends on them.
+fn token_literal(token: &Token, options: &LexerOptions) -> Result<String, ParserError> {
+    let mut lexer = Lexer::new(&token.value, options.clone());
+    match lexer.next_token() {
+        Ok(Some((_, Yylval::Str(literal)))) => Ok(literal),
+        _ => Err(ParserError::new_error(
+            "expected a string literal or an identifier",
+            token.start_byte_pos,
+            token.end_byte_pos,
+        )),
+    }
+}
+
+/// Processes the `UIDENT` or `USCONST` at `tokens[i]` and the following `UESCAPE 'c'` clause if any
+///
+/// `literals` returns the literal of the token at an index, which is the text without the quotes.
+/// Returns the decoded value and the index of the last token of the clause.
+/// ref: https://github.com/postgres/postgres/blob/REL_17_STABLE/src/backend/parser/parser.c
+pub fn process_unicode_token(
+    tokens: &[Token],
+    i: usize,
+    literals: impl Fn(usize) -> Result<String, ParserError>,
+) -> Result<(String, usize), ParserError> {
+    let token = &tokens[i];
+    let literal = literals(i)?;
+
+    /* Look ahead for UESCAPE */
+    if let Some(j) = next_token_index(tokens, i)
+        .filter(|&j| tokens[j].kind == TokenKind::KEYWORD("UESCAPE".to_string()))
+    {
+        /* Yup, so get third token, which had better be SCONST */
+        let Some(k) = next_token_index(tokens, j).filter(|&k| tokens[k].kind == TokenKind::SCONST)
+        else {
+            /* If we throw error here, it will point to third token */
+            let (start, end) = next_token_index(tokens, j)
+                .map(|k| (tokens[k].start_byte_pos, tokens[k].end_byte_pos))
+                .unwrap_or((tokens[j].end_byte_pos, tokens[j].end_byte_pos));
+            return Err(ParserError::new_error(
+                "UESCAPE must be followed by a simple string literal",
+                start,
+                end,
+            ));
+        };
+
+        let escstr = literals(k)?;
+        let mut chars = escstr.chars();
+        let escape = match (chars.next(), chars.next()) {
+            (Some(c), None) if check_uescapechar(c) => c,
+            _ => {
+                return Err(ParserError::new_error(
+                    "invalid Unicode escape character",
+                    tokens[k].start_byte_pos,
+                    tokens[k].end_byte_pos,
+                ))
+            }
+        };
+
+        /* Apply Unicode conversion */
+        let value = str_udeescape(&literal, escape, token.start_byte_pos)?;
+        Ok((value, k))
+    } else {
+        /* No UESCAPE, so convert using default escape character */
+        let value = str_udeescape(&literal, '\\', token.start_byte_pos)?;
+        Ok((value, i))
+    }
+}
+
+/// ref: https://github.com/postgres/postgres/blob/REL_17_STABLE/src/backend/parser/parser.c
+fn check_uescapechar(escape: char) -> bool {
+    // scanner_isspace()
+    let is_space = matches!(escape, ' ' | '\t' | '\n' | '\r' | '\x0c' | '\x0b');
+    !(escape.is_ascii_hexdigit() || escape == '+' || escape == '\'' || escape == '"' || is_space)
 }
 
 /// The logic for converting tokens in PostgreSQL's parser.c
-/// ref: https://github.com/postgres/postgres/blob/REL_16_STABLE/src/backend/parser/parser.c#L195
-pub fn init_tokens(tokens: &mut [Token]) {
-    fn next_token_index(tokens: &[Token], i: usize) -> Option<usize> {
-        for (j, token) in tokens.iter().enumerate().skip(i + 1) {
-            match token.kind {
-                TokenKind::C_COMMENT | TokenKind::SQL_COMMENT => continue,
-                _ => return Some(j),
+///
+/// `UIDENT` and `USCONST` become `IDENT` and `SCONST`.
+/// Returns the indices of the `UESCAPE` keyword and the string of the `UESCAPE 'c'` clauses following them,
+/// which are not passed to the parser. The comments in a clause are kept as they are.
+/// The notices of identifier truncation are added to `reports`.
+/// ref: https://github.com/postgres/postgres/blob/REL_17_STABLE/src/backend/parser/parser.c
+pub fn init_tokens(
+    tokens: &mut [Token],
+    options: &LexerOptions,
+    reports: &mut Vec<ScanReport>,
+) -> Result<Vec<usize>, ParserError> {
+    let mut clauses = vec![];
+    let mut i = 0;
+    while i < tokens.len() {
+        if matches!(tokens[i].kind, TokenKind::UIDENT | TokenKind::USCONST) {
+            let (mut value, last) =
+                process_unicode_token(tokens, i, |j| token_literal(&tokens[j], options))?;
+
+            if tokens[i].kind == TokenKind::UIDENT {
+                /* It's an identifier, so truncate as appropriate */
+                reports.extend(truncate_identifier(&mut value, tokens[i].start_byte_pos));
+                tokens[i].kind = TokenKind::IDENT;
+            } else {
+                tokens[i].kind = TokenKind::SCONST;
             }
+
+            clauses.extend((i + 1..=last).filter(|&j| !is_comment(&tokens[j].kind)));
+            i = last;
         }
-        None
+        i += 1;
     }
 
     for i in 0..tokens.len() - 1 {
@@ -216,4 +531,6 @@ pub fn init_tokens(tokens: &mut [Token]) {
             _ => (),
         }
     }
+
+    Ok(clauses)
 }
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 #[derive(Debug, PartialEq)]
 pub enum ParserError {
     ParseError {
@@ -8,6 +10,8 @@ pub enum ParserError {
     ScanReport(ScanReport),
     ScanError {
         message: String,
+        start_byte_pos: usize,
+        end_byte_pos: usize,
     },
 }
 
@@ -16,26 +20,103 @@ impl ParserError {
         Self::ScanReport(ScanReport::new(message, detail, position))
     }
 
-    pub fn new_error(message: &str) -> Self {
+    pub fn new_error(message: &str, start_byte_pos: usize, end_byte_pos: usize) -> Self {
         Self::ScanError {
             message: message.to_string(),
+            start_byte_pos,
+            end_byte_pos,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            ParserError::ParseError { message, .. } => message,
+            ParserError::ScanReport(report) => &report.message,
+            ParserError::ScanError { message, .. } => message,
+        }
+    }
+
+    /// Returns the byte range of the source the error points at
+    ///
+    /// The range of a [`ScanReport`] is empty, because PostgreSQL reports a single position for it.
+    pub fn byte_range(&self) -> Range<usize> {
+        match self {
+            ParserError::ParseError {
+                start_byte_pos,
+                end_byte_pos,
+                ..
+            }
+            | ParserError::ScanError {
+                start_byte_pos,
+                end_byte_pos,
+                ..
+            } => *start_byte_pos..*end_byte_pos,
+            ParserError::ScanReport(report) => report.position_in_bytes..report.position_in_bytes,
         }
     }
 }
 
+impl std::fmt::Display for ParserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParserError::ScanReport(report) => report.fmt(f),
+            _ => f.write_str(self.message()),
+        }
+    }
+}
+
+impl std::error::Error for ParserError {}
+
+/// Level of a [`ScanReport`], as passed to PostgreSQL's `ereport`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReportLevel {
+    Error,
+    Warning,
+    Notice,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ScanReport {
+    pub level: ReportLevel,
     pub message: String,
+    /// `DETAIL` of the report, empty if there is none
     pub detail: String,
+    /// `HINT` of the report, empty if there is none
+    pub hint: String,
     pub position_in_bytes: usize,
 }
 
 impl ScanReport {
     pub fn new(message: &str, detail: &str, position: usize) -> Self {
         Self {
+            level: ReportLevel::Error,
             message: message.to_string(),
             detail: detail.to_string(),
+            hint: String::new(),
+            position_in_bytes: position,
+        }
+    }
+
+    pub fn new_hint(message: &str, hint: &str, position: usize) -> Self {
+        Self {
+            level: ReportLevel::Error,
+            message: message.to_string(),
+            detail: String::new(),
+            hint: hint.to_string(),
             position_in_bytes: position,
         }
     }
+
+    pub fn with_level(mut self, level: ReportLevel) -> Self {
+        self.level = level;
+        self
+    }
 }
+
+impl std::fmt::Display for ScanReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ScanReport {}
@@ -0,0 +1,271 @@
+//! psql extensions of the lexer, enabled by [`super::LexerOptions::psql`]
+
+use super::{generated::State, Lexer, Token, TokenKind, Yylval};
+
+/// Meta-commands that send the query buffer to the server, ending the statement
+const SENDING_COMMANDS: &[&str] = &["crosstabview", "g", "gdesc", "gexec", "gset", "gx", "watch"];
+
+/// Progress of `COPY ... FROM STDIN` in the current statement
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum CopyState {
+    #[default]
+    None,
+    /// After `COPY`
+    Copy,
+    /// After `COPY ... FROM`
+    From,
+    /// After `COPY ... FROM STDIN`
+    Stdin,
+}
+
+/// State of the statement being lexed in the psql mode
+#[derive(Debug, Clone, Default)]
+pub struct PsqlState {
+    /// Number of tokens in the current statement, other than comments
+    tokens: usize,
+    copy: CopyState,
+    /// Whether the data of `COPY ... FROM STDIN` starts on the next line
+    copy_data: bool,
+}
+
+impl PsqlState {
+    /// Updates the state with a token returned by the lexer
+    pub(crate) fn track(&mut self, kind: &TokenKind) {
+        match kind {
+            TokenKind::C_COMMENT | TokenKind::SQL_COMMENT | TokenKind::COPY_DATA => {}
+            TokenKind::PSQL_META_COMMAND => {}
+            TokenKind::RAW(s) if s == ";" => {
+                self.copy_data |= self.copy == CopyState::Stdin;
+                self.tokens = 0;
+                self.copy = CopyState::None;
+            }
+            kind => {
+                let keyword = match kind {
+                    TokenKind::KEYWORD(keyword) => keyword.as_str(),
+                    _ => "",
+                };
+                self.copy = match (self.copy, keyword) {
+                    (CopyState::None, "COPY") if self.tokens == 0 => CopyState::Copy,
+                    (CopyState::Copy, "FROM") => CopyState::From,
+                    (CopyState::From, "STDIN") => CopyState::Stdin,
+                    (CopyState::From, _) => CopyState::Copy,
+                    (copy, _) => copy,
+                };
+                self.tokens += 1;
+            }
+        }
+    }
+}
+
+/// Returns whether the token is a psql variable reference, which the lexer returns as `IDENT` or `SCONST`
+pub(crate) fn is_psql_variable(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::IDENT | TokenKind::SCONST) && token.value.starts_with(':')
+}
+
+impl Lexer {
+    /// Returns the next token if it is one of psql, setting the location of the token
+    ///
+    /// Before a meta-command sending the query buffer, returns an empty `;` to end the statement.
+    pub(crate) fn psql_token(&mut self) -> Option<TokenKind> {
+        if self.state != State::INITIAL {
+            return None;
+        }
+
+        let rest = &self.input[self.index_bytes..];
+        if self.psql.copy_data {
+            // A comment after the `;` is lexed before the data starting on the next line
+            let line = rest.trim_start_matches([' ', '\t', '\r', '\x0c']);
+            if line.starts_with("--") || line.starts_with("/*") {
+                return None;
+            }
+            self.psql.copy_data = false;
+            let start = self.index_bytes + rest.find('\n')? + 1;
+            let len = copy_data_len(&self.input[start..]);
+            return Some(self.psql_located(TokenKind::COPY_DATA, start, len));
+        }
+
+        let start = self.index_bytes + rest.len()
+            - rest
+                .trim_start_matches([' ', '\t', '\n', '\r', '\x0c'])
+                .len();
+        let rest = &self.input[start..];
+
+        if let Some(command) = rest.strip_prefix('\\') {
+            let name: String = command
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric())
+                .collect();
+            if SENDING_COMMANDS.contains(&name.as_str()) && self.psql.tokens > 0 {
+                return Some(self.psql_located(TokenKind::RAW(";".to_string()), start, 0));
+            }
+
+            let line = rest.find('\n').map_or(rest, |end| &rest[..end]);
+            let len = line.trim_end_matches('\r').len();
+            return Some(self.psql_located(TokenKind::PSQL_META_COMMAND, start, len));
+        }
+
+        let (kind, len) = variable(rest)?;
+        self.yylval = Yylval::Str(rest[..len].to_string());
+        Some(self.psql_located(kind, start, len))
+    }
+
+    fn psql_located(&mut self, kind: TokenKind, start: usize, len: usize) -> TokenKind {
+        self.index_bytes = start;
+        self.yylloc_bytes = start;
+        self.yyleng = len;
+        self.yyllocend_bytes = start + len;
+        kind
+    }
+}
+
+/// Returns the kind and length of `:var`, `:'var'` or `:"var"` at the start of `s`
+fn variable(s: &str) -> Option<(TokenKind, usize)> {
+    let is_variable_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || !c.is_ascii();
+
+    let rest = s.strip_prefix(':')?;
+    let (kind, quote) = match rest.chars().next()? {
+        '\'' => (TokenKind::SCONST, Some('\'')),
+        '"' => (TokenKind::IDENT, Some('"')),
+        // Not `:1` as in `a[1:2]`, which is rarely a variable
+        c if is_variable_char(c) && !c.is_ascii_digit() => (TokenKind::IDENT, None),
+        _ => return None,
+    };
+
+    match quote {
+        Some(quote) => {
+            let name_len = rest[1..].find(|c| !is_variable_char(c))?;
+            (name_len > 0 && rest[1 + name_len..].starts_with(quote))
+                .then_some((kind, 1 + 1 + name_len + 1))
+        }
+        None => {
+            let name_len = rest.find(|c| !is_variable_char(c)).unwrap_or(rest.len());
+            Some((kind, 1 + name_len))
+        }
+    }
+}
+
+/// Returns the length of the data of `COPY ... FROM STDIN` up to and including the line `\.`, or the rest of the input
+fn copy_data_len(s: &str) -> usize {
+    let mut pos = 0;
+    for line in s.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content == "\\." {
+            return pos + content.len();
+        }
+        pos += line.len();
+    }
+    s.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parse_with_options, syntax_kind::SyntaxKind, tokenize::tokenize_with_options, LexerOptions,
+        ParseOptions,
+    };
+
+    fn options() -> ParseOptions {
+        ParseOptions {
+            lexer: LexerOptions {
+                psql: true,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Returns the tokens of the tree other than whitespace
+    fn tokens(src: &str) -> Vec<(SyntaxKind, String)> {
+        parse_with_options(src, &options())
+            .unwrap()
+            .root
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+            .filter(|token| token.kind() != SyntaxKind::Whitespace)
+            .map(|token| (token.kind(), token.text().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn meta_commands() {
+        let src = "\\set ON_ERROR_STOP on\r\nselect 1 \\gexec\n\\if :x\nselect 2;\n\\g\n\\endif";
+        assert_eq!(
+            tokens(src),
+            [
+                (
+                    SyntaxKind::PSQL_META_COMMAND,
+                    "\\set ON_ERROR_STOP on".to_string()
+                ),
+                (SyntaxKind::SELECT, "select".to_string()),
+                (SyntaxKind::ICONST, "1".to_string()),
+                (SyntaxKind::Semicolon, "".to_string()),
+                (SyntaxKind::PSQL_META_COMMAND, "\\gexec".to_string()),
+                (SyntaxKind::PSQL_META_COMMAND, "\\if :x".to_string()),
+                (SyntaxKind::SELECT, "select".to_string()),
+                (SyntaxKind::ICONST, "2".to_string()),
+                (SyntaxKind::Semicolon, ";".to_string()),
+                (SyntaxKind::PSQL_META_COMMAND, "\\g".to_string()),
+                (SyntaxKind::PSQL_META_COMMAND, "\\endif".to_string()),
+            ]
+        );
+
+        // Without the psql mode, a backslash is an ordinary token
+        assert_eq!(crate::lex(src).unwrap()[0].value, "\\");
+    }
+
+    #[test]
+    fn variables() {
+        let variables: Vec<_> = tokens("select :a, :'b', :\"c\", x::int, y[1:2], ':d' from :t;")
+            .into_iter()
+            .filter(|(kind, _)| *kind == SyntaxKind::PSQL_VARIABLE)
+            .map(|(_, text)| text)
+            .collect();
+        assert_eq!(variables, [":a", ":'b'", ":\"c\"", ":t"]);
+    }
+
+    #[test]
+    fn copy_data() {
+        let src = "copy t (a, b) from stdin with (format csv);\n1,\"a;b\"\n\\.\nselect 1;\ncopy t to stdout;\nselect 2;";
+        let tokens = tokens(src);
+        assert!(tokens.contains(&(SyntaxKind::COPY_DATA, "1,\"a;b\"\n\\.".to_string())));
+        assert_eq!(
+            tokens
+                .iter()
+                .filter(|(kind, _)| *kind == SyntaxKind::COPY_DATA)
+                .count(),
+            1
+        );
+
+        // Without `\.`, the data continues to the end of the input
+        assert_eq!(
+            self::tokens("copy t from stdin;\n1\t2\n").last(),
+            Some(&(SyntaxKind::COPY_DATA, "1\t2\n".to_string()))
+        );
+
+        let lexer = options().lexer;
+        let raw: Vec<_> = tokenize_with_options(src, &lexer)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(raw.iter().map(|token| token.text).collect::<String>(), src);
+
+        // A comment on the line of the `;` is a separate token
+        for (src, comment) in [
+            (
+                "copy t from stdin; -- data\n1\t2\n\\.\n",
+                (SyntaxKind::SQL_COMMENT, "-- data"),
+            ),
+            (
+                "copy t from stdin; /* data */\n1\t2\n\\.\n",
+                (SyntaxKind::C_COMMENT, "/* data */"),
+            ),
+        ] {
+            assert_eq!(
+                self::tokens(src)[4..],
+                [
+                    (SyntaxKind::Semicolon, ";".to_string()),
+                    (comment.0, comment.1.to_string()),
+                    (SyntaxKind::COPY_DATA, "1\t2\n\\.".to_string()),
+                ]
+            );
+        }
+    }
+}
@@ -1,17 +1,18 @@
 #![allow(dead_code)]
 
 use super::{
-    Lexer, ScanReport, Yylval,
+    Lexer, LexerOptions, ParserError, ScanReport, Yylval,
     generated::{State, get_keyword_map},
 };
 
 impl Lexer {
-    pub fn new(input: &str) -> Self {
+    pub fn new(input: &str, options: LexerOptions) -> Self {
         #[cfg(feature = "regex-match")]
         let rules = super::generated::get_rules();
 
         Self {
             input: input.to_string(),
+            options,
             index_bytes: 0,
             state: State::INITIAL,
             yyleng: 0,
@@ -33,6 +34,7 @@ impl Lexer {
             rules,
             keyword_map: get_keyword_map(),
             reports: Vec::new(),
+            psql: Default::default(),
         }
     }
 
@@ -137,4 +139,10 @@ impl Lexer {
     pub fn lexer_errposition(&self) -> usize {
         self.index_bytes
     }
+
+    /// Creates an error from the start of the current token to the end of the current match, like `scanner_yyerror`
+    pub fn yyerror(&self, message: &str) -> ParserError {
+        let end = self.index_bytes + self.yyleng;
+        ParserError::new_error(message, self.yylloc_bytes.min(end), end)
+    }
 }
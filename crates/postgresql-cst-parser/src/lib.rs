@@ -1,19 +1,39 @@
 #![allow(non_camel_case_types)]
 
 mod lexer;
-pub use lexer::{lex, Token, TokenKind};
+pub use lexer::{
+    lex, lex_with_options, lex_with_warnings, BackslashQuote, LexerOptions, Token, TokenKind,
+};
 
 mod parser;
 
+pub mod anonymize;
+pub mod classify;
 mod cst;
+mod cst_util;
+pub mod diagnostic;
+pub mod diff;
+pub mod edit;
+pub mod fields;
+pub mod highlight;
+pub mod keywords;
+pub mod line_index;
+pub mod lint;
+pub mod logparse;
+pub mod make;
+pub mod migration;
 pub mod syntax_kind;
+pub mod tokenize;
 mod transform;
+pub mod value;
 
 #[cfg(feature = "tree-sitter-like")]
 pub mod tree_sitter;
 
 use cst::parse_with_transformer;
 pub use cst::NodeOrToken;
+pub use cst::ParseOptions;
+pub use cst::Parsed;
 pub use cst::PostgreSQLSyntax;
 pub use cst::ResolvedNode;
 pub use cst::ResolvedToken;
@@ -22,6 +42,7 @@ pub use cst::SyntaxElementRef;
 pub use cst::SyntaxNode;
 pub use cst::SyntaxToken;
 pub use lexer::parser_error::ParserError;
+pub use lexer::parser_error::ReportLevel;
 pub use lexer::parser_error::ScanReport;
 
 use transform::ComplementMissingFromTableTransformer;
@@ -29,8 +50,18 @@ use transform::ComplementMissingSampleValueTransformer;
 use transform::ParseTransformer;
 use transform::SkipExtraComma;
 use transform::SkipExtraOperator;
+#[cfg(feature = "tree-sitter-like")]
 pub use tree_sitter::parse as ts_parse;
+#[cfg(feature = "tree-sitter-like")]
 pub use tree_sitter::parse_2way as ts_parse_2way;
+#[cfg(feature = "tree-sitter-like")]
+pub use tree_sitter::parse_2way_with_options as ts_parse_2way_with_options;
+#[cfg(feature = "tree-sitter-like")]
+pub use tree_sitter::parse_2way_with_parse_options as ts_parse_2way_with_parse_options;
+#[cfg(feature = "tree-sitter-like")]
+pub use tree_sitter::parse_with_options as ts_parse_with_options;
+#[cfg(feature = "tree-sitter-like")]
+pub use tree_sitter::parse_with_parse_options as ts_parse_with_parse_options;
 
 /// Parse SQL and construct a Complete Syntax Tree (CST).
 ///
@@ -100,23 +131,75 @@ pub fn parse(input: &str) -> Result<ResolvedNode, ParserError> {
     cst::parse(input)
 }
 
+/// Same as [`parse`], but also returns the non-fatal reports of the lexer
+///
+/// # Examples
+///
+/// ```
+/// use postgresql_cst_parser::parse_with_warnings;
+///
+/// let long_name = "a".repeat(70);
+/// let parsed = parse_with_warnings(&format!("select {long_name};")).unwrap();
+///
+/// assert_eq!(parsed.warnings.len(), 1);
+/// assert!(parsed.warnings[0].message.starts_with("identifier"));
+/// ```
+pub fn parse_with_warnings(input: &str) -> Result<Parsed, ParserError> {
+    cst::parse_with_warnings(input, &[])
+}
+
+/// Same as [`parse_with_warnings`], but with the given settings of PostgreSQL
+///
+/// # Examples
+///
+/// ```
+/// use postgresql_cst_parser::{parse_with_options, ParseOptions};
+///
+/// let mut options = ParseOptions::default();
+/// options.lexer.standard_conforming_strings = false;
+///
+/// // The backslash escapes the quote
+/// let parsed = parse_with_options(r"select 'It\'s';", &options).unwrap();
+/// assert_eq!(parsed.warnings[0].message, r"nonstandard use of \' in a string literal");
+/// ```
+pub fn parse_with_options(input: &str, options: &ParseOptions) -> Result<Parsed, ParserError> {
+    cst::parse_with_options(input, &[], options)
+}
+
 /// Corrects and parses the following syntax errors found in 2Way SQL
 /// 1. Missing sample values ​​when specifying a table name in the from clause as a replacement string
 /// 2. Missing sample values ​​in expressions found in select clauses, etc.
 /// 3. Extra commas in select clauses, from clauses, and order by clauses
 /// 4. Extra and/or in the where clause
 pub fn parse_2way(input: &str) -> Result<ResolvedNode, ParserError> {
-    parse_with_transformer(
-        input,
-        &[
-            &ComplementMissingFromTableTransformer,
-            &ComplementMissingSampleValueTransformer,
-            &SkipExtraComma,
-            &SkipExtraOperator,
-        ],
-    )
+    parse_with_transformer(input, TWO_WAY_TRANSFORMERS)
+}
+
+/// Same as [`parse_2way`], but with the given settings of PostgreSQL, also returning the warnings of the lexer
+///
+/// # Examples
+///
+/// ```
+/// use postgresql_cst_parser::{parse_2way_with_options, ParseOptions};
+///
+/// let mut options = ParseOptions::default();
+/// options.lexer.standard_conforming_strings = false;
+///
+/// let parsed = parse_2way_with_options(r"select /*param*/ as a, 'It\'s';", &options).unwrap();
+/// assert_eq!(parsed.warnings.len(), 1);
+/// ```
+pub fn parse_2way_with_options(input: &str, options: &ParseOptions) -> Result<Parsed, ParserError> {
+    cst::parse_with_options(input, TWO_WAY_TRANSFORMERS, options)
 }
 
+/// The transformers used by [`parse_2way`]
+pub(crate) const TWO_WAY_TRANSFORMERS: &[&dyn ParseTransformer] = &[
+    &ComplementMissingFromTableTransformer,
+    &ComplementMissingSampleValueTransformer,
+    &SkipExtraComma,
+    &SkipExtraOperator,
+];
+
 pub fn parse_2way_with_transformers(
     input: &str,
     transformers: &[&dyn ParseTransformer],
@@ -126,7 +209,7 @@ pub fn parse_2way_with_transformers(
 
 #[cfg(test)]
 mod tests {
-    use crate::lexer::parser_error::ScanReport;
+    use crate::syntax_kind::SyntaxKind;
 
     use super::*;
 
@@ -137,11 +220,30 @@ mod tests {
 
         let expected = Err(ParserError::ScanError {
             message: "unterminated hexadecimal string literal".to_string(),
+            start_byte_pos: 7,
+            end_byte_pos: 11,
         });
 
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_syntax_error() {
+        let expected = Err(ParserError::ParseError {
+            message: r#"syntax error at or near "from""#.to_string(),
+            start_byte_pos: 10,
+            end_byte_pos: 14,
+        });
+        assert_eq!(parse("select a, from t;"), expected);
+
+        let expected = Err(ParserError::ParseError {
+            message: "syntax error at end of input".to_string(),
+            start_byte_pos: 10,
+            end_byte_pos: 10,
+        });
+        assert_eq!(parse("select a +"), expected);
+    }
+
     #[test]
     fn test_unterminated_unterminated_bit_string_literal() {
         let input = r#"select b'10"#;
@@ -149,6 +251,8 @@ mod tests {
 
         let expected = Err(ParserError::ScanError {
             message: "unterminated bit string literal".to_string(),
+            start_byte_pos: 7,
+            end_byte_pos: 11,
         });
 
         assert_eq!(actual, expected);
@@ -160,8 +264,10 @@ mod tests {
         let actual = parse(input);
 
         let expected = Err(ParserError::ScanReport(ScanReport {
+            level: ReportLevel::Error,
             message: "invalid Unicode escape".to_string(),
-            detail: "Unicode escapes must be \\uXXXX or \\UXXXXXXXX.".to_string(),
+            detail: String::new(),
+            hint: "Unicode escapes must be \\uXXXX or \\UXXXXXXXX.".to_string(),
             position_in_bytes: 9,
         }));
 
@@ -175,6 +281,8 @@ mod tests {
 
         let expected = Err(ParserError::ScanError {
             message: "invalid Unicode surrogate pair".to_string(),
+            start_byte_pos: 15,
+            end_byte_pos: 15,
         });
 
         assert_eq!(actual, expected);
@@ -187,6 +295,8 @@ mod tests {
 
         let expected = Err(ParserError::ScanError {
             message: "invalid Unicode escape value".to_string(),
+            start_byte_pos: 9,
+            end_byte_pos: 15,
         });
 
         assert_eq!(actual, expected);
@@ -199,10 +309,262 @@ mod tests {
 
         let expected = Err(ParserError::ScanError {
             message: "invalid Unicode surrogate pair".to_string(),
+            start_byte_pos: 15,
+            end_byte_pos: 21,
         });
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_hexadecimal_escape() {
+        let root = parse(r"select e'\x41';").unwrap();
+        assert_eq!(root.text(), r"select e'\x41';");
+    }
+
+    #[test]
+    fn test_parser_error_is_std_error() {
+        fn parse_boxed(input: &str) -> Result<ResolvedNode, Box<dyn std::error::Error>> {
+            Ok(parse(input)?)
+        }
+
+        let error = parse_boxed(r#"select x'CC"#).unwrap_err();
+        assert_eq!(error.to_string(), "unterminated hexadecimal string literal");
+    }
+
+    /// Returns the level, message and position of the warnings, which are all public
+    fn warnings(parsed: &Parsed) -> Vec<(ReportLevel, &str, usize)> {
+        parsed
+            .warnings
+            .iter()
+            .map(|report| {
+                (
+                    report.level,
+                    report.message.as_str(),
+                    report.position_in_bytes,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_identifier_truncation_notice() {
+        let long_name = "A".repeat(70);
+        let input = format!(
+            r#"select {long_name}, "{long_name}", "{}";"#,
+            "あ".repeat(30)
+        );
+        let parsed = parse_with_warnings(&input).unwrap();
+
+        assert_eq!(
+            warnings(&parsed),
+            [
+                (
+                    ReportLevel::Notice,
+                    format!(
+                        r#"identifier "{}" will be truncated to "{}""#,
+                        "a".repeat(70),
+                        "a".repeat(63)
+                    )
+                    .as_str(),
+                    7,
+                ),
+                (
+                    ReportLevel::Notice,
+                    &format!(
+                        r#"identifier "{long_name}" will be truncated to "{}""#,
+                        "A".repeat(63)
+                    ),
+                    79,
+                ),
+                // 63 bytes are not on a character boundary
+                (
+                    ReportLevel::Notice,
+                    &format!(
+                        r#"identifier "{}" will be truncated to "{}""#,
+                        "あ".repeat(30),
+                        "あ".repeat(21)
+                    ),
+                    153,
+                ),
+            ]
+        );
+
+        assert!(parse_with_warnings(&format!("select {};", "a".repeat(63)))
+            .unwrap()
+            .warnings
+            .is_empty());
+    }
+
+    #[test]
+    fn test_nonstandard_escape_warning() {
+        let mut options = ParseOptions::default();
+        options.lexer.standard_conforming_strings = false;
+
+        let parsed = parse_with_options(r"select 'a\\b\n', 'c\x41', e'\\';", &options).unwrap();
+        assert_eq!(
+            parsed.warnings,
+            [
+                // warn only once per string
+                ScanReport::new_hint(
+                    r"nonstandard use of \\ in a string literal",
+                    r"Use the escape string syntax for backslashes, e.g., E'\\'.",
+                    9,
+                )
+                .with_level(ReportLevel::Warning),
+                ScanReport::new_hint(
+                    "nonstandard use of escape in a string literal",
+                    r"Use the escape string syntax for escapes, e.g., E'\r\n'.",
+                    19,
+                )
+                .with_level(ReportLevel::Warning),
+            ]
+        );
+
+        options.lexer.escape_string_warning = false;
+        let parsed = parse_with_options(r"select 'a\\b\n', 'c\x41', e'\\';", &options).unwrap();
+        assert!(parsed.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_standard_conforming_strings() {
+        let input = r"select 'It\'s';";
+        assert!(parse(input).is_err());
+
+        let mut options = ParseOptions::default();
+        options.lexer.standard_conforming_strings = false;
+        let parsed = parse_with_options(input, &options).unwrap();
+        let sconst = parsed
+            .root
+            .descendants_with_tokens()
+            .find(|e| e.kind() == syntax_kind::SyntaxKind::SCONST)
+            .unwrap();
+        assert_eq!(sconst.as_token().unwrap().text(), r"'It\'s'");
+
+        let actual = parse_with_options(r"select U&'\0041';", &options).map(|_| ());
+        let expected = Err(ParserError::ScanReport(ScanReport::new(
+            "unsafe use of string constant with Unicode escapes",
+            "String constants with Unicode escapes cannot be used when standard_conforming_strings is off.",
+            7,
+        )));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_backslash_quote() {
+        let input = r"select e'It\'s';";
+        assert!(parse(input).is_ok());
+
+        let mut options = ParseOptions::default();
+        options.lexer.backslash_quote = BackslashQuote::Off;
+        let actual = parse_with_options(input, &options).map(|_| ());
+        let expected = Err(ParserError::ScanReport(ScanReport::new_hint(
+            r"unsafe use of \' in a string literal",
+            r"Use '' to write quotes in strings. \' is insecure in client-only encodings.",
+            11,
+        )));
+        assert_eq!(actual, expected);
+
+        // `''` is always allowed
+        assert!(parse_with_options("select e'It''s';", &options).is_ok());
+    }
+
+    #[test]
+    fn test_unicode_escapes() {
+        let input = r#"select U&"d!0061t" UESCAPE '!', U&'d\0061t' /* c */ uescape '\' from t;"#;
+        let root = parse(input).unwrap();
+
+        // The comment in the `UESCAPE` clause is kept as a separate token
+        let tokens: Vec<_> = root
+            .descendants_with_tokens()
+            .filter_map(|e| e.as_token().cloned())
+            .filter(|t| t.kind() != SyntaxKind::Whitespace)
+            .map(|t| (t.kind(), t.text().to_string()))
+            .collect();
+        assert_eq!(
+            tokens,
+            [
+                (SyntaxKind::SELECT, "select".to_string()),
+                (SyntaxKind::IDENT, r#"U&"d!0061t""#.to_string()),
+                (SyntaxKind::UESCAPE, "UESCAPE".to_string()),
+                (SyntaxKind::SCONST, "'!'".to_string()),
+                (SyntaxKind::Comma, ",".to_string()),
+                (SyntaxKind::SCONST, r"U&'d\0061t'".to_string()),
+                (SyntaxKind::C_COMMENT, "/* c */".to_string()),
+                (SyntaxKind::UESCAPE, "uescape".to_string()),
+                (SyntaxKind::SCONST, r"'\'".to_string()),
+                (SyntaxKind::FROM, "from".to_string()),
+                (SyntaxKind::IDENT, "t".to_string()),
+                (SyntaxKind::Semicolon, ";".to_string()),
+            ]
+        );
+        assert_eq!(root.text(), input);
+    }
+
+    #[test]
+    fn test_unicode_escape_errors() {
+        let cases = [
+            (
+                r"select U&'a' UESCAPE 'ab';",
+                ParserError::new_error("invalid Unicode escape character", 21, 25),
+            ),
+            (
+                r"select U&'a' UESCAPE '+';",
+                ParserError::new_error("invalid Unicode escape character", 21, 24),
+            ),
+            (
+                r"select U&'a' UESCAPE x;",
+                ParserError::new_error(
+                    "UESCAPE must be followed by a simple string literal",
+                    21,
+                    22,
+                ),
+            ),
+            (
+                r"select U&'a\D800';",
+                ParserError::new_report("invalid Unicode surrogate pair", "", 16),
+            ),
+            (
+                r"select U&'a\DC00';",
+                ParserError::new_report("invalid Unicode surrogate pair", "", 11),
+            ),
+            (
+                r"select U&'a\+110000';",
+                ParserError::new_report("invalid Unicode escape value", "", 11),
+            ),
+            (
+                r"select U&'a\00X';",
+                ParserError::ScanReport(ScanReport::new_hint(
+                    "invalid Unicode escape",
+                    r"Unicode escapes must be \XXXX or \+XXXXXX.",
+                    11,
+                )),
+            ),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(parse(input).map(|_| ()), Err(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_unicode_identifier_truncation_notice() {
+        let input = format!(r#"select U&"{}";"#, r"\0041".repeat(64));
+        let parsed = parse_with_warnings(&input).unwrap();
+        assert_eq!(
+            warnings(&parsed),
+            [(
+                ReportLevel::Notice,
+                format!(
+                    r#"identifier "{}" will be truncated to "{}""#,
+                    "A".repeat(64),
+                    "A".repeat(63)
+                )
+                .as_str(),
+                7,
+            )]
+        );
+    }
 }
 
 #[cfg(test)]
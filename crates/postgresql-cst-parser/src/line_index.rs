@@ -0,0 +1,470 @@
+//! Conversion between byte offsets and line/column positions
+//!
+//! The parser reports positions as byte offsets into the source.
+//! Editors count columns in other units: LSP and JavaScript use UTF-16 code units,
+//! and PostgreSQL counts characters in its error cursor.
+//! [`LineIndex`] converts between these units.
+//!
+//! Lines are separated by `\n` or `\r\n`, in the same way as [`crate::tree_sitter::Point`].
+//! A `\r` before `\n` is not a part of the line.
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::line_index::{LineCol, LineIndex, PositionEncoding};
+//!
+//! let src = "select 'あいう',\r\n  b;";
+//! let index = LineIndex::new(src);
+//!
+//! let offset = src.find('b').unwrap();
+//! assert_eq!(index.line_col(offset, PositionEncoding::Utf8), LineCol { line: 1, col: 2 });
+//!
+//! let end_of_first_line = src.find(',').unwrap() + 1;
+//! assert_eq!(index.line_col(end_of_first_line, PositionEncoding::Utf8).col, 19);
+//! assert_eq!(index.line_col(end_of_first_line, PositionEncoding::Utf16).col, 13);
+//! assert_eq!(
+//!     index.offset(LineCol { line: 0, col: 13 }, PositionEncoding::Utf16),
+//!     Some(end_of_first_line)
+//! );
+//! ```
+
+use std::ops::Range;
+
+use cstree::text::TextRange;
+
+use crate::ParserError;
+
+/// The unit of columns and offsets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PositionEncoding {
+    /// Bytes of UTF-8, the unit used by the parser
+    Utf8,
+    /// UTF-16 code units, the unit used by LSP and JavaScript
+    Utf16,
+    /// Characters (Unicode scalar values), the unit used by PostgreSQL's error cursor
+    Utf32,
+}
+
+impl PositionEncoding {
+    fn len(self, c: char) -> usize {
+        match self {
+            PositionEncoding::Utf8 => c.len_utf8(),
+            PositionEncoding::Utf16 => c.len_utf16(),
+            PositionEncoding::Utf32 => 1,
+        }
+    }
+}
+
+/// A zero-based line and column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineCol {
+    pub line: usize,
+    pub col: usize,
+}
+
+/// A non-ASCII character in a line
+#[derive(Debug, Clone, Copy)]
+struct WideChar {
+    /// Byte offset from the start of the line
+    col: usize,
+    c: char,
+}
+
+#[derive(Debug, Clone)]
+struct Line {
+    /// Byte offset of the start of the line
+    start: usize,
+    /// Byte offset of the end of the line, excluding the line terminator
+    end: usize,
+    /// Numbers of UTF-16 code units and characters before the start of the line
+    utf16_start: usize,
+    utf32_start: usize,
+    wide_chars: Vec<WideChar>,
+}
+
+impl Line {
+    /// Converts a byte column to `encoding`
+    fn encode_col(&self, col: usize, encoding: PositionEncoding) -> usize {
+        let mut encoded = col;
+        for wide_char in &self.wide_chars {
+            if wide_char.col >= col {
+                break;
+            }
+            let len_utf8 = wide_char.c.len_utf8();
+            if col < wide_char.col + len_utf8 {
+                // `col` is inside the character
+                encoded -= col - wide_char.col;
+                break;
+            }
+            encoded -= len_utf8 - encoding.len(wide_char.c);
+        }
+        encoded
+    }
+
+    /// Converts a column in `encoding` to bytes
+    fn decode_col(&self, col: usize, encoding: PositionEncoding) -> usize {
+        let mut byte_col = col;
+        for wide_char in &self.wide_chars {
+            if wide_char.col >= byte_col {
+                break;
+            }
+            let len = encoding.len(wide_char.c);
+            if byte_col < wide_char.col + len {
+                // `col` is inside the character
+                byte_col = wide_char.col;
+                break;
+            }
+            byte_col += wide_char.c.len_utf8() - len;
+        }
+        byte_col.min(self.end - self.start)
+    }
+}
+
+/// An index of the lines of a source, for converting positions between units
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    lines: Vec<Line>,
+}
+
+impl LineIndex {
+    pub fn new(src: &str) -> Self {
+        let mut lines = vec![];
+        let mut line = Line {
+            start: 0,
+            end: 0,
+            utf16_start: 0,
+            utf32_start: 0,
+            wide_chars: vec![],
+        };
+        let (mut utf16, mut utf32) = (0, 0);
+
+        for (i, c) in src.char_indices() {
+            utf16 += c.len_utf16();
+            utf32 += 1;
+
+            if c == '\n' {
+                let end = if src[..i].ends_with('\r') { i - 1 } else { i };
+                let next = Line {
+                    start: i + 1,
+                    end: 0,
+                    utf16_start: utf16,
+                    utf32_start: utf32,
+                    wide_chars: vec![],
+                };
+                lines.push(Line {
+                    end,
+                    ..std::mem::replace(&mut line, next)
+                });
+            } else if !c.is_ascii() {
+                line.wide_chars.push(WideChar {
+                    col: i - line.start,
+                    c,
+                });
+            }
+        }
+
+        line.end = src.len();
+        lines.push(line);
+
+        Self { lines }
+    }
+
+    /// Returns the number of lines
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the byte range of the line, excluding the line terminator
+    pub fn line_range(&self, line: usize) -> Option<Range<usize>> {
+        self.lines.get(line).map(|line| line.start..line.end)
+    }
+
+    /// Returns the length of the source in bytes
+    pub fn len(&self) -> usize {
+        self.lines.last().unwrap().end
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Converts a byte offset to a line and column in `encoding`
+    ///
+    /// An offset inside a line terminator is moved to the end of the line,
+    /// an offset inside a character is moved to the start of the character,
+    /// and an offset past the end of the source is moved to the end of the source.
+    pub fn line_col(&self, offset: usize, encoding: PositionEncoding) -> LineCol {
+        let offset = offset.min(self.len());
+        let line = self.lines.partition_point(|line| line.start <= offset) - 1;
+        let byte_col = offset.min(self.lines[line].end) - self.lines[line].start;
+
+        LineCol {
+            line,
+            col: self.lines[line].encode_col(byte_col, encoding),
+        }
+    }
+
+    /// Converts a line and column in `encoding` to a byte offset
+    ///
+    /// A column past the end of the line is moved to the end of the line, as LSP requires.
+    /// Returns `None` if the line does not exist.
+    pub fn offset(&self, line_col: LineCol, encoding: PositionEncoding) -> Option<usize> {
+        let line = self.lines.get(line_col.line)?;
+        Some(line.start + line.decode_col(line_col.col, encoding))
+    }
+
+    /// Converts a byte offset to an offset from the start of the source in `encoding`
+    ///
+    /// For example, with [`PositionEncoding::Utf16`], the result is an index of a JavaScript string.
+    pub fn encoded_offset(&self, offset: usize, encoding: PositionEncoding) -> usize {
+        let offset = offset.min(self.len());
+        let line_index = self.lines.partition_point(|line| line.start <= offset) - 1;
+        let line = &self.lines[line_index];
+
+        let line_start = match encoding {
+            PositionEncoding::Utf8 => line.start,
+            PositionEncoding::Utf16 => line.utf16_start,
+            PositionEncoding::Utf32 => line.utf32_start,
+        };
+
+        // Unlike `line_col`, the line terminator is counted
+        let byte_col = offset - line.start;
+        let in_terminator = offset.saturating_sub(line.end);
+        line_start + line.encode_col(byte_col - in_terminator, encoding) + in_terminator
+    }
+
+    /// Converts an offset from the start of the source in `encoding` to a byte offset
+    ///
+    /// Returns `None` if the offset is past the end of the source.
+    pub fn byte_offset(&self, encoded_offset: usize, encoding: PositionEncoding) -> Option<usize> {
+        let line_start = |line: &Line| match encoding {
+            PositionEncoding::Utf8 => line.start,
+            PositionEncoding::Utf16 => line.utf16_start,
+            PositionEncoding::Utf32 => line.utf32_start,
+        };
+
+        let line_index = self
+            .lines
+            .partition_point(|line| line_start(line) <= encoded_offset)
+            - 1;
+        let line = &self.lines[line_index];
+        let col = encoded_offset - line_start(line);
+
+        // The line terminator consists of ASCII characters, so it has the same length in all encodings
+        let line_len = line.encode_col(line.end - line.start, encoding);
+        let byte_col = if col <= line_len {
+            line.decode_col(col, encoding)
+        } else {
+            line.end - line.start + (col - line_len)
+        };
+
+        let offset = line.start + byte_col;
+        let next_start = self
+            .lines
+            .get(line_index + 1)
+            .map_or(self.len(), |next| next.start);
+        (offset <= next_start).then_some(offset)
+    }
+
+    /// Converts a byte range, such as a [`crate::tree_sitter::Range`] or an error position, to lines and columns
+    pub fn range(&self, range: Range<usize>, encoding: PositionEncoding) -> Range<LineCol> {
+        self.line_col(range.start, encoding)..self.line_col(range.end, encoding)
+    }
+
+    /// Converts a [`TextRange`] of the CST to lines and columns
+    pub fn text_range(&self, range: TextRange, encoding: PositionEncoding) -> Range<LineCol> {
+        self.range(range.start().into()..range.end().into(), encoding)
+    }
+
+    /// Converts the byte range of a [`ParserError`] to lines and columns
+    pub fn error_range(&self, error: &ParserError, encoding: PositionEncoding) -> Range<LineCol> {
+        self.range(error.byte_range(), encoding)
+    }
+}
+
+#[cfg(feature = "tree-sitter-like")]
+impl LineIndex {
+    /// Converts a [`crate::tree_sitter::Point`], whose column is in bytes, to a line and column in `encoding`
+    pub fn point(
+        &self,
+        point: crate::tree_sitter::Point,
+        encoding: PositionEncoding,
+    ) -> Option<LineCol> {
+        let offset = self.offset(
+            LineCol {
+                line: point.row,
+                col: point.column,
+            },
+            PositionEncoding::Utf8,
+        )?;
+        Some(self.line_col(offset, encoding))
+    }
+
+    /// Converts a [`crate::tree_sitter::Range`] to lines and columns in `encoding`
+    pub fn ts_range(
+        &self,
+        range: &crate::tree_sitter::Range,
+        encoding: PositionEncoding,
+    ) -> Range<LineCol> {
+        self.range(range.start_byte..range.end_byte, encoding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LineCol, LineIndex, PositionEncoding};
+
+    fn lc(line: usize, col: usize) -> LineCol {
+        LineCol { line, col }
+    }
+
+    #[test]
+    fn ascii() {
+        let src = "select a,\n  b\nfrom t;";
+        let index = LineIndex::new(src);
+
+        assert_eq!(index.line_count(), 3);
+        assert_eq!(index.line_range(1), Some(10..13));
+        assert_eq!(index.line_range(3), None);
+
+        for encoding in [
+            PositionEncoding::Utf8,
+            PositionEncoding::Utf16,
+            PositionEncoding::Utf32,
+        ] {
+            for offset in 0..=src.len() {
+                let line_col = index.line_col(offset, encoding);
+                assert_eq!(index.offset(line_col, encoding), Some(offset));
+                assert_eq!(index.encoded_offset(offset, encoding), offset);
+                assert_eq!(index.byte_offset(offset, encoding), Some(offset));
+            }
+        }
+
+        assert_eq!(index.line_col(12, PositionEncoding::Utf8), lc(1, 2));
+        assert_eq!(index.line_col(100, PositionEncoding::Utf8), lc(2, 7));
+        assert_eq!(
+            index.byte_offset(src.len() + 1, PositionEncoding::Utf8),
+            None
+        );
+    }
+
+    #[test]
+    fn multibyte() {
+        // "あ" is 3 bytes in UTF-8 and 1 unit in UTF-16, "𠮷" is 4 bytes in UTF-8 and 2 units in UTF-16
+        let src = "select 'あ𠮷' as \"列\";\nselect 1;";
+        let index = LineIndex::new(src);
+
+        let as_offset = src.find("as").unwrap();
+        assert_eq!(index.line_col(as_offset, PositionEncoding::Utf8), lc(0, 17));
+        assert_eq!(
+            index.line_col(as_offset, PositionEncoding::Utf16),
+            lc(0, 13)
+        );
+        assert_eq!(
+            index.line_col(as_offset, PositionEncoding::Utf32),
+            lc(0, 12)
+        );
+
+        for encoding in [
+            PositionEncoding::Utf8,
+            PositionEncoding::Utf16,
+            PositionEncoding::Utf32,
+        ] {
+            for (offset, _) in src.char_indices() {
+                let line_col = index.line_col(offset, encoding);
+                assert_eq!(index.offset(line_col, encoding), Some(offset));
+                let encoded = index.encoded_offset(offset, encoding);
+                assert_eq!(index.byte_offset(encoded, encoding), Some(offset));
+            }
+        }
+
+        let second_line = src.find('\n').unwrap() + 1;
+        assert_eq!(
+            index.encoded_offset(second_line, PositionEncoding::Utf16),
+            src.encode_utf16().count() - "select 1;".len()
+        );
+        assert_eq!(
+            index.encoded_offset(second_line, PositionEncoding::Utf32),
+            src.chars().count() - "select 1;".len()
+        );
+
+        // An offset inside a character is moved to the start of the character
+        let a = src.find('あ').unwrap();
+        assert_eq!(index.line_col(a + 1, PositionEncoding::Utf16), lc(0, 8));
+        // A column inside a surrogate pair is moved to the start of the character
+        let kichi = src.find('𠮷').unwrap();
+        assert_eq!(
+            index.offset(lc(0, 10), PositionEncoding::Utf16),
+            Some(kichi)
+        );
+        // A column past the end of the line is moved to the end of the line
+        assert_eq!(
+            index.offset(lc(0, 100), PositionEncoding::Utf16),
+            Some(second_line - 1)
+        );
+    }
+
+    #[test]
+    fn crlf() {
+        let src = "select a,\r\n  b\r\nfrom t;";
+        let index = LineIndex::new(src);
+
+        assert_eq!(index.line_count(), 3);
+        assert_eq!(index.line_range(0), Some(0..9));
+        assert_eq!(index.line_range(1), Some(11..14));
+
+        let b = src.find('b').unwrap();
+        assert_eq!(index.line_col(b, PositionEncoding::Utf16), lc(1, 2));
+        // Offsets inside the line terminator are at the end of the line
+        assert_eq!(index.line_col(9, PositionEncoding::Utf16), lc(0, 9));
+        assert_eq!(index.line_col(10, PositionEncoding::Utf16), lc(0, 9));
+        assert_eq!(index.offset(lc(0, 100), PositionEncoding::Utf16), Some(9));
+
+        for offset in 0..=src.len() {
+            let encoded = index.encoded_offset(offset, PositionEncoding::Utf16);
+            assert_eq!(encoded, offset);
+            assert_eq!(
+                index.byte_offset(encoded, PositionEncoding::Utf16),
+                Some(offset)
+            );
+        }
+    }
+
+    #[test]
+    fn parser_error() {
+        let src = "select 'あ' from;";
+        let index = LineIndex::new(src);
+
+        let error = crate::parse(src).unwrap_err();
+        let range = index.error_range(&error, PositionEncoding::Utf16);
+        assert_eq!(range, lc(0, 15)..lc(0, 16));
+    }
+
+    #[cfg(feature = "tree-sitter-like")]
+    #[test]
+    fn tree_sitter_range() {
+        let src = "select 'あ',\r\n  b;";
+        let index = LineIndex::new(src);
+        let tree = crate::tree_sitter::parse(src).unwrap();
+
+        let b = tree
+            .root_node()
+            .descendant_for_byte_range(src.len() - 2, src.len() - 1)
+            .unwrap();
+        let range = b.range();
+        assert_eq!(
+            index.point(range.start_position, PositionEncoding::Utf16),
+            Some(lc(1, 2))
+        );
+        assert_eq!(
+            index.ts_range(&range, PositionEncoding::Utf16),
+            lc(1, 2)..lc(1, 3)
+        );
+
+        let literal = tree.root_node().descendant_for_byte_range(7, 7).unwrap();
+        assert_eq!(
+            index.ts_range(&literal.range(), PositionEncoding::Utf16),
+            lc(0, 7)..lc(0, 10)
+        );
+    }
+}
@@ -0,0 +1,411 @@
+//! Linter with pluggable rules
+//!
+//! A [`Rule`] is called for the nodes of the kinds it is interested in, and reports [`Violation`]s through [`LintContext`].
+//! [`Linter`] holds the registered rules and runs them over a tree.
+//! The built-in rules are in [`rules`].
+//!
+//! Violations can be suppressed by comments:
+//!
+//! - `-- noqa` suppresses all violations starting on the line of the comment,
+//!   and `-- noqa: rule-id, ...` only those of the rules.
+//! - `/* lint:disable */` suppresses all violations until `/* lint:enable */` or the end of the source,
+//!   and `/* lint:disable rule-id, ... */` only those of the rules.
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::{diagnostic::Severity, lint::Linter, parse};
+//!
+//! let src = "select * from t;\ndelete from u;\nupdate v set a = 1; -- noqa: missing-where\n";
+//! let root = parse(src).unwrap();
+//!
+//! let violations = Linter::with_builtin_rules().lint(&root);
+//! let rules: Vec<_> = violations.iter().map(|v| (v.rule, v.severity)).collect();
+//! assert_eq!(
+//!     rules,
+//!     [("select-star", Severity::Warning), ("missing-where", Severity::Error)]
+//! );
+//! ```
+
+#[cfg(feature = "lint-config")]
+mod config;
+pub mod rules;
+
+use std::{collections::HashMap, ops::Range};
+
+#[cfg(feature = "lint-config")]
+pub use config::ConfigError;
+
+use crate::{
+    cst_util::byte_range,
+    diagnostic::{Diagnostic, Severity},
+    edit::{EditBuilder, EditError, TextEdit},
+    line_index::{LineIndex, PositionEncoding},
+    syntax_kind::SyntaxKind,
+    ResolvedNode,
+};
+
+/// A check of the tree
+pub trait Rule {
+    /// Identifier used in the configuration and suppression comments, such as `select-star`
+    fn id(&self) -> &'static str;
+
+    /// One-line description of what the rule reports
+    fn description(&self) -> &'static str;
+
+    /// Severity of the violations unless configured otherwise
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Kinds of the nodes passed to [`Rule::check`]
+    fn kinds(&self) -> &'static [SyntaxKind];
+
+    /// Checks a node of one of [`Rule::kinds`], reporting violations to `ctx`
+    fn check(&self, node: &ResolvedNode, ctx: &mut LintContext);
+}
+
+/// Receives the violations of a rule
+pub struct LintContext<'a> {
+    rule: &'a dyn Rule,
+    severity: Severity,
+    violations: Vec<Violation>,
+}
+
+impl LintContext<'_> {
+    /// Reports a violation of the current rule
+    pub fn report(&mut self, range: Range<usize>, message: impl Into<String>) {
+        self.report_with_fix(range, message, Vec::new());
+    }
+
+    /// Reports a violation with the edits fixing it
+    pub fn report_with_fix(
+        &mut self,
+        range: Range<usize>,
+        message: impl Into<String>,
+        fix: Vec<TextEdit>,
+    ) {
+        self.violations.push(Violation {
+            rule: self.rule.id(),
+            severity: self.severity,
+            message: message.into(),
+            range,
+            fix,
+        });
+    }
+}
+
+/// A problem reported by a rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// [`Rule::id`] of the rule
+    pub rule: &'static str,
+    pub severity: Severity,
+    pub message: String,
+    pub range: Range<usize>,
+    /// Edits fixing the violation, empty if it cannot be fixed automatically
+    pub fix: Vec<TextEdit>,
+}
+
+impl From<&Violation> for Diagnostic {
+    fn from(violation: &Violation) -> Self {
+        Diagnostic::new(
+            violation.severity,
+            format!("{} [{}]", violation.message, violation.rule),
+            violation.range.clone(),
+        )
+    }
+}
+
+/// Settings of the rules
+///
+/// The severity of a rule is overridden by `severities`, where `None` disables the rule.
+///
+/// With the `lint-config` feature, the settings can be loaded from TOML:
+///
+/// ```toml
+/// [rules]
+/// select-star = "off"
+/// missing-where = "warning"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintConfig {
+    pub severities: HashMap<String, Option<Severity>>,
+}
+
+impl LintConfig {
+    /// Returns the severity of `rule`, or `None` if it is disabled
+    pub fn severity(&self, rule: &dyn Rule) -> Option<Severity> {
+        self.severities
+            .get(rule.id())
+            .copied()
+            .unwrap_or(Some(rule.default_severity()))
+    }
+}
+
+/// A registry of rules
+#[derive(Default)]
+pub struct Linter {
+    rules: Vec<Box<dyn Rule>>,
+    config: LintConfig,
+}
+
+impl Linter {
+    /// Creates a linter without rules
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a linter with all rules of [`rules`]
+    pub fn with_builtin_rules() -> Self {
+        let mut linter = Self::new();
+        for rule in rules::builtin_rules() {
+            linter.rules.push(rule);
+        }
+        linter
+    }
+
+    /// Adds a rule
+    pub fn register(&mut self, rule: impl Rule + 'static) -> &mut Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Sets the settings of the rules
+    pub fn configure(&mut self, config: LintConfig) -> &mut Self {
+        self.config = config;
+        self
+    }
+
+    /// Returns the registered rules
+    pub fn rules(&self) -> impl Iterator<Item = &dyn Rule> {
+        self.rules.iter().map(|rule| rule.as_ref())
+    }
+
+    /// Runs the enabled rules over the tree and returns the violations not suppressed by comments, in the order of the source
+    pub fn lint(&self, root: &ResolvedNode) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for rule in self.rules() {
+            let Some(severity) = self.config.severity(rule) else {
+                continue;
+            };
+
+            let mut ctx = LintContext {
+                rule,
+                severity,
+                violations: Vec::new(),
+            };
+            for node in root
+                .descendants()
+                .filter(|node| rule.kinds().contains(&node.kind()))
+            {
+                rule.check(node, &mut ctx);
+            }
+            violations.extend(ctx.violations);
+        }
+
+        let suppressions = Suppressions::new(root);
+        violations.retain(|violation| !suppressions.is_suppressed(violation));
+        // The sort is stable, so violations at the same position are in the order of the rules
+        violations.sort_by_key(|violation| violation.range.start);
+        violations
+    }
+}
+
+/// Applies the fixes of the violations to the source of `root`
+///
+/// Returns [`EditError::Overlap`] if two fixes change the same part of the source.
+pub fn apply_fixes(root: &ResolvedNode, violations: &[Violation]) -> Result<String, EditError> {
+    let mut builder = EditBuilder::new(root);
+    for edit in violations.iter().flat_map(|violation| &violation.fix) {
+        builder.push(edit.clone());
+    }
+    builder.apply()
+}
+
+/// Rules suppressed by comments, where `None` means all rules
+struct Suppressions {
+    /// Lines with `-- noqa`
+    lines: HashMap<usize, Option<Vec<String>>>,
+    /// Ranges between `/* lint:disable */` and `/* lint:enable */`
+    ranges: Vec<(Range<usize>, Option<Vec<String>>)>,
+    index: LineIndex,
+}
+
+impl Suppressions {
+    fn new(root: &ResolvedNode) -> Self {
+        let src = root.text().to_string();
+        let index = LineIndex::new(&src);
+        let mut lines = HashMap::new();
+        let mut ranges = Vec::new();
+        let mut disabled: Option<(usize, Option<Vec<String>>)> = None;
+
+        for token in root
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+        {
+            let Range { start, end } = byte_range(token.text_range());
+            match token.kind() {
+                SyntaxKind::SQL_COMMENT => {
+                    if let Some(rules) = directive(&token.text()[2..], "noqa") {
+                        let line = index.line_col(start, PositionEncoding::Utf8).line;
+                        lines.insert(line, rules);
+                    }
+                }
+                SyntaxKind::C_COMMENT => {
+                    let body = token.text().trim_start_matches("/*").trim_end_matches("*/");
+                    if let Some(rules) = directive(body, "lint:disable") {
+                        disabled.get_or_insert((end, rules));
+                    } else if directive(body, "lint:enable").is_some() {
+                        if let Some((disabled_at, rules)) = disabled.take() {
+                            ranges.push((disabled_at..start, rules));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some((start, rules)) = disabled {
+            ranges.push((start..src.len(), rules));
+        }
+
+        Self {
+            lines,
+            ranges,
+            index,
+        }
+    }
+
+    fn is_suppressed(&self, violation: &Violation) -> bool {
+        let applies = |rules: &Option<Vec<String>>| {
+            rules
+                .as_ref()
+                .is_none_or(|rules| rules.iter().any(|rule| rule == violation.rule))
+        };
+
+        let line = self
+            .index
+            .line_col(violation.range.start, PositionEncoding::Utf8)
+            .line;
+        self.lines.get(&line).is_some_and(applies)
+            || self
+                .ranges
+                .iter()
+                .any(|(range, rules)| range.contains(&violation.range.start) && applies(rules))
+    }
+}
+
+/// Parses a comment such as `noqa` or `noqa: rule-a, rule-b`, returning the rules or `None` for all rules
+fn directive(comment: &str, name: &str) -> Option<Option<Vec<String>>> {
+    let rest = comment.trim().strip_prefix(name)?;
+    let rest = rest.trim();
+    if rest.is_empty() {
+        return Some(None);
+    }
+
+    let rules = rest.strip_prefix(':').unwrap_or(rest);
+    if name == "noqa" && !rest.starts_with(':') {
+        // `-- noqa` must be followed by `:` to name rules, such as `-- noqa: select-star`
+        return None;
+    }
+    Some(Some(
+        rules
+            .split(',')
+            .map(|rule| rule.trim().to_string())
+            .filter(|rule| !rule.is_empty())
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{diagnostic::Severity, parse, syntax_kind::SyntaxKind, ResolvedNode};
+
+    use super::{apply_fixes, LintConfig, LintContext, Linter, Rule};
+
+    struct NoLimit;
+
+    impl Rule for NoLimit {
+        fn id(&self) -> &'static str {
+            "no-limit"
+        }
+
+        fn description(&self) -> &'static str {
+            "SELECT without LIMIT"
+        }
+
+        fn kinds(&self) -> &'static [SyntaxKind] {
+            &[SyntaxKind::select_no_parens]
+        }
+
+        fn check(&self, node: &ResolvedNode, ctx: &mut LintContext) {
+            if !node
+                .children()
+                .any(|child| child.kind() == SyntaxKind::select_limit)
+            {
+                let end = usize::from(node.text_range().end());
+                ctx.report_with_fix(
+                    end..end,
+                    "missing LIMIT",
+                    vec![crate::edit::TextEdit {
+                        range: end..end,
+                        text: " limit 10".to_string(),
+                    }],
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn custom_rule() {
+        let root = parse("select a from t; select b from u limit 1;").unwrap();
+        let mut linter = Linter::new();
+        linter.register(NoLimit);
+
+        let violations = linter.lint(&root);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].range, 15..15);
+        assert_eq!(
+            apply_fixes(&root, &violations).unwrap(),
+            "select a from t limit 10; select b from u limit 1;"
+        );
+
+        let mut config = LintConfig::default();
+        config
+            .severities
+            .insert("no-limit".to_string(), Some(Severity::Error));
+        linter.configure(config.clone());
+        assert_eq!(linter.lint(&root)[0].severity, Severity::Error);
+
+        config.severities.insert("no-limit".to_string(), None);
+        linter.configure(config);
+        assert!(linter.lint(&root).is_empty());
+    }
+
+    #[test]
+    fn suppression() {
+        let src = "\
+select * from t; -- noqa
+select * from t; -- noqa: missing-where
+select * from t; -- noqa: missing-where, select-star
+/* lint:disable select-star */
+select * from t;
+delete from t;
+/* lint:enable */
+select * from t;
+/* lint:disable */
+select * from t;
+";
+        let root = parse(src).unwrap();
+        let lines: Vec<_> = Linter::with_builtin_rules()
+            .lint(&root)
+            .iter()
+            .map(|violation| (violation.rule, &src[..violation.range.start]))
+            .map(|(rule, before)| (rule, before.matches('\n').count() + 1))
+            .collect();
+        assert_eq!(
+            lines,
+            [("select-star", 2), ("missing-where", 6), ("select-star", 8)]
+        );
+    }
+}
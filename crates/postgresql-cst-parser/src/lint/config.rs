@@ -0,0 +1,92 @@
+use crate::diagnostic::Severity;
+
+use super::LintConfig;
+
+/// Error of [`LintConfig::from_toml`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl LintConfig {
+    /// Loads the settings from TOML
+    ///
+    /// The `rules` table maps the id of a rule to `"off"`, `"warning"` or `"error"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use postgresql_cst_parser::{diagnostic::Severity, lint::LintConfig};
+    ///
+    /// let config = LintConfig::from_toml(
+    ///     r#"
+    /// [rules]
+    /// select-star = "off"
+    /// implicit-cross-join = "error"
+    /// "#,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(config.severities["select-star"], None);
+    /// assert_eq!(config.severities["implicit-cross-join"], Some(Severity::Error));
+    /// ```
+    pub fn from_toml(src: &str) -> Result<Self, ConfigError> {
+        let table = src.parse::<toml::Table>().map_err(|e| ConfigError {
+            message: e.to_string(),
+        })?;
+
+        let mut config = LintConfig::default();
+        let Some(rules) = table.get("rules") else {
+            return Ok(config);
+        };
+        let rules = rules.as_table().ok_or_else(|| ConfigError {
+            message: "`rules` must be a table".to_string(),
+        })?;
+
+        for (rule, level) in rules {
+            let severity = match level.as_str() {
+                Some("off") => None,
+                Some("warning") => Some(Severity::Warning),
+                Some("error") => Some(Severity::Error),
+                _ => {
+                    return Err(ConfigError {
+                        message: format!(
+                            "the level of `{rule}` must be \"off\", \"warning\" or \"error\""
+                        ),
+                    })
+                }
+            };
+            config.severities.insert(rule.clone(), severity);
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lint::LintConfig;
+
+    #[test]
+    fn errors() {
+        assert_eq!(LintConfig::from_toml("").unwrap(), LintConfig::default());
+        assert_eq!(
+            LintConfig::from_toml("rules = 1").unwrap_err().message,
+            "`rules` must be a table"
+        );
+        assert_eq!(
+            LintConfig::from_toml("[rules]\nselect-star = \"info\"")
+                .unwrap_err()
+                .message,
+            "the level of `select-star` must be \"off\", \"warning\" or \"error\""
+        );
+        assert!(LintConfig::from_toml("[rules").is_err());
+    }
+}
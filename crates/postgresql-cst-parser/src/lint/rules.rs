@@ -0,0 +1,332 @@
+//! Built-in lint rules
+
+use crate::{
+    cst_util::{range, tokens},
+    diagnostic::Severity,
+    edit::{list_items, TextEdit},
+    syntax_kind::SyntaxKind,
+    ResolvedNode,
+};
+
+use super::{LintContext, Rule};
+
+/// Returns all built-in rules
+pub fn builtin_rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(SelectStar),
+        Box::new(MissingWhere),
+        Box::new(ImplicitCrossJoin),
+        Box::new(NotInSubquery),
+        Box::new(OrderByOrdinal),
+    ]
+}
+
+/// `select-star`: `*` or `t.*` in the target list, except in `EXISTS` subqueries
+pub struct SelectStar;
+
+impl Rule for SelectStar {
+    fn id(&self) -> &'static str {
+        "select-star"
+    }
+
+    fn description(&self) -> &'static str {
+        "`*` in the target list depends on the columns of the tables"
+    }
+
+    fn kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::target_el]
+    }
+
+    fn check(&self, node: &ResolvedNode, ctx: &mut LintContext) {
+        if tokens(node).last().map(|token| token.kind()) != Some(SyntaxKind::Star) {
+            return;
+        }
+
+        // `exists (select * ...)` does not depend on the columns
+        let in_exists = node
+            .ancestors()
+            .find(|ancestor| ancestor.kind() == SyntaxKind::select_with_parens)
+            .and_then(|subquery| subquery.parent())
+            .is_some_and(|parent| {
+                tokens(parent)
+                    .next()
+                    .is_some_and(|token| token.kind() == SyntaxKind::EXISTS)
+            });
+        if !in_exists {
+            ctx.report(
+                range(node),
+                "avoid `*` in the target list; list the columns",
+            );
+        }
+    }
+}
+
+/// `missing-where`: `UPDATE` or `DELETE` without a `WHERE` clause
+pub struct MissingWhere;
+
+impl Rule for MissingWhere {
+    fn id(&self) -> &'static str {
+        "missing-where"
+    }
+
+    fn description(&self) -> &'static str {
+        "`UPDATE` or `DELETE` without `WHERE` changes all rows"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::UpdateStmt, SyntaxKind::DeleteStmt]
+    }
+
+    fn check(&self, node: &ResolvedNode, ctx: &mut LintContext) {
+        let has_where = node.children().any(|child| {
+            child.kind() == SyntaxKind::where_or_current_clause && !child.text_range().is_empty()
+        });
+        if !has_where {
+            let stmt = if node.kind() == SyntaxKind::UpdateStmt {
+                "UPDATE"
+            } else {
+                "DELETE"
+            };
+            ctx.report(
+                range(node),
+                format!("{stmt} without WHERE changes all rows"),
+            );
+        }
+    }
+}
+
+/// `implicit-cross-join`: tables separated by commas in the `FROM` clause
+pub struct ImplicitCrossJoin;
+
+impl Rule for ImplicitCrossJoin {
+    fn id(&self) -> &'static str {
+        "implicit-cross-join"
+    }
+
+    fn description(&self) -> &'static str {
+        "tables separated by commas are joined without a visible join condition"
+    }
+
+    fn kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::from_list]
+    }
+
+    fn check(&self, node: &ResolvedNode, ctx: &mut LintContext) {
+        // Check the outermost list only
+        if node
+            .parent()
+            .is_some_and(|parent| parent.kind() == SyntaxKind::from_list)
+        {
+            return;
+        }
+
+        if list_items(node).len() > 1 {
+            ctx.report(
+                range(node),
+                "implicit cross join; use JOIN with ON or CROSS JOIN",
+            );
+        }
+    }
+}
+
+/// `not-in-subquery`: `NOT IN (SELECT ...)`, which is never true if the subquery returns `NULL`
+pub struct NotInSubquery;
+
+impl Rule for NotInSubquery {
+    fn id(&self) -> &'static str {
+        "not-in-subquery"
+    }
+
+    fn description(&self) -> &'static str {
+        "`NOT IN` with a subquery returns no rows if the subquery returns NULL"
+    }
+
+    fn kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::a_expr]
+    }
+
+    fn check(&self, node: &ResolvedNode, ctx: &mut LintContext) {
+        let has_not_in = node.children_with_tokens().any(|child| {
+            child
+                .as_token()
+                .is_some_and(|token| token.kind() == SyntaxKind::NOT_LA)
+        });
+        let subquery = node
+            .children()
+            .find(|child| child.kind() == SyntaxKind::in_expr)
+            .and_then(|in_expr| in_expr.children().next())
+            .is_some_and(|child| child.kind() == SyntaxKind::select_with_parens);
+
+        if has_not_in && subquery {
+            ctx.report(
+                range(node),
+                "NOT IN with a subquery is never true if the subquery returns NULL; use NOT EXISTS",
+            );
+        }
+    }
+}
+
+/// `order-by-ordinal`: `ORDER BY 1`, fixed by replacing the position with the column
+pub struct OrderByOrdinal;
+
+impl Rule for OrderByOrdinal {
+    fn id(&self) -> &'static str {
+        "order-by-ordinal"
+    }
+
+    fn description(&self) -> &'static str {
+        "`ORDER BY` with the position of a column breaks when the target list changes"
+    }
+
+    fn kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::sortby]
+    }
+
+    fn check(&self, node: &ResolvedNode, ctx: &mut LintContext) {
+        let Some(expr) = node.children().next() else {
+            return;
+        };
+        let mut expr_tokens = tokens(expr);
+        let (Some(ordinal), None) = (expr_tokens.next(), expr_tokens.next()) else {
+            return;
+        };
+        if ordinal.kind() != SyntaxKind::ICONST {
+            return;
+        }
+
+        let message = "ORDER BY with a column position; use the column name";
+        match ordinal_target(node, ordinal.text()) {
+            Some(column) => ctx.report_with_fix(
+                range(expr),
+                message,
+                vec![TextEdit {
+                    range: range(expr),
+                    text: column,
+                }],
+            ),
+            None => ctx.report(range(expr), message),
+        }
+    }
+}
+
+/// Returns the name or expression of the `ordinal`-th item of the target list the `sortby` refers to
+///
+/// Returns `None` for set operations, which can be sorted by the output columns only, and for `*`.
+fn ordinal_target(sortby: &ResolvedNode, ordinal: &str) -> Option<String> {
+    let select = sortby
+        .ancestors()
+        .find(|ancestor| ancestor.kind() == SyntaxKind::sort_clause)?
+        .parent()?;
+    let simple_select = select
+        .children()
+        .find(|child| child.kind() == SyntaxKind::select_clause)?
+        .children()
+        .find(|child| child.kind() == SyntaxKind::simple_select)?;
+    // `opt_target_list` or `target_list` after `DISTINCT`, which are missing in set operations
+    let target_list = simple_select
+        .children()
+        .find_map(|child| match child.kind() {
+            SyntaxKind::target_list => Some(child),
+            SyntaxKind::opt_target_list => child.children().next(),
+            _ => None,
+        })?;
+
+    let index = ordinal.parse::<usize>().ok()?.checked_sub(1)?;
+    let target = *list_items(target_list).get(index)?;
+    if let Some(label) = target.children().find(|child| {
+        matches!(
+            child.kind(),
+            SyntaxKind::ColLabel | SyntaxKind::BareColLabel
+        )
+    }) {
+        return Some(label.text().to_string());
+    }
+
+    let expr = target.children().next()?;
+    (expr.kind() == SyntaxKind::a_expr
+        && tokens(expr).last().map(|token| token.kind()) != Some(SyntaxKind::Star))
+    .then(|| expr.text().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        lint::{apply_fixes, Linter},
+        parse,
+    };
+
+    fn lint(src: &str) -> Vec<(&'static str, String)> {
+        let root = parse(src).unwrap();
+        Linter::with_builtin_rules()
+            .lint(&root)
+            .into_iter()
+            .map(|violation| (violation.rule, src[violation.range].to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn select_star() {
+        assert_eq!(
+            lint("select *, t.* from t where exists (select * from u);"),
+            [
+                ("select-star", "*".to_string()),
+                ("select-star", "t.*".to_string())
+            ]
+        );
+        assert!(lint("select count(*), a * b from t;").is_empty());
+    }
+
+    #[test]
+    fn missing_where() {
+        assert_eq!(
+            lint("delete from t; update t set a = 1 where b; delete from t where current of c;"),
+            [("missing-where", "delete from t".to_string())]
+        );
+    }
+
+    #[test]
+    fn implicit_cross_join() {
+        assert_eq!(
+            lint("select a from t, u, v; select a from t join u on t.id = u.id;"),
+            [("implicit-cross-join", "t, u, v".to_string())]
+        );
+    }
+
+    #[test]
+    fn not_in_subquery() {
+        assert_eq!(
+            lint("select a from t where a not in (select b from u) and a not in (1, 2);"),
+            [("not-in-subquery", "a not in (select b from u)".to_string())]
+        );
+    }
+
+    #[test]
+    fn order_by_ordinal() {
+        let src = "select a + 1, b as x, c from t order by 1, 2 desc, c, 4;";
+        assert_eq!(
+            lint(src),
+            [
+                ("order-by-ordinal", "1".to_string()),
+                ("order-by-ordinal", "2".to_string()),
+                ("order-by-ordinal", "4".to_string()),
+            ]
+        );
+
+        let root = parse(src).unwrap();
+        let violations = Linter::with_builtin_rules().lint(&root);
+        assert_eq!(
+            apply_fixes(&root, &violations).unwrap(),
+            "select a + 1, b as x, c from t order by a + 1, x desc, c, 4;"
+        );
+
+        // Set operations are sorted by the output columns
+        let root = parse("select a from t union select b from u order by 1;").unwrap();
+        let violations = Linter::with_builtin_rules().lint(&root);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].fix.is_empty());
+    }
+}
@@ -0,0 +1,413 @@
+//! Extraction of statements from PostgreSQL server logs
+//!
+//! [`extract`] finds the statements logged by `log_statement` and `log_min_duration_statement`:
+//!
+//! - `LOG:  statement: ...`
+//! - `LOG:  duration: 1.234 ms  statement: ...`
+//! - `LOG:  execute <name>: ...` and `LOG:  duration: 1.234 ms  execute <name>: ...`
+//!
+//! The values of `DETAIL:  parameters: $1 = '...'` following a statement are attached to it.
+//! Both the `stderr` format, where continuation lines start with a tab, and the `csvlog` format are supported.
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::logparse::{extract, LogFormat};
+//!
+//! let log = "\
+//! 2024-05-01 10:00:00 UTC [42] LOG:  duration: 0.5 ms  execute S_1: select *
+//! \tfrom t where id = $1
+//! 2024-05-01 10:00:00 UTC [42] DETAIL:  parameters: $1 = 'it''s'
+//! 2024-05-01 10:00:01 UTC [42] LOG:  statement: selec 1
+//! ";
+//! let statements = extract(log, LogFormat::Stderr);
+//!
+//! assert_eq!(statements[0].sql, "select *\nfrom t where id = $1");
+//! assert_eq!(statements[0].prepared.as_deref(), Some("S_1"));
+//! assert_eq!(statements[0].parameters, [Some("it's".to_string())]);
+//! assert_eq!(statements[0].interpolated(), "select *\nfrom t where id = 'it''s'");
+//! assert!(statements[0].parse().is_ok());
+//!
+//! assert_eq!(statements[1].line, 4);
+//! assert!(statements[1].parse().is_err());
+//! ```
+
+use crate::{syntax_kind::SyntaxKind, tokenize::tokenize, ParserError, ResolvedNode};
+
+/// Format of a log file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogFormat {
+    /// `log_destination = 'stderr'`, with any `log_line_prefix`
+    Stderr,
+    /// `log_destination = 'csvlog'`
+    Csv,
+}
+
+/// A statement found in a log
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogStatement {
+    /// Line of the log entry, starting from 1
+    pub line: usize,
+    /// Name of the prepared statement for `execute <name>:`, such as `S_1` or `<unnamed>`
+    pub prepared: Option<String>,
+    /// Duration in milliseconds, if logged
+    pub duration: Option<f64>,
+    pub sql: String,
+    /// Values of `$1`, `$2`, ..., where `None` is `NULL`
+    pub parameters: Vec<Option<String>>,
+}
+
+impl LogStatement {
+    /// Parses the statement
+    pub fn parse(&self) -> Result<ResolvedNode, ParserError> {
+        crate::parse(&self.sql)
+    }
+
+    /// Returns the statement with the parameters replaced by their values as string literals
+    ///
+    /// Parameters without a value are kept, and so is the whole statement if it cannot be tokenized.
+    pub fn interpolated(&self) -> String {
+        let Ok(tokens) = tokenize(&self.sql).collect::<Result<Vec<_>, _>>() else {
+            return self.sql.clone();
+        };
+
+        let mut sql = String::new();
+        for token in tokens {
+            let value = (token.kind == SyntaxKind::PARAM)
+                .then(|| token.text[1..].parse::<usize>().ok())
+                .flatten()
+                .and_then(|n| self.parameters.get(n.checked_sub(1)?));
+            match value {
+                Some(Some(value)) => {
+                    sql.push('\'');
+                    sql.push_str(&value.replace('\'', "''"));
+                    sql.push('\'');
+                }
+                Some(None) => sql.push_str("NULL"),
+                None => sql.push_str(token.text),
+            }
+        }
+        sql
+    }
+}
+
+/// Returns the statements in the log, in the order of the log
+pub fn extract(log: &str, format: LogFormat) -> Vec<LogStatement> {
+    let entries = match format {
+        LogFormat::Stderr => stderr_entries(log),
+        LogFormat::Csv => csv_entries(log),
+    };
+
+    let mut statements: Vec<LogStatement> = Vec::new();
+    // Whether the previous entry is the statement the parameters belong to
+    let mut after_statement = false;
+    for entry in entries {
+        match entry.severity.as_str() {
+            "LOG" => {
+                after_statement = false;
+                if let Some(statement) = statement(entry.line, &entry.message) {
+                    statements.push(statement);
+                    after_statement = true;
+                }
+            }
+            "DETAIL" => {}
+            _ => after_statement = false,
+        }
+
+        // `DETAIL` is a separate entry in the stderr format and a field of the entry in the csvlog format
+        let detail = match entry.severity.as_str() {
+            "DETAIL" => Some(&entry.message),
+            _ => entry.detail.as_ref(),
+        };
+        if let (true, Some(detail), Some(statement)) =
+            (after_statement, detail, statements.last_mut())
+        {
+            if let Some(parameters) = detail.strip_prefix("parameters: ") {
+                statement.parameters = parse_parameters(parameters);
+            }
+        }
+    }
+    statements
+}
+
+/// A message of a log
+struct Entry {
+    line: usize,
+    /// Such as `LOG`, `ERROR` and `DETAIL`
+    severity: String,
+    message: String,
+    detail: Option<String>,
+}
+
+const SEVERITIES: &[&str] = &[
+    "DEBUG1",
+    "DEBUG2",
+    "DEBUG3",
+    "DEBUG4",
+    "DEBUG5",
+    "INFO",
+    "NOTICE",
+    "WARNING",
+    "ERROR",
+    "LOG",
+    "FATAL",
+    "PANIC",
+    "DETAIL",
+    "HINT",
+    "QUERY",
+    "CONTEXT",
+    "LOCATION",
+    "STATEMENT",
+];
+
+fn stderr_entries(log: &str) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut in_entry = false;
+    for (i, line) in log.lines().enumerate() {
+        if let Some(continuation) = line.strip_prefix('\t') {
+            if in_entry {
+                let entry = entries.last_mut().unwrap();
+                entry.message.push('\n');
+                entry.message.push_str(continuation);
+            }
+            continue;
+        }
+
+        // The earliest `SEVERITY:  ` after `log_line_prefix`
+        let found = SEVERITIES
+            .iter()
+            .filter_map(|severity| {
+                let marker = format!("{severity}:  ");
+                line.find(&marker)
+                    .map(|pos| (pos, *severity, pos + marker.len()))
+            })
+            .min();
+        in_entry = found.is_some();
+        if let Some((_, severity, message_start)) = found {
+            entries.push(Entry {
+                line: i + 1,
+                severity: severity.to_string(),
+                message: line[message_start..].to_string(),
+                detail: None,
+            });
+        }
+    }
+    entries
+}
+
+/// Columns of `error_severity`, `message` and `detail` in the csvlog format
+const CSV_SEVERITY: usize = 11;
+const CSV_MESSAGE: usize = 13;
+const CSV_DETAIL: usize = 14;
+
+fn csv_entries(log: &str) -> Vec<Entry> {
+    csv_records(log)
+        .into_iter()
+        .filter_map(|(line, mut fields)| {
+            if fields.len() <= CSV_MESSAGE {
+                return None;
+            }
+            let detail = fields
+                .get_mut(CSV_DETAIL)
+                .map(std::mem::take)
+                .filter(|detail| !detail.is_empty());
+            Some(Entry {
+                line,
+                severity: std::mem::take(&mut fields[CSV_SEVERITY]),
+                message: std::mem::take(&mut fields[CSV_MESSAGE]),
+                detail,
+            })
+        })
+        .collect()
+}
+
+/// Splits CSV into records with the line they start on, allowing newlines in quoted fields
+fn csv_records(src: &str) -> Vec<(usize, Vec<String>)> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut record_line = 1;
+
+    let mut chars = src.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    quoted = false;
+                }
+            }
+            '"' if field.is_empty() => quoted = true,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            '\n' if !quoted => {
+                fields.push(std::mem::take(&mut field));
+                records.push((record_line, std::mem::take(&mut fields)));
+                line += 1;
+                record_line = line;
+            }
+            '\r' if !quoted && chars.peek() == Some(&'\n') => {}
+            _ => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+        }
+    }
+    if !field.is_empty() || !fields.is_empty() {
+        fields.push(field);
+        records.push((record_line, fields));
+    }
+    records
+}
+
+/// Parses a message such as `duration: 1.234 ms  execute S_1: select ...`
+fn statement(line: usize, message: &str) -> Option<LogStatement> {
+    let (duration, rest) = match message.strip_prefix("duration: ") {
+        Some(rest) => {
+            let (duration, rest) = rest.split_once(" ms")?;
+            (duration.parse().ok(), rest.trim_start())
+        }
+        None => (None, message),
+    };
+
+    let (prepared, sql) = if let Some(sql) = rest.strip_prefix("statement: ") {
+        (None, sql)
+    } else {
+        let (name, sql) = rest.strip_prefix("execute ")?.split_once(": ")?;
+        (Some(name.to_string()), sql)
+    };
+
+    Some(LogStatement {
+        line,
+        prepared,
+        duration,
+        sql: sql.to_string(),
+        parameters: Vec::new(),
+    })
+}
+
+/// Parses `$1 = 'a', $2 = NULL`, where the values may be truncated by `log_parameter_max_length`
+fn parse_parameters(src: &str) -> Vec<Option<String>> {
+    let mut parameters = Vec::new();
+    let mut rest = src;
+    while let Some(param) = rest.trim_start().strip_prefix('$') {
+        let digits = param
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(param.len());
+        let Ok(n) = param[..digits].parse::<usize>() else {
+            break;
+        };
+        let Some(value) = param[digits..].strip_prefix(" = ") else {
+            break;
+        };
+
+        let (value, remaining) = if let Some(value) = value.strip_prefix("NULL") {
+            (None, value)
+        } else if let Some(quoted) = value.strip_prefix('\'') {
+            // The value ends at a quote not followed by another quote
+            let mut end = None;
+            let mut chars = quoted.char_indices().peekable();
+            while let Some((i, c)) = chars.next() {
+                if c == '\'' {
+                    if chars.peek().map(|(_, c)| *c) == Some('\'') {
+                        chars.next();
+                    } else {
+                        end = Some(i);
+                        break;
+                    }
+                }
+            }
+            let end = end.unwrap_or(quoted.len());
+            (
+                Some(quoted[..end].replace("''", "'")),
+                quoted.get(end + 1..).unwrap_or(""),
+            )
+        } else {
+            break;
+        };
+
+        if n == 0 {
+            break;
+        }
+        if parameters.len() < n {
+            parameters.resize(n, None);
+        }
+        parameters[n - 1] = value;
+        rest = remaining.strip_prefix(',').unwrap_or(remaining);
+    }
+    parameters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract, parse_parameters, LogFormat};
+
+    #[test]
+    fn stderr() {
+        let log = "\
+2024-05-01 10:00:00.000 UTC [1] user@db LOG:  statement: select 1;
+2024-05-01 10:00:00.000 UTC [1] user@db LOG:  connection authorized: user=u
+2024-05-01 10:00:01.000 UTC [1] user@db LOG:  duration: 12.5 ms  statement: update t
+\tset a = 1
+\twhere b = 'LOG:  x'
+2024-05-01 10:00:02.000 UTC [1] user@db ERROR:  relation \"u\" does not exist
+2024-05-01 10:00:02.000 UTC [1] user@db DETAIL:  parameters: $1 = '1'
+2024-05-01 10:00:03.000 UTC [1] user@db LOG:  execute <unnamed>: select $1, $2
+2024-05-01 10:00:03.000 UTC [1] user@db DETAIL:  parameters: $1 = NULL, $2 = 'a, b'
+";
+        let statements = extract(log, LogFormat::Stderr);
+        let summary: Vec<_> = statements
+            .iter()
+            .map(|s| (s.line, s.prepared.as_deref(), s.duration, s.sql.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (1, None, None, "select 1;"),
+                (
+                    3,
+                    None,
+                    Some(12.5),
+                    "update t\nset a = 1\nwhere b = 'LOG:  x'"
+                ),
+                (8, Some("<unnamed>"), None, "select $1, $2"),
+            ]
+        );
+        assert!(statements[1].parameters.is_empty());
+        assert_eq!(statements[2].parameters, [None, Some("a, b".to_string())]);
+        assert_eq!(statements[2].interpolated(), "select NULL, 'a, b'");
+    }
+
+    #[test]
+    fn csv() {
+        let log = "\
+2024-05-01 10:00:00.000 UTC,\"u\",\"db\",1,\"[local]\",1.1,1,\"SELECT\",2024-05-01 10:00:00 UTC,3/1,0,LOG,00000,\"execute S_1: select *
+from t where a = $1\",\"parameters: $1 = 'say \"\"hi\"\"'\",,,,,,,,\"psql\",\"client backend\",,0
+2024-05-01 10:00:01.000 UTC,\"u\",\"db\",1,\"[local]\",1.1,2,\"idle\",2024-05-01 10:00:00 UTC,3/2,0,ERROR,42601,\"syntax error\",,,,,,\"selec\",,,\"psql\",\"client backend\",,0
+";
+        let statements = extract(log, LogFormat::Csv);
+        assert_eq!(statements.len(), 1);
+        assert_eq!(statements[0].line, 1);
+        assert_eq!(statements[0].prepared.as_deref(), Some("S_1"));
+        assert_eq!(statements[0].sql, "select *\nfrom t where a = $1");
+        assert_eq!(statements[0].parameters, [Some("say \"hi\"".to_string())]);
+    }
+
+    #[test]
+    fn parameters() {
+        assert_eq!(
+            parse_parameters("$1 = 'it''s', $3 = NULL, $2 = ''"),
+            [Some("it's".to_string()), Some("".to_string()), None]
+        );
+        // Truncated by `log_parameter_max_length`
+        assert_eq!(
+            parse_parameters("$1 = 'abc...'"),
+            [Some("abc...".to_string())]
+        );
+    }
+}
@@ -0,0 +1,703 @@
+//! Construction of syntax trees
+//!
+//! The functions of this module build nodes from their parts, quoting identifiers and literals as needed.
+//! Each node is the root of its own tree, with the shape the parser gives to canonical text:
+//! keywords are lowercase and tokens are separated by a single space where needed.
+//! So parsing the text of a node in the same context gives the same tree.
+//!
+//! Expressions are [`Expr`]s, whose nodes are `a_expr`.
+//! Operands are parenthesized where the precedence of the operators requires it.
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::{make, syntax_kind::SyntaxKind};
+//!
+//! let stmt = make::select([
+//!     make::column_ref(["t", "id"]).into(),
+//!     make::alias(make::literal_str("it's"), "Value"),
+//! ])
+//!     .from(make::table_ref(["public", "t"]))
+//!     .where_(make::or(make::column_ref(["a"]), make::column_ref(["b"])))
+//!     .where_(make::eq(make::column_ref(["c"]), make::literal_int(1)))
+//!     .build();
+//!
+//! assert_eq!(stmt.kind(), SyntaxKind::SelectStmt);
+//! assert_eq!(
+//!     stmt.text(),
+//!     r#"select t.id, 'it''s' as "Value" from public.t where (a or b) and c = 1"#
+//! );
+//! ```
+
+use cstree::{build::GreenNodeBuilder, text::TextRange, RawSyntaxKind, Syntax};
+
+use crate::{
+    cst::PostgreSQLSyntax,
+    cst_util::is_trivia,
+    keywords::{lookup, quote_ident},
+    lexer::TokenKind,
+    parse,
+    parser::token_kind_to_component_id,
+    syntax_kind::SyntaxKind,
+    NodeOrToken, ParserError, ResolvedNode, SyntaxNode,
+};
+
+/// An expression, whose node is an `a_expr`
+#[derive(Debug, Clone)]
+pub struct Expr(ResolvedNode);
+
+/// An item of the target list, whose node is a `target_el`
+#[derive(Debug, Clone)]
+pub struct Target(ResolvedNode);
+
+/// An item of the `FROM` clause, whose node is a `table_ref`
+#[derive(Debug, Clone)]
+pub struct TableRef(ResolvedNode);
+
+impl Expr {
+    /// Returns the `a_expr` node
+    pub fn syntax(&self) -> &ResolvedNode {
+        &self.0
+    }
+}
+
+impl Target {
+    /// Returns the `target_el` node
+    pub fn syntax(&self) -> &ResolvedNode {
+        &self.0
+    }
+}
+
+impl TableRef {
+    /// Returns the `table_ref` node
+    pub fn syntax(&self) -> &ResolvedNode {
+        &self.0
+    }
+}
+
+/// An expression as an item of the target list, without an alias
+impl From<Expr> for Target {
+    fn from(expr: Expr) -> Self {
+        Target(build(SyntaxKind::target_el, |builder| {
+            copy_node(builder, &expr.0)
+        }))
+    }
+}
+
+/// Returns a column reference such as `t.id`
+///
+/// # Panics
+///
+/// Panics if `names` is empty.
+pub fn column_ref<I, S>(names: I) -> Expr
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let names: Vec<S> = names.into_iter().collect();
+    let (first, rest) = names
+        .split_first()
+        .expect("a column reference needs a name");
+    Expr(build(SyntaxKind::a_expr, |builder| {
+        node(builder, SyntaxKind::c_expr, |builder| {
+            node(builder, SyntaxKind::columnref, |builder| {
+                node(builder, SyntaxKind::ColId, |builder| {
+                    ident(builder, first.as_ref())
+                });
+                indirection(builder, rest);
+            })
+        })
+    }))
+}
+
+/// Returns a string literal, using the escape string syntax if `value` contains backslashes
+///
+/// This is the same quoting as PostgreSQL's `quote_literal()`.
+pub fn literal_str(value: &str) -> Expr {
+    let quoted = value.replace('\'', "''");
+    let literal = if value.contains('\\') {
+        format!("E'{}'", quoted.replace('\\', "\\\\"))
+    } else {
+        format!("'{quoted}'")
+    };
+    constant(|builder| {
+        node(builder, SyntaxKind::Sconst, |builder| {
+            builder.token(SyntaxKind::SCONST, &literal)
+        })
+    })
+}
+
+/// Returns an integer literal, negated with the unary `-` if negative
+pub fn literal_int(value: i64) -> Expr {
+    // The lexer makes a numeric constant of an integer that does not fit in 32 bits
+    let magnitude = value.unsigned_abs();
+    let digits = magnitude.to_string();
+    let literal = |builder: &mut Builder| {
+        constant_node(builder, |builder| {
+            if magnitude <= i32::MAX as u64 {
+                node(builder, SyntaxKind::Iconst, |builder| {
+                    builder.token(SyntaxKind::ICONST, &digits)
+                })
+            } else {
+                builder.token(SyntaxKind::FCONST, &digits)
+            }
+        })
+    };
+
+    Expr(build(SyntaxKind::a_expr, |builder| {
+        if value < 0 {
+            builder.token(SyntaxKind::Minus, "-");
+            node(builder, SyntaxKind::a_expr, literal);
+        } else {
+            literal(builder);
+        }
+    }))
+}
+
+/// Returns `true` or `false`
+pub fn literal_bool(value: bool) -> Expr {
+    constant(|builder| {
+        if value {
+            builder.token(SyntaxKind::TRUE_P, "true")
+        } else {
+            builder.token(SyntaxKind::FALSE_P, "false")
+        }
+    })
+}
+
+/// Returns `null`
+pub fn null() -> Expr {
+    constant(|builder| builder.token(SyntaxKind::NULL_P, "null"))
+}
+
+/// Parses `sql` as a single expression
+pub fn expr(sql: &str) -> Result<Expr, ParserError> {
+    const PREFIX: &str = "select ";
+
+    let sql = sql.trim();
+    let root =
+        parse(&format!("{PREFIX}{sql}")).map_err(|error| shift_error(error, PREFIX.len()))?;
+    find_node(&root, SyntaxKind::a_expr, PREFIX.len(), sql.len())
+        .map(Expr)
+        .ok_or_else(|| ParserError::ParseError {
+            message: "not a single expression".to_string(),
+            start_byte_pos: 0,
+            end_byte_pos: sql.len(),
+        })
+}
+
+/// Returns `lhs and rhs`
+pub fn and(lhs: Expr, rhs: Expr) -> Expr {
+    binary(&lhs, (SyntaxKind::AND, "and"), Precedence::And, &rhs)
+}
+
+/// Returns `lhs or rhs`
+pub fn or(lhs: Expr, rhs: Expr) -> Expr {
+    binary(&lhs, (SyntaxKind::OR, "or"), Precedence::Or, &rhs)
+}
+
+/// Returns `lhs = rhs`
+pub fn eq(lhs: Expr, rhs: Expr) -> Expr {
+    binary(
+        &lhs,
+        (SyntaxKind::Equals, "="),
+        Precedence::Comparison,
+        &rhs,
+    )
+}
+
+/// Returns `not expr`
+pub fn not(expr: Expr) -> Expr {
+    Expr(build(SyntaxKind::a_expr, |builder| {
+        builder.token(SyntaxKind::NOT, "not");
+        space(builder);
+        operand(builder, &expr, precedence(&expr.0) < Precedence::Not);
+    }))
+}
+
+/// Returns an item of the target list, `expr as name`
+pub fn alias(expr: Expr, name: &str) -> Target {
+    Target(build(SyntaxKind::target_el, |builder| {
+        copy_node(builder, &expr.0);
+        space(builder);
+        builder.token(SyntaxKind::AS, "as");
+        space(builder);
+        node(builder, SyntaxKind::ColLabel, |builder| {
+            ident(builder, name)
+        });
+    }))
+}
+
+/// Returns `*` as an item of the target list
+pub fn star() -> Target {
+    Target(build(SyntaxKind::target_el, |builder| {
+        builder.token(SyntaxKind::Star, "*")
+    }))
+}
+
+/// Returns a reference to a table such as `public.t`, as an item of the `FROM` clause
+///
+/// # Panics
+///
+/// Panics if `names` is empty.
+pub fn table_ref<I, S>(names: I) -> TableRef
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let names: Vec<S> = names.into_iter().collect();
+    let (first, rest) = names.split_first().expect("a table reference needs a name");
+    TableRef(build(SyntaxKind::table_ref, |builder| {
+        node(builder, SyntaxKind::relation_expr, |builder| {
+            node(builder, SyntaxKind::qualified_name, |builder| {
+                node(builder, SyntaxKind::ColId, |builder| {
+                    ident(builder, first.as_ref())
+                });
+                indirection(builder, rest);
+            })
+        });
+        empty(builder, SyntaxKind::opt_alias_clause);
+    }))
+}
+
+/// Starts a `SELECT` statement of the targets
+///
+/// A target is an item of the target list made by [`alias`] or [`star`], or an expression converted by `into()`.
+pub fn select(targets: impl IntoIterator<Item = Target>) -> Select {
+    Select {
+        targets: targets.into_iter().collect(),
+        from: Vec::new(),
+        condition: None,
+    }
+}
+
+/// A `SELECT` statement under construction, made by [`select`]
+#[derive(Debug, Clone)]
+pub struct Select {
+    targets: Vec<Target>,
+    from: Vec<TableRef>,
+    condition: Option<Expr>,
+}
+
+impl Select {
+    /// Adds an item of the `FROM` clause, such as one made by [`table_ref`]
+    pub fn from(mut self, rel: TableRef) -> Self {
+        self.from.push(rel);
+        self
+    }
+
+    /// Adds a condition of the `WHERE` clause, combined with the previous ones by `and`
+    pub fn where_(mut self, expr: Expr) -> Self {
+        self.condition = Some(match self.condition.take() {
+            Some(condition) => and(condition, expr),
+            None => expr,
+        });
+        self
+    }
+
+    /// Returns the `SelectStmt` node
+    pub fn build(&self) -> ResolvedNode {
+        let targets: Vec<_> = self.targets.iter().map(Target::syntax).collect();
+        let from: Vec<_> = self.from.iter().map(TableRef::syntax).collect();
+
+        build(SyntaxKind::SelectStmt, |builder| {
+            node(builder, SyntaxKind::select_no_parens, |builder| {
+                node(builder, SyntaxKind::simple_select, |builder| {
+                    builder.token(SyntaxKind::SELECT, "select");
+                    empty(builder, SyntaxKind::opt_all_clause);
+                    if targets.is_empty() {
+                        empty(builder, SyntaxKind::opt_target_list);
+                    } else {
+                        space(builder);
+                        node(builder, SyntaxKind::opt_target_list, |builder| {
+                            list(builder, SyntaxKind::target_list, &targets)
+                        });
+                    }
+                    empty(builder, SyntaxKind::into_clause);
+
+                    if from.is_empty() {
+                        empty(builder, SyntaxKind::from_clause);
+                    } else {
+                        space(builder);
+                        node(builder, SyntaxKind::from_clause, |builder| {
+                            builder.token(SyntaxKind::FROM, "from");
+                            space(builder);
+                            list(builder, SyntaxKind::from_list, &from);
+                        });
+                    }
+
+                    match &self.condition {
+                        Some(condition) => {
+                            space(builder);
+                            node(builder, SyntaxKind::where_clause, |builder| {
+                                builder.token(SyntaxKind::WHERE, "where");
+                                space(builder);
+                                copy_node(builder, &condition.0);
+                            });
+                        }
+                        None => empty(builder, SyntaxKind::where_clause),
+                    }
+
+                    for kind in [
+                        SyntaxKind::group_clause,
+                        SyntaxKind::having_clause,
+                        SyntaxKind::window_clause,
+                    ] {
+                        empty(builder, kind);
+                    }
+                })
+            })
+        })
+    }
+}
+
+/// Precedence of the operators of `a_expr`, from the lowest
+///
+/// Operators not listed here bind tighter than the comparison operators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    Or,
+    And,
+    Not,
+    Is,
+    Comparison,
+    Other,
+}
+
+fn precedence(expr: &ResolvedNode) -> Precedence {
+    if expr.kind() != SyntaxKind::a_expr {
+        return Precedence::Other;
+    }
+    if expr
+        .children()
+        .any(|child| child.kind() == SyntaxKind::subquery_Op)
+    {
+        return Precedence::Comparison;
+    }
+
+    // The operator is the first token of a prefix or binary expression
+    let operator = expr
+        .children_with_tokens()
+        .filter_map(|child| child.into_token())
+        .find(|token| !is_trivia(token.kind()));
+    match operator.map(|token| token.kind()) {
+        Some(SyntaxKind::OR) => Precedence::Or,
+        Some(SyntaxKind::AND) => Precedence::And,
+        Some(SyntaxKind::NOT) => Precedence::Not,
+        Some(SyntaxKind::IS | SyntaxKind::ISNULL | SyntaxKind::NOTNULL) => Precedence::Is,
+        Some(
+            SyntaxKind::Equals
+            | SyntaxKind::Less
+            | SyntaxKind::Greater
+            | SyntaxKind::LESS_EQUALS
+            | SyntaxKind::GREATER_EQUALS
+            | SyntaxKind::NOT_EQUALS,
+        ) => Precedence::Comparison,
+        _ => Precedence::Other,
+    }
+}
+
+/// Makes a left-associative binary expression, or a non-associative one for the comparison operators
+fn binary(lhs: &Expr, (op, text): (SyntaxKind, &str), prec: Precedence, rhs: &Expr) -> Expr {
+    let lhs_prec = precedence(&lhs.0);
+    let rhs_prec = precedence(&rhs.0);
+    Expr(build(SyntaxKind::a_expr, |builder| {
+        operand(
+            builder,
+            lhs,
+            lhs_prec < prec || (prec == Precedence::Comparison && lhs_prec == prec),
+        );
+        space(builder);
+        builder.token(op, text);
+        space(builder);
+        operand(builder, rhs, rhs_prec <= prec);
+    }))
+}
+
+type Builder = GreenNodeBuilder<'static, 'static, PostgreSQLSyntax>;
+
+/// Builds a tree whose root is a node of `kind`
+fn build(kind: SyntaxKind, children: impl FnOnce(&mut Builder)) -> ResolvedNode {
+    let mut builder = Builder::new();
+    node(&mut builder, kind, children);
+    let (green, cache) = builder.finish();
+    SyntaxNode::new_root_with_resolver(green, cache.unwrap().into_interner().unwrap())
+}
+
+fn node(builder: &mut Builder, kind: SyntaxKind, children: impl FnOnce(&mut Builder)) {
+    builder.start_node(kind);
+    children(builder);
+    builder.finish_node();
+}
+
+/// Adds an empty node, which the parser keeps only without the `remove-empty-node` feature
+fn empty(builder: &mut Builder, kind: SyntaxKind) {
+    if !cfg!(feature = "remove-empty-node") {
+        node(builder, kind, |_| {});
+    }
+}
+
+fn space(builder: &mut Builder) {
+    builder.token(SyntaxKind::Whitespace, " ");
+}
+
+/// Builds an `a_expr` of a constant such as a literal or `null`
+fn constant(children: impl FnOnce(&mut Builder)) -> Expr {
+    Expr(build(SyntaxKind::a_expr, |builder| {
+        constant_node(builder, children)
+    }))
+}
+
+fn constant_node(builder: &mut Builder, children: impl FnOnce(&mut Builder)) {
+    node(builder, SyntaxKind::c_expr, |builder| {
+        node(builder, SyntaxKind::AexprConst, children)
+    });
+}
+
+/// Adds `expr` as an operand, parenthesized if `parenthesize` is true
+fn operand(builder: &mut Builder, expr: &Expr, parenthesize: bool) {
+    if !parenthesize {
+        copy_node(builder, &expr.0);
+        return;
+    }
+
+    node(builder, SyntaxKind::a_expr, |builder| {
+        node(builder, SyntaxKind::c_expr, |builder| {
+            builder.token(SyntaxKind::LParen, "(");
+            copy_node(builder, &expr.0);
+            builder.token(SyntaxKind::RParen, ")");
+            empty(builder, SyntaxKind::opt_indirection);
+        })
+    });
+}
+
+/// Adds a quoted identifier, or an unreserved keyword, which `quote_ident` leaves unquoted
+fn ident(builder: &mut Builder, name: &str) {
+    let quoted = quote_ident(name);
+    match lookup(&quoted) {
+        Some(keyword) => node(builder, SyntaxKind::unreserved_keyword, |builder| {
+            let kind = token_kind_to_component_id(&TokenKind::KEYWORD(keyword.token.to_string()));
+            builder.token(SyntaxKind::from_raw(RawSyntaxKind(kind)), &quoted)
+        }),
+        None => builder.token(SyntaxKind::IDENT, &quoted),
+    }
+}
+
+/// Adds the `.name` parts of a qualified name, as a left-recursive `indirection`
+fn indirection<S: AsRef<str>>(builder: &mut Builder, names: &[S]) {
+    let Some((last, init)) = names.split_last() else {
+        return;
+    };
+    node(builder, SyntaxKind::indirection, |builder| {
+        indirection(builder, init);
+        node(builder, SyntaxKind::indirection_el, |builder| {
+            builder.token(SyntaxKind::Dot, ".");
+            node(builder, SyntaxKind::attr_name, |builder| {
+                node(builder, SyntaxKind::ColLabel, |builder| {
+                    ident(builder, last.as_ref())
+                })
+            });
+        });
+    });
+}
+
+/// Adds a left-recursive comma-separated list such as `target_list: target_el | target_list ',' target_el`
+fn list(builder: &mut Builder, kind: SyntaxKind, items: &[&ResolvedNode]) {
+    let Some((last, init)) = items.split_last() else {
+        return;
+    };
+    node(builder, kind, |builder| {
+        if !init.is_empty() {
+            list(builder, kind, init);
+            builder.token(SyntaxKind::Comma, ",");
+            space(builder);
+        }
+        copy_node(builder, last);
+    });
+}
+
+/// Returns a copy of the outermost node of `kind` at `offset..offset + len` as the root of a new tree
+fn find_node(
+    root: &ResolvedNode,
+    kind: SyntaxKind,
+    offset: usize,
+    len: usize,
+) -> Option<ResolvedNode> {
+    let range = TextRange::at((offset as u32).into(), (len as u32).into());
+    let node = root
+        .descendants()
+        .find(|node| node.kind() == kind && node.text_range() == range)?;
+    Some(build(kind, |builder| {
+        for child in node.children_with_tokens() {
+            copy_element(builder, child);
+        }
+    }))
+}
+
+fn copy_node(builder: &mut Builder, node: &ResolvedNode) {
+    builder.start_node(node.kind());
+    for child in node.children_with_tokens() {
+        copy_element(builder, child);
+    }
+    builder.finish_node();
+}
+
+fn copy_element(builder: &mut Builder, element: NodeOrToken) {
+    match element {
+        NodeOrToken::Node(node) => copy_node(builder, node),
+        NodeOrToken::Token(token) => builder.token(token.kind(), token.text()),
+    }
+}
+
+/// Makes the positions of `error` relative to the text after the prefix of `len` bytes
+fn shift_error(error: ParserError, len: usize) -> ParserError {
+    match error {
+        ParserError::ParseError {
+            message,
+            start_byte_pos,
+            end_byte_pos,
+        } => ParserError::ParseError {
+            message,
+            start_byte_pos: start_byte_pos.saturating_sub(len),
+            end_byte_pos: end_byte_pos.saturating_sub(len),
+        },
+        ParserError::ScanError {
+            message,
+            start_byte_pos,
+            end_byte_pos,
+        } => ParserError::ScanError {
+            message,
+            start_byte_pos: start_byte_pos.saturating_sub(len),
+            end_byte_pos: end_byte_pos.saturating_sub(len),
+        },
+        ParserError::ScanReport(mut report) => {
+            report.position_in_bytes = report.position_in_bytes.saturating_sub(len);
+            ParserError::ScanReport(report)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, syntax_kind::SyntaxKind, ParserError, ResolvedNode};
+
+    use super::*;
+
+    /// Returns the kinds and texts of the elements of `node` in preorder
+    fn shape(node: &ResolvedNode) -> Vec<(SyntaxKind, String)> {
+        node.descendants_with_tokens()
+            .map(|element| (element.kind(), element.to_string()))
+            .collect()
+    }
+
+    /// Checks that parsing the text of `node` after `prefix` gives the same tree
+    fn assert_reparse(prefix: &str, node: &ResolvedNode) {
+        let text = node.text().to_string();
+        let root = parse(&format!("{prefix}{text}")).unwrap();
+        let reparsed = find_node(&root, node.kind(), prefix.len(), text.len()).unwrap();
+        assert_eq!(shape(&reparsed), shape(node), "{text}");
+    }
+
+    #[test]
+    fn expressions() {
+        for (node, expected) in [
+            (column_ref(["t", "id"]), "t.id"),
+            (column_ref(["Select", "a b"]), r#""Select"."a b""#),
+            (literal_str("it's"), "'it''s'"),
+            (literal_str(r"C:\dir"), r"E'C:\\dir'"),
+            (literal_int(-42), "-42"),
+            (literal_bool(true), "true"),
+            (null(), "null"),
+            (
+                and(or(column_ref(["a"]), column_ref(["b"])), column_ref(["c"])),
+                "(a or b) and c",
+            ),
+            (
+                or(column_ref(["a"]), and(column_ref(["b"]), column_ref(["c"]))),
+                "a or b and c",
+            ),
+            (
+                and(column_ref(["a"]), and(column_ref(["b"]), column_ref(["c"]))),
+                "a and (b and c)",
+            ),
+            (eq(expr("a + 1").unwrap(), literal_int(-2)), "a + 1 = -2"),
+            (
+                eq(
+                    eq(column_ref(["a"]), column_ref(["b"])),
+                    expr("c is null").unwrap(),
+                ),
+                "(a = b) = (c is null)",
+            ),
+            (not(eq(column_ref(["a"]), null())), "not a = null"),
+            (
+                not(or(column_ref(["a"]), column_ref(["b"]))),
+                "not (a or b)",
+            ),
+            (
+                column_ref(["action", "select", "Name"]),
+                r#"action."select"."Name""#,
+            ),
+            (literal_int(2147483648), "2147483648"),
+            (literal_int(i64::MIN), "-9223372036854775808"),
+            (literal_bool(false), "false"),
+        ] {
+            let node = node.syntax();
+            assert_eq!(node.kind(), SyntaxKind::a_expr);
+            assert_eq!(node.text(), expected);
+            assert_eq!(node.text_range().start(), 0.into());
+            assert_reparse("select ", node);
+        }
+    }
+
+    #[test]
+    fn select_stmt() {
+        let stmt = select([
+            star(),
+            alias(column_ref(["a"]), "from"),
+            alias(literal_int(1), "action"),
+            column_ref(["b"]).into(),
+        ])
+        .from(table_ref(["t"]))
+        .from(table_ref(["s", "U"]))
+        .where_(literal_bool(false))
+        .build();
+        assert_eq!(stmt.kind(), SyntaxKind::SelectStmt);
+        assert_eq!(
+            stmt.text(),
+            r#"select *, a as "from", 1 as action, b from t, s."U" where false"#
+        );
+        assert_reparse("", &stmt);
+
+        for stmt in [
+            select([]),
+            select([star()]),
+            select([]).from(table_ref(["t"])),
+            select([star()]).where_(column_ref(["a"])),
+        ] {
+            assert_reparse("", &stmt.build());
+        }
+        assert_eq!(select([]).build().text(), "select");
+        assert_reparse("select from ", table_ref(["public", "t"]).syntax());
+    }
+
+    #[test]
+    fn expr_errors() {
+        assert_eq!(expr("  a  ").unwrap().syntax().text(), "a");
+        assert_eq!(
+            expr("a, b").map(|_| ()),
+            Err(ParserError::ParseError {
+                message: "not a single expression".to_string(),
+                start_byte_pos: 0,
+                end_byte_pos: 4,
+            })
+        );
+        assert!(matches!(
+            expr("x'CC"),
+            Err(ParserError::ScanError {
+                start_byte_pos: 0,
+                end_byte_pos: 4,
+                ..
+            })
+        ));
+    }
+}
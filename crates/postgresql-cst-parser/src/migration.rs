@@ -0,0 +1,587 @@
+//! Safety analysis of schema migrations
+//!
+//! [`analyze`] reports the lock each `CREATE TABLE`, `CREATE INDEX` and `ALTER TABLE` statement takes,
+//! whether it rewrites the table, and [`Hazard`]s that block reads or writes on a large table for a long time.
+//!
+//! Tables created earlier in the same source are assumed to be empty, so statements on them have no hazards.
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::{migration::{analyze, HazardKind, LockLevel}, parse};
+//!
+//! let root = parse("alter table t add column a int default random(), alter column b type bigint;").unwrap();
+//! let analyses = analyze(&root);
+//!
+//! assert_eq!(analyses[0].table, "t");
+//! assert_eq!(analyses[0].lock, LockLevel::AccessExclusive);
+//! assert!(analyses[0].rewrite);
+//!
+//! let hazards: Vec<_> = analyses[0].hazards.iter().map(|hazard| hazard.kind).collect();
+//! assert_eq!(hazards, [HazardKind::VolatileDefault, HazardKind::ColumnTypeChange]);
+//! ```
+
+use std::{collections::HashSet, ops::Range};
+
+use crate::{
+    cst_util::{child, is_trivia, range, tokens},
+    syntax_kind::SyntaxKind,
+    value::{token_value, Value},
+    ResolvedNode,
+};
+
+/// Table-level lock modes of PostgreSQL, from the weakest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LockLevel {
+    AccessShare,
+    RowShare,
+    RowExclusive,
+    ShareUpdateExclusive,
+    Share,
+    ShareRowExclusive,
+    Exclusive,
+    AccessExclusive,
+}
+
+impl LockLevel {
+    /// Name of the lock mode, such as `ACCESS EXCLUSIVE`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LockLevel::AccessShare => "ACCESS SHARE",
+            LockLevel::RowShare => "ROW SHARE",
+            LockLevel::RowExclusive => "ROW EXCLUSIVE",
+            LockLevel::ShareUpdateExclusive => "SHARE UPDATE EXCLUSIVE",
+            LockLevel::Share => "SHARE",
+            LockLevel::ShareRowExclusive => "SHARE ROW EXCLUSIVE",
+            LockLevel::Exclusive => "EXCLUSIVE",
+            LockLevel::AccessExclusive => "ACCESS EXCLUSIVE",
+        }
+    }
+
+    /// Returns whether the lock blocks `INSERT`, `UPDATE` and `DELETE`
+    pub fn blocks_writes(&self) -> bool {
+        *self >= LockLevel::Share
+    }
+
+    /// Returns whether the lock blocks `SELECT`
+    pub fn blocks_reads(&self) -> bool {
+        *self == LockLevel::AccessExclusive
+    }
+}
+
+impl std::fmt::Display for LockLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Kind of a [`Hazard`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HazardKind {
+    /// `CREATE INDEX` without `CONCURRENTLY` blocks writes while the index is built
+    IndexWithoutConcurrently,
+    /// `ADD COLUMN` with a volatile default, a serial type or a stored generated column rewrites the table
+    VolatileDefault,
+    /// `ALTER COLUMN ... TYPE` rewrites the table unless the types are binary coercible
+    ColumnTypeChange,
+    /// `SET NOT NULL` scans the table under `ACCESS EXCLUSIVE` unless a `CHECK (... IS NOT NULL)` constraint proves it
+    SetNotNull,
+    /// `ADD FOREIGN KEY` without `NOT VALID` checks all rows while blocking writes
+    ForeignKeyWithoutNotValid,
+    /// `ADD CHECK` without `NOT VALID` checks all rows under `ACCESS EXCLUSIVE`
+    CheckWithoutNotValid,
+}
+
+/// A part of a statement that holds a lock for a long time on a large table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hazard {
+    pub kind: HazardKind,
+    /// Range of the subcommand or the statement
+    pub range: Range<usize>,
+    pub lock: LockLevel,
+    pub rewrite: bool,
+    pub message: String,
+    /// How to make the same change safely
+    pub alternative: &'static str,
+}
+
+/// Locks and hazards of a statement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementAnalysis {
+    /// `CreateStmt`, `IndexStmt` or `AlterTableStmt`
+    pub kind: SyntaxKind,
+    pub range: Range<usize>,
+    /// Name of the table with identifiers case-folded, such as `public.t`
+    pub table: String,
+    /// The strongest lock the statement takes on the table
+    pub lock: LockLevel,
+    /// Whether the statement rewrites the table and its indexes
+    pub rewrite: bool,
+    pub hazards: Vec<Hazard>,
+}
+
+/// Functions commonly used as volatile defaults
+const VOLATILE_FUNCTIONS: &[&str] = &[
+    "clock_timestamp",
+    "gen_random_uuid",
+    "nextval",
+    "random",
+    "timeofday",
+    "uuid_generate_v1",
+    "uuid_generate_v1mc",
+    "uuid_generate_v4",
+];
+
+const SERIAL_TYPES: &[&str] = &[
+    "bigserial",
+    "serial",
+    "serial2",
+    "serial4",
+    "serial8",
+    "smallserial",
+];
+
+/// Analyzes the `CREATE TABLE`, `CREATE INDEX` and `ALTER TABLE` statements in the tree
+pub fn analyze(root: &ResolvedNode) -> Vec<StatementAnalysis> {
+    let mut analyzer = Analyzer::default();
+    root.descendants()
+        .filter_map(|node| match node.kind() {
+            SyntaxKind::CreateStmt => analyzer.create(node),
+            SyntaxKind::IndexStmt => analyzer.index(node),
+            SyntaxKind::AlterTableStmt => analyzer.alter_table(node),
+            _ => None,
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct Analyzer {
+    /// Tables created in the source
+    created: HashSet<String>,
+    /// Columns with a `CHECK (column IS NOT NULL)` constraint, as `(table, column)`
+    not_null_checked: HashSet<(String, String)>,
+}
+
+impl Analyzer {
+    fn create(&mut self, stmt: &ResolvedNode) -> Option<StatementAnalysis> {
+        let table = name(
+            stmt.children()
+                .find(|child| child.kind() == SyntaxKind::qualified_name)?,
+        );
+        self.created.insert(table.clone());
+
+        Some(StatementAnalysis {
+            kind: stmt.kind(),
+            range: range(stmt),
+            table,
+            lock: LockLevel::AccessExclusive,
+            rewrite: false,
+            hazards: Vec::new(),
+        })
+    }
+
+    fn index(&mut self, stmt: &ResolvedNode) -> Option<StatementAnalysis> {
+        let table = name(
+            stmt.children()
+                .find(|child| child.kind() == SyntaxKind::relation_expr)?,
+        );
+        let concurrently = stmt
+            .children()
+            .any(|child| child.kind() == SyntaxKind::opt_concurrently && !is_empty(child));
+
+        let mut analysis = StatementAnalysis {
+            kind: stmt.kind(),
+            range: range(stmt),
+            table,
+            lock: if concurrently {
+                LockLevel::ShareUpdateExclusive
+            } else {
+                LockLevel::Share
+            },
+            rewrite: false,
+            hazards: Vec::new(),
+        };
+        if !concurrently && !self.created.contains(&analysis.table) {
+            analysis.hazards.push(Hazard {
+                kind: HazardKind::IndexWithoutConcurrently,
+                range: range(stmt),
+                lock: LockLevel::Share,
+                rewrite: false,
+                message: "CREATE INDEX blocks writes to the table until the index is built"
+                    .to_string(),
+                alternative: "use CREATE INDEX CONCURRENTLY outside a transaction block",
+            });
+        }
+        Some(analysis)
+    }
+
+    fn alter_table(&mut self, stmt: &ResolvedNode) -> Option<StatementAnalysis> {
+        // `ALTER INDEX`, `ALTER SEQUENCE` and `ALTER VIEW` share the node
+        if tokens(stmt).nth(1)?.kind() != SyntaxKind::TABLE {
+            return None;
+        }
+        let table = name(
+            stmt.children()
+                .find(|child| child.kind() == SyntaxKind::relation_expr)?,
+        );
+        let cmds = stmt
+            .children()
+            .find(|child| child.kind() == SyntaxKind::alter_table_cmds)?;
+
+        let mut analysis = StatementAnalysis {
+            kind: stmt.kind(),
+            range: range(stmt),
+            table,
+            lock: LockLevel::AccessShare,
+            rewrite: false,
+            hazards: Vec::new(),
+        };
+        for cmd in cmds
+            .descendants()
+            .filter(|node| node.kind() == SyntaxKind::alter_table_cmd)
+            .filter(|cmd| {
+                cmd.parent().map(|parent| parent.kind()) == Some(SyntaxKind::alter_table_cmds)
+            })
+        {
+            let (lock, hazard) = self.alter_table_cmd(&analysis.table, cmd);
+            analysis.lock = analysis.lock.max(lock);
+            if let Some(hazard) = hazard {
+                analysis.rewrite |= hazard.rewrite;
+                if !self.created.contains(&analysis.table) {
+                    analysis.hazards.push(hazard);
+                }
+            }
+        }
+        Some(analysis)
+    }
+
+    /// Returns the lock of a subcommand of `ALTER TABLE` and its hazard
+    fn alter_table_cmd(&mut self, table: &str, cmd: &ResolvedNode) -> (LockLevel, Option<Hazard>) {
+        let keywords: Vec<_> = cmd
+            .children_with_tokens()
+            .filter_map(|child| child.into_token())
+            .map(|token| token.kind())
+            .filter(|kind| !is_trivia(*kind))
+            .collect();
+        let hazard = |kind, lock, rewrite, message: &str, alternative| Hazard {
+            kind,
+            range: range(cmd),
+            lock,
+            rewrite,
+            message: message.to_string(),
+            alternative,
+        };
+
+        match keywords.as_slice() {
+            // ADD [COLUMN] columnDef
+            [SyntaxKind::ADD_P] | [SyntaxKind::ADD_P, SyntaxKind::COLUMN]
+                if child(cmd, SyntaxKind::columnDef).is_some() =>
+            {
+                let column = child(cmd, SyntaxKind::columnDef).unwrap();
+                let rewrite = rewrites_on_add(column);
+                let hazard = rewrite.then(|| {
+                    hazard(
+                        HazardKind::VolatileDefault,
+                        LockLevel::AccessExclusive,
+                        true,
+                        "adding a column with a volatile default rewrites the table",
+                        "add the column without a default, set the default, and backfill the rows in batches",
+                    )
+                });
+                (LockLevel::AccessExclusive, hazard)
+            }
+            // ADD TableConstraint
+            [SyntaxKind::ADD_P] => {
+                let Some(constraint) = child(cmd, SyntaxKind::TableConstraint) else {
+                    return (LockLevel::AccessExclusive, None);
+                };
+                self.add_constraint(table, constraint, hazard)
+            }
+            // ALTER [COLUMN] ColId [SET DATA] TYPE Typename
+            [SyntaxKind::ALTER, .., SyntaxKind::TYPE_P] => (
+                LockLevel::AccessExclusive,
+                Some(hazard(
+                    HazardKind::ColumnTypeChange,
+                    LockLevel::AccessExclusive,
+                    true,
+                    "changing the type of a column rewrites the table unless the types are binary coercible",
+                    "add a column of the new type, backfill it in batches, and switch to it",
+                )),
+            ),
+            // ALTER [COLUMN] ColId SET NOT NULL
+            [SyntaxKind::ALTER, SyntaxKind::SET, SyntaxKind::NOT, SyntaxKind::NULL_P] => {
+                let column = child(cmd, SyntaxKind::ColId).map(name).unwrap_or_default();
+                let hazard = (!self
+                    .not_null_checked
+                    .contains(&(table.to_string(), column)))
+                .then(|| {
+                    hazard(
+                        HazardKind::SetNotNull,
+                        LockLevel::AccessExclusive,
+                        false,
+                        "SET NOT NULL scans the whole table while blocking reads and writes",
+                        "add CHECK (column IS NOT NULL) NOT VALID, VALIDATE CONSTRAINT, then SET NOT NULL",
+                    )
+                });
+                (LockLevel::AccessExclusive, hazard)
+            }
+            // VALIDATE CONSTRAINT name
+            [SyntaxKind::VALIDATE, SyntaxKind::CONSTRAINT] => {
+                (LockLevel::ShareUpdateExclusive, None)
+            }
+            _ => (LockLevel::AccessExclusive, None),
+        }
+    }
+
+    fn add_constraint(
+        &mut self,
+        table: &str,
+        constraint: &ResolvedNode,
+        hazard: impl Fn(HazardKind, LockLevel, bool, &str, &'static str) -> Hazard,
+    ) -> (LockLevel, Option<Hazard>) {
+        let Some(elem) = child(constraint, SyntaxKind::ConstraintElem) else {
+            return (LockLevel::AccessExclusive, None);
+        };
+        let not_valid = elem.descendants().any(|node| {
+            node.kind() == SyntaxKind::ConstraintAttributeElem
+                && tokens(node)
+                    .map(|token| token.kind())
+                    .eq([SyntaxKind::NOT, SyntaxKind::VALID])
+        });
+
+        match tokens(elem).next().map(|token| token.kind()) {
+            Some(SyntaxKind::FOREIGN) => {
+                let hazard = (!not_valid).then(|| {
+                    hazard(
+                        HazardKind::ForeignKeyWithoutNotValid,
+                        LockLevel::ShareRowExclusive,
+                        false,
+                        "adding a foreign key checks all rows while blocking writes to both tables",
+                        "add the foreign key with NOT VALID, then VALIDATE CONSTRAINT in a separate transaction",
+                    )
+                });
+                (LockLevel::ShareRowExclusive, hazard)
+            }
+            Some(SyntaxKind::CHECK) => {
+                if let Some(column) = not_null_column(elem) {
+                    self.not_null_checked.insert((table.to_string(), column));
+                }
+                let hazard = (!not_valid).then(|| {
+                    hazard(
+                        HazardKind::CheckWithoutNotValid,
+                        LockLevel::AccessExclusive,
+                        false,
+                        "adding a check constraint checks all rows while blocking reads and writes",
+                        "add the constraint with NOT VALID, then VALIDATE CONSTRAINT in a separate transaction",
+                    )
+                });
+                (LockLevel::AccessExclusive, hazard)
+            }
+            _ => (LockLevel::AccessExclusive, None),
+        }
+    }
+}
+
+/// Returns whether adding the column rewrites the table
+fn rewrites_on_add(column: &ResolvedNode) -> bool {
+    let serial = child(column, SyntaxKind::Typename).is_some_and(|typename| {
+        SERIAL_TYPES.contains(&typename.text().to_string().to_ascii_lowercase().as_str())
+    });
+
+    let constraints = column
+        .descendants()
+        .filter(|node| node.kind() == SyntaxKind::ColConstraintElem);
+    let volatile_or_stored =
+        constraints
+            .into_iter()
+            .any(|elem| match tokens(elem).next().map(|token| token.kind()) {
+                Some(SyntaxKind::DEFAULT) => elem
+                    .descendants()
+                    .filter(|node| node.kind() == SyntaxKind::func_name)
+                    .any(|func| {
+                        let func = name(func);
+                        let func = func.rsplit('.').next().unwrap_or(&func);
+                        VOLATILE_FUNCTIONS.contains(&func)
+                    }),
+                Some(SyntaxKind::GENERATED) => {
+                    tokens(elem).any(|token| token.kind() == SyntaxKind::STORED)
+                }
+                _ => false,
+            });
+
+    serial || volatile_or_stored
+}
+
+/// Returns the column of `CHECK (column IS NOT NULL)`
+fn not_null_column(check: &ResolvedNode) -> Option<String> {
+    let expr = child(check, SyntaxKind::a_expr)?;
+    let operators: Vec<_> = expr
+        .children_with_tokens()
+        .filter_map(|child| child.into_token())
+        .map(|token| token.kind())
+        .filter(|kind| !is_trivia(*kind))
+        .collect();
+    if operators != [SyntaxKind::IS, SyntaxKind::NOT, SyntaxKind::NULL_P]
+        && operators != [SyntaxKind::NOTNULL]
+    {
+        return None;
+    }
+
+    let column = child(expr, SyntaxKind::a_expr)?
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::columnref)?;
+    Some(name(column))
+}
+
+/// Returns the case-folded name, joining the parts of a qualified name with `.`
+fn name(node: &ResolvedNode) -> String {
+    tokens(node)
+        .filter(|token| token.kind() != SyntaxKind::Dot && token.kind() != SyntaxKind::ONLY)
+        .map(|token| match token_value(token) {
+            Some(Value::Identifier(name) | Value::Keyword(name)) => name,
+            _ => token.text().to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn is_empty(node: &ResolvedNode) -> bool {
+    tokens(node).next().is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+
+    use super::{analyze, HazardKind, LockLevel};
+
+    /// Returns the table, lock, rewrite and hazards of each statement
+    fn analyses(src: &str) -> Vec<(String, LockLevel, bool, Vec<HazardKind>)> {
+        analyze(&parse(src).unwrap())
+            .into_iter()
+            .map(|analysis| {
+                (
+                    analysis.table,
+                    analysis.lock,
+                    analysis.rewrite,
+                    analysis.hazards.iter().map(|hazard| hazard.kind).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn index() {
+        assert_eq!(
+            analyses("create index on Public.T (a); create unique index concurrently i on t (a);"),
+            [
+                (
+                    "public.t".to_string(),
+                    LockLevel::Share,
+                    false,
+                    vec![HazardKind::IndexWithoutConcurrently]
+                ),
+                (
+                    "t".to_string(),
+                    LockLevel::ShareUpdateExclusive,
+                    false,
+                    vec![]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn add_column() {
+        assert_eq!(
+            analyses(
+                "alter table t add column a int default 0, add b timestamptz default now();
+                 alter table t add c bigserial;
+                 alter table t add d uuid not null default gen_random_uuid();
+                 alter table t add e int generated always as (a * 2) stored;"
+            ),
+            [
+                ("t".to_string(), LockLevel::AccessExclusive, false, vec![]),
+                (
+                    "t".to_string(),
+                    LockLevel::AccessExclusive,
+                    true,
+                    vec![HazardKind::VolatileDefault]
+                ),
+                (
+                    "t".to_string(),
+                    LockLevel::AccessExclusive,
+                    true,
+                    vec![HazardKind::VolatileDefault]
+                ),
+                (
+                    "t".to_string(),
+                    LockLevel::AccessExclusive,
+                    true,
+                    vec![HazardKind::VolatileDefault]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn constraints() {
+        assert_eq!(
+            analyses(
+                "alter table t add foreign key (a) references u (id);
+                 alter table t add constraint fk foreign key (a) references u (id) not valid;
+                 alter table t validate constraint fk;
+                 alter table t alter column a set not null;
+                 alter table t add constraint a_not_null check (a is not null) not valid;
+                 alter table t validate constraint a_not_null, alter a set not null;
+                 alter table t add check (b > 0);"
+            ),
+            [
+                (
+                    "t".to_string(),
+                    LockLevel::ShareRowExclusive,
+                    false,
+                    vec![HazardKind::ForeignKeyWithoutNotValid]
+                ),
+                ("t".to_string(), LockLevel::ShareRowExclusive, false, vec![]),
+                (
+                    "t".to_string(),
+                    LockLevel::ShareUpdateExclusive,
+                    false,
+                    vec![]
+                ),
+                (
+                    "t".to_string(),
+                    LockLevel::AccessExclusive,
+                    false,
+                    vec![HazardKind::SetNotNull]
+                ),
+                ("t".to_string(), LockLevel::AccessExclusive, false, vec![]),
+                ("t".to_string(), LockLevel::AccessExclusive, false, vec![]),
+                (
+                    "t".to_string(),
+                    LockLevel::AccessExclusive,
+                    false,
+                    vec![HazardKind::CheckWithoutNotValid]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn new_table() {
+        assert_eq!(
+            analyses(
+                "create table t (id int references u);
+                 create index on t (id);
+                 alter table only t alter column id type bigint;
+                 alter index i rename to j;"
+            ),
+            [
+                ("t".to_string(), LockLevel::AccessExclusive, false, vec![]),
+                ("t".to_string(), LockLevel::Share, false, vec![]),
+                ("t".to_string(), LockLevel::AccessExclusive, true, vec![]),
+            ]
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use cstree::Syntax;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Syntax)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Syntax)]
 #[repr(u32)]
 pub enum SyntaxKind {
     ABORT_P,
@@ -1268,5 +1268,8 @@ pub enum SyntaxKind {
     C_COMMENT,
     SQL_COMMENT,
     Whitespace,
+    PSQL_META_COMMAND,
+    PSQL_VARIABLE,
+    COPY_DATA,
     Root,
 }
@@ -0,0 +1,254 @@
+//! Lossless token stream of the lexer
+//!
+//! Unlike [`crate::lex`], the tokens borrow the input and include whitespace, so that concatenating their texts gives back the input.
+//! This is useful for tools that do not need a syntax tree, such as statement splitting and redaction.
+//!
+//! The kinds of tokens are those of the lexer, before the lookahead of the parser.
+//! For example, `NOT` before `LIKE` is [`SyntaxKind::NOT`] rather than [`SyntaxKind::NOT_LA`],
+//! and `U&'...' UESCAPE '!'` is three tokens.
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::{keywords::KeywordCategory, syntax_kind::SyntaxKind, tokenize::tokenize};
+//!
+//! let tokens: Vec<_> = tokenize("select 1 -- one").collect::<Result<_, _>>().unwrap();
+//! let kinds: Vec<_> = tokens.iter().map(|token| token.kind).collect();
+//! assert_eq!(
+//!     kinds,
+//!     [
+//!         SyntaxKind::SELECT,
+//!         SyntaxKind::Whitespace,
+//!         SyntaxKind::ICONST,
+//!         SyntaxKind::Whitespace,
+//!         SyntaxKind::SQL_COMMENT,
+//!     ]
+//! );
+//! assert_eq!(tokens[0].keyword, Some(KeywordCategory::Reserved));
+//! assert_eq!(tokens[4].text, "-- one");
+//! ```
+
+use cstree::{RawSyntaxKind, Syntax};
+
+use crate::{
+    keywords::{keyword_category, KeywordCategory},
+    lexer::{is_psql_variable, Lexer, LexerOptions, TokenKind, Yylval},
+    parser::token_kind_to_component_id,
+    syntax_kind::SyntaxKind,
+    ParserError,
+};
+
+/// A token of the input, including whitespace and comments
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RawToken<'a> {
+    /// Kind of the token
+    ///
+    /// `U&"..."` is [`SyntaxKind::IDENT`] and `U&'...'` is [`SyntaxKind::SCONST`], like the tokens the parser receives.
+    pub kind: SyntaxKind,
+    pub text: &'a str,
+    pub start_byte_pos: usize,
+    pub end_byte_pos: usize,
+    /// Category of the keyword, if the token is a keyword
+    pub keyword: Option<KeywordCategory>,
+}
+
+/// Iterator over the tokens of the input, returned by [`tokenize`]
+///
+/// The iterator ends after returning an error.
+pub struct Tokenizer<'a> {
+    input: &'a str,
+    lexer: Lexer,
+    last_pos: usize,
+    pending: Option<RawToken<'a>>,
+    finished: bool,
+}
+
+/// Splits the input into tokens, including whitespace and comments
+pub fn tokenize(input: &str) -> Tokenizer<'_> {
+    tokenize_with_options(input, &LexerOptions::default())
+}
+
+/// Same as [`tokenize`], but with the given settings of the lexer
+pub fn tokenize_with_options<'a>(input: &'a str, options: &LexerOptions) -> Tokenizer<'a> {
+    Tokenizer {
+        input,
+        lexer: Lexer::new(input, options.clone()),
+        last_pos: 0,
+        pending: None,
+        finished: false,
+    }
+}
+
+impl<'a> Tokenizer<'a> {
+    fn whitespace(&self, start_byte_pos: usize, end_byte_pos: usize) -> RawToken<'a> {
+        RawToken {
+            kind: SyntaxKind::Whitespace,
+            text: &self.input[start_byte_pos..end_byte_pos],
+            start_byte_pos,
+            end_byte_pos,
+            keyword: None,
+        }
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Result<RawToken<'a>, ParserError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(token) = self.pending.take() {
+            return Some(Ok(token));
+        }
+        if self.finished {
+            return None;
+        }
+
+        let (token, yylval) = match self.lexer.next_token() {
+            Ok(Some(token)) => token,
+            Ok(None) => {
+                self.finished = true;
+                let end = self.input.len();
+                return (self.last_pos < end).then(|| {
+                    let whitespace = self.whitespace(self.last_pos, end);
+                    self.last_pos = end;
+                    Ok(whitespace)
+                });
+            }
+            Err(error) => {
+                self.finished = true;
+                return Some(Err(error));
+            }
+        };
+
+        let psql_variable = is_psql_variable(&token);
+        let kind = match token.kind {
+            TokenKind::UIDENT => TokenKind::IDENT,
+            TokenKind::USCONST => TokenKind::SCONST,
+            kind => kind,
+        };
+        let keyword = match &yylval {
+            Yylval::Keyword(name) => keyword_category(name),
+            _ => None,
+        };
+        let kind = match kind {
+            TokenKind::PSQL_META_COMMAND => SyntaxKind::PSQL_META_COMMAND,
+            TokenKind::COPY_DATA => SyntaxKind::COPY_DATA,
+            _ if psql_variable => SyntaxKind::PSQL_VARIABLE,
+            kind => SyntaxKind::from_raw(RawSyntaxKind(token_kind_to_component_id(&kind))),
+        };
+        let raw_token = RawToken {
+            kind,
+            text: &self.input[token.start_byte_pos..token.end_byte_pos],
+            start_byte_pos: token.start_byte_pos,
+            end_byte_pos: token.end_byte_pos,
+            keyword,
+        };
+
+        let start = self.last_pos;
+        self.last_pos = token.end_byte_pos;
+        if start < token.start_byte_pos {
+            self.pending = Some(raw_token);
+            Some(Ok(self.whitespace(start, token.start_byte_pos)))
+        } else {
+            Some(Ok(raw_token))
+        }
+    }
+}
+
+impl std::iter::FusedIterator for Tokenizer<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{keywords::KeywordCategory, syntax_kind::SyntaxKind, LexerOptions, ParserError};
+
+    use super::{tokenize, tokenize_with_options, RawToken};
+
+    fn tokens(input: &str) -> Vec<RawToken<'_>> {
+        tokenize(input).collect::<Result<_, _>>().unwrap()
+    }
+
+    #[test]
+    fn lossless() {
+        let input =
+            "  /* a /* nested */ comment */select\tU&\"x\", e'a\\'b' ,$$\n$$::text -- end\n";
+        let tokens = tokens(input);
+        assert_eq!(tokens.iter().map(|t| t.text).collect::<String>(), input);
+
+        let mut pos = 0;
+        for token in &tokens {
+            assert_eq!(token.start_byte_pos, pos);
+            assert_eq!(&input[token.start_byte_pos..token.end_byte_pos], token.text);
+            pos = token.end_byte_pos;
+        }
+
+        let kinds: Vec<_> = tokens
+            .iter()
+            .filter(|t| t.kind != SyntaxKind::Whitespace)
+            .map(|t| t.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                SyntaxKind::C_COMMENT,
+                SyntaxKind::SELECT,
+                SyntaxKind::IDENT,
+                SyntaxKind::Comma,
+                SyntaxKind::SCONST,
+                SyntaxKind::Comma,
+                SyntaxKind::SCONST,
+                SyntaxKind::TYPECAST,
+                SyntaxKind::TEXT_P,
+                SyntaxKind::SQL_COMMENT,
+            ]
+        );
+    }
+
+    #[test]
+    fn keywords() {
+        let keywords: Vec<_> = tokens("select name, int, left, foo from t")
+            .iter()
+            .filter(|t| !matches!(t.kind, SyntaxKind::Whitespace | SyntaxKind::Comma))
+            .map(|t| (t.text, t.keyword))
+            .collect();
+        assert_eq!(
+            keywords,
+            [
+                ("select", Some(KeywordCategory::Reserved)),
+                ("name", Some(KeywordCategory::Unreserved)),
+                ("int", Some(KeywordCategory::ColName)),
+                ("left", Some(KeywordCategory::TypeFuncName)),
+                ("foo", None),
+                ("from", Some(KeywordCategory::Reserved)),
+                ("t", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert!(tokens("").is_empty());
+        assert_eq!(tokens(" \n").len(), 1);
+    }
+
+    #[test]
+    fn error() {
+        let mut it = tokenize("select 'a");
+        assert_eq!(it.next().unwrap().unwrap().kind, SyntaxKind::SELECT);
+        assert!(matches!(
+            it.next(),
+            Some(Err(ParserError::ScanError { .. }))
+        ));
+        assert!(it.next().is_none());
+    }
+
+    #[test]
+    fn options() {
+        let options = LexerOptions {
+            standard_conforming_strings: false,
+            ..Default::default()
+        };
+        let tokens: Vec<_> = tokenize_with_options(r"select 'a\'b'", &options)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(tokens.last().unwrap().text, r"'a\'b'");
+    }
+}
@@ -2,13 +2,28 @@
 mod assert_util;
 
 mod convert;
-use convert::get_ts_tree_and_range_map;
+pub use convert::ConversionOptions;
 
-use std::{collections::HashMap, fmt::Display, rc::Rc, str};
+mod generated;
 
-use cstree::text::TextRange;
+mod language;
+pub use language::{Language, LANGUAGE};
 
-use crate::{cst, syntax_kind::SyntaxKind, NodeOrToken, ParserError, ResolvedNode};
+mod edit;
+pub use edit::InputEdit;
+
+mod query;
+pub use query::{
+    Query, QueryCapture, QueryCaptures, QueryCursor, QueryError, QueryErrorKind, QueryMatch,
+    QueryMatches,
+};
+
+use std::{fmt::Display, rc::Rc, str};
+
+use crate::{
+    cst, cst_util::is_extra, fields, syntax_kind::SyntaxKind, NodeOrToken, ParseOptions,
+    ParserError, ResolvedNode, ResolvedToken,
+};
 
 impl Display for SyntaxKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -17,61 +32,220 @@ impl Display for SyntaxKind {
 }
 
 pub fn parse(input: &str) -> Result<Tree, ParserError> {
-    let parsed = cst::parse(input)?;
-    let (root, range_map) = get_ts_tree_and_range_map(input, &parsed);
-    Ok(Tree::new(input, root, range_map))
+    parse_with_options(input, &ConversionOptions::default())
 }
 
 pub fn parse_2way(input: &str) -> Result<Tree, ParserError> {
-    let parsed = crate::parse_2way(input)?;
-    let (root, range_map) = get_ts_tree_and_range_map(input, &parsed);
-    Ok(Tree::new(input, root, range_map))
+    parse_2way_with_options(input, &ConversionOptions::default())
+}
+
+/// Same as [`parse`], but views the CST with the given [`ConversionOptions`]
+pub fn parse_with_options(input: &str, options: &ConversionOptions) -> Result<Tree, ParserError> {
+    parse_with_parse_options(input, &ParseOptions::default(), options)
+}
+
+/// Same as [`parse_2way`], but views the CST with the given [`ConversionOptions`]
+pub fn parse_2way_with_options(
+    input: &str,
+    options: &ConversionOptions,
+) -> Result<Tree, ParserError> {
+    parse_2way_with_parse_options(input, &ParseOptions::default(), options)
 }
 
+/// Same as [`parse_with_options`], but parses with the given settings of PostgreSQL
+///
+/// The warnings of the lexer are dropped. Use [`crate::parse_with_options`] to get them.
+pub fn parse_with_parse_options(
+    input: &str,
+    parse_options: &ParseOptions,
+    options: &ConversionOptions,
+) -> Result<Tree, ParserError> {
+    let parsed = cst::parse_with_options(input, &[], parse_options)?;
+    Ok(Tree::new(input, parsed.root).with_parse_settings(options.clone(), parse_options, false))
+}
+
+/// Same as [`parse_2way_with_options`], but parses with the given settings of PostgreSQL
+///
+/// The warnings of the lexer are dropped. Use [`crate::parse_2way_with_options`] to get them.
+pub fn parse_2way_with_parse_options(
+    input: &str,
+    parse_options: &ParseOptions,
+    options: &ConversionOptions,
+) -> Result<Tree, ParserError> {
+    let parsed = crate::parse_2way_with_options(input, parse_options)?;
+    Ok(Tree::new(input, parsed.root).with_parse_settings(options.clone(), parse_options, true))
+}
+
+/// A tree-sitter-like tree
+///
+/// The tree is a view over the lossless CST.
+/// Whitespace tokens are skipped, and wrapper nodes are removed or flattened on the fly according to [`ConversionOptions`].
 pub struct Tree {
     src: String,
     root: ResolvedNode,
-    range_map: Rc<HashMap<TextRange, Range>>,
+    context: Rc<Context>,
+    /// Whether the source is parsed as 2-Way SQL, used in [`Tree::reparse`]
+    is_2way: bool,
+    /// The settings the source is parsed with, used in [`Tree::reparse`]
+    parse_options: ParseOptions,
+}
+
+/// State shared by the nodes of a tree
+#[derive(Debug, Clone)]
+struct Context {
+    options: ConversionOptions,
+    /// Byte offsets of the line feeds in the source
+    new_line_indices: Vec<usize>,
+    /// Positions of the token boundaries moved by [`Tree::edit`], sorted by their offset in the source
+    ///
+    /// `None` until the tree is edited.
+    edited_offsets: Option<Vec<(usize, usize, Point)>>,
+}
+
+impl Context {
+    fn new(src: &str, options: ConversionOptions) -> Self {
+        let new_line_indices = src
+            .char_indices()
+            .filter(|&(_, c)| c == '\n')
+            .map(|(i, _)| i)
+            .collect();
+
+        Self {
+            options,
+            new_line_indices,
+            edited_offsets: None,
+        }
+    }
+
+    fn point(&self, offset: usize) -> Point {
+        let row = match self.new_line_indices.binary_search(&offset) {
+            Ok(i) => i,
+            Err(i) => i,
+        };
+
+        let column = offset
+            - match row {
+                0 => 0,
+                i => self.new_line_indices[i - 1] + 1,
+            };
+
+        Point { row, column }
+    }
+
+    fn range(&self, node_or_token: NodeOrToken) -> Range {
+        let text_range = node_or_token.text_range();
+        let (start_byte, start_position) = self.edited_position(text_range.start().into());
+        let (end_byte, end_position) = self.edited_position(text_range.end().into());
+
+        Range {
+            start_byte,
+            end_byte,
+            start_position,
+            end_position,
+        }
+    }
+
+    /// Returns the position of an offset of the source after the edits
+    fn edited_position(&self, offset: usize) -> (usize, Point) {
+        let edited = self.edited_offsets.as_ref().and_then(|edited_offsets| {
+            let i = edited_offsets
+                .binary_search_by_key(&offset, |&(offset, _, _)| offset)
+                .ok()?;
+            let (_, byte, point) = edited_offsets[i];
+            Some((byte, point))
+        });
+
+        edited.unwrap_or_else(|| (offset, self.point(offset)))
+    }
+
+    /// Moves the token boundaries of the tree by the edit
+    fn edit(&mut self, root: &ResolvedNode, edit: &InputEdit) {
+        if self.edited_offsets.is_none() {
+            let mut offsets: Vec<usize> = root
+                .descendants_with_tokens()
+                .flat_map(|node_or_token| {
+                    let text_range = node_or_token.text_range();
+                    [text_range.start().into(), text_range.end().into()]
+                })
+                .collect();
+            offsets.sort_unstable();
+            offsets.dedup();
+
+            self.edited_offsets = Some(
+                offsets
+                    .into_iter()
+                    .map(|offset| (offset, offset, self.point(offset)))
+                    .collect(),
+            );
+        }
+
+        for (_, byte, point) in self.edited_offsets.iter_mut().flatten() {
+            (*byte, *point) = edit.apply(*byte, *point);
+        }
+    }
 }
 
 impl Tree {
-    pub fn new<T: Into<String>>(
-        src: T,
-        root: ResolvedNode,
-        range_map: HashMap<TextRange, Range>,
-    ) -> Self {
+    pub fn new<T: Into<String>>(src: T, root: ResolvedNode) -> Self {
+        let src = src.into();
+        let context = Context::new(&src, ConversionOptions::default());
         Self {
-            src: src.into(),
+            src,
             root,
-            range_map: Rc::new(range_map),
+            context: Rc::new(context),
+            is_2way: false,
+            parse_options: ParseOptions::default(),
         }
     }
 
+    fn with_parse_settings(
+        mut self,
+        options: ConversionOptions,
+        parse_options: &ParseOptions,
+        is_2way: bool,
+    ) -> Self {
+        Rc::make_mut(&mut self.context).options = options;
+        self.parse_options = parse_options.clone();
+        self.is_2way = is_2way;
+        self
+    }
+
     pub fn root_node(&self) -> Node<'_> {
         Node {
             input: &self.src,
-            range_map: Rc::clone(&self.range_map),
+            context: Rc::clone(&self.context),
             node_or_token: NodeOrToken::Node(&self.root),
         }
     }
+
+    pub fn walk(&self) -> TreeCursor<'_> {
+        self.root_node().walk()
+    }
+
+    pub fn language(&self) -> Language {
+        LANGUAGE
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Node<'a> {
     input: &'a str,
-    range_map: Rc<HashMap<TextRange, Range>>,
+    context: Rc<Context>,
+    /// The node or token of the original CST
     pub node_or_token: NodeOrToken<'a>,
 }
 
 #[derive(Debug, Clone)]
 pub struct TreeCursor<'a> {
     pub input: &'a str,
-    range_map: Rc<HashMap<TextRange, Range>>,
+    context: Rc<Context>,
     node_or_token: NodeOrToken<'a>,
+    /// The node the cursor was created from. The cursor never moves outside of it.
+    root: NodeOrToken<'a>,
 }
 
 // https://github.com/tree-sitter/tree-sitter/blob/90666c951d53c13cc6cf5002d971a6debed74244/lib/binding_rust/lib.rs#L74-L78
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Point {
     pub row: usize,
     pub column: usize,
@@ -84,7 +258,7 @@ impl std::fmt::Display for Point {
 }
 
 // https://github.com/tree-sitter/tree-sitter/blob/90666c951d53c13cc6cf5002d971a6debed74244/lib/binding_rust/lib.rs#L80-L88
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Range {
     pub start_byte: usize,
     pub end_byte: usize,
@@ -98,12 +272,41 @@ impl std::fmt::Display for Range {
     }
 }
 
+/// Whether nodes of the kind are named nodes in the tree-sitter sense
+///
+/// Non-terminal nodes and tokens that carry a value (identifiers, literals, operators, parameters and comments) are named.
+/// Keywords and punctuation are anonymous, like string literals in a tree-sitter grammar.
+pub fn is_named_kind(kind: SyntaxKind) -> bool {
+    if (kind as u32) >= crate::parser::num_terminal_symbol() {
+        return kind != SyntaxKind::Whitespace;
+    }
+
+    generated::NAMED_TOKENS.contains(&kind)
+}
+
 impl<'a> Node<'a> {
+    fn with(&self, node_or_token: NodeOrToken<'a>) -> Node<'a> {
+        Node {
+            input: self.input,
+            context: Rc::clone(&self.context),
+            node_or_token,
+        }
+    }
+
     pub fn walk(&self) -> TreeCursor<'a> {
         TreeCursor {
             input: self.input,
-            range_map: Rc::clone(&self.range_map),
+            context: Rc::clone(&self.context),
             node_or_token: self.node_or_token,
+            root: self.node_or_token,
+        }
+    }
+
+    /// Returns a numeric id for this node that is unique within its tree
+    pub fn id(&self) -> usize {
+        match self.node_or_token {
+            NodeOrToken::Node(node) => node as *const ResolvedNode as usize,
+            NodeOrToken::Token(token) => token as *const ResolvedToken as usize,
         }
     }
 
@@ -111,11 +314,45 @@ impl<'a> Node<'a> {
         self.node_or_token.kind()
     }
 
+    /// Returns the id of the node kind in [`Language`]
+    pub fn kind_id(&self) -> u16 {
+        self.kind() as u16
+    }
+
+    pub fn is_named(&self) -> bool {
+        is_named_kind(self.kind())
+    }
+
+    /// Whether this node was inserted to recover from an error (e.g. a missing 2Way SQL sample value)
+    pub fn is_missing(&self) -> bool {
+        self.node_or_token.as_token().is_some() && self.node_or_token.text_range().is_empty()
+    }
+
+    /// Whether this node is or contains a node inserted to recover from an error
+    pub fn has_error(&self) -> bool {
+        match self.node_or_token {
+            NodeOrToken::Node(node) => node
+                .descendants_with_tokens()
+                .any(|e| self.with(e).is_missing()),
+            NodeOrToken::Token(_) => self.is_missing(),
+        }
+    }
+
     pub fn range(&self) -> Range {
-        self.range_map
-            .get(&self.node_or_token.text_range())
-            .cloned()
-            .unwrap()
+        self.context.range(self.node_or_token)
+    }
+
+    pub fn start_byte(&self) -> usize {
+        self.range().start_byte
+    }
+
+    pub fn end_byte(&self) -> usize {
+        self.range().end_byte
+    }
+
+    pub fn byte_range(&self) -> std::ops::Range<usize> {
+        let range = self.range();
+        range.start_byte..range.end_byte
     }
 
     pub fn start_position(&self) -> Point {
@@ -126,53 +363,243 @@ impl<'a> Node<'a> {
         self.range().end_position
     }
 
+    /// Returns the text of this node in the source the tree was parsed from
+    ///
+    /// The text includes the whitespace inside the node, even if whitespace tokens are skipped.
     pub fn text(&self) -> &'a str {
-        let Range {
-            start_byte,
-            end_byte,
-            ..
-        } = self.range();
+        &self.input[self.node_or_token.text_range()]
+    }
+
+    pub fn utf8_text<'b>(&self, source: &'b [u8]) -> Result<&'b str, str::Utf8Error> {
+        str::from_utf8(&source[self.byte_range()])
+    }
 
-        &self.input[start_byte..end_byte]
+    /// Iterates over this node's children without a cursor
+    pub(crate) fn children_iter(&self) -> impl Iterator<Item = Node<'a>> + 'a {
+        let stop = self.node_or_token.as_node().copied();
+        let first = stop.and_then(|node| self.context.options.first_child(node));
+        let context = Rc::clone(&self.context);
+        let node = self.clone();
+        std::iter::successors(first, move |&child| {
+            context.options.next_sibling(child, stop)
+        })
+        .map(move |child| node.with(child))
+    }
+
+    /// Iterates over this node and its descendants in preorder
+    pub(crate) fn descendants(&self) -> impl Iterator<Item = Node<'a>> {
+        let mut stack = vec![self.clone()];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            let children: Vec<_> = node.children_iter().collect();
+            stack.extend(children.into_iter().rev());
+            Some(node)
+        })
     }
 
     pub fn child_count(&self) -> usize {
-        if let Some(node) = self.node_or_token.as_node() {
-            node.children_with_tokens().count()
-        } else {
-            0
-        }
+        self.children_iter().count()
+    }
+
+    pub fn child(&self, i: usize) -> Option<Node<'a>> {
+        self.children_iter().nth(i)
+    }
+
+    pub fn named_child_count(&self) -> usize {
+        self.children_iter()
+            .filter(|child| child.is_named())
+            .count()
+    }
+
+    pub fn named_child(&self, i: usize) -> Option<Node<'a>> {
+        self.children_iter().filter(|child| child.is_named()).nth(i)
+    }
+
+    /// Iterates over this node's children
+    ///
+    /// The cursor is used for the iteration, like the tree-sitter API. It is moved to this node.
+    pub fn children<'cursor>(
+        &self,
+        cursor: &'cursor mut TreeCursor<'a>,
+    ) -> impl Iterator<Item = Node<'a>> + 'cursor {
+        cursor.reset(self.clone());
+        let mut has_next = cursor.goto_first_child();
+        std::iter::from_fn(move || {
+            if !has_next {
+                return None;
+            }
+            let node = cursor.node();
+            has_next = cursor.goto_next_sibling();
+            Some(node)
+        })
+    }
+
+    /// Iterates over this node's named children
+    pub fn named_children<'cursor>(
+        &self,
+        cursor: &'cursor mut TreeCursor<'a>,
+    ) -> impl Iterator<Item = Node<'a>> + 'cursor {
+        self.children(cursor).filter(|child| child.is_named())
+    }
+
+    /// Returns the first child with the given field name
+    ///
+    /// Field names are defined in [`crate::fields`].
+    pub fn child_by_field_name(&self, field_name: &str) -> Option<Node<'a>> {
+        self.children_by_field_name(field_name).next()
+    }
+
+    /// Iterates over the children with the given field name
+    pub fn children_by_field_name<'b>(
+        &'b self,
+        field_name: &'b str,
+    ) -> impl Iterator<Item = Node<'a>> + 'b {
+        let fields = fields::field_names(self.kind(), self.children_iter().map(|c| c.kind()));
+        self.children_iter()
+            .zip(fields)
+            .filter(move |(_, field)| *field == Some(field_name))
+            .map(|(child, _)| child)
+    }
+
+    /// Returns the field name of the child at `child_index`
+    pub fn field_name_for_child(&self, child_index: usize) -> Option<&'static str> {
+        fields::field_names(self.kind(), self.children_iter().map(|c| c.kind()))
+            .nth(child_index)
+            .flatten()
     }
 
     pub fn next_sibling(&self) -> Option<Node<'a>> {
-        self.node_or_token
-            .next_sibling_or_token()
-            .map(|sibling| Node {
-                input: self.input,
-                range_map: Rc::clone(&self.range_map),
-                node_or_token: sibling,
-            })
+        self.context
+            .options
+            .next_sibling(self.node_or_token, None)
+            .map(|sibling| self.with(sibling))
+    }
+
+    pub fn prev_sibling(&self) -> Option<Node<'a>> {
+        self.context
+            .options
+            .prev_sibling(self.node_or_token, None)
+            .map(|sibling| self.with(sibling))
+    }
+
+    pub fn next_named_sibling(&self) -> Option<Node<'a>> {
+        let mut sibling = self.next_sibling();
+        while let Some(node) = sibling {
+            if node.is_named() {
+                return Some(node);
+            }
+            sibling = node.next_sibling();
+        }
+        None
+    }
+
+    pub fn prev_named_sibling(&self) -> Option<Node<'a>> {
+        let mut sibling = self.prev_sibling();
+        while let Some(node) = sibling {
+            if node.is_named() {
+                return Some(node);
+            }
+            sibling = node.prev_sibling();
+        }
+        None
     }
 
     pub fn parent(&self) -> Option<Node<'a>> {
-        self.node_or_token.parent().map(|parent| Node {
-            input: self.input,
-            range_map: Rc::clone(&self.range_map),
-            node_or_token: NodeOrToken::Node(parent),
-        })
+        self.context
+            .options
+            .parent(self.node_or_token, None)
+            .map(|parent| self.with(NodeOrToken::Node(parent)))
+    }
+
+    /// Returns the smallest node within this node that spans the given byte range
+    pub fn descendant_for_byte_range(&self, start: usize, end: usize) -> Option<Node<'a>> {
+        self.descendant_for_byte_range_impl(start, end, false)
+    }
+
+    /// Returns the smallest named node within this node that spans the given byte range
+    pub fn named_descendant_for_byte_range(&self, start: usize, end: usize) -> Option<Node<'a>> {
+        self.descendant_for_byte_range_impl(start, end, true)
+    }
+
+    fn descendant_for_byte_range_impl(
+        &self,
+        start: usize,
+        end: usize,
+        named: bool,
+    ) -> Option<Node<'a>> {
+        // Same condition as ts_node_descendant_for_byte_range: the node must exceed the start of the range
+        let contains = |node: &Node| {
+            let range = node.byte_range();
+            range.start <= start && end <= range.end && start < range.end
+        };
+
+        let range = self.byte_range();
+        if start < range.start || range.end < end {
+            return None;
+        }
+
+        let mut current = self.clone();
+        let mut last_named = self.clone();
+        while let Some(child) = current.children_iter().find(|child| contains(child)) {
+            if child.is_named() {
+                last_named = child.clone();
+            }
+            current = child;
+        }
+
+        Some(if named { last_named } else { current })
+    }
+
+    /// Returns an S-expression representing the named nodes of this subtree
+    pub fn to_sexp(&self) -> String {
+        fn write_sexp(node: &Node, out: &mut String) {
+            out.push('(');
+            out.push_str(&node.kind().to_string());
+            for child in node.children_iter() {
+                if child.is_named() {
+                    out.push(' ');
+                    write_sexp(&child, out);
+                }
+            }
+            out.push(')');
+        }
+
+        let mut out = String::new();
+        write_sexp(self, &mut out);
+        out
     }
 
     pub fn is_comment(&self) -> bool {
         matches!(self.kind(), SyntaxKind::C_COMMENT | SyntaxKind::SQL_COMMENT)
     }
+
+    /// Whether this node is outside the grammar: a comment, a psql meta-command or `COPY` data
+    pub fn is_extra(&self) -> bool {
+        is_extra(self.kind())
+    }
+}
+
+impl PartialEq for Node<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Node<'_> {}
+
+impl std::hash::Hash for Node<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id().hash(state)
+    }
 }
 
 impl<'a> From<Node<'a>> for TreeCursor<'a> {
     fn from(value: Node<'a>) -> Self {
         Self {
             input: value.input,
-            range_map: value.range_map,
+            context: value.context,
             node_or_token: value.node_or_token,
+            root: value.node_or_token,
         }
     }
 }
@@ -181,14 +608,78 @@ impl<'a> TreeCursor<'a> {
     pub fn node(&self) -> Node<'a> {
         Node {
             input: self.input,
-            range_map: Rc::clone(&self.range_map),
+            context: Rc::clone(&self.context),
             node_or_token: self.node_or_token,
         }
     }
 
+    /// The node the cursor was created from, which is never treated as a removed or flattened node
+    fn stop(&self) -> Option<&'a ResolvedNode> {
+        self.root.as_node().copied()
+    }
+
+    /// Returns the field name of the current node
+    pub fn field_name(&self) -> Option<&'static str> {
+        if self.node_or_token == self.root {
+            return None;
+        }
+        let parent = self
+            .context
+            .options
+            .parent(self.node_or_token, self.stop())?;
+        let parent = self.node().with(NodeOrToken::Node(parent));
+        fields::field_names(parent.kind(), parent.children_iter().map(|c| c.kind()))
+            .zip(parent.children_iter())
+            .find(|(_, child)| child.node_or_token == self.node_or_token)
+            .and_then(|(field, _)| field)
+    }
+
+    /// Re-initializes the cursor to start at the given node
+    pub fn reset(&mut self, node: Node<'a>) {
+        self.context = node.context;
+        self.input = node.input;
+        self.node_or_token = node.node_or_token;
+        self.root = node.node_or_token;
+    }
+
+    /// Returns the depth of the cursor's current node relative to the node the cursor was created from
+    pub fn depth(&self) -> u32 {
+        let mut depth = 0;
+        let mut current = self.node_or_token;
+        while current != self.root {
+            let Some(parent) = self.context.options.parent(current, self.stop()) else {
+                break;
+            };
+            current = NodeOrToken::Node(parent);
+            depth += 1;
+        }
+        depth
+    }
+
+    fn root_node(&self) -> Node<'a> {
+        self.node().with(self.root)
+    }
+
+    /// Returns the index of the cursor's current node out of all of the descendants of the original node (in preorder)
+    pub fn descendant_index(&self) -> usize {
+        self.root_node()
+            .descendants()
+            .position(|node| node.node_or_token == self.node_or_token)
+            .unwrap_or(0)
+    }
+
+    /// Moves the cursor to the n-th descendant of the original node (in preorder), where 0 is the original node itself
+    pub fn goto_descendant(&mut self, descendant_index: usize) {
+        self.node_or_token = self
+            .root_node()
+            .descendants()
+            .nth(descendant_index)
+            .map_or(self.root, |node| node.node_or_token);
+    }
+
     pub fn goto_first_child(&mut self) -> bool {
         if let Some(current_node) = self.node_or_token.as_node() {
-            if let Some(child) = current_node.first_child_or_token() {
+            if let Some(child) = self.context.options.first_child(current_node) {
                 self.node_or_token = child;
                 return true;
             }
@@ -196,8 +687,34 @@ impl<'a> TreeCursor<'a> {
         false
     }
 
+    pub fn goto_last_child(&mut self) -> bool {
+        if let Some(current_node) = self.node_or_token.as_node() {
+            if let Some(child) = self.context.options.last_child(current_node) {
+                self.node_or_token = child;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Moves the cursor to the first child that extends beyond the given byte offset
+    /// Returns the index of the child node if one was found
+    pub fn goto_first_child_for_byte(&mut self, index: usize) -> Option<usize> {
+        let node = self.node();
+        let (i, child) = node
+            .children_iter()
+            .enumerate()
+            .find(|(_, child)| child.end_byte() > index)?;
+        self.node_or_token = child.node_or_token;
+        Some(i)
+    }
+
     pub fn goto_parent(&mut self) -> bool {
-        if let Some(parent) = self.node_or_token.parent() {
+        if self.node_or_token == self.root {
+            return false;
+        }
+
+        if let Some(parent) = self.context.options.parent(self.node_or_token, self.stop()) {
             self.node_or_token = NodeOrToken::Node(parent);
             true
         } else {
@@ -206,7 +723,32 @@ impl<'a> TreeCursor<'a> {
     }
 
     pub fn goto_next_sibling(&mut self) -> bool {
-        if let Some(sibling) = self.node_or_token.next_sibling_or_token() {
+        if self.node_or_token == self.root {
+            return false;
+        }
+
+        if let Some(sibling) = self
+            .context
+            .options
+            .next_sibling(self.node_or_token, self.stop())
+        {
+            self.node_or_token = sibling;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn goto_previous_sibling(&mut self) -> bool {
+        if self.node_or_token == self.root {
+            return false;
+        }
+
+        if let Some(sibling) = self
+            .context
+            .options
+            .prev_sibling(self.node_or_token, self.stop())
+        {
             self.node_or_token = sibling;
             true
         } else {
@@ -222,21 +764,23 @@ impl<'a> TreeCursor<'a> {
     }
 }
 
-pub fn as_tree_sitter_cursor<'a>(
-    input: &'a str,
-    node: &'a ResolvedNode,
-    range_map: HashMap<TextRange, Range>,
-) -> TreeCursor<'a> {
+/// Creates a tree-sitter-like cursor over a CST node, with the default [`ConversionOptions`]
+pub fn as_tree_sitter_cursor<'a>(input: &'a str, node: &'a ResolvedNode) -> TreeCursor<'a> {
     TreeCursor {
         input,
-        range_map: Rc::new(range_map),
+        context: Rc::new(Context::new(input, ConversionOptions::default())),
         node_or_token: NodeOrToken::Node(node),
+        root: NodeOrToken::Node(node),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{syntax_kind::SyntaxKind, tree_sitter::parse};
+    use crate::{
+        syntax_kind::SyntaxKind,
+        tree_sitter::{parse, parse_with_parse_options},
+        ParseOptions,
+    };
 
     #[test]
     fn empty_src_range() {
@@ -334,6 +878,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::if_same_then_else)]
     fn range_multiple_line() {
         let src = r#"
 select
@@ -441,6 +986,28 @@ from
         assert!(cursor.node().is_comment());
     }
 
+    #[test]
+    fn test_psql_extras() {
+        let mut parse_options = ParseOptions::default();
+        parse_options.lexer.psql = true;
+        let src = "\\set x 1\ncopy t from stdin;\n1\n\\.\n";
+        let tree = parse_with_parse_options(src, &parse_options, &Default::default()).unwrap();
+
+        let extras: Vec<_> = tree
+            .root_node()
+            .children_iter()
+            .filter(|node| node.is_extra())
+            .map(|node| (node.kind(), node.is_comment()))
+            .collect();
+        assert_eq!(
+            extras,
+            [
+                (SyntaxKind::PSQL_META_COMMAND, false),
+                (SyntaxKind::COPY_DATA, false)
+            ]
+        );
+    }
+
     #[test]
     fn test_multiple_statements() {
         let src = "SELECT 1; SELECT 2;";
@@ -462,4 +1029,194 @@ from
 
         assert_eq!(stmt_count, 2);
     }
+
+    #[test]
+    fn test_field_names() {
+        let src = "select a + 1 from t where a > 1;";
+        let tree = parse(src).unwrap();
+        let select = tree.root_node().child(0).unwrap().child(0).unwrap();
+        assert_eq!(select.kind(), SyntaxKind::select_no_parens);
+
+        let where_clause = select.child_by_field_name("where").unwrap();
+        assert_eq!(where_clause.kind(), SyntaxKind::where_clause);
+        assert_eq!(where_clause.text(), "where a > 1");
+        assert_eq!(
+            select.child_by_field_name("targets").unwrap().kind(),
+            SyntaxKind::target_list
+        );
+        assert!(select.child_by_field_name("having").is_none());
+
+        let fields: Vec<_> = (0..select.child_count())
+            .map(|i| select.field_name_for_child(i))
+            .collect();
+        assert_eq!(fields, [None, Some("targets"), Some("from"), Some("where")]);
+
+        let condition = where_clause.child_by_field_name("condition").unwrap();
+        assert_eq!(condition.child_by_field_name("left").unwrap().text(), "a");
+        assert_eq!(condition.child_by_field_name("right").unwrap().text(), "1");
+        assert_eq!(condition.children_by_field_name("right").count(), 1);
+
+        let mut cursor = where_clause.walk();
+        assert_eq!(cursor.field_name(), None);
+        assert!(cursor.goto_first_child());
+        assert_eq!(cursor.field_name(), None);
+        assert!(cursor.goto_next_sibling());
+        assert_eq!(cursor.field_name(), Some("condition"));
+    }
+
+    #[test]
+    fn test_child_access() {
+        let src = "select a, b from t;";
+        let tree = parse(src).unwrap();
+        let root = tree.root_node();
+
+        assert_eq!(root.child(0).unwrap().kind(), SyntaxKind::SelectStmt);
+        assert!(root.child(root.child_count()).is_none());
+
+        let select_stmt = root.child(0).unwrap().child(0).unwrap();
+        assert_eq!(select_stmt.kind(), SyntaxKind::select_no_parens);
+
+        // SELECT is anonymous, target_list is named
+        assert_eq!(select_stmt.child(0).unwrap().kind(), SyntaxKind::SELECT);
+        assert!(!select_stmt.child(0).unwrap().is_named());
+        assert_eq!(
+            select_stmt.named_child(0).unwrap().kind(),
+            SyntaxKind::target_list
+        );
+        assert!(select_stmt.named_child_count() < select_stmt.child_count());
+
+        let mut cursor = tree.walk();
+        let kinds: Vec<_> = select_stmt
+            .children(&mut cursor)
+            .map(|child| child.kind())
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                SyntaxKind::SELECT,
+                SyntaxKind::target_list,
+                SyntaxKind::from_clause
+            ]
+        );
+
+        let named_kinds: Vec<_> = select_stmt
+            .named_children(&mut cursor)
+            .map(|child| child.kind())
+            .collect();
+        assert_eq!(
+            named_kinds,
+            [SyntaxKind::target_list, SyntaxKind::from_clause]
+        );
+    }
+
+    #[test]
+    fn test_siblings() {
+        let src = "select a, b;";
+        let tree = parse(src).unwrap();
+        let target_list = tree.root_node().descendant_for_byte_range(7, 11).unwrap();
+        assert_eq!(target_list.kind(), SyntaxKind::target_list);
+
+        let first = target_list.named_child(0).unwrap();
+        let comma = first.next_sibling().unwrap();
+        assert_eq!(comma.kind(), SyntaxKind::Comma);
+        assert_eq!(comma.prev_sibling().unwrap(), first);
+
+        let second = first.next_named_sibling().unwrap();
+        assert_eq!(second.text(), "b");
+        assert_eq!(second.prev_named_sibling().unwrap(), first);
+        assert!(first.prev_sibling().is_none());
+    }
+
+    #[test]
+    fn test_descendant_for_byte_range() {
+        let src = "select abc, 1 from t;";
+        let tree = parse(src).unwrap();
+        let root = tree.root_node();
+
+        let token = root.descendant_for_byte_range(8, 9).unwrap();
+        assert_eq!(token.kind(), SyntaxKind::IDENT);
+        assert_eq!(token.text(), "abc");
+        assert_eq!(token.byte_range(), 7..10);
+
+        let named = root.named_descendant_for_byte_range(0, 3).unwrap();
+        assert_eq!(named.kind(), SyntaxKind::select_no_parens);
+
+        assert!(root.descendant_for_byte_range(0, 100).is_none());
+    }
+
+    #[test]
+    fn test_to_sexp_and_id() {
+        let src = "select 1;";
+        let tree = parse(src).unwrap();
+        let root = tree.root_node();
+
+        assert_eq!(
+            root.to_sexp(),
+            "(Root (SelectStmt (select_no_parens (target_list (target_el (a_expr (c_expr (AexprConst (Iconst (ICONST))))))))))"
+        );
+
+        let a = root.child(0).unwrap();
+        let b = root.child(0).unwrap();
+        assert_eq!(a.id(), b.id());
+        assert_ne!(a.id(), root.id());
+    }
+
+    #[test]
+    fn test_missing_node() {
+        let src = "select 1, /*param*/ ;";
+        let tree = crate::tree_sitter::parse_2way(src).unwrap();
+        let root = tree.root_node();
+        assert!(root.has_error());
+
+        let missing = root.descendants().find(|node| node.is_missing());
+        assert_eq!(missing.map(|node| node.kind()), Some(SyntaxKind::SCONST));
+
+        let tree = parse("select 1;").unwrap();
+        assert!(!tree.root_node().has_error());
+    }
+
+    #[test]
+    fn test_utf8_text() {
+        let src = "select 'あ';";
+        let tree = parse(src).unwrap();
+        let sconst = tree.root_node().descendant_for_byte_range(7, 12).unwrap();
+        assert_eq!(sconst.utf8_text(src.as_bytes()).unwrap(), "'あ'");
+    }
+
+    #[test]
+    fn test_cursor_api() {
+        let src = "select a, b;";
+        let tree = parse(src).unwrap();
+        let mut cursor = tree.walk();
+        assert_eq!(cursor.depth(), 0);
+
+        assert!(cursor.goto_first_child());
+        assert_eq!(cursor.depth(), 1);
+        assert!(cursor.goto_first_child());
+        assert!(cursor.goto_last_child());
+        assert_eq!(cursor.node().kind(), SyntaxKind::target_list);
+
+        assert_eq!(cursor.goto_first_child_for_byte(8), Some(1));
+        assert_eq!(cursor.node().kind(), SyntaxKind::Comma);
+        assert!(cursor.goto_previous_sibling());
+        assert_eq!(cursor.node().text(), "a");
+        assert!(!cursor.goto_previous_sibling());
+        assert_eq!(cursor.depth(), 4);
+
+        let index = cursor.descendant_index();
+        cursor.goto_descendant(0);
+        assert_eq!(cursor.node().kind(), SyntaxKind::Root);
+        cursor.goto_descendant(index);
+        assert_eq!(cursor.node().text(), "a");
+
+        // A cursor does not move outside of the node it was created from
+        let target_list = tree.root_node().descendant_for_byte_range(7, 11).unwrap();
+        cursor.reset(target_list);
+        assert_eq!(cursor.depth(), 0);
+        assert!(!cursor.goto_parent());
+        assert!(!cursor.goto_next_sibling());
+        assert!(cursor.goto_first_child());
+        assert!(cursor.goto_parent());
+        assert_eq!(cursor.node().kind(), SyntaxKind::target_list);
+    }
 }
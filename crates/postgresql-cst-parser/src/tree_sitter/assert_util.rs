@@ -1,7 +1,14 @@
-use crate::{syntax_kind::SyntaxKind, ResolvedNode};
+use crate::syntax_kind::SyntaxKind;
+
+use super::{parse_with_options, ConversionOptions, Node, Tree};
+
+/// Parses `input` into a tree without any conversion, which has the same structure as the CST.
+pub fn parse_raw(input: &str) -> Tree {
+    parse_with_options(input, &ConversionOptions::empty().keep_whitespace(true)).unwrap()
+}
 
 /// Asserts that there is at least one node of the specified `SyntaxKind` in the given syntax tree.
-pub fn assert_exists(root: &ResolvedNode, kind: SyntaxKind) {
+pub fn assert_exists(root: &Node, kind: SyntaxKind) {
     let exists = root.descendants().any(|node| node.kind() == kind);
     assert!(
         exists,
@@ -10,7 +17,7 @@ pub fn assert_exists(root: &ResolvedNode, kind: SyntaxKind) {
 }
 
 /// Asserts that there are no nodes of the specified `SyntaxKind` in the given syntax tree.
-pub fn assert_not_exists(root: &ResolvedNode, kind: SyntaxKind) {
+pub fn assert_not_exists(root: &Node, kind: SyntaxKind) {
     let exists = root.descendants().any(|node| node.kind() == kind);
     assert!(
         !exists,
@@ -19,7 +26,7 @@ pub fn assert_not_exists(root: &ResolvedNode, kind: SyntaxKind) {
 }
 
 /// Asserts that the exact number of nodes of the specified `SyntaxKind` matches the given count.
-pub fn assert_node_count(root: &ResolvedNode, kind: SyntaxKind, expected_count: usize) {
+pub fn assert_node_count(root: &Node, kind: SyntaxKind, expected_count: usize) {
     let actual_count = root
         .descendants()
         .filter(|node| node.kind() == kind)
@@ -32,14 +39,15 @@ pub fn assert_node_count(root: &ResolvedNode, kind: SyntaxKind, expected_count:
 
 /// Asserts that there are no directly nested nodes of the specified `SyntaxKind`.
 /// In other words, a node of `kind` cannot have another `kind` node as its immediate child.
-pub fn assert_no_direct_nested_kind(root: &ResolvedNode, kind: SyntaxKind) {
+pub fn assert_no_direct_nested_kind(root: &Node, kind: SyntaxKind) {
     let target_nodes = root.descendants().filter(|node| node.kind() == kind);
 
     for node in target_nodes {
         if let Some(parent) = node.parent() {
             assert!(
                 !(node.kind() == kind && parent.kind() == kind),
-                "Found a `{parent:?}` node that directly contains another {kind:?} node as a child."
+                "Found a `{:?}` node that directly contains another {kind:?} node as a child.",
+                parent.node_or_token.as_node().unwrap()
             )
         }
     }
@@ -47,7 +55,7 @@ pub fn assert_no_direct_nested_kind(root: &ResolvedNode, kind: SyntaxKind) {
 
 /// Asserts that there is at least one directly nested node of the specified `SyntaxKind`.
 /// In other words, there must be a node of `kind` that has another `kind` node as its immediate child.
-pub fn assert_direct_nested_kind(root: &ResolvedNode, kind: SyntaxKind) {
+pub fn assert_direct_nested_kind(root: &Node, kind: SyntaxKind) {
     let has_direct_nesting = root
         .descendants()
         .filter(|node| node.kind() == kind)
@@ -68,12 +76,12 @@ pub fn assert_direct_nested_kind(root: &ResolvedNode, kind: SyntaxKind) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::cst;
 
     #[test]
     fn test_assert_exists_passes() {
         let input = "select a, b, c from t;";
-        let root = cst::parse(input).unwrap();
+        let tree = parse_raw(input);
+        let root = tree.root_node();
         assert_exists(&root, SyntaxKind::SelectStmt);
         assert_exists(&root, SyntaxKind::from_clause);
     }
@@ -82,14 +90,16 @@ mod tests {
     #[should_panic(expected = "Expected at least one node of kind InsertStmt, but none was found.")]
     fn test_assert_exists_fails() {
         let input = "select a, b, c from t;";
-        let root = cst::parse(input).unwrap();
+        let tree = parse_raw(input);
+        let root = tree.root_node();
         assert_exists(&root, SyntaxKind::InsertStmt);
     }
 
     #[test]
     fn test_assert_not_exists_passes() {
         let input = "select a, b, c from t;";
-        let root = cst::parse(input).unwrap();
+        let tree = parse_raw(input);
+        let root = tree.root_node();
         assert_not_exists(&root, SyntaxKind::InsertStmt);
         assert_not_exists(&root, SyntaxKind::with_clause);
     }
@@ -98,13 +108,15 @@ mod tests {
     #[should_panic(expected = "Expected no nodes of kind from_clause, but at least one was found.")]
     fn test_assert_not_exists_fails() {
         let input = "select a, b, c from t;";
-        let root = cst::parse(input).unwrap();
+        let tree = parse_raw(input);
+        let root = tree.root_node();
         assert_not_exists(&root, SyntaxKind::from_clause);
     }
     #[test]
     fn test_assert_node_count_passes() {
         let input = "select a, b, c from t;";
-        let root = cst::parse(input).unwrap();
+        let tree = parse_raw(input);
+        let root = tree.root_node();
 
         assert_node_count(&root, SyntaxKind::SelectStmt, 1);
         assert_node_count(&root, SyntaxKind::target_el, 3);
@@ -115,7 +127,8 @@ mod tests {
     #[should_panic(expected = "Expected 0 nodes of kind SelectStmt, but found 1.")]
     fn test_assert_node_count_fails() {
         let input = "select a, b, c from t;";
-        let root = cst::parse(input).unwrap();
+        let tree = parse_raw(input);
+        let root = tree.root_node();
 
         assert_node_count(&root, SyntaxKind::SelectStmt, 0);
     }
@@ -123,7 +136,8 @@ mod tests {
     #[test]
     fn test_no_direct_nested_kind_passes() {
         let input = "select a;";
-        let root = cst::parse(input).unwrap();
+        let tree = parse_raw(input);
+        let root = tree.root_node();
 
         assert_no_direct_nested_kind(&root, SyntaxKind::target_list);
     }
@@ -134,7 +148,8 @@ mod tests {
     )]
     fn test_no_direct_nested_kind_fails() {
         let input = "select a,b,c;";
-        let root = cst::parse(input).unwrap();
+        let tree = parse_raw(input);
+        let root = tree.root_node();
 
         assert_no_direct_nested_kind(&root, SyntaxKind::target_list);
     }
@@ -142,7 +157,8 @@ mod tests {
     #[test]
     fn test_direct_nested_kind_passes() {
         let input = "select a,b,c;";
-        let root = cst::parse(input).unwrap();
+        let tree = parse_raw(input);
+        let root = tree.root_node();
 
         assert_direct_nested_kind(&root, SyntaxKind::target_list);
     }
@@ -153,7 +169,8 @@ mod tests {
     )]
     fn test_direct_nested_kind_fails() {
         let input = "select a;";
-        let root = cst::parse(input).unwrap();
+        let tree = parse_raw(input);
+        let root = tree.root_node();
 
         assert_direct_nested_kind(&root, SyntaxKind::SelectStmt);
     }
@@ -0,0 +1,377 @@
+use std::rc::Rc;
+
+use crate::{cst, syntax_kind::SyntaxKind, ParserError, TWO_WAY_TRANSFORMERS};
+
+use super::{Point, Range, Tree};
+
+// https://github.com/tree-sitter/tree-sitter/blob/90666c951d53c13cc6cf5002d971a6debed74244/lib/binding_rust/lib.rs#L90-L99
+/// A summary of a change to a text document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputEdit {
+    pub start_byte: usize,
+    pub old_end_byte: usize,
+    pub new_end_byte: usize,
+    pub start_position: Point,
+    pub old_end_position: Point,
+    pub new_end_position: Point,
+}
+
+impl InputEdit {
+    /// Moves a position of the old text to the edited text, in the same way as `ts_node_edit`
+    ///
+    /// Positions after the removed text are shifted, and positions inside it are moved to the end of the inserted text.
+    pub(super) fn apply(&self, byte: usize, point: Point) -> (usize, Point) {
+        if byte >= self.old_end_byte {
+            (
+                self.new_end_byte + (byte - self.old_end_byte),
+                point_add(
+                    self.new_end_position,
+                    point_sub(point, self.old_end_position),
+                ),
+            )
+        } else if byte > self.start_byte {
+            (self.new_end_byte, self.new_end_position)
+        } else {
+            (byte, point)
+        }
+    }
+}
+
+fn point_add(a: Point, b: Point) -> Point {
+    if b.row > 0 {
+        Point {
+            row: a.row + b.row,
+            column: b.column,
+        }
+    } else {
+        Point {
+            row: a.row,
+            column: a.column + b.column,
+        }
+    }
+}
+
+fn point_sub(a: Point, b: Point) -> Point {
+    if a.row > b.row {
+        Point {
+            row: a.row - b.row,
+            column: a.column,
+        }
+    } else {
+        Point {
+            row: 0,
+            column: a.column.saturating_sub(b.column),
+        }
+    }
+}
+
+fn point_at(src: &str, byte: usize) -> Point {
+    let before = &src[..byte];
+    match before.rfind('\n') {
+        Some(i) => Point {
+            row: before.matches('\n').count(),
+            column: byte - (i + 1),
+        },
+        None => Point {
+            row: 0,
+            column: byte,
+        },
+    }
+}
+
+/// A token compared by [`Tree::changed_ranges`]
+#[derive(PartialEq, Eq)]
+struct TokenSignature<'a> {
+    start_byte: usize,
+    end_byte: usize,
+    kind: SyntaxKind,
+    text: &'a str,
+    ancestors: Vec<SyntaxKind>,
+}
+
+impl Tree {
+    /// Edits the tree to keep it in sync with the source code that has been edited
+    ///
+    /// The ranges of the nodes are shifted, but the source held by the tree is not updated,
+    /// so [`super::Node::text`] still returns the text of the source the tree was parsed from.
+    /// Use [`super::Node::utf8_text`] with the edited source, or call [`Tree::reparse`] to get a tree for it.
+    pub fn edit(&mut self, edit: &InputEdit) {
+        Rc::make_mut(&mut self.context).edit(&self.root, edit);
+    }
+
+    /// Parses the edited source with the same mode, [`crate::ParseOptions`] and [`super::ConversionOptions`] as this tree
+    ///
+    /// The leading statements that end before the first changed byte are copied from this tree instead of being parsed again.
+    /// Pass the result to [`Tree::changed_ranges`] together with this (edited) tree to find the ranges to update.
+    pub fn reparse(&self, input: &str) -> Result<Tree, ParserError> {
+        let transformers = if self.is_2way {
+            TWO_WAY_TRANSFORMERS
+        } else {
+            &[]
+        };
+        let parsed = cst::reparse(
+            &self.root,
+            &self.src,
+            input,
+            transformers,
+            &self.parse_options,
+        )?;
+
+        Ok(Tree::new(input, parsed).with_parse_settings(
+            self.context.options.clone(),
+            &self.parse_options,
+            self.is_2way,
+        ))
+    }
+
+    /// Returns the ranges whose syntactic structure differs between an edited old tree and a new tree
+    ///
+    /// A token is unchanged if it has the same range, kind, text and ancestor kinds in both trees.
+    /// The ranges are in the coordinates of the new tree.
+    pub fn changed_ranges(&self, new_tree: &Tree) -> Vec<Range> {
+        let old_tokens = self.token_signatures();
+        let new_tokens = new_tree.token_signatures();
+
+        let mut changed: Vec<(usize, usize)> = vec![];
+        let mut push = |start: usize, end: usize| match changed.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => changed.push((start, end)),
+        };
+
+        let (mut i, mut j) = (0, 0);
+        while i < old_tokens.len() || j < new_tokens.len() {
+            match (old_tokens.get(i), new_tokens.get(j)) {
+                (Some(old), Some(new)) if old == new => {
+                    i += 1;
+                    j += 1;
+                }
+                (Some(old), Some(new)) => {
+                    if old.start_byte <= new.start_byte {
+                        push(old.start_byte, old.end_byte.max(new.start_byte));
+                        i += 1;
+                    } else {
+                        push(new.start_byte, new.end_byte.max(old.start_byte));
+                        j += 1;
+                    }
+                }
+                (Some(old), None) => {
+                    push(old.start_byte, old.end_byte);
+                    i += 1;
+                }
+                (None, Some(new)) => {
+                    push(new.start_byte, new.end_byte);
+                    j += 1;
+                }
+                (None, None) => unreachable!(),
+            }
+        }
+
+        let src = &new_tree.src;
+        changed
+            .into_iter()
+            .map(|(start, end)| {
+                let start = start.min(src.len());
+                let end = end.min(src.len());
+                Range {
+                    start_byte: start,
+                    end_byte: end,
+                    start_position: point_at(src, start),
+                    end_position: point_at(src, end),
+                }
+            })
+            .collect()
+    }
+
+    fn token_signatures(&self) -> Vec<TokenSignature<'_>> {
+        self.root_node()
+            .descendants()
+            .filter_map(|node| {
+                let token = node.node_or_token.into_token()?;
+                let range = node.range();
+                Some(TokenSignature {
+                    start_byte: range.start_byte,
+                    end_byte: range.end_byte,
+                    kind: token.kind(),
+                    text: token.text(),
+                    ancestors: std::iter::successors(node.parent(), |parent| parent.parent())
+                        .map(|parent| parent.kind())
+                        .collect(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tree_sitter::{parse, parse_2way, parse_with_parse_options, ConversionOptions, Point},
+        ParseOptions,
+    };
+
+    use super::InputEdit;
+
+    fn replace(src: &str, start: usize, old_end: usize, text: &str) -> (String, InputEdit) {
+        let new_src = format!("{}{}{}", &src[..start], text, &src[old_end..]);
+        let edit = InputEdit {
+            start_byte: start,
+            old_end_byte: old_end,
+            new_end_byte: start + text.len(),
+            start_position: super::point_at(src, start),
+            old_end_position: super::point_at(src, old_end),
+            new_end_position: super::point_at(&new_src, start + text.len()),
+        };
+        (new_src, edit)
+    }
+
+    #[test]
+    fn edit_shifts_ranges() {
+        let src = "select a,\n  b from t;";
+        let mut tree = parse(src).unwrap();
+
+        // "a" -> "abc"
+        let (new_src, edit) = replace(src, 7, 8, "abc");
+        tree.edit(&edit);
+
+        let root = tree.root_node();
+        let from = root
+            .descendant_for_byte_range(
+                new_src.find("from").unwrap(),
+                new_src.find("from").unwrap() + 4,
+            )
+            .unwrap();
+        assert_eq!(from.utf8_text(new_src.as_bytes()).unwrap(), "from");
+        assert_eq!(from.start_position(), Point { row: 1, column: 4 });
+
+        let a = root.descendant_for_byte_range(7, 7).unwrap();
+        assert_eq!(a.byte_range(), 7..10);
+        assert_eq!(a.utf8_text(new_src.as_bytes()).unwrap(), "abc");
+    }
+
+    #[test]
+    fn edit_across_lines() {
+        let src = "select a,\n  b\nfrom t;";
+        let mut tree = parse(src).unwrap();
+
+        // remove the line break before "b"
+        let (new_src, edit) = replace(src, 9, 12, " ");
+        tree.edit(&edit);
+
+        let t = tree
+            .root_node()
+            .descendant_for_byte_range(new_src.len() - 2, new_src.len() - 1)
+            .unwrap();
+        assert_eq!(t.utf8_text(new_src.as_bytes()).unwrap(), "t");
+        assert_eq!(t.start_position(), Point { row: 1, column: 5 });
+    }
+
+    #[test]
+    fn edits_in_sequence() {
+        let src = "select a from t;";
+        let mut tree = parse(src).unwrap();
+
+        // "a" -> "a, b", then "t" -> "tbl"
+        let (src, edit) = replace(src, 8, 8, ", b");
+        tree.edit(&edit);
+        let start = src.rfind('t').unwrap();
+        let (new_src, edit) = replace(&src, start, start + 1, "tbl");
+        tree.edit(&edit);
+
+        let root = tree.root_node();
+        let table = root.descendant_for_byte_range(start, start).unwrap();
+        assert_eq!(table.byte_range(), start..start + 3);
+        assert_eq!(table.utf8_text(new_src.as_bytes()).unwrap(), "tbl");
+        assert_eq!(root.byte_range(), 0..new_src.len());
+    }
+
+    #[test]
+    fn reparse_and_changed_ranges() {
+        let src = "select a from t;\nselect b from u;";
+        let mut tree = parse(src).unwrap();
+
+        // "u" -> "u where b = 1"
+        let start = src.rfind('u').unwrap();
+        let (new_src, edit) = replace(src, start + 1, start + 1, " where b = 1");
+        tree.edit(&edit);
+        let new_tree = tree.reparse(&new_src).unwrap();
+
+        let ranges = tree.changed_ranges(&new_tree);
+        assert_eq!(ranges.len(), 1);
+        // The first statement is not changed
+        assert!(ranges[0].start_byte > src.find(';').unwrap());
+        assert_eq!(ranges[0].start_position.row, 1);
+        assert!(new_src[ranges[0].start_byte..ranges[0].end_byte].contains("where b = 1"));
+
+        assert!(new_tree
+            .changed_ranges(&new_tree.reparse(&new_src).unwrap())
+            .is_empty());
+    }
+
+    fn dump(tree: &crate::tree_sitter::Tree) -> String {
+        format!("{:#?}", tree.root)
+    }
+
+    #[test]
+    fn reparse_reuses_leading_statements() {
+        let src = "-- head\nselect a from t; /* c */ select b\nfrom u;\nselect c;";
+        let tree = parse(src).unwrap();
+
+        for (start, old_end, text) in [
+            (src.rfind('c').unwrap(), src.len() - 1, "c, d"),
+            (
+                src.find('u').unwrap(),
+                src.find('u').unwrap() + 1,
+                "u where b = 1",
+            ),
+            (src.find('a').unwrap(), src.find('a').unwrap() + 1, "x"),
+            (0, 0, "select 1;"),
+            (src.len(), src.len(), " select 2;"),
+        ] {
+            let (new_src, _) = replace(src, start, old_end, text);
+            let reparsed = tree.reparse(&new_src).unwrap();
+            assert_eq!(
+                dump(&reparsed),
+                dump(&parse(&new_src).unwrap()),
+                "{new_src}"
+            );
+        }
+    }
+
+    #[test]
+    fn reparse_2way_reuses_leading_statements() {
+        let src = "select /*param*/ as a\nfrom /*#foo*/\n;\nselect distinct , t.* from tbl t;";
+        let tree = parse_2way(src).unwrap();
+
+        let (new_src, _) = replace(src, src.len() - 2, src.len() - 1, "v");
+        let reparsed = tree.reparse(&new_src).unwrap();
+        assert_eq!(dump(&reparsed), dump(&parse_2way(&new_src).unwrap()));
+    }
+
+    #[test]
+    fn reparse_with_parse_options() {
+        let mut parse_options = ParseOptions::default();
+        parse_options.lexer.standard_conforming_strings = false;
+
+        let src = r"select 'It\'s';";
+        assert!(parse(src).is_err());
+        let tree =
+            parse_with_parse_options(src, &parse_options, &ConversionOptions::default()).unwrap();
+
+        let (new_src, _) = replace(src, src.len() - 1, src.len(), r"; select 'a\'b';");
+        assert!(tree.reparse(&new_src).is_ok());
+    }
+
+    #[test]
+    fn changed_ranges_for_renamed_token() {
+        let src = "select a from t;";
+        let mut tree = parse_2way(src).unwrap();
+
+        let (new_src, edit) = replace(src, 14, 15, "x");
+        tree.edit(&edit);
+        let new_tree = tree.reparse(&new_src).unwrap();
+
+        let ranges = tree.changed_ranges(&new_tree);
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0].start_byte..ranges[0].end_byte, 14..15);
+    }
+}
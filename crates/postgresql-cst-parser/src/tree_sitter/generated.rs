@@ -0,0 +1,52 @@
+//! Rules of the tree-sitter-like tree, generated by the parser generator from `resources/tree_sitter.txt`
+
+use crate::syntax_kind::SyntaxKind;
+
+/// Tokens that are named nodes
+pub(crate) const NAMED_TOKENS: &[SyntaxKind] = &[
+    SyntaxKind::IDENT,
+    SyntaxKind::SCONST,
+    SyntaxKind::ICONST,
+    SyntaxKind::FCONST,
+    SyntaxKind::BCONST,
+    SyntaxKind::XCONST,
+    SyntaxKind::PARAM,
+    SyntaxKind::Op,
+];
+
+/// Kinds whose directly nested nodes are flattened by default
+pub(crate) const DEFAULT_FLATTEN_KINDS: &[SyntaxKind] = &[
+    SyntaxKind::target_list,
+    SyntaxKind::from_list,
+    SyntaxKind::indirection,
+    SyntaxKind::expr_list,
+    SyntaxKind::func_arg_list,
+    SyntaxKind::when_clause_list,
+    SyntaxKind::group_by_list,
+    SyntaxKind::sortby_list,
+    SyntaxKind::qualified_name_list,
+    SyntaxKind::for_locking_items,
+    SyntaxKind::cte_list,
+    SyntaxKind::name_list,
+    SyntaxKind::set_clause_list,
+    SyntaxKind::set_target_list,
+    SyntaxKind::insert_column_list,
+    SyntaxKind::index_params,
+    SyntaxKind::values_clause,
+    SyntaxKind::TableFuncElementList,
+    SyntaxKind::array_expr_list,
+];
+
+/// Kinds whose nodes are removed by default
+pub(crate) const DEFAULT_REMOVE_KINDS: &[SyntaxKind] = &[
+    SyntaxKind::parse_toplevel,
+    SyntaxKind::stmtmulti,
+    SyntaxKind::toplevel_stmt,
+    SyntaxKind::stmt,
+    SyntaxKind::simple_select,
+    SyntaxKind::select_clause,
+    SyntaxKind::opt_select_limit,
+    SyntaxKind::opt_target_list,
+    SyntaxKind::opt_sort_clause,
+    SyntaxKind::select_limit,
+];
@@ -0,0 +1,150 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use cstree::{RawSyntaxKind, Syntax};
+
+use crate::syntax_kind::SyntaxKind;
+
+use super::is_named_kind;
+
+/// Metadata of the node kinds, like `tree_sitter::Language`
+///
+/// The id of a node kind is the value of its [`SyntaxKind`].
+///
+/// # Examples
+///
+/// ```
+/// use postgresql_cst_parser::tree_sitter::LANGUAGE;
+///
+/// let id = LANGUAGE.id_for_node_kind("SelectStmt", true).unwrap();
+/// assert_eq!(LANGUAGE.node_kind_for_id(id), Some("SelectStmt"));
+/// assert!(LANGUAGE.node_kind_is_named(id));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Language {
+    _private: (),
+}
+
+/// The PostgreSQL language
+pub const LANGUAGE: Language = Language { _private: () };
+
+impl Language {
+    /// Returns the number of node kinds
+    pub fn node_kind_count(&self) -> usize {
+        SyntaxKind::Root as usize + 1
+    }
+
+    /// Returns the name of the node kind for the id
+    pub fn node_kind_for_id(&self, id: u16) -> Option<&'static str> {
+        kind_names().get(id as usize).map(|name| name.as_str())
+    }
+
+    /// Returns the id of the node kind with the name
+    ///
+    /// Returns `None` if there is no such kind, or it is not named as specified.
+    pub fn id_for_node_kind(&self, kind: &str, named: bool) -> Option<u16> {
+        static IDS: OnceLock<HashMap<&'static str, u16>> = OnceLock::new();
+
+        let id = *IDS
+            .get_or_init(|| {
+                kind_names()
+                    .iter()
+                    .enumerate()
+                    .map(|(id, name)| (name.as_str(), id as u16))
+                    .collect()
+            })
+            .get(kind)?;
+
+        (self.node_kind_is_named(id) == named).then_some(id)
+    }
+
+    /// Whether nodes of the kind are named nodes
+    pub fn node_kind_is_named(&self, id: u16) -> bool {
+        self.syntax_kind_for_id(id).is_some_and(is_named_kind)
+    }
+
+    /// Returns the [`SyntaxKind`] for the id
+    pub fn syntax_kind_for_id(&self, id: u16) -> Option<SyntaxKind> {
+        ((id as usize) < self.node_kind_count())
+            .then(|| SyntaxKind::from_raw(RawSyntaxKind(id as u32)))
+    }
+
+    /// Returns `node-types.json` in the format of tree-sitter
+    ///
+    /// The file is generated by the parser generator from the grammar and the default rules of [`super::ConversionOptions`],
+    /// so it describes the children of nodes in the tree-sitter-like tree. The removed kinds, which do not appear in the tree, are not listed.
+    pub fn node_types_json(&self) -> &'static str {
+        include_str!("node-types.json")
+    }
+}
+
+fn kind_names() -> &'static [String] {
+    static NAMES: OnceLock<Vec<String>> = OnceLock::new();
+
+    NAMES.get_or_init(|| {
+        (0..=SyntaxKind::Root as u32)
+            .map(|raw| format!("{:?}", SyntaxKind::from_raw(RawSyntaxKind(raw))))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{syntax_kind::SyntaxKind, tree_sitter::generated::DEFAULT_REMOVE_KINDS};
+
+    use super::LANGUAGE;
+
+    #[test]
+    fn node_kinds() {
+        assert_eq!(LANGUAGE.node_kind_count(), SyntaxKind::Root as usize + 1);
+
+        for id in 0..LANGUAGE.node_kind_count() as u16 {
+            let name = LANGUAGE.node_kind_for_id(id).unwrap();
+            let named = LANGUAGE.node_kind_is_named(id);
+            assert_eq!(LANGUAGE.id_for_node_kind(name, named), Some(id));
+            assert_eq!(LANGUAGE.id_for_node_kind(name, !named), None);
+        }
+
+        let count = LANGUAGE.node_kind_count() as u16;
+        assert_eq!(LANGUAGE.node_kind_for_id(count), None);
+        assert!(!LANGUAGE.node_kind_is_named(count));
+        assert_eq!(LANGUAGE.id_for_node_kind("no_such_kind", true), None);
+
+        let select = LANGUAGE.id_for_node_kind("SELECT", false).unwrap();
+        assert_eq!(
+            LANGUAGE.syntax_kind_for_id(select),
+            Some(SyntaxKind::SELECT)
+        );
+        assert!(LANGUAGE.id_for_node_kind("IDENT", true).is_some());
+    }
+
+    #[test]
+    fn node_types_json() {
+        let json = LANGUAGE.node_types_json();
+        let entries: Vec<_> = json
+            .lines()
+            .filter(|line| line.starts_with("{\"type\":"))
+            .collect();
+        let ids: Vec<_> = (0..LANGUAGE.node_kind_count() as u16)
+            .filter(|&id| !DEFAULT_REMOVE_KINDS.contains(&LANGUAGE.syntax_kind_for_id(id).unwrap()))
+            .collect();
+        assert_eq!(entries.len(), ids.len());
+
+        for (&id, entry) in ids.iter().zip(&entries) {
+            let prefix = format!(
+                r#"{{"type":"{}","named":{}"#,
+                LANGUAGE.node_kind_for_id(id).unwrap(),
+                LANGUAGE.node_kind_is_named(id)
+            );
+            assert!(entry.starts_with(&prefix), "{entry}");
+        }
+
+        // The children of the removed `simple_select` are those of `select_no_parens`
+        let select = entries
+            .iter()
+            .find(|entry| entry.starts_with(r#"{"type":"select_no_parens","#))
+            .unwrap();
+        assert!(select.contains(r#""where":{"multiple":true,"required":false,"types":[{"type":"where_clause","named":true}]}"#));
+        assert!(!json.contains(r#"{"type":"simple_select","#));
+        assert!(json.contains(r#"{"type":"target_list","named":true,"children":{"multiple":true,"required":true,"types":[{"type":"target_el","named":true}]}}"#));
+    }
+}
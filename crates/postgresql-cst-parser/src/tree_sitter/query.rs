@@ -0,0 +1,1403 @@
+//! A subset of the tree-sitter query language
+//!
+//! ref: https://tree-sitter.github.io/tree-sitter/using-parsers/queries/index.html
+//!
+//! Supported syntax:
+//!   - Node patterns `(kind child...)`, wildcards `(_)` (any named node) and `_` (any node)
+//!   - Anonymous node patterns `"select"` (matches a token by its text, case-insensitively)
+//!   - Captures `@name`
+//!   - Quantifiers `*`, `+`, `?`, alternations `[...]`, groupings `((a) (b))` and anchors `.`
+//!   - Predicates `#eq?`, `#not-eq?`, `#any-of?` and `#not-any-of?`, and `#match?` and `#not-match?` with the `regex-match` feature
+//!
+//!   - Fields `name: pattern`, with the field names of [`crate::fields`]
+//!
+//! Negated fields `!name` are not supported.
+
+use std::{
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    fmt::Display,
+    sync::OnceLock,
+};
+
+use cstree::{text::TextSize, RawSyntaxKind, Syntax};
+#[cfg(feature = "regex-match")]
+use regex::Regex;
+
+use crate::{fields, syntax_kind::SyntaxKind};
+
+use super::Node;
+
+// https://github.com/tree-sitter/tree-sitter/blob/90666c951d53c13cc6cf5002d971a6debed74244/lib/binding_rust/lib.rs#L237-L246
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryErrorKind {
+    Syntax,
+    NodeType,
+    Field,
+    Capture,
+    Predicate,
+    Structure,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryError {
+    pub row: usize,
+    pub column: usize,
+    pub offset: usize,
+    pub message: String,
+    pub kind: QueryErrorKind,
+}
+
+impl Display for QueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.kind {
+            QueryErrorKind::Syntax => "Invalid syntax",
+            QueryErrorKind::NodeType => "Invalid node type",
+            QueryErrorKind::Field => "Invalid field name",
+            QueryErrorKind::Capture => "Invalid capture name",
+            QueryErrorKind::Predicate => "Invalid predicate",
+            QueryErrorKind::Structure => "Impossible pattern",
+        };
+        write!(
+            f,
+            "Query error at {}:{}. {kind}: {}",
+            self.row + 1,
+            self.column + 1,
+            self.message
+        )
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Quantifier {
+    One,
+    ZeroOrOne,
+    ZeroOrMore,
+    OneOrMore,
+}
+
+#[derive(Debug, Clone)]
+enum PatternKind {
+    /// `(kind child...)`. `None` is the wildcard `(_)` that matches any named node
+    Node {
+        kind: Option<SyntaxKind>,
+        children: Vec<Child>,
+        /// A trailing anchor `.` requires the last child pattern to match the last child
+        anchor_end: bool,
+    },
+    /// `"text"`. `None` is the wildcard `_`
+    Anonymous(Option<String>),
+    /// `[pattern...]`
+    Alternation(Vec<Pattern>),
+    /// `(pattern pattern...)`, a sequence of sibling patterns
+    Group(Vec<Child>),
+}
+
+#[derive(Debug, Clone)]
+struct Pattern {
+    kind: PatternKind,
+    captures: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+struct Child {
+    pattern: Pattern,
+    quantifier: Quantifier,
+    /// A preceding anchor `.` requires this pattern to match the next sibling (or the first child)
+    anchored: bool,
+    /// `field: pattern` requires the child to have the field name
+    field: Option<String>,
+}
+
+#[derive(Debug)]
+enum PredicateArg {
+    Capture(u32),
+    String(String),
+}
+
+#[derive(Debug)]
+enum TextPredicate {
+    Eq {
+        capture: u32,
+        value: PredicateArg,
+        negated: bool,
+    },
+    #[cfg(feature = "regex-match")]
+    Match {
+        capture: u32,
+        regex: Regex,
+        negated: bool,
+    },
+    AnyOf {
+        capture: u32,
+        values: Vec<String>,
+        negated: bool,
+    },
+}
+
+#[derive(Debug)]
+struct QueryPattern {
+    pattern: Pattern,
+    predicates: Vec<TextPredicate>,
+    start_byte: usize,
+}
+
+/// A set of patterns that match nodes in a syntax tree
+#[derive(Debug)]
+pub struct Query {
+    patterns: Vec<QueryPattern>,
+    capture_names: Vec<String>,
+}
+
+/// A particular node that has been captured with a particular name within a query
+#[derive(Debug, Clone)]
+pub struct QueryCapture<'tree> {
+    pub node: Node<'tree>,
+    pub index: u32,
+}
+
+/// A match of a pattern to a particular set of nodes
+#[derive(Debug, Clone)]
+pub struct QueryMatch<'tree> {
+    pub pattern_index: usize,
+    pub captures: Vec<QueryCapture<'tree>>,
+}
+
+impl<'tree> QueryMatch<'tree> {
+    /// Returns the nodes captured with the given capture index
+    pub fn nodes_for_capture_index(
+        &self,
+        capture_index: u32,
+    ) -> impl Iterator<Item = &Node<'tree>> {
+        self.captures
+            .iter()
+            .filter(move |c| c.index == capture_index)
+            .map(|c| &c.node)
+    }
+}
+
+/// A stateful object for executing a `Query` on a syntax tree
+#[derive(Debug, Default)]
+pub struct QueryCursor {
+    match_limit: Option<usize>,
+}
+
+impl Query {
+    /// Creates a new query from a string containing one or more S-expression patterns
+    pub fn new(source: &str) -> Result<Query, QueryError> {
+        let mut parser = QueryParser {
+            source,
+            pos: 0,
+            capture_names: Vec::new(),
+        };
+
+        let mut patterns = Vec::new();
+        loop {
+            parser.skip_trivia();
+            if parser.peek().is_none() {
+                break;
+            }
+
+            let start_byte = parser.pos;
+            let mut predicates = Vec::new();
+            let pattern = parser.parse_pattern(&mut predicates, true)?;
+            patterns.push(QueryPattern {
+                pattern,
+                predicates,
+                start_byte,
+            });
+        }
+
+        Ok(Query {
+            patterns,
+            capture_names: parser.capture_names,
+        })
+    }
+
+    /// Returns the names of the captures used in the query
+    pub fn capture_names(&self) -> &[String] {
+        &self.capture_names
+    }
+
+    /// Returns the index of the capture with the given name
+    pub fn capture_index_for_name(&self, name: &str) -> Option<u32> {
+        self.capture_names
+            .iter()
+            .position(|n| n == name)
+            .map(|i| i as u32)
+    }
+
+    /// Returns the number of patterns in the query
+    pub fn pattern_count(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// Returns the byte offset where the given pattern starts in the query's source
+    pub fn start_byte_for_pattern(&self, pattern_index: usize) -> usize {
+        self.patterns[pattern_index].start_byte
+    }
+}
+
+impl QueryCursor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of matches returned by `matches` and `captures`
+    pub fn set_match_limit(&mut self, limit: u32) {
+        self.match_limit = Some(limit as usize);
+    }
+
+    /// Iterates over all of the matches in the order that they were found
+    ///
+    /// Matches are ordered by the position of the node that the pattern matched (preorder), then by the pattern index.
+    pub fn matches<'query, 'tree>(
+        &mut self,
+        query: &'query Query,
+        node: Node<'tree>,
+    ) -> QueryMatches<'query, 'tree> {
+        QueryMatches {
+            query,
+            nodes: Box::new(node.descendants()),
+            pending: VecDeque::new(),
+            remaining: self.match_limit,
+        }
+    }
+
+    /// Iterates over all of the individual captures in the order that they appear
+    ///
+    /// Each item is a match and the index of the capture within `QueryMatch::captures`.
+    pub fn captures<'query, 'tree>(
+        &mut self,
+        query: &'query Query,
+        node: Node<'tree>,
+    ) -> QueryCaptures<'query, 'tree> {
+        QueryCaptures {
+            matches: self.matches(query, node),
+            pending: BinaryHeap::new(),
+            frontier: Some(TextSize::from(0)),
+            count: 0,
+        }
+    }
+}
+
+/// Iterator over the matches of a query, returned by [`QueryCursor::matches`]
+pub struct QueryMatches<'query, 'tree> {
+    query: &'query Query,
+    nodes: Box<dyn Iterator<Item = Node<'tree>> + 'tree>,
+    /// Matches found at the last visited node, with the start of the node
+    pending: VecDeque<(QueryMatch<'tree>, TextSize)>,
+    remaining: Option<usize>,
+}
+
+impl<'tree> QueryMatches<'_, 'tree> {
+    /// Returns the next match and the start of the node it was found at
+    fn next_with_start(&mut self) -> Option<(QueryMatch<'tree>, TextSize)> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+
+        while self.pending.is_empty() {
+            let node = self.nodes.next()?;
+            let start = node.node_or_token.text_range().start();
+            for (pattern_index, pattern) in self.query.patterns.iter().enumerate() {
+                // Alternatives and quantifiers may find the same captures more than once
+                let mut seen = HashSet::new();
+                for captures in match_top_level(&pattern.pattern, &node) {
+                    if !pattern
+                        .predicates
+                        .iter()
+                        .all(|predicate| satisfies(predicate, &captures))
+                    {
+                        continue;
+                    }
+
+                    let key: Vec<_> = captures
+                        .iter()
+                        .map(|(index, node)| (*index, node.node_or_token))
+                        .collect();
+                    if !seen.insert(key) {
+                        continue;
+                    }
+
+                    let query_match = QueryMatch {
+                        pattern_index,
+                        captures: captures
+                            .into_iter()
+                            .map(|(index, node)| QueryCapture { node, index })
+                            .collect(),
+                    };
+                    self.pending.push_back((query_match, start));
+                }
+            }
+        }
+
+        if let Some(remaining) = &mut self.remaining {
+            *remaining -= 1;
+        }
+        self.pending.pop_front()
+    }
+}
+
+impl<'tree> Iterator for QueryMatches<'_, 'tree> {
+    type Item = QueryMatch<'tree>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_start().map(|(query_match, _)| query_match)
+    }
+}
+
+/// Iterator over the captures of a query, returned by [`QueryCursor::captures`]
+pub struct QueryCaptures<'query, 'tree> {
+    matches: QueryMatches<'query, 'tree>,
+    pending: BinaryHeap<PendingCapture<'tree>>,
+    /// Start of the node of the last match, before which no later match has captures, or `None` after the last match
+    frontier: Option<TextSize>,
+    count: usize,
+}
+
+/// A capture waiting until no earlier capture can be found
+struct PendingCapture<'tree> {
+    start: TextSize,
+    end: TextSize,
+    /// Order in which the capture was found, to keep the order of captures at the same range
+    order: usize,
+    query_match: QueryMatch<'tree>,
+    index: usize,
+}
+
+impl PendingCapture<'_> {
+    fn key(&self) -> impl Ord {
+        // `BinaryHeap` is a max-heap
+        (
+            std::cmp::Reverse(self.start),
+            self.end,
+            std::cmp::Reverse(self.order),
+        )
+    }
+}
+
+impl PartialEq for PendingCapture<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for PendingCapture<'_> {}
+
+impl PartialOrd for PendingCapture<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PendingCapture<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl<'tree> Iterator for QueryCaptures<'_, 'tree> {
+    type Item = (QueryMatch<'tree>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // Captures of later matches start at or after the node of the last match
+            if let Some(capture) = self.pending.peek() {
+                if self
+                    .frontier
+                    .is_none_or(|frontier| capture.start < frontier)
+                {
+                    let capture = self.pending.pop().unwrap();
+                    return Some((capture.query_match, capture.index));
+                }
+            }
+
+            self.frontier?;
+            match self.matches.next_with_start() {
+                Some((query_match, start)) => {
+                    self.frontier = Some(start);
+                    for (index, capture) in query_match.captures.iter().enumerate() {
+                        let range = capture.node.node_or_token.text_range();
+                        self.pending.push(PendingCapture {
+                            start: range.start(),
+                            end: range.end(),
+                            order: self.count,
+                            query_match: query_match.clone(),
+                            index,
+                        });
+                        self.count += 1;
+                    }
+                }
+                None => self.frontier = None,
+            }
+        }
+    }
+}
+
+type Captures<'a> = Vec<(u32, Node<'a>)>;
+
+/// A child node and its field name
+struct Sibling<'a> {
+    node: Node<'a>,
+    field: Option<&'static str>,
+}
+
+fn children<'a>(node: &Node<'a>) -> Vec<Sibling<'a>> {
+    let children: Vec<_> = node.children_iter().collect();
+    let fields: Vec<_> =
+        fields::field_names(node.kind(), children.iter().map(|child| child.kind())).collect();
+    children
+        .into_iter()
+        .zip(fields)
+        .map(|(node, field)| Sibling { node, field })
+        .collect()
+}
+
+fn match_top_level<'a>(pattern: &Pattern, node: &Node<'a>) -> Vec<Captures<'a>> {
+    let PatternKind::Group(items) = &pattern.kind else {
+        return match_element(pattern, node);
+    };
+
+    // A top-level grouping matches a sequence of siblings starting with `node`
+    let Some((first, rest)) = items.split_first() else {
+        return vec![];
+    };
+
+    let siblings = match node.parent() {
+        Some(parent) => children(&parent),
+        None => vec![Sibling {
+            node: node.clone(),
+            field: None,
+        }],
+    };
+    let Some(index) = siblings
+        .iter()
+        .position(|s| s.node.node_or_token == node.node_or_token)
+    else {
+        return vec![];
+    };
+
+    let mut results = Vec::new();
+    for first_captures in match_element(&first.pattern, node) {
+        for (_, rest_captures) in match_seq(rest, &siblings, index + 1, false) {
+            let mut captures = own_captures(pattern, node);
+            captures.extend(first_captures.iter().cloned());
+            captures.extend(rest_captures);
+            results.push(captures);
+        }
+    }
+    results
+}
+
+fn own_captures<'a>(pattern: &Pattern, node: &Node<'a>) -> Captures<'a> {
+    pattern
+        .captures
+        .iter()
+        .map(|&index| (index, node.clone()))
+        .collect()
+}
+
+/// Matches a pattern against a single node and returns every possible set of captures
+fn match_element<'a>(pattern: &Pattern, node: &Node<'a>) -> Vec<Captures<'a>> {
+    match &pattern.kind {
+        PatternKind::Node {
+            kind,
+            children: child_patterns,
+            anchor_end,
+        } => {
+            let matched = match kind {
+                Some(kind) => *kind == node.kind(),
+                None => node.is_named(),
+            };
+            if !matched {
+                return vec![];
+            }
+
+            if child_patterns.is_empty() {
+                return vec![own_captures(pattern, node)];
+            }
+
+            let children = children(node);
+            match_seq(child_patterns, &children, 0, *anchor_end)
+                .into_iter()
+                .map(|(_, child_captures)| {
+                    let mut captures = own_captures(pattern, node);
+                    captures.extend(child_captures);
+                    captures
+                })
+                .collect()
+        }
+        PatternKind::Anonymous(text) => {
+            let matched = match text {
+                Some(text) => {
+                    node.node_or_token.as_token().is_some()
+                        && node.text().eq_ignore_ascii_case(text)
+                }
+                None => true,
+            };
+
+            if matched {
+                vec![own_captures(pattern, node)]
+            } else {
+                vec![]
+            }
+        }
+        PatternKind::Alternation(alternatives) => alternatives
+            .iter()
+            .flat_map(|alternative| match_element(alternative, node))
+            .map(|alternative_captures| {
+                let mut captures = own_captures(pattern, node);
+                captures.extend(alternative_captures);
+                captures
+            })
+            .collect(),
+        PatternKind::Group(_) => vec![],
+    }
+}
+
+/// Matches one (unquantified) child pattern starting at `pos`
+/// Returns the position after the matched nodes and the captures for each possible match
+fn match_item<'a>(item: &Child, nodes: &[Sibling<'a>], pos: usize) -> Vec<(usize, Captures<'a>)> {
+    if let PatternKind::Group(items) = &item.pattern.kind {
+        let start = if item.anchored {
+            next_non_comment(nodes, pos)
+        } else {
+            pos
+        };
+        return match_seq(items, nodes, start, false)
+            .into_iter()
+            .map(|(end, group_captures)| {
+                let mut captures: Captures = item
+                    .pattern
+                    .captures
+                    .iter()
+                    .filter_map(|&index| nodes.get(start).map(|s| (index, s.node.clone())))
+                    .collect();
+                captures.extend(group_captures);
+                (end, captures)
+            })
+            .collect();
+    }
+
+    let candidates = if item.anchored {
+        let i = next_non_comment(nodes, pos);
+        i..(i + 1).min(nodes.len())
+    } else {
+        pos..nodes.len()
+    };
+
+    candidates
+        .filter(|&i| {
+            item.field
+                .as_deref()
+                .is_none_or(|field| nodes[i].field == Some(field))
+        })
+        .flat_map(|i| {
+            match_element(&item.pattern, &nodes[i].node)
+                .into_iter()
+                .map(move |captures| (i + 1, captures))
+        })
+        .collect()
+}
+
+fn next_non_comment(nodes: &[Sibling], pos: usize) -> usize {
+    (pos..nodes.len())
+        .find(|&i| !nodes[i].node.is_extra())
+        .unwrap_or(nodes.len())
+}
+
+/// Matches a sequence of child patterns against `nodes[pos..]`
+/// With `anchor_end`, the sequence must be followed by nothing but extras.
+fn match_seq<'a>(
+    items: &[Child],
+    nodes: &[Sibling<'a>],
+    pos: usize,
+    anchor_end: bool,
+) -> Vec<(usize, Captures<'a>)> {
+    let Some((first, rest)) = items.split_first() else {
+        if anchor_end && !nodes[pos..].iter().all(|s| s.node.is_extra()) {
+            return vec![];
+        }
+        return vec![(pos, vec![])];
+    };
+
+    let combine = |first_captures: &Captures<'a>, next: usize| {
+        match_seq(rest, nodes, next, anchor_end)
+            .into_iter()
+            .map(|(end, rest_captures)| {
+                let mut captures = first_captures.clone();
+                captures.extend(rest_captures);
+                (end, captures)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    match first.quantifier {
+        Quantifier::One | Quantifier::ZeroOrOne => {
+            let results: Vec<_> = match_item(first, nodes, pos)
+                .iter()
+                .flat_map(|(next, captures)| combine(captures, *next))
+                .collect();
+
+            if results.is_empty() && first.quantifier == Quantifier::ZeroOrOne {
+                combine(&vec![], pos)
+            } else {
+                results
+            }
+        }
+        Quantifier::ZeroOrMore | Quantifier::OneOrMore => {
+            let min = usize::from(first.quantifier == Quantifier::OneOrMore);
+            match_repetition(first, rest, nodes, pos, anchor_end, min, vec![])
+        }
+    }
+}
+
+/// Matches `item` repeated at least `min` more times, followed by `rest`
+/// Repetitions are greedy: each way the item can match is tried in order, and the first one
+/// that lets the rest of the sequence match wins. Shorter repetitions are only tried when no
+/// longer one matches.
+fn match_repetition<'a>(
+    item: &Child,
+    rest: &[Child],
+    nodes: &[Sibling<'a>],
+    pos: usize,
+    anchor_end: bool,
+    min: usize,
+    captures: Captures<'a>,
+) -> Vec<(usize, Captures<'a>)> {
+    for (next, item_captures) in match_item(item, nodes, pos) {
+        if next <= pos {
+            continue;
+        }
+        let mut all_captures = captures.clone();
+        all_captures.extend(item_captures);
+        let results = match_repetition(
+            item,
+            rest,
+            nodes,
+            next,
+            anchor_end,
+            min.saturating_sub(1),
+            all_captures,
+        );
+        if !results.is_empty() {
+            return results;
+        }
+    }
+
+    if min > 0 {
+        return vec![];
+    }
+    match_seq(rest, nodes, pos, anchor_end)
+        .into_iter()
+        .map(|(end, rest_captures)| {
+            let mut all_captures = captures.clone();
+            all_captures.extend(rest_captures);
+            (end, all_captures)
+        })
+        .collect()
+}
+
+fn satisfies(predicate: &TextPredicate, captures: &Captures) -> bool {
+    let texts = |capture: u32| {
+        captures
+            .iter()
+            .filter(move |(index, _)| *index == capture)
+            .map(|(_, node)| node.text())
+    };
+
+    match predicate {
+        TextPredicate::Eq {
+            capture,
+            value,
+            negated,
+        } => texts(*capture).all(|text| {
+            let eq = match value {
+                PredicateArg::String(s) => text == s,
+                PredicateArg::Capture(other) => texts(*other).all(|other| other == text),
+            };
+            eq != *negated
+        }),
+        #[cfg(feature = "regex-match")]
+        TextPredicate::Match {
+            capture,
+            regex,
+            negated,
+        } => texts(*capture).all(|text| regex.is_match(text) != *negated),
+        TextPredicate::AnyOf {
+            capture,
+            values,
+            negated,
+        } => texts(*capture).all(|text| values.iter().any(|v| v == text) != *negated),
+    }
+}
+
+fn syntax_kind_by_name(name: &str) -> Option<SyntaxKind> {
+    static KINDS: OnceLock<HashMap<String, SyntaxKind>> = OnceLock::new();
+
+    KINDS
+        .get_or_init(|| {
+            (0..=SyntaxKind::Root as u32)
+                .map(|raw| SyntaxKind::from_raw(RawSyntaxKind(raw)))
+                .map(|kind| (format!("{kind:?}"), kind))
+                .collect()
+        })
+        .get(name)
+        .copied()
+}
+
+struct QueryParser<'a> {
+    source: &'a str,
+    pos: usize,
+    capture_names: Vec<String>,
+}
+
+impl QueryParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn error(&self, offset: usize, kind: QueryErrorKind, message: impl Into<String>) -> QueryError {
+        let before = &self.source[..offset];
+        let row = before.matches('\n').count();
+        let column = offset - before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+        QueryError {
+            row,
+            column,
+            offset,
+            message: message.into(),
+            kind,
+        }
+    }
+
+    fn skip_trivia(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.bump();
+            } else if c == ';' {
+                // comment
+                while let Some(c) = self.bump() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), QueryError> {
+        self.skip_trivia();
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.error(
+                self.pos,
+                QueryErrorKind::Syntax,
+                format!("expected `{expected}`"),
+            ))
+        }
+    }
+
+    fn parse_identifier(&mut self) -> &str {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c.is_alphanumeric() || matches!(c, '_' | '-' | '?' | '!') {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        &self.source[start..self.pos]
+    }
+
+    fn parse_string(&mut self) -> Result<String, QueryError> {
+        let start = self.pos;
+        self.bump(); // opening quote
+
+        let mut s = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(s),
+                Some('\\') => match self.bump() {
+                    Some('n') => s.push('\n'),
+                    Some('t') => s.push('\t'),
+                    Some('r') => s.push('\r'),
+                    Some('0') => s.push('\0'),
+                    Some(c) => s.push(c),
+                    None => break,
+                },
+                Some(c) => s.push(c),
+                None => break,
+            }
+        }
+
+        Err(self.error(start, QueryErrorKind::Syntax, "unterminated string"))
+    }
+
+    fn parse_capture_name(&mut self) -> &str {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c.is_alphanumeric() || matches!(c, '_' | '-' | '.') {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        &self.source[start..self.pos]
+    }
+
+    fn capture_index(&mut self, name: &str) -> u32 {
+        match self.capture_names.iter().position(|n| n == name) {
+            Some(i) => i as u32,
+            None => {
+                self.capture_names.push(name.to_string());
+                (self.capture_names.len() - 1) as u32
+            }
+        }
+    }
+
+    /// Parses a pattern followed by its quantifier and captures
+    fn parse_pattern(
+        &mut self,
+        predicates: &mut Vec<TextPredicate>,
+        top_level: bool,
+    ) -> Result<Pattern, QueryError> {
+        let start = self.pos;
+        let child = self.parse_child(predicates)?;
+
+        // Alternatives are matched against the node itself, which has no field name to check
+        if child.field.is_some() {
+            return Err(self.error(
+                start,
+                QueryErrorKind::Syntax,
+                "fields are only allowed on child patterns",
+            ));
+        }
+
+        if top_level && child.anchored {
+            return Err(self.error(
+                start,
+                QueryErrorKind::Syntax,
+                "anchors are only allowed inside a pattern",
+            ));
+        }
+
+        if top_level && child.quantifier != Quantifier::One {
+            return Err(self.error(
+                start,
+                QueryErrorKind::Syntax,
+                "quantifiers are only allowed inside a pattern",
+            ));
+        }
+
+        Ok(child.pattern)
+    }
+
+    fn parse_child(&mut self, predicates: &mut Vec<TextPredicate>) -> Result<Child, QueryError> {
+        self.skip_trivia();
+
+        let mut anchored = false;
+        if self.peek() == Some('.') {
+            self.bump();
+            anchored = true;
+            self.skip_trivia();
+        }
+
+        let field = self.parse_field()?;
+
+        let start = self.pos;
+        let kind = match self.peek() {
+            Some('(') => {
+                self.bump();
+                self.skip_trivia();
+                match self.peek() {
+                    Some('(' | '[' | '"' | '.') => {
+                        let (items, _) = self.parse_children(predicates)?;
+                        PatternKind::Group(items)
+                    }
+                    Some(c) if c.is_alphanumeric() || c == '_' => {
+                        let name_start = self.pos;
+                        let name = self.parse_identifier().to_string();
+                        let kind = if name == "_" {
+                            None
+                        } else {
+                            Some(syntax_kind_by_name(&name).ok_or_else(|| {
+                                self.error(name_start, QueryErrorKind::NodeType, name.clone())
+                            })?)
+                        };
+
+                        let (children, anchor_end) = self.parse_children(predicates)?;
+                        PatternKind::Node {
+                            kind,
+                            children,
+                            anchor_end,
+                        }
+                    }
+                    _ => {
+                        return Err(self.error(
+                            self.pos,
+                            QueryErrorKind::Syntax,
+                            "expected a node kind",
+                        ))
+                    }
+                }
+            }
+            Some('[') => {
+                self.bump();
+                let mut alternatives = Vec::new();
+                loop {
+                    self.skip_trivia();
+                    match self.peek() {
+                        Some(']') => {
+                            self.bump();
+                            break;
+                        }
+                        Some(_) => alternatives.push(self.parse_pattern(predicates, false)?),
+                        None => {
+                            return Err(self.error(
+                                start,
+                                QueryErrorKind::Syntax,
+                                "unterminated alternation",
+                            ))
+                        }
+                    }
+                }
+                PatternKind::Alternation(alternatives)
+            }
+            Some('"') => PatternKind::Anonymous(Some(self.parse_string()?)),
+            Some('_') => {
+                self.bump();
+                PatternKind::Anonymous(None)
+            }
+            _ => return Err(self.error(start, QueryErrorKind::Syntax, "expected a pattern")),
+        };
+
+        let quantifier = match self.peek() {
+            Some('*') => Quantifier::ZeroOrMore,
+            Some('+') => Quantifier::OneOrMore,
+            Some('?') => Quantifier::ZeroOrOne,
+            _ => Quantifier::One,
+        };
+        if quantifier != Quantifier::One {
+            self.bump();
+        }
+
+        let mut captures = Vec::new();
+        loop {
+            self.skip_trivia();
+            if self.peek() != Some('@') {
+                break;
+            }
+            self.bump();
+            let name_start = self.pos;
+            let name = self.parse_capture_name().to_string();
+            if name.is_empty() {
+                return Err(self.error(name_start, QueryErrorKind::Capture, "empty capture name"));
+            }
+            captures.push(self.capture_index(&name));
+        }
+
+        if field.is_some() && matches!(kind, PatternKind::Group(_)) {
+            return Err(self.error(
+                start,
+                QueryErrorKind::Syntax,
+                "fields are not allowed on a grouping",
+            ));
+        }
+
+        Ok(Child {
+            pattern: Pattern { kind, captures },
+            quantifier,
+            anchored,
+            field,
+        })
+    }
+
+    /// Parses `name:` before a child pattern, if any
+    fn parse_field(&mut self) -> Result<Option<String>, QueryError> {
+        let start = self.pos;
+        if !self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            return Ok(None);
+        }
+
+        let name = self.parse_identifier().to_string();
+        self.skip_trivia();
+        if self.peek() != Some(':') {
+            self.pos = start;
+            return Ok(None);
+        }
+        self.bump();
+        self.skip_trivia();
+
+        if !fields::is_field_name(&name) {
+            return Err(self.error(start, QueryErrorKind::Field, name));
+        }
+        Ok(Some(name))
+    }
+
+    /// Parses child patterns and predicates up to the closing parenthesis
+    fn parse_children(
+        &mut self,
+        predicates: &mut Vec<TextPredicate>,
+    ) -> Result<(Vec<Child>, bool), QueryError> {
+        let mut children = Vec::new();
+        let mut anchor_end = false;
+
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                Some(')') => {
+                    self.bump();
+                    return Ok((children, anchor_end));
+                }
+                Some('(') if self.source[self.pos + 1..].trim_start().starts_with('#') => {
+                    predicates.push(self.parse_predicate()?);
+                }
+                Some('.') => {
+                    let rest = self.source[self.pos + 1..].trim_start();
+                    if rest.starts_with(')') {
+                        self.bump();
+                        anchor_end = true;
+                    } else {
+                        children.push(self.parse_child(predicates)?);
+                    }
+                }
+                Some(_) => children.push(self.parse_child(predicates)?),
+                None => {
+                    return Err(self.error(self.pos, QueryErrorKind::Syntax, "expected `)`"));
+                }
+            }
+        }
+    }
+
+    fn parse_predicate(&mut self) -> Result<TextPredicate, QueryError> {
+        let start = self.pos;
+        self.expect('(')?;
+        self.expect('#')?;
+        let name = self.parse_identifier().to_string();
+
+        let mut args = Vec::new();
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                Some(')') => {
+                    self.bump();
+                    break;
+                }
+                Some('@') => {
+                    self.bump();
+                    let name_start = self.pos;
+                    let capture = self.parse_capture_name().to_string();
+                    let Some(index) = self.capture_names.iter().position(|n| *n == capture) else {
+                        return Err(self.error(name_start, QueryErrorKind::Capture, capture));
+                    };
+                    args.push(PredicateArg::Capture(index as u32));
+                }
+                Some('"') => args.push(PredicateArg::String(self.parse_string()?)),
+                Some(_) => args.push(PredicateArg::String(self.parse_identifier().to_string())),
+                None => {
+                    return Err(self.error(start, QueryErrorKind::Syntax, "expected `)`"));
+                }
+            }
+        }
+
+        let invalid = |message: &str| self.error(start, QueryErrorKind::Predicate, message);
+
+        let mut args = args.into_iter();
+        let capture = match args.next() {
+            Some(PredicateArg::Capture(capture)) => capture,
+            _ => {
+                return Err(invalid(&format!(
+                    "first argument to #{name} must be a capture name"
+                )))
+            }
+        };
+
+        let predicate = match name.as_str() {
+            "eq?" | "not-eq?" => {
+                let (Some(value), None) = (args.next(), args.next()) else {
+                    return Err(invalid(&format!("#{name} requires two arguments")));
+                };
+                TextPredicate::Eq {
+                    capture,
+                    value,
+                    negated: name == "not-eq?",
+                }
+            }
+            #[cfg(feature = "regex-match")]
+            "match?" | "not-match?" => {
+                let (Some(PredicateArg::String(pattern)), None) = (args.next(), args.next()) else {
+                    return Err(invalid(&format!(
+                        "#{name} requires a capture and a regular expression"
+                    )));
+                };
+                let regex = Regex::new(&pattern).map_err(|e| invalid(&e.to_string()))?;
+                TextPredicate::Match {
+                    capture,
+                    regex,
+                    negated: name == "not-match?",
+                }
+            }
+            "any-of?" | "not-any-of?" => {
+                let values = args
+                    .map(|arg| match arg {
+                        PredicateArg::String(s) => Ok(s),
+                        PredicateArg::Capture(_) => {
+                            Err(invalid(&format!("arguments to #{name} must be strings")))
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                TextPredicate::AnyOf {
+                    capture,
+                    values,
+                    negated: name == "not-any-of?",
+                }
+            }
+            #[cfg(not(feature = "regex-match"))]
+            "match?" | "not-match?" => {
+                return Err(invalid(&format!(
+                    "#{name} requires the `regex-match` feature"
+                )))
+            }
+            _ => return Err(invalid(&format!("unknown predicate #{name}"))),
+        };
+
+        Ok(predicate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tree_sitter::{parse, Query, QueryCursor, QueryErrorKind};
+
+    fn captured_texts(query: &str, src: &str, capture: &str) -> Vec<String> {
+        let tree = parse(src).unwrap();
+        let query = Query::new(query).unwrap();
+        let index = query.capture_index_for_name(capture).unwrap();
+
+        QueryCursor::new()
+            .matches(&query, tree.root_node())
+            .flat_map(|m| {
+                m.nodes_for_capture_index(index)
+                    .map(|n| n.text().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn capture_child() {
+        let texts = captured_texts(
+            "(columnref (ColId) @col)",
+            "select a, t.b from t where c = 1;",
+            "col",
+        );
+        assert_eq!(texts, ["a", "t", "c"]);
+    }
+
+    #[test]
+    fn each_child_is_a_separate_match() {
+        let texts = captured_texts("(target_list (target_el) @el)", "select a, b + 1, c;", "el");
+        assert_eq!(texts, ["a", "b + 1", "c"]);
+    }
+
+    #[test]
+    fn wildcard_and_anonymous_node() {
+        let texts = captured_texts(
+            r#"(where_clause "where" (_) @cond)"#,
+            "select * from t WHERE a = 1;",
+            "cond",
+        );
+        assert_eq!(texts, ["a = 1"]);
+    }
+
+    #[test]
+    fn eq_predicate() {
+        let texts = captured_texts(
+            r#"((columnref) @col (#eq? @col "b"))"#,
+            "select a, b from t where b > 0;",
+            "col",
+        );
+        assert_eq!(texts, ["b", "b"]);
+
+        let texts = captured_texts(
+            r#"((columnref) @col (#not-eq? @col "b"))"#,
+            "select a, b from t;",
+            "col",
+        );
+        assert_eq!(texts, ["a"]);
+    }
+
+    #[test]
+    #[cfg(feature = "regex-match")]
+    fn match_predicate() {
+        let texts = captured_texts(
+            r#"(func_application (func_name) @f (#match? @f "^(?i)count$"))"#,
+            "select COUNT(*), sum(a), count(b) from t;",
+            "f",
+        );
+        assert_eq!(texts, ["COUNT", "count"]);
+    }
+
+    #[test]
+    fn any_of_predicate() {
+        let texts = captured_texts(
+            r#"((IDENT) @id (#any-of? @id "a" "c"))"#,
+            "select a, b, c;",
+            "id",
+        );
+        assert_eq!(texts, ["a", "c"]);
+    }
+
+    #[test]
+    fn alternation() {
+        let texts = captured_texts("[(ICONST) (SCONST)] @lit", "select 1, 'a', b;", "lit");
+        assert_eq!(texts, ["1", "'a'"]);
+    }
+
+    #[test]
+    fn quantifier() {
+        let tree = parse("select a, b, c;").unwrap();
+        let query = Query::new("(target_list (target_el)+ @el)").unwrap();
+
+        let matches: Vec<_> = QueryCursor::new()
+            .matches(&query, tree.root_node())
+            .collect();
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].captures.len(), 3);
+    }
+
+    #[test]
+    fn quantifier_backtracks() {
+        // The nearest way to match the group leaves no room for the trailing `, d`
+        let query =
+            r#"(target_list ((target_el) @a "," (target_el) @b)+ . "," . (target_el) @last .)"#;
+        let src = "select a, b, c, d;";
+
+        assert_eq!(captured_texts(query, src, "a"), ["a"]);
+        assert_eq!(captured_texts(query, src, "b"), ["c"]);
+        assert_eq!(captured_texts(query, src, "last"), ["d"]);
+    }
+
+    #[test]
+    fn anchor() {
+        let texts = captured_texts(
+            "(target_list . (target_el) @first)",
+            "select a, b, c;",
+            "first",
+        );
+        assert_eq!(texts, ["a"]);
+
+        let texts = captured_texts(
+            "(target_list (target_el) @last .)",
+            "select a, b, c;",
+            "last",
+        );
+        assert_eq!(texts, ["c"]);
+    }
+
+    #[test]
+    fn multiple_patterns() {
+        let tree = parse("select 1 from t;").unwrap();
+        let query = Query::new(
+            r#"
+; comment
+(ICONST) @num
+(relation_expr) @rel
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(query.pattern_count(), 2);
+        assert_eq!(query.capture_names(), ["num", "rel"]);
+
+        let pattern_indices: Vec<_> = QueryCursor::new()
+            .matches(&query, tree.root_node())
+            .map(|m| m.pattern_index)
+            .collect();
+        assert_eq!(pattern_indices, [0, 1]);
+    }
+
+    #[test]
+    fn captures_in_order() {
+        let tree = parse("select a + 1, b from t;").unwrap();
+        let query = Query::new("(ICONST) @num (target_el) @el (columnref) @col").unwrap();
+
+        let captures: Vec<_> = QueryCursor::new()
+            .captures(&query, tree.root_node())
+            .map(|(m, i)| {
+                let capture = &m.captures[i];
+                (
+                    query.capture_names()[capture.index as usize].as_str(),
+                    capture.node.text(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            captures,
+            [
+                ("el", "a + 1"),
+                ("col", "a"),
+                ("num", "1"),
+                ("el", "b"),
+                ("col", "b"),
+            ]
+        );
+
+        let mut cursor = QueryCursor::new();
+        cursor.set_match_limit(2);
+        assert_eq!(cursor.matches(&query, tree.root_node()).count(), 2);
+    }
+
+    #[test]
+    fn fields() {
+        let texts = captured_texts(
+            "(a_expr left: (_) @left right: (_) @right)",
+            "select a + 1 from t where b = c;",
+            "right",
+        );
+        assert_eq!(texts, ["1", "c"]);
+
+        let texts = captured_texts(
+            "(select_no_parens where: (where_clause) @where)",
+            "select a from t where b = c; select 1;",
+            "where",
+        );
+        assert_eq!(texts, ["where b = c"]);
+    }
+
+    #[test]
+    fn errors() {
+        let err = Query::new("(no_such_kind)").unwrap_err();
+        assert_eq!(err.kind, QueryErrorKind::NodeType);
+        assert_eq!((err.row, err.column), (0, 1));
+
+        let err = Query::new("(columnref\n  (ColId) @c").unwrap_err();
+        assert_eq!(err.kind, QueryErrorKind::Syntax);
+
+        let err = Query::new(r#"((ColId) @c (#eq? @d "a"))"#).unwrap_err();
+        assert_eq!(err.kind, QueryErrorKind::Capture);
+
+        let err = Query::new(r#"((ColId) @c (#unknown? @c))"#).unwrap_err();
+        assert_eq!(err.kind, QueryErrorKind::Predicate);
+
+        let err = Query::new("(a_expr no_such_field: (_))").unwrap_err();
+        assert_eq!(err.kind, QueryErrorKind::Field);
+
+        let err = Query::new("(ColId)+ @c").unwrap_err();
+        assert_eq!(err.kind, QueryErrorKind::Syntax);
+    }
+}
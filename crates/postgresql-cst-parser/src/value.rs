@@ -0,0 +1,393 @@
+//! Decoded values of literal and identifier tokens
+//!
+//! The syntax tree keeps the source text of tokens, such as `E'it\'s'` or `"Foo"`.
+//! The functions of this module return the values PostgreSQL sees for them.
+//!
+//! # Examples
+//!
+//! ```
+//! use postgresql_cst_parser::{value::{TokenValues, Value}, LexerOptions};
+//!
+//! let src = r#"select e'it\'s' as "Foo", Bar from t where a = 0x_FF;"#;
+//! let root = postgresql_cst_parser::parse(src).unwrap();
+//! let token_values = TokenValues::new(src, &LexerOptions::default()).unwrap();
+//! let values: Vec<_> = root
+//!     .descendants_with_tokens()
+//!     .filter_map(|element| element.as_token().and_then(|token| token_values.get(token)))
+//!     .filter(|value| !matches!(value, Value::Keyword(_)))
+//!     .collect();
+//!
+//! assert_eq!(
+//!     values,
+//!     [
+//!         &Value::String("it's".to_string()),
+//!         &Value::Identifier("Foo".to_string()),
+//!         &Value::Identifier("bar".to_string()),
+//!         &Value::Identifier("t".to_string()),
+//!         &Value::Identifier("a".to_string()),
+//!         &Value::Integer(255),
+//!     ]
+//! );
+//! ```
+
+use crate::{
+    cst_util::is_trivia,
+    lexer::{
+        lexer_ported::{process_unicode_token, truncate_identifier},
+        Lexer, LexerOptions, Token, TokenKind, Yylval,
+    },
+    syntax_kind::SyntaxKind,
+    ParserError, ResolvedToken,
+};
+
+/// The value of a token
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Value {
+    /// `SCONST` and `USCONST`: the string after processing quotes and escapes
+    String(String),
+    /// `IDENT` and `UIDENT`: the case-folded or unquoted name, truncated to `NAMEDATALEN - 1` bytes
+    Identifier(String),
+    /// Keywords: the lowercase name, which is the name of the keyword used as an identifier
+    Keyword(String),
+    /// `ICONST`: an integer that fits in `int4`
+    Integer(i32),
+    /// `FCONST`: a decimal number without underscores, such as `1.5e3` or `4294967296`
+    ///
+    /// Integers with a `0x`, `0o` or `0b` prefix are converted to decimal.
+    Numeric(String),
+    /// `BCONST`: the binary digits of `B'...'`
+    BitString(String),
+    /// `XCONST`: the hexadecimal digits of `X'...'`
+    HexString(String),
+    /// `PARAM`: the number of `$n`
+    Param(i32),
+}
+
+/// Returns the value of a literal, identifier or keyword token, assuming the default [`LexerOptions`]
+///
+/// The text of the token is lexed on every call. Use [`TokenValues`] to decode many tokens of a tree.
+pub fn token_value(token: &ResolvedToken) -> Option<Value> {
+    token_value_with_options(token, &LexerOptions::default())
+}
+
+/// Same as [`token_value`], but with the settings the token was lexed with
+///
+/// The settings matter for strings without the `E` prefix, whose backslashes depend on `standard_conforming_strings`.
+pub fn token_value_with_options(token: &ResolvedToken, options: &LexerOptions) -> Option<Value> {
+    match with_uescape_clause(token) {
+        Some(text) => decode(&text, options),
+        None => decode(token.text(), options),
+    }
+}
+
+/// Returns the text of a Unicode escape token up to the end of the `UESCAPE 'c'` clause following it
+///
+/// The tokens of the clause follow the token in the tree, like comments.
+fn with_uescape_clause(token: &ResolvedToken) -> Option<String> {
+    if !token
+        .text()
+        .get(..2)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("u&"))
+    {
+        return None;
+    }
+
+    let mut text = token.text().to_string();
+    let mut saw_uescape = false;
+    let mut next = token.next_token();
+    while let Some(token) = next {
+        text.push_str(token.text());
+        match token.kind() {
+            kind if is_trivia(kind) => {}
+            SyntaxKind::UESCAPE if !saw_uescape => saw_uescape = true,
+            SyntaxKind::SCONST if saw_uescape => return Some(text),
+            _ => return None,
+        }
+        next = token.next_token();
+    }
+    None
+}
+
+/// Returns the value of `text` if it is exactly one literal, identifier or keyword token
+///
+/// This also accepts the `value` of a [`crate::Token`] returned by [`crate::lex`].
+pub fn decode(text: &str, options: &LexerOptions) -> Option<Value> {
+    match lex_values(text, options).ok()?.as_slice() {
+        [(0, end, value), ..] if *end == text.len() => value.clone(),
+        _ => None,
+    }
+}
+
+/// The values of all tokens of a source, decoded by a single pass of the lexer
+///
+/// # Examples
+///
+/// ```
+/// use postgresql_cst_parser::{parse, value::{TokenValues, Value}, LexerOptions};
+///
+/// let src = r#"select "Foo", 'a''b' from t;"#;
+/// let root = parse(src).unwrap();
+/// let values = TokenValues::new(src, &LexerOptions::default()).unwrap();
+///
+/// let foo = root
+///     .descendants_with_tokens()
+///     .filter_map(|element| element.into_token())
+///     .find(|token| token.text() == r#""Foo""#)
+///     .unwrap();
+/// assert_eq!(values.get(foo), Some(&Value::Identifier("Foo".to_string())));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenValues {
+    /// The start positions and the values of the tokens that have one, in the order of the source
+    values: Vec<(usize, Value)>,
+}
+
+impl TokenValues {
+    /// Lexes `input`, which must be the source the tree was parsed from with the same settings
+    pub fn new(input: &str, options: &LexerOptions) -> Result<Self, ParserError> {
+        let values = lex_values(input, options)?
+            .into_iter()
+            .filter_map(|(start, _, value)| Some((start, value?)))
+            .collect();
+        Ok(Self { values })
+    }
+
+    /// Returns the value of a literal, identifier or keyword token of the tree
+    pub fn get(&self, token: &ResolvedToken) -> Option<&Value> {
+        let start = usize::from(token.text_range().start());
+        let i = self
+            .values
+            .binary_search_by_key(&start, |&(start, _)| start)
+            .ok()?;
+        Some(&self.values[i].1)
+    }
+}
+
+/// Lexes `input` and returns the start and end positions and the value of each token
+///
+/// The end of a `UIDENT` or `USCONST` token is the end of the `UESCAPE 'c'` clause following it, whose tokens come next.
+fn lex_values(
+    input: &str,
+    options: &LexerOptions,
+) -> Result<Vec<(usize, usize, Option<Value>)>, ParserError> {
+    let mut lexer = Lexer::new(input, options.clone());
+    let mut tokens: Vec<Token> = vec![];
+    let mut yylvals = vec![];
+    while let Some((token, yylval)) = lexer.next_token()? {
+        tokens.push(token);
+        yylvals.push(yylval);
+    }
+
+    let mut values = vec![];
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = &tokens[i];
+        if matches!(token.kind, TokenKind::UIDENT | TokenKind::USCONST) {
+            // The literals are computed by the lexer
+            let (mut value, last) = process_unicode_token(&tokens, i, |j| match &yylvals[j] {
+                Yylval::Str(s) => Ok(s.clone()),
+                _ => Err(ParserError::new_error(
+                    "expected a string literal or an identifier",
+                    tokens[j].start_byte_pos,
+                    tokens[j].end_byte_pos,
+                )),
+            })?;
+
+            let value = if token.kind == TokenKind::UIDENT {
+                truncate_identifier(&mut value, token.start_byte_pos);
+                Value::Identifier(value)
+            } else {
+                Value::String(value)
+            };
+            values.push((token.start_byte_pos, tokens[last].end_byte_pos, Some(value)));
+            i += 1;
+            continue;
+        }
+
+        let yylval = std::mem::replace(&mut yylvals[i], Yylval::Uninitialized);
+        let value = match (&token.kind, yylval) {
+            (TokenKind::SCONST, Yylval::Str(s)) => Some(Value::String(s)),
+            (TokenKind::IDENT, Yylval::Str(s)) => Some(Value::Identifier(s)),
+            (TokenKind::KEYWORD(_), Yylval::Keyword(s)) => Some(Value::Keyword(s)),
+            (TokenKind::ICONST, Yylval::I(i)) => Some(Value::Integer(i)),
+            (TokenKind::FCONST, Yylval::Str(s)) => Some(Value::Numeric(to_decimal(&s))),
+            (TokenKind::BCONST, Yylval::Str(s)) => Some(Value::BitString(s[1..].to_string())),
+            (TokenKind::XCONST, Yylval::Str(s)) => Some(Value::HexString(s[1..].to_string())),
+            (TokenKind::PARAM, Yylval::I(i)) => Some(Value::Param(i)),
+            _ => None,
+        };
+        values.push((token.start_byte_pos, token.end_byte_pos, value));
+        i += 1;
+    }
+
+    Ok(values)
+}
+
+/// Removes the underscores of a number and converts an integer with a radix prefix to decimal
+fn to_decimal(s: &str) -> String {
+    let s = s.replace('_', "");
+    let radix = match s
+        .get(..2)
+        .map(|prefix| prefix.to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("0x") => 16,
+        Some("0o") => 8,
+        Some("0b") => 2,
+        _ => return s,
+    };
+
+    // Little-endian decimal digits, which is large enough for integers of any length
+    let mut digits = vec![0u32];
+    for c in s[2..].chars() {
+        let mut carry = c.to_digit(radix).unwrap();
+        for digit in digits.iter_mut() {
+            let v = *digit * radix + carry;
+            *digit = v % 10;
+            carry = v / 10;
+        }
+        while carry > 0 {
+            digits.push(carry % 10);
+            carry /= 10;
+        }
+    }
+
+    digits
+        .iter()
+        .rev()
+        .map(|d| char::from_digit(*d, 10).unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lex, LexerOptions};
+
+    use super::{decode, token_value, TokenValues, Value};
+
+    fn values(input: &str) -> Vec<Value> {
+        lex(input)
+            .unwrap()
+            .iter()
+            .filter_map(|token| decode(&token.value, &LexerOptions::default()))
+            .collect()
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(
+            values(
+                "'it''s' e'a\\tb\\x41\\u0042' $tag$x'$y$tag$ 'a'\n'b' U&'d\\0061t' U&'\\+01F600'"
+            ),
+            [
+                Value::String("it's".to_string()),
+                Value::String("a\tbAB".to_string()),
+                Value::String("x'$y".to_string()),
+                Value::String("ab".to_string()),
+                Value::String("dat".to_string()),
+                Value::String("😀".to_string()),
+            ]
+        );
+
+        let options = LexerOptions {
+            standard_conforming_strings: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            decode("'a\\\\b'", &options),
+            Some(Value::String("a\\b".to_string()))
+        );
+        assert_eq!(
+            decode("'a\\\\b'", &LexerOptions::default()),
+            Some(Value::String("a\\\\b".to_string()))
+        );
+    }
+
+    #[test]
+    fn identifiers() {
+        assert_eq!(
+            values(r#"Foo "Foo" "a""b" U&"d\0061t" SELECT"#),
+            [
+                Value::Identifier("foo".to_string()),
+                Value::Identifier("Foo".to_string()),
+                Value::Identifier("a\"b".to_string()),
+                Value::Identifier("dat".to_string()),
+                Value::Keyword("select".to_string()),
+            ]
+        );
+
+        assert_eq!(
+            decode(r#"U&"d!0061t" UESCAPE '!'"#, &LexerOptions::default()),
+            Some(Value::Identifier("dat".to_string()))
+        );
+
+        let long = "A".repeat(70);
+        assert_eq!(
+            values(&format!("{long} U&\"{long}\"")),
+            [
+                Value::Identifier("a".repeat(63)),
+                Value::Identifier("A".repeat(63)),
+            ]
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(
+            values("42 1_000 0x_1F 0o17 0b101 2147483648 0xFFFF_FFFF 0b1_0000_0000_0000_0000_0000_0000_0000_0000 1.5e3 .5 $3"),
+            [
+                Value::Integer(42),
+                Value::Integer(1000),
+                Value::Integer(31),
+                Value::Integer(15),
+                Value::Integer(5),
+                Value::Numeric("2147483648".to_string()),
+                Value::Numeric("4294967295".to_string()),
+                Value::Numeric("4294967296".to_string()),
+                Value::Numeric("1.5e3".to_string()),
+                Value::Numeric(".5".to_string()),
+                Value::Param(3),
+            ]
+        );
+    }
+
+    #[test]
+    fn bit_strings() {
+        assert_eq!(
+            values("b'1010' X'1f'"),
+            [
+                Value::BitString("1010".to_string()),
+                Value::HexString("1f".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn token_values() {
+        let src = "select U&\"d!0061t\" UESCAPE /* c */ '!', 'a'\n'b', \"A\", 1 from t;";
+        let root = crate::parse(src).unwrap();
+        let token_values = TokenValues::new(src, &LexerOptions::default()).unwrap();
+
+        for token in root
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+        {
+            assert_eq!(
+                token_values.get(token),
+                token_value(token).as_ref(),
+                "{}",
+                token.text()
+            );
+        }
+    }
+
+    #[test]
+    fn not_a_single_token() {
+        let options = LexerOptions::default();
+        assert_eq!(decode("", &options), None);
+        assert_eq!(decode("a b", &options), None);
+        assert_eq!(decode(" a", &options), None);
+        assert_eq!(decode("+", &options), None);
+        assert_eq!(decode("U&'\\0000'", &options), None);
+    }
+}